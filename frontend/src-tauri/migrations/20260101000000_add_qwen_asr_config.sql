@@ -0,0 +1,4 @@
+-- Migration: Add Qwen ASR decoding config to transcript_settings table
+-- Stores GPU device, thread count and temperature as JSON
+
+ALTER TABLE transcript_settings ADD COLUMN qwenAsrConfig TEXT;
@@ -0,0 +1,4 @@
+-- Migration: Add OpenAI-compatible transcription API key to transcript_settings table
+-- The endpoint config stays in the preferences store; only the key moves here
+
+ALTER TABLE transcript_settings ADD COLUMN openaiCompatibleApiKey TEXT;
@@ -24,11 +24,43 @@ fn main() {
     }
 
     // Link C++ standard library
-    #[cfg(target_os = "macos")]
-    println!("cargo:rustc-link-lib=c++");
+    match target_os().as_str() {
+        "macos" => println!("cargo:rustc-link-lib=c++"),
+        "linux" => println!("cargo:rustc-link-lib=stdc++"),
+        _ => {}
+    }
+}
+
+/// OS being compiled for. `cfg!(target_os)` in a build script is the host's,
+/// which is wrong when cross-compiling.
+fn target_os() -> String {
+    std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default()
+}
 
-    #[cfg(target_os = "linux")]
-    println!("cargo:rustc-link-lib=stdc++");
+/// Whether `struct_name` in `header` declares an integer field named `field`,
+/// e.g. `int32_t max_tokens = 1024;`. A bare substring search would also match
+/// comments, other structs and fields like `max_tokens_per_chunk`.
+fn declares_int_field(header: &str, struct_name: &str, field: &str) -> bool {
+    let Some(start) = header.find(&format!("struct {} ", struct_name)) else {
+        return false;
+    };
+    let rest = &header[start..];
+    let body = match (rest.find('{'), rest.find("};")) {
+        (Some(open), Some(close)) if open < close => &rest[open + 1..close],
+        _ => return false,
+    };
+    body.lines()
+        .map(|line| line.split("//").next().unwrap_or_default())
+        .flat_map(|line| line.split(';'))
+        .any(|declaration| {
+            // Drop a default value: `= 1024` or `{1024}`
+            let declaration = declaration.split(['=', '{']).next().unwrap_or_default();
+            let mut words = declaration.split_whitespace().rev();
+            words.next() == Some(field)
+                && words.any(|word| {
+                    matches!(word, "int" | "int32_t" | "int64_t" | "size_t" | "unsigned" | "long")
+                })
+        })
 }
 
 fn build_stub_only() {
@@ -42,6 +74,7 @@ fn build_stub_only() {
 
 fn build_with_vendor(vendor_dir: &std::path::Path) {
     let ggml_dir = vendor_dir.join("ggml");
+    let target_os = target_os();
 
     // --- Step 1: Build GGML via cmake ---
     let mut ggml_cmake = cmake::Config::new(&ggml_dir);
@@ -60,8 +93,8 @@ fn build_with_vendor(vendor_dir: &std::path::Path) {
         .profile("Release");
 
     // macOS Metal support (future)
-    #[cfg(target_os = "macos")]
-    if cfg!(feature = "metal") {
+    let metal_enabled = target_os == "macos" && cfg!(feature = "metal");
+    if metal_enabled {
         ggml_cmake.define("GGML_METAL", "ON");
     }
 
@@ -111,6 +144,68 @@ fn build_with_vendor(vendor_dir: &std::path::Path) {
         // Our C wrapper
         .file("qwen3_asr_c.cpp");
 
+    // Reported by qwen3_asr_build_info. GGML is always configured with
+    // GGML_CUDA=OFF, so QWEN3_ASR_HAS_CUDA stays undefined even with the cuda feature.
+    if metal_enabled {
+        build.define("QWEN3_ASR_HAS_METAL", None);
+    }
+
+    // Accelerate (vDSP) computes the mel FFT on macOS
+    if target_os == "macos" {
+        build.define("QWEN3_ASR_HAS_ACCELERATE", None);
+    }
+
+    // Runtime mel backend switching needs the vendor to build both FFT paths
+    if cfg!(feature = "portable-mel") {
+        let mel_src = std::fs::read_to_string(vendor_src.join("mel_spectrogram.cpp")).unwrap_or_default();
+        if mel_src.contains("set_mel_fft_backend") {
+            build.define("QWEN3_ASR_MEL_RUNTIME_SWITCH", None);
+        } else {
+            println!("cargo:warning=portable-mel requested but vendor mel_spectrogram.cpp has no set_mel_fft_backend; using the compile-time FFT only");
+        }
+    }
+
+    // Cross-chunk decoder prompts need a vendor decoder that takes a text prompt
+    let vendor_header = std::fs::read_to_string(vendor_src.join("qwen3_asr.h")).unwrap_or_default();
+    if vendor_header.contains("std::string prompt") {
+        build.define("QWEN3_ASR_HAS_DECODER_PROMPT", None);
+    } else {
+        println!("cargo:warning=vendor transcribe_params has no prompt field; decoder_prompt will be ignored");
+    }
+    if vendor_header.contains("std::string language") {
+        build.define("QWEN3_ASR_HAS_LANGUAGE", None);
+    } else {
+        println!("cargo:warning=vendor transcribe_params has no language field; language will be auto-detected");
+    }
+    if declares_int_field(&vendor_header, "transcribe_params", "max_tokens") {
+        build.define("QWEN3_ASR_HAS_MAX_TOKENS", None);
+    } else {
+        println!("cargo:warning=vendor transcribe_params has no max_tokens field; max_new_tokens will be ignored");
+    }
+    if vendor_header.contains("abort_callback") {
+        build.define("QWEN3_ASR_HAS_ABORT_CALLBACK", None);
+    } else {
+        println!("cargo:warning=vendor transcribe_params has no abort_callback; a running decode can't be cancelled");
+    }
+    if vendor_header.contains("token_callback") {
+        build.define("QWEN3_ASR_HAS_TOKEN_CALLBACK", None);
+    } else {
+        println!("cargo:warning=vendor transcribe_params has no token_callback; streaming gets the text in one callback");
+    }
+    if vendor_header.contains("token_logprobs") {
+        build.define("QWEN3_ASR_HAS_TOKEN_LOGPROBS", None);
+        if vendor_header.contains("no_speech_prob") {
+            build.define("QWEN3_ASR_HAS_NO_SPEECH_PROB", None);
+        }
+    } else {
+        println!("cargo:warning=vendor transcribe_result has no token_logprobs; Qwen ASR confidence will read as 1.0");
+    }
+    if vendor_header.contains("word_timestamps") {
+        build.define("QWEN3_ASR_HAS_WORD_TIMESTAMPS", None);
+    } else {
+        println!("cargo:warning=vendor transcribe_result has no word_timestamps; Qwen ASR results will have no word timings");
+    }
+
     // Optimization for release builds
     let profile = std::env::var("PROFILE").unwrap_or_default();
     if profile == "release" {
@@ -120,8 +215,7 @@ fn build_with_vendor(vendor_dir: &std::path::Path) {
     build.compile("qwen3_asr_c");
 
     // --- Step 3: Platform-specific framework linking ---
-    #[cfg(target_os = "macos")]
-    {
+    if target_os == "macos" {
         // Accelerate is needed for mel spectrogram (vDSP FFT)
         println!("cargo:rustc-link-lib=framework=Accelerate");
 
@@ -132,19 +226,8 @@ fn build_with_vendor(vendor_dir: &std::path::Path) {
         }
     }
 
-    #[cfg(target_os = "linux")]
-    {
-        if cfg!(feature = "cuda") {
-            println!("cargo:rustc-link-lib=cuda");
-            println!("cargo:rustc-link-lib=cublas");
-        }
-    }
-
-    #[cfg(target_os = "windows")]
-    {
-        if cfg!(feature = "cuda") {
-            println!("cargo:rustc-link-lib=cuda");
-            println!("cargo:rustc-link-lib=cublas");
-        }
+    if (target_os == "linux" || target_os == "windows") && cfg!(feature = "cuda") {
+        println!("cargo:rustc-link-lib=cuda");
+        println!("cargo:rustc-link-lib=cublas");
     }
 }
@@ -5,13 +5,17 @@
 
 #include "qwen3_asr_c.h"
 
+#include <atomic>
 #include <cstring>
 #include <cstdlib>
 #include <string>
 #include <chrono>
+#include <sstream>
+#include <vector>
 
 #ifdef QWEN3_ASR_HAS_VENDOR
 #include "qwen3_asr.h"
+#include "ggml-backend.h"
 #else
 // Stub implementation for compilation without vendor library
 struct Qwen3ASRModel {
@@ -27,8 +31,162 @@ struct qwen3_asr_context {
     Qwen3ASRModel* model;
 #endif
     bool model_loaded;
+    std::atomic<bool> cancel_requested{false};
+    std::atomic<bool> discard_requested{false};
+    // Word timings of the last transcription, for qwen3_asr_get_word_timestamps
+    std::vector<std::pair<std::string, std::pair<int32_t, int32_t>>> last_words;
 };
 
+#ifndef QWEN3_ASR_HAS_VENDOR
+// Stub: spread the placeholder words evenly over the audio
+static void set_stub_word_timings(qwen3_asr_context* ctx, const std::string& text, int32_t n_samples) {
+    std::vector<std::string> words;
+    std::istringstream stream(text);
+    for (std::string word; stream >> word;) words.push_back(word);
+    if (words.empty()) return;
+
+    int64_t total_ms = (int64_t)n_samples * 1000 / 16000;
+    for (size_t i = 0; i < words.size(); i++) {
+        int32_t start = (int32_t)(total_ms * (int64_t)i / (int64_t)words.size());
+        int32_t end = (int32_t)(total_ms * (int64_t)(i + 1) / (int64_t)words.size());
+        ctx->last_words.push_back({words[i], {start, end}});
+    }
+}
+
+// Stub: a decoder prompt is echoed back as the transcript, one word per token,
+// so tests can feed the Rust side any decoder output
+static std::vector<std::string> stub_tokens(const struct qwen3_asr_params& params) {
+    std::vector<std::string> tokens;
+    if (params.decoder_prompt && *params.decoder_prompt) {
+        std::istringstream stream(params.decoder_prompt);
+        for (std::string word; stream >> word;) tokens.push_back(tokens.empty() ? word : " " + word);
+    } else {
+        tokens = {"[Qwen3", "-ASR", " streaming", " stub]"};
+    }
+    return tokens;
+}
+#endif
+
+static std::atomic<int32_t> g_mel_backend{QWEN3_ASR_MEL_BACKEND_AUTO};
+
+static int32_t resolve_mel_backend(int32_t backend) {
+    if (backend != QWEN3_ASR_MEL_BACKEND_AUTO) return backend;
+#ifdef QWEN3_ASR_HAS_ACCELERATE
+    return QWEN3_ASR_MEL_BACKEND_ACCELERATE;
+#else
+    return QWEN3_ASR_MEL_BACKEND_PORTABLE;
+#endif
+}
+
+// Point the vendor mel code at the selected FFT before each transcription
+static void apply_mel_backend() {
+#ifdef QWEN3_ASR_MEL_RUNTIME_SWITCH
+    qwen3_asr::set_mel_fft_backend(
+        resolve_mel_backend(g_mel_backend.load()) == QWEN3_ASR_MEL_BACKEND_ACCELERATE
+            ? qwen3_asr::MelFftBackend::Accelerate
+            : qwen3_asr::MelFftBackend::Portable);
+#endif
+}
+
+#ifdef QWEN3_ASR_HAS_VENDOR
+// Seed the decoder with earlier text when the vendor decoder accepts a prompt
+static void apply_decoder_prompt(qwen3_asr::transcribe_params& tp, const struct qwen3_asr_params& params) {
+#ifdef QWEN3_ASR_HAS_DECODER_PROMPT
+    tp.prompt = params.decoder_prompt ? params.decoder_prompt : "";
+#else
+    (void)tp;
+    (void)params;
+#endif
+}
+
+// Force the output language when the vendor decoder supports it; otherwise it auto-detects.
+// A null language leaves the vendor's default in place.
+static void apply_language(qwen3_asr::transcribe_params& tp, const struct qwen3_asr_params& params) {
+#ifdef QWEN3_ASR_HAS_LANGUAGE
+    if (params.language) tp.language = params.language;
+#else
+    (void)tp;
+    (void)params;
+#endif
+}
+
+// Cap decoding so a looping decoder can't run on for hundreds of tokens
+static void apply_max_new_tokens(qwen3_asr::transcribe_params& tp, const struct qwen3_asr_params& params) {
+#ifdef QWEN3_ASR_HAS_MAX_TOKENS
+    if (params.max_new_tokens > 0) tp.max_tokens = params.max_new_tokens;
+#else
+    (void)tp;
+    (void)params;
+#endif
+}
+
+// Let the vendor decode loop poll for qwen3_asr_cancel / qwen3_asr_request_discard
+// between tokens, so either one ends a running decode early
+static void apply_abort_check(qwen3_asr::transcribe_params& tp, qwen3_asr_context* ctx) {
+#ifdef QWEN3_ASR_HAS_ABORT_CALLBACK
+    tp.abort_callback = [ctx]() {
+        return ctx->cancel_requested.load() || ctx->discard_requested.load();
+    };
+#else
+    (void)tp;
+    (void)ctx;
+#endif
+}
+
+// Forward each decoded piece of text to the streaming callback when the vendor
+// decoder reports them; a false return stops the decode. Returns whether it did.
+static bool apply_token_callback(qwen3_asr::transcribe_params& tp, qwen3_asr_context* ctx,
+                                 qwen3_asr_token_callback callback, void* user_data) {
+#ifdef QWEN3_ASR_HAS_TOKEN_CALLBACK
+    if (!callback) return false;
+    tp.token_callback = [ctx, callback, user_data](const std::string& piece) {
+        if (ctx->cancel_requested.load() || ctx->discard_requested.load()) return false;
+        if (callback(piece.c_str(), user_data)) return true;
+        ctx->cancel_requested.store(true);
+        return false;
+    };
+    return true;
+#else
+    (void)tp;
+    (void)ctx;
+    (void)callback;
+    (void)user_data;
+    return false;
+#endif
+}
+
+// Copy the decoder's token scores into the result when the vendor reports them
+template <typename VendorResult>
+static void apply_scores(struct qwen3_asr_result& result, const VendorResult& res) {
+#ifdef QWEN3_ASR_HAS_TOKEN_LOGPROBS
+    if (!res.token_logprobs.empty()) {
+        float sum = 0.0f;
+        for (float logprob : res.token_logprobs) sum += logprob;
+        result.avg_logprob = sum / (float)res.token_logprobs.size();
+    }
+#ifdef QWEN3_ASR_HAS_NO_SPEECH_PROB
+    result.no_speech_prob = res.no_speech_prob;
+#endif
+#else
+    (void)result;
+    (void)res;
+#endif
+}
+
+// Keep the decoder's word timings for qwen3_asr_get_word_timestamps
+template <typename VendorResult>
+static void apply_word_timings(qwen3_asr_context* ctx, const VendorResult& res) {
+#ifdef QWEN3_ASR_HAS_WORD_TIMESTAMPS
+    for (const auto& w : res.word_timestamps) {
+        ctx->last_words.push_back({w.word, {(int32_t)w.start_ms, (int32_t)w.end_ms}});
+    }
+#else
+    (void)ctx;
+    (void)res;
+#endif
+}
+#endif
+
 static char* strdup_safe(const std::string& s) {
     char* out = (char*)malloc(s.size() + 1);
     if (out) {
@@ -45,6 +203,9 @@ struct qwen3_asr_params qwen3_asr_default_params(void) {
     params.use_gpu = true;
     params.gpu_device = 0;
     params.temperature = 0.0f;  // greedy decoding
+    params.decoder_prompt = nullptr;
+    params.language = nullptr;  // auto-detect
+    params.max_new_tokens = 0;  // vendor default
     return params;
 }
 
@@ -81,7 +242,7 @@ bool qwen3_asr_load_model(qwen3_asr_context* ctx, const char* model_path) {
     FILE* f = fopen(model_path, "rb");
     if (!f) return false;
 
-    // Check GGUF magic header: 0x46475547 ("GGUF" in little-endian)
+    // Check GGUF magic header: 0x46554747 ("GGUF" read as a little-endian u32)
     uint32_t magic = 0;
     if (fread(&magic, sizeof(magic), 1, f) != 1) {
         fclose(f);
@@ -89,7 +250,7 @@ bool qwen3_asr_load_model(qwen3_asr_context* ctx, const char* model_path) {
     }
     fclose(f);
 
-    if (magic != 0x46475547) {
+    if (magic != 0x46554747) {
         return false;  // Not a valid GGUF file
     }
 
@@ -113,11 +274,16 @@ struct qwen3_asr_result qwen3_asr_transcribe(
     result.n_tokens = 0;
     result.duration_ms = 0.0f;
     result.success = false;
+    result.avg_logprob = 0.0f;
+    result.no_speech_prob = 0.0f;
 
     if (!ctx || !ctx->model_loaded || !samples || n_samples <= 0) {
         return result;
     }
 
+    ctx->cancel_requested.store(false);
+    ctx->discard_requested.store(false);
+    ctx->last_words.clear();
     auto start = std::chrono::high_resolution_clock::now();
 
 #ifdef QWEN3_ASR_HAS_VENDOR
@@ -125,20 +291,30 @@ struct qwen3_asr_result qwen3_asr_transcribe(
     tp.n_threads = params.n_threads > 0 ? params.n_threads : 4;
     tp.print_progress = false;
     tp.print_timing = false;
+    apply_decoder_prompt(tp, params);
+    apply_language(tp, params);
+    apply_max_new_tokens(tp, params);
+    apply_abort_check(tp, ctx);
 
+    apply_mel_backend();
     auto res = ctx->model->transcribe(samples, n_samples, tp);
     result.text = strdup_safe(res.text);
     result.n_tokens = (int32_t)res.tokens.size();
     result.success = res.success;
+    apply_scores(result, res);
+    apply_word_timings(ctx, res);
 #else
-    // Stub: return placeholder
+    // Stub: return placeholder, or the echoed decoder prompt
     float duration_sec = (float)n_samples / 16000.0f;
     std::string stub_text = "[Qwen3-ASR stub: " + std::to_string(n_samples) +
                            " samples, " + std::to_string(duration_sec) + "s audio]";
+    if (params.decoder_prompt && *params.decoder_prompt) stub_text = params.decoder_prompt;
     result.text = strdup_safe(stub_text);
     result.n_tokens = 1;
     result.success = true;
+    set_stub_word_timings(ctx, stub_text, n_samples);
 #endif
+    if (ctx->discard_requested.load()) result.success = false;
 
     auto end = std::chrono::high_resolution_clock::now();
     result.duration_ms = std::chrono::duration<float, std::milli>(end - start).count();
@@ -159,46 +335,59 @@ struct qwen3_asr_result qwen3_asr_transcribe_streaming(
     result.n_tokens = 0;
     result.duration_ms = 0.0f;
     result.success = false;
+    result.avg_logprob = 0.0f;
+    result.no_speech_prob = 0.0f;
 
     if (!ctx || !ctx->model_loaded || !samples || n_samples <= 0) {
         return result;
     }
 
+    ctx->cancel_requested.store(false);
+    ctx->discard_requested.store(false);
+    ctx->last_words.clear();
     auto start = std::chrono::high_resolution_clock::now();
 
 #ifdef QWEN3_ASR_HAS_VENDOR
-    // The vendor's progress_callback gives (tokens_generated, max_tokens), not token text.
-    // Use batch mode and call the callback once with the full result.
     qwen3_asr::transcribe_params tp;
     tp.n_threads = params.n_threads > 0 ? params.n_threads : 4;
     tp.print_progress = false;
     tp.print_timing = false;
+    apply_decoder_prompt(tp, params);
+    apply_language(tp, params);
+    apply_max_new_tokens(tp, params);
+    apply_abort_check(tp, ctx);
+    bool streamed = apply_token_callback(tp, ctx, callback, user_data);
 
+    apply_mel_backend();
     auto res = ctx->model->transcribe(samples, n_samples, tp);
-    if (res.success && callback) {
+    // Without per-token text the callback gets the whole result once
+    if (!streamed && res.success && callback && !ctx->cancel_requested.load() && !ctx->discard_requested.load()) {
         callback(res.text.c_str(), user_data);
     }
     result.text = strdup_safe(res.text);
     result.n_tokens = (int32_t)res.tokens.size();
     result.success = res.success;
+    apply_scores(result, res);
+    apply_word_timings(ctx, res);
 #else
     // Stub: emit a few tokens via callback, then return full text
     std::string full_text;
-    const char* stub_tokens[] = {"[Qwen3", "-ASR", " streaming", " stub]"};
-    int n_stub_tokens = 4;
-
-    for (int i = 0; i < n_stub_tokens; i++) {
+    for (const std::string& token : stub_tokens(params)) {
+        if (ctx->cancel_requested.load() || ctx->discard_requested.load()) break;
+        if (params.max_new_tokens > 0 && result.n_tokens >= params.max_new_tokens) break;
         if (callback) {
-            bool should_continue = callback(stub_tokens[i], user_data);
+            bool should_continue = callback(token.c_str(), user_data);
             if (!should_continue) break;
         }
-        full_text += stub_tokens[i];
+        full_text += token;
         result.n_tokens++;
     }
 
     result.text = strdup_safe(full_text);
     result.success = true;
+    set_stub_word_timings(ctx, full_text, n_samples);
 #endif
+    if (ctx->discard_requested.load()) result.success = false;
 
     auto end = std::chrono::high_resolution_clock::now();
     result.duration_ms = std::chrono::duration<float, std::milli>(end - start).count();
@@ -206,11 +395,143 @@ struct qwen3_asr_result qwen3_asr_transcribe_streaming(
     return result;
 }
 
+int32_t qwen3_asr_gpu_device_count(void) {
+#ifdef QWEN3_ASR_HAS_VENDOR
+    int32_t count = 0;
+    for (size_t i = 0; i < ggml_backend_dev_count(); i++) {
+        if (ggml_backend_dev_type(ggml_backend_dev_get(i)) == GGML_BACKEND_DEVICE_TYPE_GPU) {
+            count++;
+        }
+    }
+    return count;
+#else
+    // Stub: no GPU backends
+    return 0;
+#endif
+}
+
+bool qwen3_asr_mel_backend_available(int32_t backend) {
+    switch (backend) {
+    case QWEN3_ASR_MEL_BACKEND_AUTO:
+        return true;
+    case QWEN3_ASR_MEL_BACKEND_ACCELERATE:
+#ifdef QWEN3_ASR_HAS_ACCELERATE
+        return true;
+#else
+        return false;
+#endif
+    case QWEN3_ASR_MEL_BACKEND_PORTABLE:
+        // Built alongside Accelerate only when the runtime switch is compiled in
+#if !defined(QWEN3_ASR_HAS_ACCELERATE) || defined(QWEN3_ASR_MEL_RUNTIME_SWITCH)
+        return true;
+#else
+        return false;
+#endif
+    default:
+        return false;
+    }
+}
+
+bool qwen3_asr_set_mel_backend(int32_t backend) {
+    if (!qwen3_asr_mel_backend_available(backend)) return false;
+    g_mel_backend.store(backend);
+    return true;
+}
+
+int32_t qwen3_asr_get_mel_backend(void) {
+    return resolve_mel_backend(g_mel_backend.load());
+}
+
+bool qwen3_asr_warmup(qwen3_asr_context* ctx, struct qwen3_asr_params params) {
+    if (!ctx || !ctx->model_loaded) return false;
+
+#ifdef QWEN3_ASR_HAS_VENDOR
+    // One second of silence and a single token is enough to allocate the
+    // encoder and decoder buffers for the configured backend
+    std::vector<float> silence(16000, 0.0f);
+    params.decoder_prompt = nullptr;
+    params.language = nullptr;
+    params.max_new_tokens = 1;
+    struct qwen3_asr_result res = qwen3_asr_transcribe(ctx, silence.data(), (int32_t)silence.size(), params);
+    qwen3_asr_free_text(res.text);
+    ctx->last_words.clear();
+    return res.success;
+#else
+    (void)params;
+    return true;
+#endif
+}
+
 bool qwen3_asr_is_model_loaded(const qwen3_asr_context* ctx) {
     if (!ctx) return false;
     return ctx->model_loaded;
 }
 
+struct qwen3_asr_build_info qwen3_asr_build_info(void) {
+    struct qwen3_asr_build_info info;
+#ifdef QWEN3_ASR_HAS_VENDOR
+    info.has_vendor = true;
+#else
+    info.has_vendor = false;
+#endif
+#ifdef QWEN3_ASR_HAS_METAL
+    info.metal = true;
+#else
+    info.metal = false;
+#endif
+#ifdef QWEN3_ASR_HAS_CUDA
+    info.cuda = true;
+#else
+    info.cuda = false;
+#endif
+#ifdef QWEN3_ASR_HAS_ACCELERATE
+    info.accelerate = true;
+#else
+    info.accelerate = false;
+#endif
+    info.fft_backend = resolve_mel_backend(QWEN3_ASR_MEL_BACKEND_AUTO);
+    // The stub token loop always checks for a cancel between tokens
+#if !defined(QWEN3_ASR_HAS_VENDOR) || defined(QWEN3_ASR_HAS_ABORT_CALLBACK)
+    info.interruptible_decode = true;
+#else
+    info.interruptible_decode = false;
+#endif
+    return info;
+}
+
+struct qwen3_asr_word* qwen3_asr_get_word_timestamps(const qwen3_asr_context* ctx, int32_t* n_words) {
+    if (n_words) *n_words = 0;
+    if (!ctx || !n_words || ctx->last_words.empty()) return nullptr;
+
+    size_t count = ctx->last_words.size();
+    auto* words = (struct qwen3_asr_word*)calloc(count, sizeof(struct qwen3_asr_word));
+    if (!words) return nullptr;
+
+    for (size_t i = 0; i < count; i++) {
+        words[i].start_ms = ctx->last_words[i].second.first;
+        words[i].end_ms = ctx->last_words[i].second.second;
+        words[i].word = strdup_safe(ctx->last_words[i].first);
+    }
+    *n_words = (int32_t)count;
+    return words;
+}
+
+void qwen3_asr_free_word_timestamps(struct qwen3_asr_word* words, int32_t n_words) {
+    if (!words) return;
+    for (int32_t i = 0; i < n_words; i++) {
+        free(words[i].word);
+    }
+    free(words);
+}
+
+void qwen3_asr_cancel(qwen3_asr_context* ctx) {
+    if (ctx) ctx->cancel_requested.store(true);
+}
+
+void qwen3_asr_request_discard(qwen3_asr_context* ctx) {
+    if (ctx) ctx->discard_requested.store(true);
+}
+
 void qwen3_asr_free(qwen3_asr_context* ctx) {
     if (!ctx) return;
 
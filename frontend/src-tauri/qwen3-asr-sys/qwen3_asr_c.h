@@ -18,6 +18,13 @@ struct qwen3_asr_params {
     bool    use_gpu;       // Enable GPU acceleration
     int32_t gpu_device;    // GPU device index
     float   temperature;   // Sampling temperature (0.0 = greedy)
+    const char* decoder_prompt; // Text fed to the decoder before audio tokens (NULL = none).
+                                // Borrowed; must stay valid for the duration of the call.
+    const char* language;       // Language to decode in, e.g. "English" (NULL = auto-detect).
+                                // A forced language is not echoed as a "language X" tag.
+                                // Borrowed; must stay valid for the duration of the call.
+    int32_t max_new_tokens;     // Most tokens to decode (0 = vendor default). Streaming stops
+                                // calling back at the cap and returns the text so far.
 };
 
 // Transcription result
@@ -26,6 +33,15 @@ struct qwen3_asr_result {
     int32_t n_tokens;      // Number of tokens generated
     float   duration_ms;   // Processing time in milliseconds
     bool    success;       // Whether transcription succeeded
+    float   avg_logprob;   // Mean token log-probability (0.0 = certain / not computed)
+    float   no_speech_prob; // Probability the audio held no speech (0.0 = not computed)
+};
+
+// One word of the last transcription, timed relative to the start of its audio
+struct qwen3_asr_word {
+    int32_t start_ms;      // Word start in milliseconds
+    int32_t end_ms;        // Word end in milliseconds
+    char*   word;          // Word text (freed by qwen3_asr_free_word_timestamps)
 };
 
 // Streaming token callback
@@ -57,7 +73,8 @@ struct qwen3_asr_result qwen3_asr_transcribe(
 );
 
 // Transcribe audio samples with streaming token output
-// callback is invoked for each decoded token
+// callback is invoked for each decoded token. Vendor decoders that don't report
+// token text invoke it once with the full text after decoding.
 struct qwen3_asr_result qwen3_asr_transcribe_streaming(
     qwen3_asr_context* ctx,
     const float* samples,
@@ -67,6 +84,67 @@ struct qwen3_asr_result qwen3_asr_transcribe_streaming(
     void* user_data
 );
 
+// Run a short decode on silence so the first real transcription doesn't pay for
+// buffer allocation and graph building. Returns true on success; the stub build
+// has nothing to warm and returns true as long as a model is loaded.
+bool qwen3_asr_warmup(qwen3_asr_context* ctx, struct qwen3_asr_params params);
+
+// Features compiled into this binary, for telling a CPU-only or stub build
+// apart from a misbehaving GPU one
+struct qwen3_asr_build_info {
+    bool    has_vendor;    // Real qwen3-asr.cpp (false = stub returning placeholder text)
+    bool    metal;         // GGML Metal backend
+    bool    cuda;          // GGML CUDA backend
+    bool    accelerate;    // Apple Accelerate (vDSP) mel FFT
+    int32_t fft_backend;   // Default mel FFT backend (QWEN3_ASR_MEL_BACKEND_*, never AUTO)
+    bool    interruptible_decode; // Cancel and discard stop a running decode between tokens
+};
+
+struct qwen3_asr_build_info qwen3_asr_build_info(void);
+
+// Word timings of the last transcription on ctx, or NULL with *n_words = 0 when
+// there are none (the decoder doesn't report them, or nothing was transcribed).
+// Free the array with qwen3_asr_free_word_timestamps.
+struct qwen3_asr_word* qwen3_asr_get_word_timestamps(const qwen3_asr_context* ctx, int32_t* n_words);
+
+// Free an array returned by qwen3_asr_get_word_timestamps
+void qwen3_asr_free_word_timestamps(struct qwen3_asr_word* words, int32_t n_words);
+
+// Ask an in-progress transcription on ctx to stop as soon as possible.
+// Safe to call from another thread. The flag is cleared when the next
+// transcription starts. The decode loop checks it between tokens when
+// qwen3_asr_build_info reports interruptible_decode; otherwise the vendor
+// decoder runs to completion and only the post-decode callback is skipped.
+void qwen3_asr_cancel(qwen3_asr_context* ctx);
+
+// Discard the in-progress transcription on ctx: unlike qwen3_asr_cancel, the call
+// returns with success = false and its text is not a result. The decode stops at
+// the next token when qwen3_asr_build_info reports interruptible_decode; otherwise
+// the call still returns only once its decode is done. Safe to call from another
+// thread; cleared when the next transcription starts.
+void qwen3_asr_request_discard(qwen3_asr_context* ctx);
+
+// Number of GPU devices the backend can use (0 when built CPU-only)
+int32_t qwen3_asr_gpu_device_count(void);
+
+// Mel spectrogram FFT backends
+// AUTO picks the fastest compiled-in backend (Accelerate on macOS).
+// PORTABLE is the plain C++ FFT: several times slower than vDSP for the mel step,
+// so only select it to check whether a transcription discrepancy is FFT-related.
+#define QWEN3_ASR_MEL_BACKEND_AUTO       0
+#define QWEN3_ASR_MEL_BACKEND_ACCELERATE 1
+#define QWEN3_ASR_MEL_BACKEND_PORTABLE   2
+
+// Select the mel backend for subsequent transcriptions
+// Returns false if the backend is unknown or not compiled in
+bool qwen3_asr_set_mel_backend(int32_t backend);
+
+// Backend used for the next transcription (never AUTO)
+int32_t qwen3_asr_get_mel_backend(void);
+
+// Whether a backend is compiled in and selectable
+bool qwen3_asr_mel_backend_available(int32_t backend);
+
 // Check if a model is loaded
 bool qwen3_asr_is_model_loaded(const qwen3_asr_context* ctx);
 
@@ -31,6 +31,17 @@ pub struct qwen3_asr_params {
     pub gpu_device: i32,
     /// Sampling temperature (0.0 = greedy decoding)
     pub temperature: c_float,
+    /// Text fed to the decoder ahead of the audio (null = none). Borrowed for
+    /// the duration of the call; ignored when the vendor decoder has no prompt.
+    pub decoder_prompt: *const c_char,
+    /// Language to decode in, as the model names it (e.g. "English"; null =
+    /// auto-detect). A forced language suppresses the "language X" tag at the
+    /// start of the output. Borrowed for the duration of the call; ignored when
+    /// the vendor decoder can't force a language.
+    pub language: *const c_char,
+    /// Most tokens to decode (0 = no cap beyond the vendor default). Streaming
+    /// stops calling back once the cap is hit and returns the text so far.
+    pub max_new_tokens: i32,
 }
 
 /// Transcription result.
@@ -44,8 +55,51 @@ pub struct qwen3_asr_result {
     pub duration_ms: c_float,
     /// Whether transcription succeeded
     pub success: bool,
+    /// Mean log-probability of the decoded tokens (0.0 = certain, and the
+    /// value reported by builds without token scores)
+    pub avg_logprob: c_float,
+    /// Probability that the audio held no speech (0.0 when not computed)
+    pub no_speech_prob: c_float,
 }
 
+/// One word of the last transcription, timed relative to the start of its audio.
+#[repr(C)]
+#[derive(Debug)]
+pub struct qwen3_asr_word {
+    /// Word start in milliseconds
+    pub start_ms: i32,
+    /// Word end in milliseconds
+    pub end_ms: i32,
+    /// Word text, freed together with the array by `qwen3_asr_free_word_timestamps`
+    pub word: *mut c_char,
+}
+
+/// Features compiled into this build of the library.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct qwen3_asr_build_info {
+    /// Real qwen3-asr.cpp; `false` means the stub that returns placeholder text
+    pub has_vendor: bool,
+    /// GGML Metal backend
+    pub metal: bool,
+    /// GGML CUDA backend
+    pub cuda: bool,
+    /// Apple Accelerate (vDSP) mel FFT
+    pub accelerate: bool,
+    /// Default mel FFT backend (`QWEN3_ASR_MEL_BACKEND_*`, never AUTO)
+    pub fft_backend: i32,
+    /// Cancel and discard stop a running decode between tokens
+    pub interruptible_decode: bool,
+}
+
+/// Mel backend: fastest compiled-in backend.
+pub const QWEN3_ASR_MEL_BACKEND_AUTO: i32 = 0;
+/// Mel backend: Apple Accelerate (vDSP) FFT, macOS only.
+pub const QWEN3_ASR_MEL_BACKEND_ACCELERATE: i32 = 1;
+/// Mel backend: portable C++ FFT. Several times slower than vDSP for the mel
+/// step; intended for isolating FFT-related accuracy differences.
+pub const QWEN3_ASR_MEL_BACKEND_PORTABLE: i32 = 2;
+
 /// Streaming token callback type.
 ///
 /// Called for each decoded token during streaming transcription.
@@ -82,7 +136,8 @@ extern "C" {
 
     /// Transcribe audio samples with streaming token output.
     ///
-    /// The callback is invoked for each decoded token.
+    /// The callback is invoked for each decoded token. Vendor decoders that
+    /// don't report token text invoke it once with the full text instead.
     pub fn qwen3_asr_transcribe_streaming(
         ctx: *mut qwen3_asr_context,
         samples: *const c_float,
@@ -92,6 +147,52 @@ extern "C" {
         user_data: *mut c_void,
     ) -> qwen3_asr_result;
 
+    /// Run a short dummy decode so the first real transcription doesn't pay for
+    /// allocation and graph building. A no-op returning `true` in the stub build;
+    /// `false` when no model is loaded or the decode failed.
+    pub fn qwen3_asr_warmup(ctx: *mut qwen3_asr_context, params: qwen3_asr_params) -> bool;
+
+    /// Features compiled into this build.
+    pub fn qwen3_asr_build_info() -> qwen3_asr_build_info;
+
+    /// Word timings of the last transcription on `ctx`. Returns null and sets
+    /// `n_words` to 0 when there are none. Free a non-null array with
+    /// `qwen3_asr_free_word_timestamps`.
+    pub fn qwen3_asr_get_word_timestamps(
+        ctx: *const qwen3_asr_context,
+        n_words: *mut c_int,
+    ) -> *mut qwen3_asr_word;
+
+    /// Free an array returned by `qwen3_asr_get_word_timestamps`.
+    pub fn qwen3_asr_free_word_timestamps(words: *mut qwen3_asr_word, n_words: c_int);
+
+    /// Ask an in-progress transcription on `ctx` to stop. Safe to call from
+    /// another thread while `ctx` is alive. The decode loop checks it between
+    /// tokens when `qwen3_asr_build_info` reports `interruptible_decode`;
+    /// otherwise the vendor decoder runs to completion and only skips the
+    /// token callback afterwards.
+    pub fn qwen3_asr_cancel(ctx: *mut qwen3_asr_context);
+
+    /// Discard the in-progress transcription on `ctx`: the call returns with
+    /// `success = false`. The decode stops at the next token on builds with
+    /// `interruptible_decode`; otherwise it still runs to the end first. Safe
+    /// to call from another thread while `ctx` is alive; cleared when the next
+    /// transcription starts.
+    pub fn qwen3_asr_request_discard(ctx: *mut qwen3_asr_context);
+
+    /// Number of GPU devices available to the backend (0 for CPU-only builds).
+    pub fn qwen3_asr_gpu_device_count() -> c_int;
+
+    /// Select the mel FFT backend for later transcriptions. Returns `false` if
+    /// the backend is unknown or not compiled in.
+    pub fn qwen3_asr_set_mel_backend(backend: c_int) -> bool;
+
+    /// Mel backend used for the next transcription (never AUTO).
+    pub fn qwen3_asr_get_mel_backend() -> c_int;
+
+    /// Whether a mel backend is compiled in.
+    pub fn qwen3_asr_mel_backend_available(backend: c_int) -> bool;
+
     /// Check if a model is currently loaded.
     pub fn qwen3_asr_is_model_loaded(ctx: *const qwen3_asr_context) -> bool;
 
@@ -114,6 +215,162 @@ mod tests {
             assert!(params.use_gpu);
             assert_eq!(params.gpu_device, 0);
             assert_eq!(params.temperature, 0.0);
+            assert!(params.decoder_prompt.is_null());
+            assert!(params.language.is_null());
+            assert_eq!(params.max_new_tokens, 0);
+        }
+    }
+
+    /// Context with a model loaded; the stub accepts any file with the GGUF magic
+    unsafe fn stub_context(name: &str) -> *mut qwen3_asr_context {
+        let path = std::env::temp_dir().join(format!("qwen3-asr-sys-{}-{}.gguf", name, std::process::id()));
+        std::fs::write(&path, b"GGUF\x03\x00\x00\x00").unwrap();
+        let c_path = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+        let ctx = qwen3_asr_init();
+        assert!(qwen3_asr_load_model(ctx, c_path.as_ptr()));
+        let _ = std::fs::remove_file(path);
+        ctx
+    }
+
+    #[test]
+    fn test_streaming_stops_at_max_new_tokens() {
+        unsafe extern "C" fn count_token(_token: *const c_char, user_data: *mut c_void) -> bool {
+            *(user_data as *mut i32) += 1;
+            true
+        }
+
+        unsafe {
+            let ctx = stub_context("max-tokens");
+
+            let silence = vec![0.0f32; 16000 * 120];
+            let mut params = qwen3_asr_default_params();
+            params.max_new_tokens = 2;
+            let mut callbacks = 0i32;
+            let result = qwen3_asr_transcribe_streaming(
+                ctx,
+                silence.as_ptr(),
+                silence.len() as c_int,
+                params,
+                Some(count_token),
+                &mut callbacks as *mut i32 as *mut c_void,
+            );
+
+            assert!(result.success);
+            assert!(result.n_tokens <= 2);
+            assert!(callbacks <= 2);
+            qwen3_asr_free_text(result.text);
+            qwen3_asr_free(ctx);
+        }
+    }
+
+    #[test]
+    fn test_word_timestamps_cover_the_last_transcription() {
+        unsafe {
+            let ctx = stub_context("words");
+            let mut n_words: c_int = -1;
+            assert!(qwen3_asr_get_word_timestamps(ctx, &mut n_words).is_null());
+            assert_eq!(n_words, 0);
+
+            let audio = vec![0.0f32; 16000 * 2];
+            let result = qwen3_asr_transcribe(ctx, audio.as_ptr(), audio.len() as c_int, qwen3_asr_default_params());
+            assert!(result.success);
+            qwen3_asr_free_text(result.text);
+
+            let words = qwen3_asr_get_word_timestamps(ctx, &mut n_words);
+            assert!(!words.is_null());
+            assert!(n_words > 0);
+            let timings = std::slice::from_raw_parts(words, n_words as usize);
+            assert_eq!(timings[0].start_ms, 0);
+            assert_eq!(timings[timings.len() - 1].end_ms, 2000);
+            assert!(timings.windows(2).all(|w| w[0].end_ms <= w[1].start_ms));
+            assert!(timings.iter().all(|w| !w.word.is_null()));
+            qwen3_asr_free_word_timestamps(words, n_words);
+            qwen3_asr_free(ctx);
+        }
+    }
+
+    #[test]
+    fn test_warmup_needs_a_loaded_model() {
+        unsafe {
+            let empty = qwen3_asr_init();
+            assert!(!qwen3_asr_warmup(empty, qwen3_asr_default_params()));
+            qwen3_asr_free(empty);
+
+            let ctx = stub_context("warmup");
+            assert!(qwen3_asr_warmup(ctx, qwen3_asr_default_params()));
+            let mut n_words: c_int = -1;
+            assert!(qwen3_asr_get_word_timestamps(ctx, &mut n_words).is_null());
+            qwen3_asr_free(ctx);
+        }
+    }
+
+    #[test]
+    fn test_gpu_device_count_non_negative() {
+        unsafe {
+            assert!(qwen3_asr_gpu_device_count() >= 0);
+        }
+    }
+
+    #[test]
+    fn test_mel_backend_selection() {
+        unsafe {
+            assert!(qwen3_asr_set_mel_backend(QWEN3_ASR_MEL_BACKEND_AUTO));
+            assert_ne!(qwen3_asr_get_mel_backend(), QWEN3_ASR_MEL_BACKEND_AUTO);
+            assert!(!qwen3_asr_set_mel_backend(99));
+
+            if qwen3_asr_mel_backend_available(QWEN3_ASR_MEL_BACKEND_PORTABLE) {
+                assert!(qwen3_asr_set_mel_backend(QWEN3_ASR_MEL_BACKEND_PORTABLE));
+                assert_eq!(qwen3_asr_get_mel_backend(), QWEN3_ASR_MEL_BACKEND_PORTABLE);
+                qwen3_asr_set_mel_backend(QWEN3_ASR_MEL_BACKEND_AUTO);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cancel_stops_the_decode_between_tokens() {
+        struct Cancel {
+            ctx: *mut qwen3_asr_context,
+            tokens: i32,
+        }
+        unsafe extern "C" fn cancel_after_first(_token: *const c_char, user_data: *mut c_void) -> bool {
+            let cancel = &mut *(user_data as *mut Cancel);
+            cancel.tokens += 1;
+            qwen3_asr_cancel(cancel.ctx);
+            true
+        }
+
+        unsafe {
+            if !qwen3_asr_build_info().interruptible_decode {
+                return;
+            }
+            let ctx = stub_context("cancel");
+            let silence = vec![0.0f32; 16000];
+            let mut cancel = Cancel { ctx, tokens: 0 };
+            let result = qwen3_asr_transcribe_streaming(
+                ctx,
+                silence.as_ptr(),
+                silence.len() as c_int,
+                qwen3_asr_default_params(),
+                Some(cancel_after_first),
+                &mut cancel as *mut Cancel as *mut c_void,
+            );
+
+            assert_eq!(cancel.tokens, 1);
+            assert_eq!(result.n_tokens, 1);
+            qwen3_asr_free_text(result.text);
+            qwen3_asr_free(ctx);
+        }
+    }
+
+    #[test]
+    fn test_build_info_matches_mel_backends() {
+        unsafe {
+            let info = qwen3_asr_build_info();
+            assert_ne!(info.fft_backend, QWEN3_ASR_MEL_BACKEND_AUTO);
+            assert_eq!(
+                info.accelerate,
+                qwen3_asr_mel_backend_available(QWEN3_ASR_MEL_BACKEND_ACCELERATE)
+            );
         }
     }
 
@@ -123,6 +380,8 @@ mod tests {
             let ctx = qwen3_asr_init();
             assert!(!ctx.is_null());
             assert!(!qwen3_asr_is_model_loaded(ctx));
+            qwen3_asr_cancel(ctx);
+            qwen3_asr_request_discard(ctx);
             qwen3_asr_free(ctx);
         }
     }
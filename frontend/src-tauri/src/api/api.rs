@@ -972,12 +972,18 @@ pub async fn api_save_transcript<R: Runtime>(
 
     let pool = state.db_manager.pool();
 
+    // Save under the id the recording was announced with, so events carrying it match
+    let meeting_id = folder_path
+        .as_deref()
+        .and_then(|folder| crate::audio::recording_saver::read_meeting_id(std::path::Path::new(folder)));
+
     // Now, call the repository with the correctly typed data.
     match TranscriptsRepository::save_transcript(
         pool,
         &meeting_title,
         &transcripts_to_save,
         folder_path,
+        meeting_id,
     )
     .await
     {
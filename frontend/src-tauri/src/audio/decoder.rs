@@ -5,6 +5,7 @@
 use anyhow::{anyhow, Result};
 use log::{debug, error, info, warn};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::path::Path;
 use std::process::{Command, Stdio};
@@ -108,6 +109,108 @@ impl DecodedAudio {
     }
 }
 
+/// Side of a stereo recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StereoChannel {
+    Left,
+    Right,
+}
+
+/// A dual-channel meeting recording split into the local mic and the system audio
+#[derive(Debug, Clone)]
+pub struct ChannelRoles {
+    pub mic: Vec<f32>,
+    pub system: Vec<f32>,
+    pub mic_channel: StereoChannel,
+    /// False when the caller's override decided the layout
+    pub detected: bool,
+}
+
+impl DecodedAudio {
+    /// Split a stereo recording into mic and system channels. `mic_channel` overrides
+    /// detection for capture setups the heuristic gets wrong. None unless stereo, or
+    /// when both channels carry the same signal (mono saved as stereo).
+    pub fn channel_roles(&self, mic_channel: Option<StereoChannel>) -> Option<ChannelRoles> {
+        if self.channels != 2 {
+            return None;
+        }
+        let (left, right): (Vec<f32>, Vec<f32>) =
+            self.samples.chunks_exact(2).map(|frame| (frame[0], frame[1])).unzip();
+        if left.iter().zip(&right).all(|(l, r)| (l - r).abs() <= f32::EPSILON) {
+            return None;
+        }
+
+        let detected = mic_channel.is_none();
+        let mic_channel =
+            mic_channel.unwrap_or_else(|| estimate_mic_channel(&left, &right, self.sample_rate));
+        if detected {
+            info!("Detected the microphone on the {:?} channel", mic_channel);
+        }
+        let (mic, system) = match mic_channel {
+            StereoChannel::Left => (left, right),
+            StereoChannel::Right => (right, left),
+        };
+        Some(ChannelRoles { mic, system, mic_channel, detected })
+    }
+}
+
+/// Frame RMS above which a channel counts as active (about -40 dBFS)
+const ACTIVE_FRAME_RMS: f32 = 0.01;
+/// Noise floors below this (about -80 dBFS) are treated as digital silence
+const SILENT_FLOOR_RMS: f32 = 1e-4;
+/// How much higher one noise floor must be to decide the layout on its own
+const MIC_FLOOR_RATIO: f32 = 2.0;
+
+/// Per-channel level statistics over 20ms frames
+struct ChannelLevels {
+    noise_floor: f32,  // 10th-percentile frame RMS
+    active_ratio: f32, // Fraction of frames above ACTIVE_FRAME_RMS
+}
+
+impl ChannelLevels {
+    fn measure(samples: &[f32], sample_rate: u32) -> Self {
+        let frame_len = (sample_rate as usize / 50).max(1);
+        let mut rms: Vec<f32> = samples
+            .chunks(frame_len)
+            .map(|frame| (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt())
+            .collect();
+        if rms.is_empty() {
+            return Self { noise_floor: 0.0, active_ratio: 0.0 };
+        }
+        let active = rms.iter().filter(|&&r| r > ACTIVE_FRAME_RMS).count();
+        rms.sort_by(|a, b| a.total_cmp(b));
+        Self {
+            noise_floor: rms[rms.len() / 10],
+            active_ratio: active as f32 / rms.len() as f32,
+        }
+    }
+}
+
+/// Estimate which channel is the near-field mic. A mic always picks up room and
+/// self-noise, so its quietest frames sit well above a loopback channel, which falls
+/// to digital silence between remote speakers. With similar floors, the channel that
+/// is active more consistently wins; a tie keeps the usual mic-on-left layout.
+pub fn estimate_mic_channel(left: &[f32], right: &[f32], sample_rate: u32) -> StereoChannel {
+    let left = ChannelLevels::measure(left, sample_rate);
+    let right = ChannelLevels::measure(right, sample_rate);
+    debug!(
+        "Stereo channel levels: left floor {:.5}, active {:.2}; right floor {:.5}, active {:.2}",
+        left.noise_floor, left.active_ratio, right.noise_floor, right.active_ratio
+    );
+
+    let louder_floor = |a: &ChannelLevels, b: &ChannelLevels| {
+        a.noise_floor > (b.noise_floor * MIC_FLOOR_RATIO).max(SILENT_FLOOR_RMS)
+    };
+    if louder_floor(&left, &right) {
+        StereoChannel::Left
+    } else if louder_floor(&right, &left) || right.active_ratio > left.active_ratio {
+        StereoChannel::Right
+    } else {
+        StereoChannel::Left
+    }
+}
+
 /// Resample large audio files in fixed-size chunks through the sinc resampler.
 ///
 /// Processes `input` in 60-second chunks using the high-quality sinc resampler
@@ -822,4 +925,54 @@ mod tests {
         // No extension
         assert!(!needs_ffmpeg_conversion(Path::new("noext")));
     }
+
+    #[test]
+    fn test_channel_roles_detect_mic_by_noise_floor() {
+        // Right: mic with a steady noise floor; left: loopback that is silent between bursts
+        let samples: Vec<f32> = (0..16000)
+            .flat_map(|i| {
+                let system = if (i / 1600) % 2 == 0 { 0.3 * ((i as f32) * 0.05).sin() } else { 0.0 };
+                let mic = 0.002 * ((i as f32) * 1.3).sin() + if i > 12000 { 0.2 } else { 0.0 };
+                [system, mic]
+            })
+            .collect();
+        let audio = DecodedAudio {
+            samples,
+            sample_rate: 16000,
+            channels: 2,
+            duration_seconds: 1.0,
+        };
+
+        let roles = audio.channel_roles(None).unwrap();
+        assert_eq!(roles.mic_channel, StereoChannel::Right);
+        assert!(roles.detected);
+        assert_eq!(roles.mic.len(), 16000);
+
+        // The override wins over detection
+        let roles = audio.channel_roles(Some(StereoChannel::Left)).unwrap();
+        assert_eq!(roles.mic_channel, StereoChannel::Left);
+        assert!(!roles.detected);
+        assert_eq!(roles.system[100], audio.samples[201]);
+    }
+
+    #[test]
+    fn test_channel_roles_require_stereo() {
+        let audio = DecodedAudio {
+            samples: vec![0.1; 100],
+            sample_rate: 16000,
+            channels: 1,
+            duration_seconds: 0.00625,
+        };
+        assert!(audio.channel_roles(None).is_none());
+        assert_eq!(estimate_mic_channel(&[], &[], 16000), StereoChannel::Left);
+
+        // Mono upmixed to two identical channels has nothing to split
+        let audio = DecodedAudio {
+            samples: vec![0.1, 0.1, -0.2, -0.2],
+            sample_rate: 16000,
+            channels: 2,
+            duration_seconds: 0.000125,
+        };
+        assert!(audio.channel_roles(None).is_none());
+    }
 }
@@ -418,6 +418,7 @@ mod tests {
     use super::*;
     use tempfile::tempdir;
     use super::super::recording_state::DeviceType;
+    use super::super::recording_state::ChunkPriority;
 
     #[tokio::test]
     async fn test_checkpoint_creation() {
@@ -440,6 +441,8 @@ mod tests {
                 timestamp: i as f64 * 0.5,  // timestamp in seconds
                 chunk_id: i as u64,
                 device_type: DeviceType::Microphone,
+                leading_overlap_secs: 0.0,
+                priority: ChunkPriority::Live,
             };
             saver.add_chunk(chunk).unwrap();
         }
@@ -80,7 +80,7 @@ pub use system_audio_commands::{
 };
 
 // Export new simplified components
-pub use recording_state::{RecordingState, AudioChunk, ProcessedAudioChunk, AudioError, DeviceType as RecordingDeviceType};
+pub use recording_state::{RecordingState, AudioChunk, ChunkPriority, ProcessedAudioChunk, AudioError, DeviceType as RecordingDeviceType};
 pub use pipeline::{AudioPipelineManager};
 pub use stream::{AudioStreamManager};
 pub use recording_manager::{RecordingManager};
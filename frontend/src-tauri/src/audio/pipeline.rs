@@ -9,9 +9,38 @@ use super::batch_processor::AudioMetricsBatcher;
 use rubato::{Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction};
 
 use super::devices::AudioDevice;
-use super::recording_state::{AudioChunk, AudioError, RecordingState, DeviceType};
+use super::recording_state::{AudioChunk, AudioError, ChunkPriority, RecordingState, DeviceType};
 use super::audio_processing::{audio_to_mono, LoudnessNormalizer, NoiseSuppressionProcessor, HighPassFilter};
-use super::vad::{ContinuousVadProcessor};
+use super::vad::{ContinuousVadProcessor, SpeechSegment};
+use super::transcription::worker::admit_transcription_chunk;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Upper bound for the configurable chunk overlap
+const MAX_CHUNK_OVERLAP_MS: u32 = 2000;
+
+/// Audio (ms) of the previous VAD segment's tail to prepend to the next one.
+/// 0 disables overlap.
+static CHUNK_OVERLAP_MS: AtomicU32 = AtomicU32::new(0);
+
+/// Set how much of the previous chunk's tail is re-decoded at the start of the next chunk
+#[tauri::command]
+pub async fn set_transcription_chunk_overlap_ms(overlap_ms: u32) -> Result<u32, String> {
+    if overlap_ms > MAX_CHUNK_OVERLAP_MS {
+        return Err(format!(
+            "Chunk overlap must be at most {} ms, got {}",
+            MAX_CHUNK_OVERLAP_MS, overlap_ms
+        ));
+    }
+    CHUNK_OVERLAP_MS.store(overlap_ms, Ordering::Relaxed);
+    info!("Transcription chunk overlap set to {} ms", overlap_ms);
+    Ok(overlap_ms)
+}
+
+/// Get the current chunk overlap in ms
+#[tauri::command]
+pub async fn get_transcription_chunk_overlap_ms() -> Result<u32, String> {
+    Ok(CHUNK_OVERLAP_MS.load(Ordering::Relaxed))
+}
 
 /// Ring buffer for synchronized audio mixing
 /// Accumulates samples from mic and system streams until we have aligned windows
@@ -613,6 +642,8 @@ impl AudioCapture {
             timestamp,
             chunk_id,
             device_type: self.device_type.clone(),
+            leading_overlap_secs: 0.0,
+            priority: ChunkPriority::Live,
         };
 
         // NOTE: Raw audio is NOT sent to recording saver to prevent echo
@@ -694,6 +725,9 @@ pub struct AudioPipeline {
     mixer: ProfessionalAudioMixer,
     // Recording sender for pre-mixed audio
     recording_sender_for_mixed: Option<mpsc::UnboundedSender<AudioChunk>>,
+    // Tail of the last VAD segment, for boundary overlap (see CHUNK_OVERLAP_MS)
+    last_segment_tail: Vec<f32>,
+    last_segment_end_ms: Option<f64>,
 }
 
 impl AudioPipeline {
@@ -760,6 +794,8 @@ impl AudioPipeline {
             ring_buffer,
             mixer,
             recording_sender_for_mixed: None,  // Will be set by manager
+            last_segment_tail: Vec::new(),
+            last_segment_end_ms: None,
         }
     }
 
@@ -841,15 +877,11 @@ impl AudioPipeline {
                                             info!("📤 Sending VAD segment: {:.1}ms, {} samples",
                                                   duration_ms, segment.samples.len());
 
-                                            let transcription_chunk = AudioChunk {
-                                                data: segment.samples,
-                                                sample_rate: 16000,
-                                                timestamp: segment.start_timestamp_ms / 1000.0,
-                                                chunk_id: self.chunk_id_counter,
-                                                device_type: DeviceType::Microphone,  // Mixed audio
-                                            };
+                                            let transcription_chunk = self.build_transcription_chunk(segment);
 
-                                            if let Err(e) = self.transcription_sender.send(transcription_chunk) {
+                                            if !admit_transcription_chunk(&transcription_chunk) {
+                                                debug!("⏭️ Transcription backlog full, dropping VAD segment");
+                                            } else if let Err(e) = self.transcription_sender.send(transcription_chunk) {
                                                 warn!("Failed to send VAD segment: {}", e);
                                             } else {
                                                 self.chunk_id_counter += 1;
@@ -873,6 +905,8 @@ impl AudioPipeline {
                                     timestamp: chunk.timestamp,
                                     chunk_id: self.chunk_id_counter,
                                     device_type: DeviceType::Microphone,  // Mixed audio
+                                    leading_overlap_secs: 0.0,
+                                    priority: ChunkPriority::Live,
                                 };
                                 let _ = sender.send(recording_chunk);
                             }
@@ -897,6 +931,45 @@ impl AudioPipeline {
         Ok(())
     }
 
+    /// Turn a VAD segment into a transcription chunk, prepending the previous
+    /// segment's tail when the two are (nearly) contiguous so words cut at the
+    /// boundary are decoded whole. The worker dedups the repeated text.
+    fn build_transcription_chunk(&mut self, segment: SpeechSegment) -> AudioChunk {
+        const VAD_SAMPLE_RATE: u32 = 16000;
+
+        let overlap_ms = CHUNK_OVERLAP_MS.load(Ordering::Relaxed);
+        let contiguous = self.last_segment_end_ms.is_some_and(|end_ms| {
+            segment.start_timestamp_ms - end_ms <= overlap_ms as f64
+        });
+
+        let mut data = Vec::new();
+        if overlap_ms > 0 && contiguous && !self.last_segment_tail.is_empty() {
+            let overlap_samples = (VAD_SAMPLE_RATE as usize * overlap_ms as usize) / 1000;
+            let tail_start = self.last_segment_tail.len().saturating_sub(overlap_samples);
+            data.extend_from_slice(&self.last_segment_tail[tail_start..]);
+        }
+        let leading_overlap_secs = data.len() as f64 / VAD_SAMPLE_RATE as f64;
+
+        // Remember this segment's tail for the next chunk (bounded by the max overlap)
+        let max_tail = (VAD_SAMPLE_RATE as usize * MAX_CHUNK_OVERLAP_MS as usize) / 1000;
+        let tail_start = segment.samples.len().saturating_sub(max_tail);
+        self.last_segment_tail.clear();
+        self.last_segment_tail.extend_from_slice(&segment.samples[tail_start..]);
+        self.last_segment_end_ms = Some(segment.end_timestamp_ms);
+
+        data.extend_from_slice(&segment.samples);
+
+        AudioChunk {
+            data,
+            sample_rate: VAD_SAMPLE_RATE,
+            timestamp: segment.start_timestamp_ms / 1000.0,
+            chunk_id: self.chunk_id_counter,
+            device_type: DeviceType::Microphone,  // Mixed audio
+            leading_overlap_secs,
+            priority: ChunkPriority::Live,
+        }
+    }
+
     fn flush_remaining_audio(&mut self) -> Result<()> {
         info!("Flushing remaining audio from pipeline (processed {} chunks)", self.processed_chunks);
 
@@ -911,15 +984,11 @@ impl AudioPipeline {
                         info!("📤 Sending final VAD segment to Whisper: {:.1}ms duration, {} samples",
                               duration_ms, segment.samples.len());
 
-                        let transcription_chunk = AudioChunk {
-                            data: segment.samples,
-                            sample_rate: 16000,
-                            timestamp: segment.start_timestamp_ms / 1000.0,
-                            chunk_id: self.chunk_id_counter,
-                            device_type: DeviceType::Microphone,
-                        };
+                        let transcription_chunk = self.build_transcription_chunk(segment);
 
-                        if let Err(e) = self.transcription_sender.send(transcription_chunk) {
+                        if !admit_transcription_chunk(&transcription_chunk) {
+                            debug!("⏭️ Transcription backlog full, dropping final VAD segment");
+                        } else if let Err(e) = self.transcription_sender.send(transcription_chunk) {
                             warn!("Failed to send final VAD segment: {}", e);
                         } else {
                             self.chunk_id_counter += 1;
@@ -1039,6 +1108,8 @@ impl AudioPipelineManager {
                 timestamp: 0.0,
                 chunk_id: u64::MAX, // Special ID to indicate flush
                 device_type: super::recording_state::DeviceType::Microphone,
+                leading_overlap_secs: 0.0,
+                priority: ChunkPriority::Live,
             };
 
             if let Err(e) = sender.send(flush_chunk) {
@@ -1059,6 +1130,8 @@ impl AudioPipelineManager {
                         timestamp: 0.0,
                         chunk_id: u64::MAX - (i as u64),
                         device_type: super::recording_state::DeviceType::Microphone,
+                        leading_overlap_secs: 0.0,
+                        priority: ChunkPriority::Live,
                     };
                     let _ = sender.send(additional_flush);
                 }
@@ -236,6 +236,10 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
         .await
         .map_err(|e| format!("Failed to start recording: {}", e))?;
 
+    // Identifies this session in the transcription-session-complete event; the
+    // meeting is saved under the same id
+    let recording_id = manager.get_meeting_id();
+
     // Store the manager globally to keep it alive
     {
         let mut global_manager = RECORDING_MANAGER.lock().unwrap();
@@ -248,7 +252,8 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     reset_speech_detected_flag(); // Reset for new recording session
 
     // Start optimized parallel transcription task and store handle
-    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
+    let task_handle =
+        transcription::start_transcription_task(app.clone(), transcription_receiver, recording_id);
     {
         let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
         *global_task = Some(task_handle);
@@ -404,6 +409,10 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
         .await
         .map_err(|e| format!("Failed to start recording: {}", e))?;
 
+    // Identifies this session in the transcription-session-complete event; the
+    // meeting is saved under the same id
+    let recording_id = manager.get_meeting_id();
+
     // Store the manager globally to keep it alive
     {
         let mut global_manager = RECORDING_MANAGER.lock().unwrap();
@@ -416,7 +425,8 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     reset_speech_detected_flag(); // Reset for new recording session
 
     // Start optimized parallel transcription task and store handle
-    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
+    let task_handle =
+        transcription::start_transcription_task(app.clone(), transcription_receiver, recording_id);
     {
         let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
         *global_task = Some(task_handle);
@@ -834,6 +844,17 @@ pub async fn stop_recording<R: Runtime>(
             }
         }
 
+        // Opt-in local metrics file (never uploaded)
+        if let Some(ref folder) = meeting_folder {
+            match super::recording_preferences::load_recording_preferences(&app).await {
+                Ok(prefs) if prefs.save_transcription_metrics => {
+                    super::transcription::metrics::write_last_metrics_file(folder);
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Failed to load recording preferences for metrics: {}", e),
+            }
+        }
+
         (meeting_folder, meeting_name)
     } else {
         info!("ℹ️ No recording manager available for cleanup");
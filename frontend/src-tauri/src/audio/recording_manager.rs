@@ -68,7 +68,9 @@ impl RecordingManager {
     ) -> Result<mpsc::UnboundedReceiver<AudioChunk>> {
         info!("Starting recording manager (auto_save: {})", auto_save);
 
-        // Set up transcription channel
+        // Set up transcription channel; the pipeline caps what it puts in it
+        // (see worker::admit_transcription_chunk)
+        super::transcription::worker::reset_transcription_backlog();
         let (transcription_sender, transcription_receiver) = mpsc::unbounded_channel::<AudioChunk>();
 
         // CRITICAL FIX: Create recording sender for pre-mixed audio from pipeline
@@ -456,6 +458,11 @@ impl RecordingManager {
         self.recording_saver.get_meeting_name()
     }
 
+    /// Id the meeting will be saved under in the database
+    pub fn get_meeting_id(&self) -> Option<String> {
+        self.recording_saver.get_meeting_id()
+    }
+
     /// Cleanup all resources without saving
     pub async fn cleanup_without_save(&mut self) {
         if self.is_recording() {
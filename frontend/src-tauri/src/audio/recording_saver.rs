@@ -5,7 +5,7 @@ use log::{info, warn, error};
 use tauri::{AppHandle, Runtime, Emitter};
 use tokio::sync::mpsc;
 use serde::{Serialize, Deserialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use super::recording_state::AudioChunk;
 use super::audio_processing::create_meeting_folder;
@@ -246,7 +246,8 @@ impl RecordingSaver {
         // Create initial metadata
         let metadata = MeetingMetadata {
             version: "1.0".to_string(),
-            meeting_id: None,  // Will be set by backend
+            // Reused as the database id when the recording is saved
+            meeting_id: Some(format!("meeting-{}", uuid::Uuid::new_v4())),
             meeting_name: Some(meeting_name.to_string()),
             created_at: chrono::Utc::now().to_rfc3339(),
             completed_at: None,
@@ -459,6 +460,11 @@ impl RecordingSaver {
     }
 
     /// Get the meeting folder path (for passing to backend)
+    /// Id the meeting is saved under, assigned when its folder is created
+    pub fn get_meeting_id(&self) -> Option<String> {
+        self.metadata.as_ref().and_then(|metadata| metadata.meeting_id.clone())
+    }
+
     pub fn get_meeting_folder(&self) -> Option<&PathBuf> {
         self.meeting_folder.as_ref()
     }
@@ -483,3 +489,10 @@ impl Default for RecordingSaver {
         Self::new()
     }
 }
+
+/// Meeting id stored in a meeting folder's metadata.json, if any
+pub fn read_meeting_id(folder: &Path) -> Option<String> {
+    let json = std::fs::read_to_string(folder.join("metadata.json")).ok()?;
+    let metadata: serde_json::Value = serde_json::from_str(&json).ok()?;
+    metadata.get("meeting_id")?.as_str().map(str::to_string)
+}
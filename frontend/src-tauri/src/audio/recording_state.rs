@@ -14,6 +14,15 @@ pub enum DeviceType {
     System,
 }
 
+/// Scheduling class for transcription. Workers always take queued live chunks
+/// first, so background re-transcription can't delay the live transcript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChunkPriority {
+    #[default]
+    Live,
+    Background,
+}
+
 /// Audio chunk with metadata for processing
 #[derive(Debug, Clone)]
 pub struct AudioChunk {
@@ -22,6 +31,10 @@ pub struct AudioChunk {
     pub timestamp: f64,
     pub chunk_id: u64,
     pub device_type: DeviceType,
+    // Seconds of the previous chunk's tail prepended to `data`; `timestamp` is where
+    // the new audio starts, so the overlap lies just before it
+    pub leading_overlap_secs: f64,
+    pub priority: ChunkPriority,
 }
 
 /// Processed audio chunk (post-VAD) for recording
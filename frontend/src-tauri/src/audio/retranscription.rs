@@ -1,6 +1,6 @@
 // Retranscription module - allows re-processing stored audio with different settings
 
-use crate::audio::decoder::decode_audio_file;
+use crate::audio::decoder::{decode_audio_file, DecodedAudio, StereoChannel};
 use crate::audio::vad::get_speech_chunks_with_progress;
 use super::common::{create_transcript_segments, split_segment_at_silence, write_transcripts_json};
 use super::constants::AUDIO_EXTENSIONS;
@@ -22,6 +22,40 @@ static RETRANSCRIPTION_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
 /// Global flag to signal cancellation
 static RETRANSCRIPTION_CANCELLED: AtomicBool = AtomicBool::new(false);
 
+/// Which channel of a stereo recording holds the local mic; None = detect per file
+static STEREO_MIC_CHANNEL: std::sync::Mutex<Option<StereoChannel>> = std::sync::Mutex::new(None);
+
+/// Manual override for `DecodedAudio::channel_roles`, for capture setups whose
+/// channel layout the heuristic gets wrong
+pub fn stereo_mic_channel() -> Option<StereoChannel> {
+    *STEREO_MIC_CHANNEL.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 16kHz mono audio from one source, transcribed on its own
+struct SpeakerTrack {
+    /// Value for the transcripts `speaker` column ("mic" or "system"); None for a mixed recording
+    speaker: Option<&'static str>,
+    samples: Vec<f32>,
+}
+
+/// Split a dual-channel recording into mic and system tracks so each segment is
+/// labelled with its source; anything else is transcribed as one mixed track.
+fn speaker_tracks(decoded: DecodedAudio, mic_channel: Option<StereoChannel>) -> Vec<SpeakerTrack> {
+    let Some(roles) = decoded.channel_roles(mic_channel) else {
+        return vec![SpeakerTrack { speaker: None, samples: decoded.to_whisper_format() }];
+    };
+    let channel = |samples: Vec<f32>| DecodedAudio {
+        samples,
+        sample_rate: decoded.sample_rate,
+        channels: 1,
+        duration_seconds: decoded.duration_seconds,
+    };
+    vec![
+        SpeakerTrack { speaker: Some("mic"), samples: channel(roles.mic).to_whisper_format() },
+        SpeakerTrack { speaker: Some("system"), samples: channel(roles.system).to_whisper_format() },
+    ]
+}
+
 /// RAII guard for RETRANSCRIPTION_IN_PROGRESS flag
 /// Ensures flag is cleared even if retranscription panics or returns early
 struct RetranscriptionGuard;
@@ -217,13 +251,19 @@ async fn run_retranscription<R: Runtime>(
         return Err(anyhow!("Retranscription cancelled"));
     }
 
-    // Convert to 16kHz mono format (CPU-intensive, run in blocking task)
-    let audio_samples = tokio::task::spawn_blocking(move || {
-        decoded.to_whisper_format()
-    })
-    .await
-    .map_err(|e| anyhow!("Resample task panicked: {}", e))?;
-    info!("Converted to 16kHz mono format: {} samples", audio_samples.len());
+    // Convert to 16kHz mono format, one track per channel for a mic/system stereo
+    // recording (CPU-intensive, run in blocking task)
+    let mic_channel = stereo_mic_channel();
+    let tracks = tokio::task::spawn_blocking(move || speaker_tracks(decoded, mic_channel))
+        .await
+        .map_err(|e| anyhow!("Resample task panicked: {}", e))?;
+    for track in &tracks {
+        info!(
+            "Converted {} track to 16kHz mono format: {} samples",
+            track.speaker.unwrap_or("mixed"),
+            track.samples.len()
+        );
+    }
 
     emit_progress(&app, &meeting_id, "vad", 20, "Detecting speech segments...");
 
@@ -235,32 +275,40 @@ async fn run_retranscription<R: Runtime>(
     // Use VAD to find natural speech boundaries (same approach as live transcription)
     // IMPORTANT: Run VAD in a blocking task to avoid blocking the async runtime
     // For large files (35+ minutes), VAD processing can take several minutes
-    let app_for_vad = app.clone();
-    let meeting_id_for_vad = meeting_id.clone();
-
-    let speech_segments = tokio::task::spawn_blocking(move || {
-        get_speech_chunks_with_progress(
-            &audio_samples,
-            VAD_REDEMPTION_TIME_MS,
-            |vad_progress, segments_found| {
-                // Map VAD progress (0-100) to overall progress (20-25)
-                let overall_progress = 20 + (vad_progress as f32 * 0.05) as u32;
-                emit_progress(
-                    &app_for_vad,
-                    &meeting_id_for_vad,
-                    "vad",
-                    overall_progress,
-                    &format!("Detecting speech segments... {}% ({} found)", vad_progress, segments_found),
-                );
-
-                // Return false to cancel if cancellation requested
-                !RETRANSCRIPTION_CANCELLED.load(Ordering::SeqCst)
-            },
-        )
-    })
-    .await
-    .map_err(|e| anyhow!("VAD task panicked: {}", e))?
-    .map_err(|e| anyhow!("VAD processing failed: {}", e))?;
+    let track_count = tracks.len();
+    let mut speech_segments: Vec<(crate::audio::vad::SpeechSegment, Option<&'static str>)> = Vec::new();
+    for (track_index, SpeakerTrack { speaker, samples }) in tracks.into_iter().enumerate() {
+        let app_for_vad = app.clone();
+        let meeting_id_for_vad = meeting_id.clone();
+
+        let track_segments = tokio::task::spawn_blocking(move || {
+            get_speech_chunks_with_progress(
+                &samples,
+                VAD_REDEMPTION_TIME_MS,
+                |vad_progress, segments_found| {
+                    // Map VAD progress (0-100) of every track to overall progress (20-25)
+                    let tracks_done = (track_index * 100 + vad_progress as usize) as f32 / track_count as f32;
+                    let overall_progress = 20 + (tracks_done * 0.05) as u32;
+                    emit_progress(
+                        &app_for_vad,
+                        &meeting_id_for_vad,
+                        "vad",
+                        overall_progress,
+                        &format!("Detecting speech segments... {}% ({} found)", vad_progress, segments_found),
+                    );
+
+                    // Return false to cancel if cancellation requested
+                    !RETRANSCRIPTION_CANCELLED.load(Ordering::SeqCst)
+                },
+            )
+        })
+        .await
+        .map_err(|e| anyhow!("VAD task panicked: {}", e))?
+        .map_err(|e| anyhow!("VAD processing failed: {}", e))?;
+        speech_segments.extend(track_segments.into_iter().map(|segment| (segment, speaker)));
+    }
+    // Interleave the tracks so transcripts come out in playback order
+    speech_segments.sort_by(|a, b| a.0.start_timestamp_ms.total_cmp(&b.0.start_timestamp_ms));
 
     let total_segments = speech_segments.len();
     info!("VAD detected {} speech segments (redemption_time={}ms)", total_segments, VAD_REDEMPTION_TIME_MS);
@@ -268,7 +316,7 @@ async fn run_retranscription<R: Runtime>(
     // Diagnostic: log segment duration distribution
     if !speech_segments.is_empty() {
         let durations_ms: Vec<f64> = speech_segments.iter()
-            .map(|s| s.end_timestamp_ms - s.start_timestamp_ms)
+            .map(|(s, _)| s.end_timestamp_ms - s.start_timestamp_ms)
             .collect();
         let total_speech_ms: f64 = durations_ms.iter().sum();
         let avg_duration = total_speech_ms / durations_ms.len() as f64;
@@ -281,7 +329,7 @@ async fn run_retranscription<R: Runtime>(
             (total_speech_ms / 1000.0 / duration_seconds) * 100.0
         );
         // Log first 10 segments for detailed inspection
-        for (i, seg) in speech_segments.iter().take(10).enumerate() {
+        for (i, (seg, _)) in speech_segments.iter().take(10).enumerate() {
             let dur = seg.end_timestamp_ms - seg.start_timestamp_ms;
             debug!("  Segment {}: {:.0}ms-{:.0}ms ({:.0}ms, {} samples)",
                 i, seg.start_timestamp_ms, seg.end_timestamp_ms, dur, seg.samples.len());
@@ -315,8 +363,8 @@ async fn run_retranscription<R: Runtime>(
     // for the lowest-energy window near the target split point and cut there.
     const MAX_SEGMENT_SAMPLES: usize = 25 * 16000; // 25 seconds at 16kHz
 
-    let mut processable_segments: Vec<crate::audio::vad::SpeechSegment> = Vec::new();
-    for segment in &speech_segments {
+    let mut processable_segments: Vec<(crate::audio::vad::SpeechSegment, Option<&'static str>)> = Vec::new();
+    for (segment, speaker) in &speech_segments {
         if segment.samples.len() > MAX_SEGMENT_SAMPLES {
             debug!(
                 "Splitting large segment ({:.0}ms, {} samples) at silence boundaries",
@@ -326,9 +374,9 @@ async fn run_retranscription<R: Runtime>(
 
             let sub_segments = split_segment_at_silence(segment, MAX_SEGMENT_SAMPLES);
             debug!("Split into {} sub-segments", sub_segments.len());
-            processable_segments.extend(sub_segments);
+            processable_segments.extend(sub_segments.into_iter().map(|sub| (sub, *speaker)));
         } else {
-            processable_segments.push(segment.clone());
+            processable_segments.push((segment.clone(), *speaker));
         }
     }
 
@@ -337,9 +385,10 @@ async fn run_retranscription<R: Runtime>(
 
     // Process each speech segment with progress updates
     let mut all_transcripts: Vec<(String, f64, f64)> = Vec::new(); // (text, start_ms, end_ms)
+    let mut speakers: Vec<Option<&'static str>> = Vec::new(); // Parallel to all_transcripts
     let mut total_confidence = 0.0f32;
 
-    for (i, segment) in processable_segments.iter().enumerate() {
+    for (i, (segment, speaker)) in processable_segments.iter().enumerate() {
         // Check for cancellation before each segment
         if RETRANSCRIPTION_CANCELLED.load(Ordering::SeqCst) {
             return Err(anyhow!("Retranscription cancelled"));
@@ -393,6 +442,7 @@ async fn run_retranscription<R: Runtime>(
                 if trimmed.len() > 80 { let mut end = 80; while !trimmed.is_char_boundary(end) { end -= 1; } &trimmed[..end] } else { trimmed }
             );
             all_transcripts.push((text, segment.start_timestamp_ms, segment.end_timestamp_ms));
+            speakers.push(*speaker);
             total_confidence += conf;
         } else {
             debug!("Segment {}/{}: {:.1}s — empty transcription", i + 1, processable_count, segment_duration_sec);
@@ -439,10 +489,10 @@ async fn run_retranscription<R: Runtime>(
         .await
         .map_err(|e| anyhow!("Failed to delete existing transcripts: {}", e))?;
 
-    for segment in &segments {
+    for (segment, speaker) in segments.iter().zip(&speakers) {
         sqlx::query(
-            "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration)
-             VALUES (?, ?, ?, ?, ?, ?, ?)"
+            "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, speaker)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
         )
         .bind(&segment.id)
         .bind(&meeting_id)
@@ -451,6 +501,7 @@ async fn run_retranscription<R: Runtime>(
         .bind(segment.audio_start_time)
         .bind(segment.audio_end_time)
         .bind(segment.duration)
+        .bind(*speaker)
         .execute(&mut *tx)
         .await
         .map_err(|e| anyhow!("Failed to insert transcript: {}", e))?;
@@ -831,6 +882,19 @@ pub async fn is_retranscription_in_progress_command() -> bool {
     is_retranscription_in_progress()
 }
 
+/// Pin the mic to one stereo channel, or pass null to detect it per recording
+#[tauri::command]
+pub async fn set_stereo_mic_channel(channel: Option<StereoChannel>) -> Result<(), String> {
+    *STEREO_MIC_CHANNEL.lock().unwrap_or_else(|e| e.into_inner()) = channel;
+    info!("Stereo mic channel set to {:?}", channel);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_stereo_mic_channel() -> Result<Option<StereoChannel>, String> {
+    Ok(stereo_mic_channel())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -911,6 +975,37 @@ mod tests {
         assert!(segments[1].id.starts_with("transcript-"));
     }
 
+    #[test]
+    fn test_speaker_tracks_split_mic_and_system() {
+        let stereo = |mic_on_left: bool| DecodedAudio {
+            samples: (0..3200)
+                .flat_map(|i| {
+                    let (mic, system) = (0.1 + (i as f32 * 0.3).sin() * 0.01, if i % 2 == 0 { 0.5 } else { 0.0 });
+                    if mic_on_left { [mic, system] } else { [system, mic] }
+                })
+                .collect(),
+            sample_rate: 16000,
+            channels: 2,
+            duration_seconds: 0.2,
+        };
+
+        // The override picks the mic channel; the other one is system audio
+        let audio = stereo(false);
+        let right: Vec<f32> = audio.samples.iter().skip(1).step_by(2).copied().collect();
+        let tracks = speaker_tracks(audio, Some(StereoChannel::Right));
+        assert_eq!(tracks.iter().map(|t| t.speaker).collect::<Vec<_>>(), vec![Some("mic"), Some("system")]);
+        assert_eq!(tracks[0].samples, right);
+
+        let tracks = speaker_tracks(stereo(true), Some(StereoChannel::Left));
+        assert_eq!(tracks[1].samples[0], 0.5);
+
+        // Mono stays one unlabelled track
+        let mono = DecodedAudio { samples: vec![0.1; 1600], sample_rate: 16000, channels: 1, duration_seconds: 0.1 };
+        let tracks = speaker_tracks(mono, None);
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].speaker, None);
+    }
+
     #[test]
     fn test_cancellation_flag() {
         // Reset flag to known state
@@ -2,11 +2,15 @@
 //
 // TranscriptionEngine enum and model initialization/validation logic.
 
-use super::openai_provider::OpenAIProvider;
-use super::provider::TranscriptionProvider;
+use super::openai_provider::{OpenAICompatibleConfig, OpenAICompatibleProvider, OpenAIProvider};
+use super::provider::{resolve_language, TranscriptionError, TranscriptionProvider};
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
 use log::{info, warn};
+use serde::Serialize;
 use std::sync::Arc;
-use tauri::{AppHandle, Manager, Runtime};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tauri_plugin_store::StoreExt;
 
 // ============================================================================
 // TRANSCRIPTION ENGINE ENUM
@@ -41,6 +45,15 @@ impl TranscriptionEngine {
         }
     }
 
+    /// Run the model's warmup decode if it has one and it hasn't run yet.
+    /// Returns false only when a warmup was attempted and failed.
+    pub async fn warm_up(&self) -> bool {
+        match self {
+            Self::QwenAsr(engine) => !engine.is_model_loaded().await || engine.warm_up().await,
+            Self::Whisper(_) | Self::Parakeet(_) | Self::Provider(_) => true,
+        }
+    }
+
     /// Get the provider name for logging
     pub fn provider_name(&self) -> &str {
         match self {
@@ -50,16 +63,60 @@ impl TranscriptionEngine {
             Self::Provider(provider) => provider.provider_name(),
         }
     }
+
+    /// Transcribe one buffer of 16kHz mono audio and return the cleaned text.
+    /// Used outside the recording worker, which drives each engine itself.
+    pub async fn transcribe_once(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> Result<String, TranscriptionError> {
+        let engine_error = |e: anyhow::Error| TranscriptionError::EngineFailed(e.to_string());
+        let text = match self {
+            Self::Whisper(engine) => engine.transcribe_audio(audio, language).await.map_err(engine_error)?,
+            Self::Parakeet(engine) => engine.transcribe_audio(audio).await.map_err(engine_error)?,
+            Self::QwenAsr(engine) => {
+                let raw = engine.transcribe_audio(audio).await.map_err(engine_error)?;
+                crate::qwen_asr_engine::clean_qwen_asr_output(&raw)
+            }
+            Self::Provider(provider) => provider.transcribe(audio, language).await?.text,
+        };
+        Ok(text.trim().to_string())
+    }
+
+    /// Whether auto-translate (speech to English) is honoured
+    pub fn supports_translation(&self) -> bool {
+        match self {
+            Self::Whisper(_) => true,
+            Self::Parakeet(_) | Self::QwenAsr(_) => false,
+            Self::Provider(provider) => provider.supports_translation(),
+        }
+    }
 }
 
 // ============================================================================
 // MODEL VALIDATION AND INITIALIZATION
 // ============================================================================
 
-/// Validate that transcription models (Whisper or Parakeet) are ready before starting recording
-pub async fn validate_transcription_model_ready<R: Runtime>(
-    app: &AppHandle<R>,
-) -> Result<(), String> {
+/// Readiness of the configured transcription provider's model, so callers can
+/// decide on a fallback without parsing error strings
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelReadiness {
+    pub provider: String,
+    pub model: Option<String>,
+    pub loaded: bool,     // Model is loaded (hosted providers: credentials are configured)
+    pub downloaded: bool, // A model is on disk; always true for hosted providers
+    pub error: Option<String>,
+}
+
+impl ModelReadiness {
+    pub fn is_ready(&self) -> bool {
+        self.loaded && self.error.is_none()
+    }
+}
+
+/// Check the configured provider, loading its model if needed
+pub async fn transcription_model_readiness<R: Runtime>(app: &AppHandle<R>) -> ModelReadiness {
     // Check transcript configuration to determine which engine to validate
     let config = match crate::api::api::api_get_transcript_config(
         app.clone(),
@@ -93,7 +150,176 @@ pub async fn validate_transcription_model_ready<R: Runtime>(
         }
     };
 
-    // Validate based on provider
+    let downloaded = match config.provider.as_str() {
+        "localWhisper" => crate::whisper_engine::commands::whisper_has_available_models()
+            .await
+            .unwrap_or(false),
+        "parakeet" => crate::parakeet_engine::commands::parakeet_has_available_models()
+            .await
+            .unwrap_or(false),
+        "qwenAsr" => crate::qwen_asr_engine::commands::qwen_asr_has_available_models()
+            .await
+            .unwrap_or(false),
+        _ => true,
+    };
+
+    match validate_provider_model(app, &config).await {
+        Ok(model) => ModelReadiness {
+            provider: config.provider,
+            model,
+            loaded: true,
+            downloaded,
+            error: None,
+        },
+        Err(e) => ModelReadiness {
+            provider: config.provider,
+            model: (!config.model.trim().is_empty()).then_some(config.model),
+            loaded: false,
+            downloaded,
+            error: Some(e),
+        },
+    }
+}
+
+/// Validate that transcription models are ready before starting recording
+pub async fn validate_transcription_model_ready<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<(), String> {
+    let readiness = transcription_model_readiness(app).await;
+    match readiness.error {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Get the configured provider's model readiness
+#[tauri::command]
+pub async fn get_transcription_model_readiness<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<ModelReadiness, String> {
+    Ok(transcription_model_readiness(&app).await)
+}
+
+/// Result of an end-to-end transcription check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PreflightStatus {
+    /// The test signal was transcribed to non-empty text
+    Ready,
+    /// The provider or its model couldn't be loaded; see `error`
+    ProviderNotReady,
+    /// Transcription ran but returned no text
+    EmptyOutput,
+    /// Transcription ran and failed; see `error`
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptionPreflight {
+    pub status: PreflightStatus,
+    pub provider: String,
+    pub model: Option<String>,
+    pub text: Option<String>,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+const PREFLIGHT_SAMPLE_RATE: usize = 16000;
+const PREFLIGHT_SECONDS: usize = 2;
+
+/// Two seconds of a voiced, vowel-like signal: a 140Hz harmonic series shaped
+/// by two formants and a 4Hz syllable envelope. Enough to get past VAD and
+/// through a decoder; models may still legitimately return nothing for it.
+fn preflight_test_signal() -> Vec<f32> {
+    const FUNDAMENTAL_HZ: f32 = 140.0;
+    const FORMANTS_HZ: [f32; 2] = [700.0, 1200.0];
+    const SYLLABLE_HZ: f32 = 4.0;
+
+    let total = PREFLIGHT_SAMPLE_RATE * PREFLIGHT_SECONDS;
+    (0..total)
+        .map(|i| {
+            let t = i as f32 / PREFLIGHT_SAMPLE_RATE as f32;
+            let voiced: f32 = (1..=20)
+                .map(|h| {
+                    let freq = FUNDAMENTAL_HZ * h as f32;
+                    let gain: f32 = FORMANTS_HZ
+                        .iter()
+                        .map(|f| 1.0 / (1.0 + ((freq - f) / 150.0).powi(2)))
+                        .sum();
+                    gain * (std::f32::consts::TAU * freq * t).sin() / h as f32
+                })
+                .sum();
+            let envelope = 0.5 - 0.5 * (std::f32::consts::TAU * SYLLABLE_HZ * t).cos();
+            (0.25 * envelope * voiced).clamp(-1.0, 1.0)
+        })
+        .collect()
+}
+
+/// Check that the configured provider loads and actually transcribes audio,
+/// going through the same engine selection as a recording.
+pub async fn transcription_preflight<R: Runtime>(app: &AppHandle<R>) -> TranscriptionPreflight {
+    let readiness = transcription_model_readiness(app).await;
+    let mut result = TranscriptionPreflight {
+        status: PreflightStatus::ProviderNotReady,
+        provider: readiness.provider.clone(),
+        model: readiness.model.clone(),
+        text: None,
+        latency_ms: None,
+        error: readiness.error.clone(),
+    };
+    if !readiness.is_ready() {
+        return result;
+    }
+
+    let engine = match get_or_init_transcription_engine(app).await {
+        Ok(engine) => engine,
+        Err(e) => {
+            result.error = Some(e);
+            return result;
+        }
+    };
+    result.provider = engine.provider_name().to_string();
+    result.model = engine.get_current_model().await.or(result.model);
+
+    let language = resolve_language(
+        crate::get_language_preference_internal(),
+        engine.supports_translation(),
+    );
+    let started = std::time::Instant::now();
+    let transcription = engine.transcribe_once(preflight_test_signal(), language).await;
+    result.latency_ms = Some(started.elapsed().as_millis() as u64);
+
+    match transcription {
+        Ok(text) if text.is_empty() => result.status = PreflightStatus::EmptyOutput,
+        Ok(text) => {
+            result.status = PreflightStatus::Ready;
+            result.text = Some(text);
+        }
+        Err(e) => {
+            result.status = PreflightStatus::Failed;
+            result.error = Some(e.to_string());
+        }
+    }
+    info!(
+        "🩺 Transcription preflight with {}: {:?} in {:?}ms",
+        result.provider, result.status, result.latency_ms
+    );
+    result
+}
+
+/// Transcribe a short test signal with the configured provider (run before a meeting)
+#[tauri::command]
+pub async fn run_transcription_preflight<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<TranscriptionPreflight, String> {
+    Ok(transcription_preflight(&app).await)
+}
+
+/// Validate one provider's model, returning the ready model name
+async fn validate_provider_model<R: Runtime>(
+    app: &AppHandle<R>,
+    config: &crate::api::api::TranscriptConfig,
+) -> Result<Option<String>, String> {
     match config.provider.as_str() {
         "localWhisper" => {
             info!("🔍 Validating Whisper model...");
@@ -115,7 +341,7 @@ pub async fn validate_transcription_model_ready<R: Runtime>(
                         "✅ Whisper model validation successful: {} is ready",
                         model_name
                     );
-                    Ok(())
+                    Ok(Some(model_name))
                 }
                 Err(e) => {
                     warn!("❌ Whisper model validation failed: {}", e);
@@ -144,7 +370,7 @@ pub async fn validate_transcription_model_ready<R: Runtime>(
                         "✅ Parakeet model validation successful: {} is ready",
                         model_name
                     );
-                    Ok(())
+                    Ok(Some(model_name))
                 }
                 Err(e) => {
                     warn!("❌ Parakeet model validation failed: {}", e);
@@ -170,7 +396,7 @@ pub async fn validate_transcription_model_ready<R: Runtime>(
                         "✅ Qwen ASR model validation successful: {} is ready",
                         model_name
                     );
-                    Ok(())
+                    Ok(Some(model_name))
                 }
                 Err(e) => {
                     warn!("❌ Qwen ASR model validation failed: {}", e);
@@ -200,19 +426,329 @@ pub async fn validate_transcription_model_ready<R: Runtime>(
                 );
             }
 
-            Ok(())
+            Ok(Some(config.model.clone()))
+        }
+        "openaiCompatible" => {
+            info!("🔍 Validating OpenAI-compatible transcription provider...");
+            let compatible = load_openai_compatible_config(app).await.ok_or_else(|| {
+                "OpenAI-compatible transcription requires a base URL. Please configure it in Settings > Transcription."
+                    .to_string()
+            })?;
+            compatible.validate()?;
+            Ok(Some(compatible.model))
         }
         other => {
             warn!("❌ Unsupported transcription provider: {}", other);
             Err(format!(
-                "Provider '{}' is not supported for transcription. Please select 'localWhisper', 'parakeet', 'qwenAsr', or 'openai'.",
+                "Provider '{}' is not supported for transcription. Please select 'localWhisper', 'parakeet', 'qwenAsr', 'openai', or 'openaiCompatible'.",
                 other
             ))
         }
     }
 }
 
-/// Get or initialize the appropriate transcription engine based on provider configuration
+// ============================================================================
+// PROVIDER READINESS
+// ============================================================================
+
+/// Transcription providers the app knows how to run
+pub const KNOWN_PROVIDERS: [&str; 5] =
+    ["localWhisper", "parakeet", "qwenAsr", "openai", "openaiCompatible"];
+
+const DEFAULT_OPENAI_TRANSCRIPT_MODEL: &str = "gpt-4o-mini-transcribe";
+
+/// Readiness of a single transcription provider, used by the provider picker
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderReadiness {
+    pub provider: String,
+    pub ready: bool,
+    pub active: bool, // Selected in the saved transcript config
+    pub current_model: Option<String>,
+    pub reason: Option<String>, // Why the provider is not ready
+}
+
+/// List every known provider with whether it can transcribe right now.
+///
+/// Local engines are ready when at least one model is downloaded; hosted
+/// providers are ready when an API key is saved (the key is not verified
+/// against the remote service). `current_model` is the loaded model for local
+/// engines, falling back to the configured model for the active provider.
+#[tauri::command]
+pub async fn list_ready_providers<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<ProviderReadiness>, String> {
+    let config = crate::api::api::api_get_transcript_config(app.clone(), app.state(), None)
+        .await
+        .ok()
+        .flatten();
+
+    let mut providers = Vec::with_capacity(KNOWN_PROVIDERS.len());
+    for provider in KNOWN_PROVIDERS {
+        let configured_model = config
+            .as_ref()
+            .filter(|c| c.provider == provider && !c.model.trim().is_empty())
+            .map(|c| c.model.clone());
+        let active = config.as_ref().is_some_and(|c| c.provider == provider);
+
+        let (ready, loaded_model, reason) = match provider {
+            "localWhisper" => {
+                let ready = crate::whisper_engine::commands::whisper_has_available_models()
+                    .await
+                    .unwrap_or(false);
+                let loaded = crate::whisper_engine::commands::whisper_get_current_model()
+                    .await
+                    .ok()
+                    .flatten();
+                (ready, loaded, (!ready).then(|| "No Whisper models downloaded".to_string()))
+            }
+            "parakeet" => {
+                let ready = crate::parakeet_engine::commands::parakeet_has_available_models()
+                    .await
+                    .unwrap_or(false);
+                let loaded = crate::parakeet_engine::commands::parakeet_get_current_model()
+                    .await
+                    .ok()
+                    .flatten();
+                (ready, loaded, (!ready).then(|| "No Parakeet models downloaded".to_string()))
+            }
+            "qwenAsr" => {
+                let ready = crate::qwen_asr_engine::commands::qwen_asr_has_available_models()
+                    .await
+                    .unwrap_or(false);
+                let loaded = crate::qwen_asr_engine::commands::qwen_asr_get_current_model()
+                    .await
+                    .ok()
+                    .flatten();
+                (ready, loaded, (!ready).then(|| "No Qwen ASR models downloaded".to_string()))
+            }
+            "openai" => {
+                let api_key = crate::api::api::api_get_transcript_api_key(
+                    app.clone(),
+                    app.state(),
+                    provider.to_string(),
+                    None,
+                )
+                .await
+                .unwrap_or_default();
+                let ready = !api_key.trim().is_empty();
+                let model = Some(
+                    configured_model
+                        .clone()
+                        .unwrap_or_else(|| DEFAULT_OPENAI_TRANSCRIPT_MODEL.to_string()),
+                );
+                (ready, model, (!ready).then(|| "OpenAI API key not configured".to_string()))
+            }
+            "openaiCompatible" => match load_openai_compatible_config(&app).await {
+                Some(compatible) => {
+                    let error = compatible.validate().err();
+                    (error.is_none(), Some(compatible.model), error)
+                }
+                None => (false, None, Some("Base URL not configured".to_string())),
+            },
+            _ => (false, None, Some("Unsupported provider".to_string())),
+        };
+
+        providers.push(ProviderReadiness {
+            provider: provider.to_string(),
+            ready,
+            active,
+            current_model: loaded_model.or(configured_model),
+            reason,
+        });
+    }
+
+    Ok(providers)
+}
+
+// ============================================================================
+// PROVIDER PRIORITY
+// ============================================================================
+
+/// Fallback order used when the configured provider can't be used. Hosted
+/// providers are left out by default so audio never leaves the machine unless
+/// the user adds them.
+pub const DEFAULT_PROVIDER_PRIORITY: [&str; 3] = ["qwenAsr", "localWhisper", "parakeet"];
+
+const TRANSCRIPTION_PREFERENCES_STORE: &str = "transcription_preferences.json";
+const PROVIDER_PRIORITY_KEY: &str = "provider_priority";
+
+/// Load the saved provider priority, falling back to `DEFAULT_PROVIDER_PRIORITY`
+pub fn load_provider_priority<R: Runtime>(app: &AppHandle<R>) -> Vec<String> {
+    let saved = app
+        .store(TRANSCRIPTION_PREFERENCES_STORE)
+        .ok()
+        .and_then(|store| store.get(PROVIDER_PRIORITY_KEY))
+        .and_then(|value| serde_json::from_value::<Vec<String>>(value).ok());
+
+    match saved {
+        Some(priority) if !priority.is_empty() => priority,
+        _ => DEFAULT_PROVIDER_PRIORITY.iter().map(|p| p.to_string()).collect(),
+    }
+}
+
+/// Tell the user a fallback provider is transcribing because `provider` failed.
+/// The frontend shows this as a warning toast.
+pub fn emit_provider_fallback<R: Runtime>(app: &AppHandle<R>, provider: &str, fallback: &str, error: &str) {
+    let _ = app.emit(
+        "transcription-provider-fallback",
+        serde_json::json!({
+            "provider": provider,
+            "fallbackProvider": fallback,
+            "error": error,
+            "userMessage": format!(
+                "Transcription provider '{}' is unavailable, using '{}' instead.",
+                provider, fallback
+            ),
+        }),
+    );
+}
+
+/// Providers to try, in order: `configured` first, then the saved priority list,
+/// keeping only providers that are ready (see `list_ready_providers`).
+pub async fn ordered_ready_providers<R: Runtime>(
+    app: &AppHandle<R>,
+    configured: Option<&str>,
+) -> Vec<String> {
+    let ready: Vec<String> = list_ready_providers(app.clone())
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|p| p.ready)
+        .map(|p| p.provider)
+        .collect();
+
+    let priority = load_provider_priority(app);
+    let mut ordered: Vec<String> = Vec::new();
+    for provider in configured.into_iter().chain(priority.iter().map(String::as_str)) {
+        if ready.iter().any(|r| r == provider) && !ordered.iter().any(|o| o == provider) {
+            ordered.push(provider.to_string());
+        }
+    }
+    ordered
+}
+
+/// Get the transcription provider fallback priority
+#[tauri::command]
+pub async fn get_transcription_provider_priority<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<String>, String> {
+    Ok(load_provider_priority(&app))
+}
+
+/// Set the transcription provider fallback priority. Entries must be known
+/// providers and may not repeat.
+#[tauri::command]
+pub async fn set_transcription_provider_priority<R: Runtime>(
+    app: AppHandle<R>,
+    priority: Vec<String>,
+) -> Result<Vec<String>, String> {
+    for (i, provider) in priority.iter().enumerate() {
+        if !KNOWN_PROVIDERS.contains(&provider.as_str()) {
+            return Err(format!(
+                "Unknown transcription provider '{}'. Expected one of: {}",
+                provider,
+                KNOWN_PROVIDERS.join(", ")
+            ));
+        }
+        if priority[..i].contains(provider) {
+            return Err(format!("Provider '{}' is listed more than once", provider));
+        }
+    }
+
+    let store = app
+        .store(TRANSCRIPTION_PREFERENCES_STORE)
+        .map_err(|e| format!("Failed to access store: {}", e))?;
+    store.set(PROVIDER_PRIORITY_KEY, serde_json::json!(priority));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save provider priority: {}", e))?;
+
+    info!("Transcription provider priority set to {:?}", priority);
+    Ok(load_provider_priority(&app))
+}
+
+const OPENAI_COMPATIBLE_CONFIG_KEY: &str = "openai_compatible_config";
+const OPENAI_COMPATIBLE_PROVIDER: &str = "openaiCompatible";
+
+/// Load the saved OpenAI-compatible endpoint config, if any. The endpoint comes
+/// from the preferences store and the API key from the settings database.
+pub async fn load_openai_compatible_config<R: Runtime>(app: &AppHandle<R>) -> Option<OpenAICompatibleConfig> {
+    let store = app.store(TRANSCRIPTION_PREFERENCES_STORE).ok()?;
+    let mut config: OpenAICompatibleConfig = store
+        .get(OPENAI_COMPATIBLE_CONFIG_KEY)
+        .and_then(|value| serde_json::from_value(value).ok())?;
+
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    // Configs saved by older versions kept the key in the store; move it out
+    if let Some(legacy_key) = config.api_key.take().filter(|key| !key.trim().is_empty()) {
+        if let Err(e) = SettingsRepository::save_transcript_api_key(pool, OPENAI_COMPATIBLE_PROVIDER, &legacy_key).await {
+            warn!("Failed to move the OpenAI-compatible API key to the settings database: {}", e);
+            config.api_key = Some(legacy_key);
+            return Some(config);
+        }
+        if let Ok(value) = serde_json::to_value(&config) {
+            store.set(OPENAI_COMPATIBLE_CONFIG_KEY, value);
+            if let Err(e) = store.save() {
+                warn!("Failed to remove the OpenAI-compatible API key from the store: {}", e);
+            }
+        }
+        info!("Moved the OpenAI-compatible API key to the settings database");
+    }
+
+    config.api_key = match SettingsRepository::get_transcript_api_key(pool, OPENAI_COMPATIBLE_PROVIDER).await {
+        Ok(key) => key.filter(|key| !key.trim().is_empty()),
+        Err(e) => {
+            warn!("Failed to read the OpenAI-compatible API key: {}", e);
+            None
+        }
+    };
+    Some(config)
+}
+
+/// Get the OpenAI-compatible transcription endpoint config
+#[tauri::command]
+pub async fn get_openai_compatible_transcription_config<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Option<OpenAICompatibleConfig>, String> {
+    Ok(load_openai_compatible_config(&app).await)
+}
+
+/// Save the base URL, auth scheme, key and model used by the "openaiCompatible" provider.
+/// The key goes to the settings database like the other transcription keys.
+#[tauri::command]
+pub async fn set_openai_compatible_transcription_config<R: Runtime>(
+    app: AppHandle<R>,
+    mut config: OpenAICompatibleConfig,
+) -> Result<(), String> {
+    config.validate()?;
+
+    let api_key = config.api_key.take().unwrap_or_default();
+    let state = app.state::<AppState>();
+    SettingsRepository::save_transcript_api_key(state.db_manager.pool(), OPENAI_COMPATIBLE_PROVIDER, api_key.trim())
+        .await
+        .map_err(|e| format!("Failed to save OpenAI-compatible API key: {}", e))?;
+
+    let store = app
+        .store(TRANSCRIPTION_PREFERENCES_STORE)
+        .map_err(|e| format!("Failed to access store: {}", e))?;
+    store.set(
+        OPENAI_COMPATIBLE_CONFIG_KEY,
+        serde_json::to_value(&config).map_err(|e| e.to_string())?,
+    );
+    store
+        .save()
+        .map_err(|e| format!("Failed to save OpenAI-compatible config: {}", e))?;
+
+    info!("Saved OpenAI-compatible transcription endpoint {}", config.endpoint());
+    Ok(())
+}
+
+/// Get or initialize the appropriate transcription engine based on provider configuration.
+///
+/// The configured provider is tried first; if it can't be initialized, the
+/// remaining ready providers are tried in priority order.
 pub async fn get_or_init_transcription_engine<R: Runtime>(
     app: &AppHandle<R>,
 ) -> Result<TranscriptionEngine, String> {
@@ -249,7 +785,50 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
         }
     };
 
-    // Initialize the appropriate engine based on provider
+    let primary_error = match init_engine_for_provider(app, &config).await {
+        Ok(engine) => return Ok(engine),
+        Err(e) => e,
+    };
+
+    for provider in ordered_ready_providers(app, Some(&config.provider)).await {
+        if provider == config.provider {
+            continue;
+        }
+
+        let api_key = if provider == "openai" {
+            crate::api::api::api_get_transcript_api_key(app.clone(), app.state(), provider.clone(), None)
+                .await
+                .ok()
+        } else {
+            None
+        };
+        let fallback_config = crate::api::api::TranscriptConfig {
+            provider: provider.clone(),
+            model: String::new(),
+            api_key,
+        };
+
+        match init_engine_for_provider(app, &fallback_config).await {
+            Ok(engine) => {
+                warn!(
+                    "⚠️ Provider '{}' unavailable ({}), falling back to '{}'",
+                    config.provider, primary_error, provider
+                );
+                emit_provider_fallback(app, &config.provider, &provider, &primary_error);
+                return Ok(engine);
+            }
+            Err(e) => info!("Fallback provider '{}' unavailable: {}", provider, e),
+        }
+    }
+
+    Err(primary_error)
+}
+
+/// Initialize the engine for a single provider configuration
+async fn init_engine_for_provider<R: Runtime>(
+    app: &AppHandle<R>,
+    config: &crate::api::api::TranscriptConfig,
+) -> Result<TranscriptionEngine, String> {
     match config.provider.as_str() {
         "parakeet" => {
             info!("🦜 Initializing Parakeet transcription engine");
@@ -281,9 +860,9 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
             info!("🧠 Initializing Qwen ASR transcription engine");
 
             let engine = {
-                let guard = crate::qwen_asr_engine::commands::QWEN_ASR_ENGINE
-                    .lock()
-                    .unwrap();
+                let guard = crate::qwen_asr_engine::commands::lock_recovering(
+                    &crate::qwen_asr_engine::commands::QWEN_ASR_ENGINE,
+                );
                 guard.as_ref().cloned()
             };
 
@@ -315,12 +894,27 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
             }
 
             let model = if config.model.trim().is_empty() {
-                "gpt-4o-mini-transcribe".to_string()
+                DEFAULT_OPENAI_TRANSCRIPT_MODEL.to_string()
             } else {
                 config.model.clone()
             };
 
-            let provider = OpenAIProvider::new(api_key, model);
+            let provider = OpenAIProvider::new(api_key, model).map_err(|e| e.to_string())?;
+            Ok(TranscriptionEngine::Provider(Arc::new(provider)))
+        }
+        "openaiCompatible" => {
+            info!("☁️ Initializing OpenAI-compatible transcription provider");
+
+            let mut compatible = load_openai_compatible_config(app).await.ok_or_else(|| {
+                "OpenAI-compatible transcription requires a base URL. Please configure it in Settings > Transcription."
+                    .to_string()
+            })?;
+            if !config.model.trim().is_empty() {
+                compatible.model = config.model.clone();
+            }
+
+            let provider =
+                OpenAICompatibleProvider::with_config(&compatible).map_err(|e| e.to_string())?;
             Ok(TranscriptionEngine::Provider(Arc::new(provider)))
         }
         "localWhisper" => {
@@ -329,7 +923,7 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
             Ok(TranscriptionEngine::Whisper(whisper_engine))
         }
         other => Err(format!(
-            "Provider '{}' is not supported for transcription. Please select 'localWhisper', 'parakeet', 'qwenAsr', or 'openai'.",
+            "Provider '{}' is not supported for transcription. Please select 'localWhisper', 'parakeet', 'qwenAsr', 'openai', or 'openaiCompatible'.",
             other
         )),
     }
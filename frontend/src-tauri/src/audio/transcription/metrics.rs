@@ -0,0 +1,234 @@
+// audio/transcription/metrics.rs
+//
+// Local transcription quality metrics for a recording. Nothing here leaves the
+// machine: stats are kept in memory and, when the user opts in, written as a
+// JSON file into the meeting folder.
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{LazyLock, Mutex};
+
+/// File name of the metrics file written next to the recording
+pub const METRICS_FILE_NAME: &str = "transcription_metrics.json";
+
+/// Why a chunk did not produce a transcript segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropReason {
+    Empty,
+    LowConfidence,
+    Overlap,
+    TooFewWords,
+    TooShort,
+    ModelNotLoaded,
+    Error,
+}
+
+impl DropReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::LowConfidence => "low_confidence",
+            Self::Overlap => "overlap",
+            Self::TooFewWords => "too_few_words",
+            Self::TooShort => "too_short",
+            Self::ModelNotLoaded => "model_not_loaded",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Counts sent with `transcription-session-summary` when a recording's transcription ends
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SessionSummary {
+    pub recording_id: Option<String>,
+    pub total_chunks: u64,
+    pub emitted_segments: u64,
+    pub dropped_too_short: u64,
+    pub dropped_empty: u64,
+    pub dropped_low_confidence: u64,
+    pub dropped_overlap: u64,
+    pub dropped_too_few_words: u64,
+    pub errors: u64, // Engine failures, including the model being unloaded mid-recording
+    pub retries: u64,
+}
+
+/// Aggregate stats for one recording session
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecordingMetrics {
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub started_at: Option<String>,
+    pub chunks_processed: u64,
+    pub chunks_emitted: u64,
+    pub audio_seconds: f64,
+    pub processing_seconds: f64,
+    pub avg_rtf: Option<f64>, // processing time / audio time; below 1.0 is faster than real time
+    pub avg_confidence: Option<f32>, // Only over segments whose provider reports confidence
+    pub chunks_dropped: BTreeMap<String, u64>,
+    #[serde(default)]
+    pub retries: u64,
+    #[serde(skip)]
+    confidence_sum: f64,
+    #[serde(skip)]
+    confidence_count: u64,
+}
+
+impl RecordingMetrics {
+    fn dropped(&self, reason: DropReason) -> u64 {
+        self.chunks_dropped.get(reason.as_str()).copied().unwrap_or(0)
+    }
+
+    /// Summarize the session; `total_chunks` is what was queued, including chunks
+    /// that never reached the engine
+    pub fn summary(&self, recording_id: Option<String>, total_chunks: u64) -> SessionSummary {
+        SessionSummary {
+            recording_id,
+            total_chunks,
+            emitted_segments: self.chunks_emitted,
+            dropped_too_short: self.dropped(DropReason::TooShort),
+            dropped_empty: self.dropped(DropReason::Empty),
+            dropped_low_confidence: self.dropped(DropReason::LowConfidence),
+            dropped_overlap: self.dropped(DropReason::Overlap),
+            dropped_too_few_words: self.dropped(DropReason::TooFewWords),
+            errors: self.dropped(DropReason::Error) + self.dropped(DropReason::ModelNotLoaded),
+            retries: self.retries,
+        }
+    }
+}
+
+static CURRENT_METRICS: LazyLock<Mutex<RecordingMetrics>> =
+    LazyLock::new(|| Mutex::new(RecordingMetrics::default()));
+
+static LAST_METRICS: LazyLock<Mutex<Option<RecordingMetrics>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+fn with_current<F: FnOnce(&mut RecordingMetrics)>(f: F) {
+    let mut metrics = CURRENT_METRICS.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut metrics);
+}
+
+/// Start collecting metrics for a new recording session
+pub fn begin_recording(provider: &str, model: Option<String>) {
+    with_current(|m| {
+        *m = RecordingMetrics {
+            provider: Some(provider.to_string()),
+            model,
+            started_at: Some(chrono::Utc::now().to_rfc3339()),
+            ..Default::default()
+        };
+    });
+}
+
+/// Record that a chunk went through the engine
+pub fn record_chunk(audio_seconds: f64, processing_seconds: f64) {
+    with_current(|m| {
+        m.chunks_processed += 1;
+        m.audio_seconds += audio_seconds;
+        m.processing_seconds += processing_seconds;
+    });
+}
+
+/// Record that a chunk produced an emitted transcript segment
+pub fn record_emitted(confidence: Option<f32>) {
+    with_current(|m| {
+        m.chunks_emitted += 1;
+        if let Some(c) = confidence {
+            m.confidence_sum += c as f64;
+            m.confidence_count += 1;
+        }
+    });
+}
+
+/// Record that a chunk was dropped
+pub fn record_drop(reason: DropReason) {
+    with_current(|m| {
+        *m.chunks_dropped.entry(reason.as_str().to_string()).or_insert(0) += 1;
+    });
+}
+
+/// Record that a chunk was transcribed a second time
+pub fn record_retry() {
+    with_current(|m| m.retries += 1);
+}
+
+/// Finalize the current session; the result is kept for `get_last_recording_metrics`
+pub fn finish_recording() -> RecordingMetrics {
+    let mut metrics = {
+        let mut current = CURRENT_METRICS.lock().unwrap_or_else(|e| e.into_inner());
+        std::mem::take(&mut *current)
+    };
+
+    metrics.avg_rtf = (metrics.audio_seconds > 0.0)
+        .then(|| metrics.processing_seconds / metrics.audio_seconds);
+    metrics.avg_confidence = (metrics.confidence_count > 0)
+        .then(|| (metrics.confidence_sum / metrics.confidence_count as f64) as f32);
+
+    info!(
+        "📊 Transcription metrics: {} chunks, {} emitted, RTF {:?}, dropped {:?}",
+        metrics.chunks_processed, metrics.chunks_emitted, metrics.avg_rtf, metrics.chunks_dropped
+    );
+
+    *LAST_METRICS.lock().unwrap_or_else(|e| e.into_inner()) = Some(metrics.clone());
+    metrics
+}
+
+/// Get the metrics of the most recently finished recording
+pub fn last_recording_metrics() -> Option<RecordingMetrics> {
+    LAST_METRICS.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Write the last recording's metrics into the meeting folder
+pub fn write_last_metrics_file(meeting_folder: &Path) {
+    let Some(metrics) = last_recording_metrics() else {
+        return;
+    };
+
+    let path = meeting_folder.join(METRICS_FILE_NAME);
+    match serde_json::to_vec_pretty(&metrics) {
+        Ok(json) => match std::fs::write(&path, json) {
+            Ok(()) => info!("📊 Saved transcription metrics to {}", path.display()),
+            Err(e) => warn!("Failed to write transcription metrics file: {}", e),
+        },
+        Err(e) => warn!("Failed to serialize transcription metrics: {}", e),
+    }
+}
+
+/// Get aggregate transcription stats for the last finished recording
+#[tauri::command]
+pub async fn get_last_recording_metrics() -> Result<Option<RecordingMetrics>, String> {
+    Ok(last_recording_metrics())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_counts_drops_by_reason() {
+        let mut metrics = RecordingMetrics {
+            chunks_emitted: 5,
+            retries: 1,
+            ..Default::default()
+        };
+        for reason in [
+            DropReason::Empty,
+            DropReason::Empty,
+            DropReason::Overlap,
+            DropReason::Error,
+            DropReason::ModelNotLoaded,
+        ] {
+            *metrics.chunks_dropped.entry(reason.as_str().to_string()).or_insert(0) += 1;
+        }
+
+        let summary = metrics.summary(Some("rec".to_string()), 11);
+        assert_eq!(summary.total_chunks, 11);
+        assert_eq!(summary.emitted_segments, 5);
+        assert_eq!(summary.dropped_empty, 2);
+        assert_eq!(summary.dropped_overlap, 1);
+        assert_eq!(summary.dropped_too_short, 0);
+        assert_eq!(summary.errors, 2);
+        assert_eq!(summary.retries, 1);
+    }
+}
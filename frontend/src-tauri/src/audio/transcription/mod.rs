@@ -3,21 +3,26 @@
 // Transcription module: Provider abstraction, engine management, and worker pool.
 
 pub mod engine;
+pub mod metrics;
 pub mod openai_provider;
 pub mod parakeet_provider;
 pub mod provider;
 pub mod qwen_asr_provider;
+pub mod sink;
+pub mod text_normalize;
+pub mod text_overlap;
+pub mod websocket_sink;
 pub mod whisper_provider;
 pub mod worker;
 
 // Re-export commonly used types
 pub use engine::{
-    get_or_init_transcription_engine, get_or_init_whisper, validate_transcription_model_ready,
-    TranscriptionEngine,
+    get_or_init_transcription_engine, get_or_init_whisper, transcription_model_readiness,
+    validate_transcription_model_ready, ModelReadiness, TranscriptionEngine,
 };
-pub use openai_provider::OpenAIProvider;
+pub use openai_provider::{AuthScheme, OpenAICompatibleConfig, OpenAICompatibleProvider, OpenAIProvider};
 pub use parakeet_provider::ParakeetProvider;
-pub use provider::{TranscriptResult, TranscriptionError, TranscriptionProvider};
+pub use provider::{TranscriptResult, TranscriptionError, TranscriptionProvider, WordTiming};
 pub use qwen_asr_provider::QwenAsrProvider;
 pub use whisper_provider::WhisperProvider;
 pub use worker::{reset_speech_detected_flag, start_transcription_task, TranscriptUpdate};
@@ -1,39 +1,177 @@
 // audio/transcription/openai_provider.rs
 //
-// OpenAI Speech-to-Text provider implementation using /v1/audio/transcriptions.
+// Speech-to-Text provider for OpenAI and any service exposing an
+// OpenAI-compatible /v1/audio/transcriptions endpoint (Groq, LM Studio, vLLM, ...).
+// Auto-translate uses the matching /v1/audio/translations endpoint.
 
-use super::provider::{TranscriptResult, TranscriptionError, TranscriptionProvider};
+use super::provider::{
+    TranscriptResult, TranscriptionError, TranscriptionProvider, AUTO_TRANSLATE_LANGUAGE,
+};
 use async_trait::async_trait;
 use reqwest::multipart::{Form, Part};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
-const OPENAI_TRANSCRIPT_ENDPOINT: &str = "https://api.openai.com/v1/audio/transcriptions";
+pub const OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+const TRANSCRIPTIONS_PATH: &str = "/audio/transcriptions";
+const TRANSLATIONS_PATH: &str = "/audio/translations";
 const OPENAI_REQUEST_TIMEOUT_SECS: u64 = 30;
 const SAMPLE_RATE_HZ: u32 = 16_000;
-const CHANNELS: u16 = 1;
 
 #[derive(Debug, Deserialize)]
 struct OpenAITranscriptionResponse {
     text: String,
 }
 
-pub struct OpenAIProvider {
+/// Which audio endpoint a request goes to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioTask {
+    /// Text in the spoken language (`/audio/transcriptions`)
+    Transcribe,
+    /// English text whatever the spoken language (`/audio/translations`)
+    Translate,
+}
+
+impl AudioTask {
+    /// The app's auto-translate language preference maps to the translations endpoint
+    pub fn for_language(language: Option<&str>) -> Self {
+        match language {
+            Some(lang) if lang.trim().eq_ignore_ascii_case(AUTO_TRANSLATE_LANGUAGE) => Self::Translate,
+            _ => Self::Transcribe,
+        }
+    }
+}
+
+/// How the API key is sent to the transcription endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AuthScheme {
+    /// `Authorization: Bearer <key>` (OpenAI, Groq, most servers)
+    Bearer,
+    /// Key sent as-is in a custom header, e.g. `api-key` for Azure
+    Header { name: String },
+    /// No authentication (local servers such as LM Studio or vLLM)
+    None,
+}
+
+/// Connection settings for an OpenAI-compatible transcription service
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenAICompatibleConfig {
+    /// API base URL, e.g. "https://api.groq.com/openai/v1" or "http://localhost:1234/v1"
+    pub base_url: String,
+    pub auth_scheme: AuthScheme,
+    pub api_key: Option<String>,
+    pub model: String,
+    /// Whether the service exposes /audio/translations. Off unless set, since many
+    /// compatible servers only implement transcriptions; auto-translate then
+    /// falls back to plain transcription.
+    #[serde(default)]
+    pub supports_translation: bool,
+}
+
+impl OpenAICompatibleConfig {
+    /// Full transcription endpoint; a base URL that already names the endpoint is used as-is
+    pub fn endpoint(&self) -> String {
+        let base = self.base_url.trim().trim_end_matches('/');
+        if base.ends_with(TRANSCRIPTIONS_PATH) {
+            base.to_string()
+        } else {
+            format!("{}{}", base, TRANSCRIPTIONS_PATH)
+        }
+    }
+
+    /// Translation (speech to English) endpoint next to the transcription one
+    pub fn translation_endpoint(&self) -> String {
+        let base = self.base_url.trim().trim_end_matches('/');
+        let base = base.strip_suffix(TRANSCRIPTIONS_PATH).unwrap_or(base);
+        format!("{}{}", base, TRANSLATIONS_PATH)
+    }
+
+    /// Check the config is usable before saving it or building a provider
+    pub fn validate(&self) -> Result<(), String> {
+        let base = self.base_url.trim();
+        if !(base.starts_with("http://") || base.starts_with("https://")) {
+            return Err("Base URL must start with http:// or https://".to_string());
+        }
+        if self.model.trim().is_empty() {
+            return Err("A transcription model is required".to_string());
+        }
+        let has_key = self.api_key.as_deref().is_some_and(|k| !k.trim().is_empty());
+        match &self.auth_scheme {
+            AuthScheme::None => Ok(()),
+            AuthScheme::Header { name } if name.trim().is_empty() => {
+                Err("Auth header name is required".to_string())
+            }
+            _ if !has_key => Err("An API key is required for this auth scheme".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The OpenAI provider is the compatible provider pointed at api.openai.com
+pub type OpenAIProvider = OpenAICompatibleProvider;
+
+pub struct OpenAICompatibleProvider {
     client: reqwest::Client,
+    endpoint: String,
+    translation_endpoint: String,
+    auth_scheme: AuthScheme,
     api_key: String,
     model: String,
+    supports_translation: bool,
+    name: &'static str,
 }
 
-impl OpenAIProvider {
-    pub fn new(api_key: String, model: String) -> Self {
-        Self {
-            client: reqwest::Client::builder()
-                .timeout(Duration::from_secs(OPENAI_REQUEST_TIMEOUT_SECS))
-                .build()
-                .unwrap_or_else(|_| reqwest::Client::new()),
-            api_key,
+impl OpenAICompatibleProvider {
+    /// Create an OpenAI provider using the default OpenAI URL and bearer auth.
+    pub fn new(api_key: String, model: String) -> Result<Self, TranscriptionError> {
+        let config = OpenAICompatibleConfig {
+            base_url: OPENAI_BASE_URL.to_string(),
+            auth_scheme: AuthScheme::Bearer,
+            api_key: Some(api_key),
             model,
-        }
+            supports_translation: true,
+        };
+        Self::build(&config, "OpenAI")
+    }
+
+    /// Create a provider for any OpenAI-compatible transcription service.
+    pub fn with_config(config: &OpenAICompatibleConfig) -> Result<Self, TranscriptionError> {
+        config.validate().map_err(TranscriptionError::EngineFailed)?;
+        Self::build(config, "OpenAI-compatible")
+    }
+
+    /// Build with a request timeout.
+    ///
+    /// Fails rather than falling back to `reqwest::Client::new()`, which has no
+    /// timeout and could hang a transcription worker forever on a bad network.
+    fn build(config: &OpenAICompatibleConfig, name: &'static str) -> Result<Self, TranscriptionError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(OPENAI_REQUEST_TIMEOUT_SECS))
+            .build()
+            .map_err(|e| {
+                log::error!("Failed to build {} HTTP client: {}", name, e);
+                TranscriptionError::EngineFailed(format!(
+                    "Failed to create HTTP client for {}: {}",
+                    name, e
+                ))
+            })?;
+
+        Ok(Self {
+            client,
+            endpoint: config.endpoint(),
+            translation_endpoint: config.translation_endpoint(),
+            auth_scheme: config.auth_scheme.clone(),
+            api_key: config.api_key.clone().unwrap_or_default(),
+            model: config.model.clone(),
+            supports_translation: config.supports_translation,
+            name,
+        })
+    }
+
+    fn has_required_key(&self) -> bool {
+        self.auth_scheme == AuthScheme::None || !self.api_key.trim().is_empty()
     }
 
     fn normalize_language(language: Option<String>) -> Option<String> {
@@ -49,55 +187,52 @@ impl OpenAIProvider {
         }
     }
 
-    fn to_wav_bytes(audio: &[f32]) -> Vec<u8> {
-        // Convert float samples to PCM16.
-        let mut pcm = Vec::with_capacity(audio.len() * 2);
-        for &sample in audio {
-            let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
-            pcm.extend_from_slice(&value.to_le_bytes());
-        }
+    fn truncate_error_text(s: &str, max_chars: usize) -> String {
+        s.chars().take(max_chars).collect::<String>()
+    }
 
-        // Build RIFF/WAV header for PCM16 mono 16kHz.
-        let data_size = pcm.len() as u32;
-        let file_size = 36 + data_size;
-        let bits_per_sample = 16u16;
-        let block_align = CHANNELS * (bits_per_sample / 8);
-        let byte_rate = SAMPLE_RATE_HZ * block_align as u32;
-
-        let mut wav = Vec::with_capacity(44 + pcm.len());
-        wav.extend_from_slice(b"RIFF");
-        wav.extend_from_slice(&file_size.to_le_bytes());
-        wav.extend_from_slice(b"WAVE");
-        wav.extend_from_slice(b"fmt ");
-        wav.extend_from_slice(&16u32.to_le_bytes());
-        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
-        wav.extend_from_slice(&CHANNELS.to_le_bytes());
-        wav.extend_from_slice(&SAMPLE_RATE_HZ.to_le_bytes());
-        wav.extend_from_slice(&byte_rate.to_le_bytes());
-        wav.extend_from_slice(&block_align.to_le_bytes());
-        wav.extend_from_slice(&bits_per_sample.to_le_bytes());
-        wav.extend_from_slice(b"data");
-        wav.extend_from_slice(&data_size.to_le_bytes());
-        wav.extend_from_slice(&pcm);
-        wav
+    /// Endpoint and text form fields (besides the audio file) for a request.
+    /// The translations endpoint rejects a `language` field, so it's only sent
+    /// when transcribing. A translation on a service without the endpoint is sent
+    /// as an auto-detected transcription.
+    fn request_plan(&self, task: AudioTask, language: Option<String>) -> (&str, Vec<(&'static str, String)>) {
+        let mut fields = vec![("model", self.model.clone())];
+        let task = if task == AudioTask::Translate && !self.supports_translation {
+            log::warn!(
+                "{} is not configured for translation; transcribing in the spoken language instead",
+                self.name
+            );
+            AudioTask::Transcribe
+        } else {
+            task
+        };
+        match task {
+            AudioTask::Transcribe => {
+                if let Some(lang) = Self::normalize_language(language) {
+                    fields.push(("language", lang));
+                }
+                (&self.endpoint, fields)
+            }
+            AudioTask::Translate => (&self.translation_endpoint, fields),
+        }
     }
 
-    fn truncate_error_text(s: &str, max_chars: usize) -> String {
-        s.chars().take(max_chars).collect::<String>()
+    /// Transcribe and translate audio to English via the translations endpoint
+    pub async fn translate(&self, audio: Vec<f32>) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        self.send(audio, AudioTask::Translate, None).await
     }
-}
 
-#[async_trait]
-impl TranscriptionProvider for OpenAIProvider {
-    async fn transcribe(
+    async fn send(
         &self,
         audio: Vec<f32>,
+        task: AudioTask,
         language: Option<String>,
     ) -> std::result::Result<TranscriptResult, TranscriptionError> {
-        if self.api_key.trim().is_empty() {
-            return Err(TranscriptionError::EngineFailed(
-                "OpenAI API key is missing".to_string(),
-            ));
+        if !self.has_required_key() {
+            return Err(TranscriptionError::EngineFailed(format!(
+                "{} API key is missing",
+                self.name
+            )));
         }
 
         if audio.len() < 1600 {
@@ -107,24 +242,26 @@ impl TranscriptionProvider for OpenAIProvider {
             });
         }
 
-        let wav = Self::to_wav_bytes(&audio);
+        let wav = crate::audio::common::pcm16_wav_bytes(&audio, SAMPLE_RATE_HZ);
         let audio_part = Part::bytes(wav)
             .file_name("chunk.wav")
             .mime_str("audio/wav")
             .map_err(|e| TranscriptionError::EngineFailed(e.to_string()))?;
 
-        let mut form = Form::new()
-            .part("file", audio_part)
-            .text("model", self.model.clone());
+        let (endpoint, fields) = self.request_plan(task, language);
+        let mut form = Form::new().part("file", audio_part);
+        for (name, value) in fields {
+            form = form.text(name, value);
+        }
 
-        if let Some(lang) = Self::normalize_language(language) {
-            form = form.text("language", lang);
+        let mut request = self.client.post(endpoint);
+        match &self.auth_scheme {
+            AuthScheme::Bearer => request = request.bearer_auth(&self.api_key),
+            AuthScheme::Header { name } => request = request.header(name.as_str(), &self.api_key),
+            AuthScheme::None => {}
         }
 
-        let response = self
-            .client
-            .post(OPENAI_TRANSCRIPT_ENDPOINT)
-            .bearer_auth(&self.api_key)
+        let response = request
             .multipart(form)
             .send()
             .await
@@ -135,8 +272,11 @@ impl TranscriptionProvider for OpenAIProvider {
             let response_text = response.text().await.unwrap_or_default();
             let preview = Self::truncate_error_text(&response_text, 240);
             return Err(TranscriptionError::EngineFailed(format!(
-                "OpenAI transcription failed ({}): {}",
-                status, preview
+                "{} {} failed ({}): {}",
+                self.name,
+                if task == AudioTask::Translate { "translation" } else { "transcription" },
+                status,
+                preview
             )));
         }
 
@@ -145,15 +285,23 @@ impl TranscriptionProvider for OpenAIProvider {
             .await
             .map_err(|e| TranscriptionError::EngineFailed(e.to_string()))?;
 
-        Ok(TranscriptResult {
-            text: result.text.trim().to_string(),
-            confidence: None,
-            is_partial: false,
-        })
+        Ok(TranscriptResult::from_text(&result.text, None, false))
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for OpenAICompatibleProvider {
+    async fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        let task = AudioTask::for_language(language.as_deref());
+        self.send(audio, task, language).await
     }
 
     async fn is_model_loaded(&self) -> bool {
-        !self.api_key.trim().is_empty() && !self.model.trim().is_empty()
+        self.has_required_key() && !self.model.trim().is_empty()
     }
 
     async fn get_current_model(&self) -> Option<String> {
@@ -165,6 +313,118 @@ impl TranscriptionProvider for OpenAIProvider {
     }
 
     fn provider_name(&self) -> &'static str {
-        "OpenAI"
+        self.name
+    }
+
+    fn supports_translation(&self) -> bool {
+        self.supports_translation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(base_url: &str, auth_scheme: AuthScheme, api_key: Option<&str>) -> OpenAICompatibleConfig {
+        OpenAICompatibleConfig {
+            base_url: base_url.to_string(),
+            auth_scheme,
+            api_key: api_key.map(str::to_string),
+            model: "whisper-large-v3".to_string(),
+            supports_translation: false,
+        }
+    }
+
+    #[test]
+    fn endpoint_appends_transcriptions_path() {
+        let c = config("https://api.groq.com/openai/v1/", AuthScheme::Bearer, Some("k"));
+        assert_eq!(c.endpoint(), "https://api.groq.com/openai/v1/audio/transcriptions");
+
+        let c = config("http://localhost:8000/v1/audio/transcriptions", AuthScheme::None, None);
+        assert_eq!(c.endpoint(), "http://localhost:8000/v1/audio/transcriptions");
+    }
+
+    #[test]
+    fn translation_endpoint_sits_next_to_transcriptions() {
+        let c = config("https://api.openai.com/v1", AuthScheme::Bearer, Some("k"));
+        assert_eq!(c.translation_endpoint(), "https://api.openai.com/v1/audio/translations");
+
+        let c = config("http://localhost:8000/v1/audio/transcriptions/", AuthScheme::None, None);
+        assert_eq!(c.translation_endpoint(), "http://localhost:8000/v1/audio/translations");
+    }
+
+    #[test]
+    fn validate_requires_key_unless_auth_is_none() {
+        assert!(config("http://localhost:1234/v1", AuthScheme::None, None).validate().is_ok());
+        assert!(config("https://api.groq.com/openai/v1", AuthScheme::Bearer, None).validate().is_err());
+        assert!(config(
+            "https://example.openai.azure.com",
+            AuthScheme::Header { name: "api-key".to_string() },
+            Some("k")
+        )
+        .validate()
+        .is_ok());
+        assert!(config("localhost:1234/v1", AuthScheme::None, None).validate().is_err());
+    }
+
+    #[test]
+    fn request_plan_picks_endpoint_and_fields_per_task() {
+        let mut c = config("https://api.openai.com/v1", AuthScheme::Bearer, Some("k"));
+        c.supports_translation = true;
+        let provider = OpenAICompatibleProvider::with_config(&c).unwrap();
+
+        let (endpoint, fields) = provider.request_plan(AudioTask::Transcribe, Some("de".to_string()));
+        assert_eq!(endpoint, "https://api.openai.com/v1/audio/transcriptions");
+        assert_eq!(
+            fields,
+            vec![("model", "whisper-large-v3".to_string()), ("language", "de".to_string())]
+        );
+
+        let (endpoint, fields) = provider.request_plan(AudioTask::Translate, Some("de".to_string()));
+        assert_eq!(endpoint, "https://api.openai.com/v1/audio/translations");
+        assert_eq!(fields, vec![("model", "whisper-large-v3".to_string())]);
+    }
+
+    #[test]
+    fn translation_falls_back_to_transcription_unless_supported() {
+        let c = config("http://localhost:1234/v1", AuthScheme::None, None);
+        let provider = OpenAICompatibleProvider::with_config(&c).unwrap();
+        assert!(!provider.supports_translation());
+
+        let (endpoint, fields) = provider.request_plan(AudioTask::Translate, None);
+        assert_eq!(endpoint, "http://localhost:1234/v1/audio/transcriptions");
+        assert_eq!(fields, vec![("model", "whisper-large-v3".to_string())]);
+
+        let provider = OpenAIProvider::new("k".to_string(), "whisper-1".to_string()).unwrap();
+        assert!(provider.supports_translation());
+    }
+
+    #[test]
+    fn config_without_translation_flag_defaults_to_unsupported() {
+        let c: OpenAICompatibleConfig = serde_json::from_str(
+            r#"{"baseUrl":"http://localhost:1234/v1","authScheme":{"type":"none"},"apiKey":null,"model":"m"}"#,
+        )
+        .unwrap();
+        assert!(!c.supports_translation);
+    }
+
+    #[test]
+    fn auto_translate_selects_translation_task() {
+        assert_eq!(AudioTask::for_language(Some("auto-translate")), AudioTask::Translate);
+        assert_eq!(AudioTask::for_language(Some("auto")), AudioTask::Transcribe);
+        assert_eq!(AudioTask::for_language(Some("en")), AudioTask::Transcribe);
+        assert_eq!(AudioTask::for_language(None), AudioTask::Transcribe);
+    }
+
+    #[test]
+    fn auth_scheme_serializes_tagged() {
+        let json = serde_json::to_value(AuthScheme::Header { name: "api-key".to_string() }).unwrap();
+        assert_eq!(json, serde_json::json!({ "type": "header", "name": "api-key" }));
+    }
+
+    #[test]
+    fn blank_response_text_is_no_speech() {
+        let response: OpenAITranscriptionResponse = serde_json::from_str(r#"{"text":" \n"}"#).unwrap();
+        assert!(TranscriptResult::from_text(&response.text, None, false).is_no_speech());
     }
 }
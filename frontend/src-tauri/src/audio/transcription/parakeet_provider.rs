@@ -34,11 +34,8 @@ impl TranscriptionProvider for ParakeetProvider {
         }
 
         match self.engine.transcribe_audio(audio).await {
-            Ok(text) => Ok(TranscriptResult {
-                text: text.trim().to_string(),
-                confidence: None, // Parakeet doesn't provide confidence scores
-                is_partial: false, // Parakeet doesn't provide partial results
-            }),
+            // Parakeet doesn't provide confidence scores or partial results
+            Ok(text) => Ok(TranscriptResult::from_text(&text, None, false)),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
     }
@@ -9,6 +9,24 @@ use async_trait::async_trait;
 // TRANSCRIPTION PROVIDER TRAIT & ERROR TYPES
 // ============================================================================
 
+/// Language preference meaning "detect the spoken language and translate it to English".
+/// English is the only translation target the engines support.
+pub const AUTO_TRANSLATE_LANGUAGE: &str = "auto-translate";
+
+/// Language hint for a provider given the user's preference. Auto-translate on a
+/// provider that can't translate falls back to auto-detected plain transcription.
+pub fn resolve_language(preference: Option<String>, supports_translation: bool) -> Option<String> {
+    match preference {
+        Some(lang) if lang.eq_ignore_ascii_case(AUTO_TRANSLATE_LANGUAGE) && !supports_translation => {
+            log::warn!(
+                "Auto-translate is selected but the active provider can't translate; transcribing in the spoken language instead"
+            );
+            Some("auto".to_string())
+        }
+        other => other,
+    }
+}
+
 /// Granular error types for transcription operations
 #[derive(Debug, Clone)]
 pub enum TranscriptionError {
@@ -37,12 +55,52 @@ impl std::fmt::Display for TranscriptionError {
 
 impl std::error::Error for TranscriptionError {}
 
+/// Word-level timing, in seconds relative to the start of the transcribed audio
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordTiming {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
+}
+
 /// Unified transcription result across all providers
 #[derive(Debug, Clone)]
 pub struct TranscriptResult {
     pub text: String,
     pub confidence: Option<f32>, // None if provider doesn't support confidence scores
     pub is_partial: bool,
+    pub words: Option<Vec<WordTiming>>, // None if provider doesn't support word timings
+}
+
+impl TranscriptResult {
+    /// Build a result from a provider's text, trimming it. Text that trims to nothing
+    /// becomes `no_speech()`, so the other fields never disagree about an empty result.
+    pub fn from_text(text: &str, confidence: Option<f32>, is_partial: bool) -> Self {
+        let text = text.trim();
+        if text.is_empty() {
+            return Self::no_speech();
+        }
+        Self {
+            text: text.to_string(),
+            confidence,
+            is_partial,
+            words: None,
+        }
+    }
+
+    /// Successful transcription that recognized no speech
+    pub fn no_speech() -> Self {
+        Self {
+            text: String::new(),
+            confidence: None,
+            is_partial: false,
+            words: None,
+        }
+    }
+
+    pub fn is_no_speech(&self) -> bool {
+        self.text.trim().is_empty()
+    }
 }
 
 /// Trait for transcription providers (Whisper, Parakeet, future providers)
@@ -56,6 +114,10 @@ pub trait TranscriptionProvider: Send + Sync {
     ///
     /// # Returns
     /// * `TranscriptResult` with text, optional confidence, and partial flag
+    ///
+    /// Audio with no recognizable speech is not an error: return
+    /// `Ok(TranscriptResult::no_speech())` (or build the result with `from_text`).
+    /// The worker skips such chunks and counts them as completed without warning.
     async fn transcribe(
         &self,
         audio: Vec<f32>,
@@ -70,4 +132,48 @@ pub trait TranscriptionProvider: Send + Sync {
 
     /// Get the provider name (for logging/debugging)
     fn provider_name(&self) -> &'static str;
+
+    /// Whether the provider produces its own partial results. Batch-only providers
+    /// return false and can opt into emulated streaming in the worker.
+    fn supports_streaming(&self) -> bool {
+        false
+    }
+
+    /// Whether the provider translates to English when given `AUTO_TRANSLATE_LANGUAGE`.
+    /// Providers that return false get plain transcription instead.
+    fn supports_translation(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_translate_falls_back_without_translation_support() {
+        let translate = Some(AUTO_TRANSLATE_LANGUAGE.to_string());
+        assert_eq!(resolve_language(translate.clone(), true), translate);
+        assert_eq!(resolve_language(translate, false).as_deref(), Some("auto"));
+        assert_eq!(resolve_language(Some("de".to_string()), false).as_deref(), Some("de"));
+        assert_eq!(resolve_language(None, false), None);
+    }
+
+    #[test]
+    fn blank_text_is_no_speech_whatever_the_provider_reports() {
+        // Whisper reports confidence and partial flags even for silence
+        let whisper = TranscriptResult::from_text(" \n", Some(0.42), true);
+        assert!(whisper.is_no_speech());
+        assert_eq!(whisper.confidence, None);
+        assert!(!whisper.is_partial);
+
+        // Parakeet has neither
+        let parakeet = TranscriptResult::from_text("", None, false);
+        assert!(parakeet.is_no_speech());
+
+        let spoken = TranscriptResult::from_text("  hello there ", Some(0.9), false);
+        assert!(!spoken.is_no_speech());
+        assert_eq!(spoken.text, "hello there");
+        assert_eq!(spoken.confidence, Some(0.9));
+    }
 }
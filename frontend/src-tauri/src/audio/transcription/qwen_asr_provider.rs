@@ -2,7 +2,8 @@
 //
 // Qwen3-ASR transcription provider implementation.
 
-use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult, WordTiming};
+use crate::qwen_asr_engine::QwenAsrWordTiming;
 use async_trait::async_trait;
 use regex::Regex;
 use std::sync::{Arc, LazyLock};
@@ -18,6 +19,21 @@ impl QwenAsrProvider {
     }
 }
 
+/// Word timings from a Qwen decode, relative to the chunk (the worker offsets them);
+/// None when the decoder reported none
+pub(crate) fn qwen_word_timings(words: Vec<QwenAsrWordTiming>) -> Option<Vec<WordTiming>> {
+    (!words.is_empty()).then(|| {
+        words
+            .into_iter()
+            .map(|w| WordTiming {
+                text: w.text,
+                start: w.start,
+                end: w.end,
+            })
+            .collect()
+    })
+}
+
 fn clean_qwen_asr_output(text: &str) -> String {
     static LANGUAGE_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
         Regex::new(concat!(
@@ -79,11 +95,14 @@ impl TranscriptionProvider for QwenAsrProvider {
             log::debug!("Qwen3-ASR transcribing with language hint: {}", lang);
         }
 
-        match self.engine.transcribe_audio(audio).await {
-            Ok(text) => Ok(TranscriptResult {
-                text: clean_qwen_asr_output(&text),
-                confidence: None, // Qwen3-ASR doesn't provide confidence scores
-                is_partial: false,
+        match self.engine.transcribe_audio_with_confidence(audio).await {
+            Ok(transcript) => Ok(TranscriptResult {
+                words: qwen_word_timings(transcript.words),
+                ..TranscriptResult::from_text(
+                    &clean_qwen_asr_output(&transcript.text),
+                    Some(transcript.confidence()),
+                    false,
+                )
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
@@ -0,0 +1,67 @@
+// audio/transcription/sink.rs
+//
+// Fan-out of live transcript events. The Tauri event bus is always the primary
+// channel; additional sinks (e.g. the local websocket server) can be registered
+// at runtime and receive the same payloads.
+
+use super::worker::TranscriptUpdate;
+use log::warn;
+use std::sync::{Arc, LazyLock, RwLock};
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// A consumer of live transcript events besides the Tauri frontend
+pub trait TranscriptSink: Send + Sync {
+    /// Unique name, used to replace or remove the sink
+    fn name(&self) -> &'static str;
+
+    /// A finalized (or refinement) transcript segment, as in `transcript-update`
+    fn send_update(&self, update: &TranscriptUpdate);
+
+    /// A streaming partial, as in `transcript-partial`
+    fn send_partial(&self, partial: &serde_json::Value);
+}
+
+static EXTRA_SINKS: LazyLock<RwLock<Vec<Arc<dyn TranscriptSink>>>> =
+    LazyLock::new(|| RwLock::new(Vec::new()));
+
+/// Register a sink, replacing any existing sink with the same name
+pub fn register_sink(sink: Arc<dyn TranscriptSink>) {
+    let mut sinks = EXTRA_SINKS.write().unwrap_or_else(|e| e.into_inner());
+    sinks.retain(|s| s.name() != sink.name());
+    sinks.push(sink);
+}
+
+/// Remove a sink by name
+pub fn unregister_sink(name: &str) {
+    let mut sinks = EXTRA_SINKS.write().unwrap_or_else(|e| e.into_inner());
+    sinks.retain(|s| s.name() != name);
+}
+
+fn extra_sinks() -> Vec<Arc<dyn TranscriptSink>> {
+    EXTRA_SINKS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Emit a `transcript-update` to the frontend and all registered sinks
+pub fn emit_transcript_update<R: Runtime>(
+    app: &AppHandle<R>,
+    update: &TranscriptUpdate,
+) -> tauri::Result<()> {
+    let result = app.emit("transcript-update", update);
+    for sink in extra_sinks() {
+        sink.send_update(update);
+    }
+    result
+}
+
+/// Emit a `transcript-partial` to the frontend and all registered sinks
+pub fn emit_transcript_partial<R: Runtime>(app: &AppHandle<R>, partial: serde_json::Value) {
+    if let Err(e) = app.emit("transcript-partial", &partial) {
+        warn!("Failed to emit transcript partial: {}", e);
+    }
+    for sink in extra_sinks() {
+        sink.send_partial(&partial);
+    }
+}
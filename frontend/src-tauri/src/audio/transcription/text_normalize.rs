@@ -0,0 +1,361 @@
+// audio/transcription/text_normalize.rs
+//
+// Language-specific cleanup of transcript text, applied after engine-specific
+// cleaning (e.g. Qwen language-prefix stripping). Unknown languages pass through
+// unchanged.
+
+use regex::Regex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::LazyLock;
+
+/// Convert spelled-out English numbers ("twenty five") to digits. Off by default.
+static NORMALIZE_SPELLED_NUMBERS: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LanguageFamily {
+    Chinese,
+    Japanese,
+    English,
+}
+
+/// Map a language code ("zh", "en-US") or Qwen language name ("Chinese") to a family
+fn language_family(language: &str) -> Option<LanguageFamily> {
+    let language = language.trim().to_ascii_lowercase();
+    let base = language.split(['-', '_']).next().unwrap_or_default();
+    match base {
+        "zh" | "yue" | "chinese" | "cantonese" => Some(LanguageFamily::Chinese),
+        "ja" | "japanese" => Some(LanguageFamily::Japanese),
+        "en" | "english" => Some(LanguageFamily::English),
+        _ => None,
+    }
+}
+
+/// Extract the language Qwen3-ASR tagged its raw output with (`language Chinese...`)
+pub fn detect_qwen_language(raw: &str) -> Option<String> {
+    static QWEN_LANGUAGE_RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?i)^\s*language\s+([a-z]+)").expect("valid regex"));
+
+    let name = QWEN_LANGUAGE_RE.captures(raw)?.get(1)?.as_str();
+    // The tag is glued to the transcript ("language EnglishWhat's"), so only
+    // accept a known name as prefix
+    ["english", "chinese", "japanese", "cantonese", "yue", "korean"]
+        .iter()
+        .find(|known| name.to_ascii_lowercase().starts_with(*known))
+        .map(|known| known.to_string())
+}
+
+/// Apply per-language normalization to cleaned transcript text.
+/// `language` may be a code or a Qwen language name; `None`/unknown is a no-op.
+pub fn normalize_for_language(text: &str, language: Option<&str>) -> String {
+    normalize_with_options(
+        text,
+        language,
+        NORMALIZE_SPELLED_NUMBERS.load(Ordering::Relaxed),
+    )
+}
+
+fn normalize_with_options(text: &str, language: Option<&str>, spelled_numbers: bool) -> String {
+    match language.and_then(language_family) {
+        Some(LanguageFamily::Chinese) => collapse_cjk_spaces(&fullwidth_punctuation(text, false)),
+        Some(LanguageFamily::Japanese) => collapse_cjk_spaces(&fullwidth_punctuation(text, true)),
+        Some(LanguageFamily::English) if spelled_numbers => spelled_numbers_to_digits(text),
+        _ => text.to_string(),
+    }
+}
+
+/// Han, kana and CJK/full-width punctuation: scripts written without spaces.
+/// Hangul is excluded because Korean uses word spacing.
+fn is_unspaced_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3000}'..='\u{303F}'   // CJK symbols and punctuation
+        | '\u{3040}'..='\u{30FF}' // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+        | '\u{FF00}'..='\u{FFEF}' // Half/full-width forms
+    )
+}
+
+/// Drop whitespace the model inserted between CJK characters
+fn collapse_cjk_spaces(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            let run_end = chars[i..]
+                .iter()
+                .position(|c| !c.is_whitespace())
+                .map_or(chars.len(), |n| i + n);
+            let between_cjk = i > 0
+                && run_end < chars.len()
+                && is_unspaced_cjk(chars[i - 1])
+                && is_unspaced_cjk(chars[run_end]);
+            if !between_cjk {
+                out.extend(&chars[i..run_end]);
+            }
+            i = run_end;
+        } else {
+            out.push(chars[i]);
+            i += 1;
+        }
+    }
+
+    out
+}
+
+/// Replace ASCII punctuation that directly follows a CJK character with its
+/// full-width form. Japanese uses `、` for commas.
+fn fullwidth_punctuation(text: &str, japanese: bool) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        let after_cjk = i > 0 && is_unspaced_cjk(chars[i - 1]);
+        // Keep "3.5" and similar intact
+        let before_digit = chars.get(i + 1).is_some_and(|n| n.is_ascii_digit());
+        let replacement = match c {
+            ',' if japanese => Some('、'),
+            ',' => Some('，'),
+            '.' if !before_digit => Some('。'),
+            '?' => Some('？'),
+            '!' => Some('！'),
+            ':' if !japanese => Some('：'),
+            ';' if !japanese => Some('；'),
+            _ => None,
+        };
+        match replacement {
+            Some(full) if after_cjk => out.push(full),
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberToken {
+    Unit(u64),
+    Teen(u64),
+    Ten(u64),
+    Hundred,
+    Scale(u64),
+    And,
+}
+
+fn number_token(word: &str) -> Option<NumberToken> {
+    const UNITS: [&str; 10] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    const TEENS: [&str; 10] = [
+        "ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen",
+        "eighteen", "nineteen",
+    ];
+    const TENS: [&str; 8] = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    let word = word.to_ascii_lowercase();
+    if let Some(i) = UNITS.iter().position(|w| *w == word) {
+        return Some(NumberToken::Unit(i as u64));
+    }
+    if let Some(i) = TEENS.iter().position(|w| *w == word) {
+        return Some(NumberToken::Teen(10 + i as u64));
+    }
+    if let Some(i) = TENS.iter().position(|w| *w == word) {
+        return Some(NumberToken::Ten(20 + 10 * i as u64));
+    }
+    match word.as_str() {
+        "hundred" => Some(NumberToken::Hundred),
+        "thousand" => Some(NumberToken::Scale(1_000)),
+        "million" => Some(NumberToken::Scale(1_000_000)),
+        "and" => Some(NumberToken::And),
+        _ => None,
+    }
+}
+
+/// Whether `token` may follow `last` in a well-formed number
+fn token_can_follow(last: Option<NumberToken>, token: NumberToken) -> bool {
+    use NumberToken::*;
+
+    // "zero" only stands alone
+    if last == Some(Unit(0)) || (last.is_some() && token == Unit(0)) {
+        return false;
+    }
+    match token {
+        Unit(_) => matches!(last, None | Some(Ten(_) | Hundred | Scale(_) | And)),
+        Teen(_) | Ten(_) => matches!(last, None | Some(Hundred | Scale(_) | And)),
+        Hundred => matches!(last, Some(Unit(_) | Teen(_) | Ten(_))),
+        Scale(_) => matches!(last, Some(Unit(_) | Teen(_) | Ten(_) | Hundred)),
+        And => matches!(last, Some(Hundred | Scale(_))),
+    }
+}
+
+/// Parse a run of English number words; `None` if it isn't a well-formed number
+fn parse_number_words(words: &[&str]) -> Option<u64> {
+    let mut total = 0u64;
+    let mut current = 0u64;
+    let mut last: Option<NumberToken> = None;
+    let mut last_scale = u64::MAX;
+
+    for word in words {
+        let token = number_token(word)?;
+        if !token_can_follow(last, token) {
+            return None;
+        }
+        // "fifteen hundred" is fine, "three hundred hundred" or "million thousand million" is not
+        match token {
+            NumberToken::Hundred if current >= 100 => return None,
+            NumberToken::Scale(scale) if scale >= last_scale => return None,
+            _ => {}
+        }
+
+        match token {
+            NumberToken::Unit(v) | NumberToken::Teen(v) | NumberToken::Ten(v) => current += v,
+            NumberToken::Hundred => current *= 100,
+            NumberToken::Scale(scale) => {
+                total += current * scale;
+                current = 0;
+                last_scale = scale;
+            }
+            NumberToken::And => {}
+        }
+        last = Some(token);
+    }
+
+    // A trailing "and" means the run wasn't a single number
+    (last != Some(NumberToken::And)).then_some(total + current)
+}
+
+/// Replace spelled-out numbers of 10 and above with digits. Smaller numbers
+/// stay as words, which also avoids rewriting "no one" or "the one".
+fn spelled_numbers_to_digits(text: &str) -> String {
+    static NUMBER_RUN_RE: LazyLock<Regex> = LazyLock::new(|| {
+        let word = concat!(
+            r"(?:zero|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|",
+            r"thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen|twenty|",
+            r"thirty|forty|fifty|sixty|seventy|eighty|ninety|hundred|thousand|million)"
+        );
+        Regex::new(&format!(r"(?i)\b{word}(?:(?:\s+|-)(?:and\s+)?{word})*\b"))
+            .expect("valid regex")
+    });
+
+    NUMBER_RUN_RE
+        .replace_all(text, |caps: &regex::Captures| {
+            let run = &caps[0];
+            let words: Vec<&str> = run
+                .split(|c: char| c.is_whitespace() || c == '-')
+                .filter(|w| !w.is_empty())
+                .collect();
+            match parse_number_words(&words) {
+                Some(value) if value >= 10 => value.to_string(),
+                _ => run.to_string(),
+            }
+        })
+        .into_owned()
+}
+
+/// Enable or disable converting spelled-out English numbers to digits
+#[tauri::command]
+pub async fn set_normalize_spelled_numbers(enabled: bool) -> Result<(), String> {
+    NORMALIZE_SPELLED_NUMBERS.store(enabled, Ordering::Relaxed);
+    log::info!("Spelled-out number normalization set to {}", enabled);
+    Ok(())
+}
+
+/// Whether spelled-out English numbers are converted to digits
+#[tauri::command]
+pub async fn get_normalize_spelled_numbers() -> Result<bool, String> {
+    Ok(NORMALIZE_SPELLED_NUMBERS.load(Ordering::Relaxed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chinese_collapses_inter_character_spaces() {
+        assert_eq!(
+            normalize_with_options("我 们 今 天 开 会 。", Some("zh"), false),
+            "我们今天开会。"
+        );
+        // Spaces around Latin words are kept
+        assert_eq!(
+            normalize_with_options("我们用 Rust 写 代码", Some("Chinese"), false),
+            "我们用 Rust 写代码"
+        );
+    }
+
+    #[test]
+    fn chinese_uses_fullwidth_punctuation() {
+        assert_eq!(
+            normalize_with_options("你好, 今天几点? 三点.", Some("zh-CN"), false),
+            "你好，今天几点？三点。"
+        );
+        assert_eq!(normalize_with_options("版本3.5", Some("zh"), false), "版本3.5");
+    }
+
+    #[test]
+    fn japanese_uses_japanese_punctuation() {
+        assert_eq!(
+            normalize_with_options("はい, そう です.", Some("ja"), false),
+            "はい、そうです。"
+        );
+    }
+
+    #[test]
+    fn korean_keeps_word_spacing() {
+        let text = "오늘 회의를 시작하겠습니다.";
+        assert_eq!(normalize_with_options(text, Some("ko"), true), text);
+    }
+
+    #[test]
+    fn english_spelled_numbers_only_when_enabled() {
+        let text = "We have twenty-five people and one hundred and five chairs";
+        assert_eq!(normalize_with_options(text, Some("en"), false), text);
+        assert_eq!(
+            normalize_with_options(text, Some("english"), true),
+            "We have 25 people and 105 chairs"
+        );
+    }
+
+    #[test]
+    fn english_small_and_malformed_numbers_stay_words() {
+        assert_eq!(
+            normalize_with_options("no one saw two or three of them", Some("en"), true),
+            "no one saw two or three of them"
+        );
+        assert_eq!(
+            normalize_with_options("one two three four", Some("en"), true),
+            "one two three four"
+        );
+        assert_eq!(
+            normalize_with_options("in fifteen hundred or two thousand and twenty four", Some("en"), true),
+            "in 1500 or 2024"
+        );
+        assert_eq!(
+            normalize_with_options("thousand million thousand", Some("en"), true),
+            "thousand million thousand"
+        );
+    }
+
+    #[test]
+    fn unknown_language_is_noop() {
+        let text = "我 们 twenty five";
+        assert_eq!(normalize_with_options(text, None, true), text);
+        assert_eq!(normalize_with_options(text, Some("auto"), true), text);
+        assert_eq!(normalize_with_options(text, Some("fr"), true), text);
+    }
+
+    #[test]
+    fn detects_qwen_language_tag() {
+        assert_eq!(detect_qwen_language("language Chinese吃吃吃。").as_deref(), Some("chinese"));
+        assert_eq!(
+            detect_qwen_language("language EnglishWhat's your name?").as_deref(),
+            Some("english")
+        );
+        assert_eq!(detect_qwen_language("language None Hello"), None);
+        assert_eq!(detect_qwen_language("Hello"), None);
+    }
+}
@@ -0,0 +1,268 @@
+// audio/transcription/text_overlap.rs
+//
+// Removal of text repeated across consecutive transcripts, shared by the
+// transcription worker and engines that stitch overlapping audio windows.
+
+// Shortest overlap remove_text_overlap strips between segments, to avoid false positives
+const MIN_TEXT_OVERLAP_CHARS: usize = 4;
+// Four Chinese/Japanese characters are often a whole phrase, so CJK segments must share more
+const MIN_CJK_TEXT_OVERLAP_CHARS: usize = 6;
+// Share of letters that must be CJK for a segment to count as CJK text
+const CJK_TEXT_RATIO: f64 = 0.5;
+// Fillers that recur between CJK segments without the audio being repeated; an overlap
+// made up only of these is kept
+const CJK_FILLER_PHRASES: &[&str] = &[
+    "就是说", "就是", "然后", "那个", "这个", "我觉得", "对对对", "对", "嗯", "啊", "呃",
+    "えーと", "えっと", "あの", "そうですね", "なんか",
+];
+
+/// Remove overlapping text between consecutive transcript segments.
+///
+/// When VAD splits continuous speech, adjacent chunks can produce overlapping transcriptions.
+/// This function finds the longest suffix of `previous` that is a prefix of `current`
+/// and returns `current` with that overlap removed. Space-delimited text is aligned on
+/// whole words so a shared partial token ("the roadm" / "roadmap") is never cut.
+pub(crate) fn remove_text_overlap(previous: &str, current: &str) -> String {
+    let previous = previous.trim();
+    let current = current.trim_start();
+
+    if previous.is_empty() || current.is_empty() {
+        return current.to_string();
+    }
+
+    if is_cjk_text(previous) && is_cjk_text(current) {
+        return remove_char_overlap(previous, current, MIN_CJK_TEXT_OVERLAP_CHARS, CJK_FILLER_PHRASES);
+    }
+    // Scripts written without spaces (e.g. Chinese, Japanese) have no word boundaries
+    if !previous.contains(' ') && !current.contains(' ') {
+        return remove_char_overlap(previous, current, MIN_TEXT_OVERLAP_CHARS, &[]);
+    }
+
+    let prev_words: Vec<&str> = previous.split_whitespace().collect();
+    let curr_words: Vec<&str> = current.split_whitespace().collect();
+    let max_check = curr_words.len().min(prev_words.len());
+
+    // Longest run of whole words; it must still span the minimum overlap length
+    let best_overlap = (1..=max_check).rev().find(|&overlap_len| {
+        let overlap = &curr_words[..overlap_len];
+        let separators = overlap_len - 1;
+        let overlap_chars = overlap.iter().map(|w| w.chars().count()).sum::<usize>() + separators;
+        prev_words[prev_words.len() - overlap_len..] == *overlap && overlap_chars >= MIN_TEXT_OVERLAP_CHARS
+    });
+
+    match best_overlap {
+        Some(overlap_len) => {
+            let last_word = curr_words[overlap_len - 1];
+            // Byte offset just past the last overlapping word in `current`
+            let end = last_word.as_ptr() as usize - current.as_ptr() as usize + last_word.len();
+            log::info!(
+                "📝 Removed {} words of text overlap between consecutive segments",
+                overlap_len
+            );
+            current[end..].trim_start().to_string()
+        }
+        None => current.to_string(),
+    }
+}
+
+/// Character-level overlap removal for text without word separators. An overlap
+/// consisting only of `fillers` is left in place.
+fn remove_char_overlap(previous: &str, current: &str, min_overlap: usize, fillers: &[&str]) -> String {
+    // Find the longest suffix of `previous` that matches a prefix of `current`.
+    // We compare character-by-character using a sliding window.
+    let prev_chars: Vec<char> = previous.chars().collect();
+    let curr_chars: Vec<char> = current.chars().collect();
+
+    let mut best_overlap = 0;
+
+    // IMPORTANT: we must allow overlap to exceed half of the current text.
+    // In continuous speech, next segment can be mostly repeated context with
+    // only a few new trailing words.
+    let max_check = curr_chars.len().min(prev_chars.len());
+
+    for overlap_len in min_overlap..=max_check {
+        let prev_suffix_start = prev_chars.len() - overlap_len;
+        let prev_suffix = &prev_chars[prev_suffix_start..];
+        let curr_prefix = &curr_chars[..overlap_len];
+
+        if prev_suffix == curr_prefix {
+            best_overlap = overlap_len;
+        }
+    }
+
+    if best_overlap >= min_overlap {
+        let overlap: String = curr_chars[..best_overlap].iter().collect();
+        if is_filler_only(&overlap, fillers) {
+            log::info!("📝 Overlap '{}' is only filler words, keeping it", overlap);
+            return current.to_string();
+        }
+        let deduped: String = curr_chars[best_overlap..].iter().collect();
+        log::info!(
+            "📝 Removed {} chars of text overlap between consecutive segments",
+            best_overlap
+        );
+        deduped.trim_start().to_string()
+    } else {
+        current.to_string()
+    }
+}
+
+/// Whether `text`, ignoring punctuation and whitespace, is a run of filler phrases.
+fn is_filler_only(text: &str, fillers: &[&str]) -> bool {
+    if fillers.is_empty() {
+        return false;
+    }
+    let letters: String = text.chars().filter(|c| c.is_alphanumeric()).collect();
+    let mut rest = letters.as_str();
+    // Fillers are listed longest-first where one contains another
+    while let Some(filler) = fillers.iter().find(|f| rest.starts_with(**f)) {
+        rest = &rest[filler.len()..];
+    }
+    rest.is_empty()
+}
+
+/// Whether most letters in `text` are CJK characters.
+fn is_cjk_text(text: &str) -> bool {
+    let (cjk, letters) = text
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .fold((0usize, 0usize), |(cjk, letters), c| (cjk + usize::from(is_cjk_char(c)), letters + 1));
+    letters > 0 && cjk as f64 / letters as f64 > CJK_TEXT_RATIO
+}
+
+pub(crate) fn is_cjk_char(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3040..=0x30FF // Hiragana, Katakana
+            | 0x3400..=0x4DBF // CJK Extension A
+            | 0x4E00..=0x9FFF // CJK Unified Ideographs
+            | 0xAC00..=0xD7AF // Hangul Syllables
+            | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+    )
+}
+
+/// Whether `c` is written without spaces around it: Chinese and Japanese
+/// characters and full-width punctuation. Korean separates words with spaces.
+fn is_unspaced_char(c: char) -> bool {
+    let hangul = matches!(c as u32, 0xAC00..=0xD7AF);
+    (is_cjk_char(c) && !hangul) || matches!(c as u32, 0x3000..=0x303F | 0xFF00..=0xFFEF)
+}
+
+/// Append the next segment's text to a running transcript, separated by a space
+/// unless either side of the join is unspaced (Chinese/Japanese) text.
+pub(crate) fn append_transcript(transcript: &mut String, text: &str) {
+    let text = text.trim();
+    if text.is_empty() {
+        return;
+    }
+    let unspaced = transcript.chars().next_back().is_some_and(is_unspaced_char)
+        || text.chars().next().is_some_and(is_unspaced_char);
+    if !transcript.is_empty() && !unspaced {
+        transcript.push(' ');
+    }
+    transcript.push_str(text);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{append_transcript, remove_text_overlap};
+
+    #[test]
+    fn appends_with_spaces_only_between_spaced_scripts() {
+        let mut transcript = String::new();
+        append_transcript(&mut transcript, "ship it");
+        append_transcript(&mut transcript, " friday ");
+        append_transcript(&mut transcript, "");
+        assert_eq!(transcript, "ship it friday");
+
+        let mut transcript = "我们今天讨论".to_string();
+        append_transcript(&mut transcript, "项目进度。");
+        append_transcript(&mut transcript, "然后");
+        assert_eq!(transcript, "我们今天讨论项目进度。然后");
+
+        let mut transcript = "회의를".to_string();
+        append_transcript(&mut transcript, "시작합니다");
+        assert_eq!(transcript, "회의를 시작합니다");
+    }
+
+    #[test]
+    fn removes_overlap_larger_than_half_of_current() {
+        let previous = "let's review the roadmap for q2 and q3";
+        let current = "roadmap for q2 and q3 plus hiring plan";
+        assert_eq!(remove_text_overlap(previous, current), "plus hiring plan");
+    }
+
+    #[test]
+    fn keeps_whole_word_when_only_a_prefix_overlaps() {
+        let previous = "next we need to finalize the roadm";
+        let current = "roadmap for the next quarter";
+        assert_eq!(remove_text_overlap(previous, current), current);
+
+        // A lone word still isn't cut down to its tail
+        assert_eq!(remove_text_overlap(previous, "roadmap"), "roadmap");
+    }
+
+    #[test]
+    fn removes_multi_word_overlap_on_word_boundaries() {
+        let previous = "so the main goal is shipping the new onboarding flow";
+        let current = "the new onboarding flow before the end of march";
+        let deduped = remove_text_overlap(previous, current);
+        assert_eq!(deduped, "before the end of march");
+        assert!(deduped
+            .split_whitespace()
+            .all(|word| current.split_whitespace().any(|w| w == word)));
+    }
+
+    #[test]
+    fn removes_char_overlap_for_text_without_spaces() {
+        assert_eq!(remove_text_overlap("我们今天讨论项目进度", "讨论项目进度和预算"), "和预算");
+    }
+
+    #[test]
+    fn keeps_new_chinese_utterance_sharing_a_short_phrase() {
+        // "这个问题" is a whole phrase here, not repeated audio
+        let previous = "我们下周再讨论这个问题";
+        let current = "这个问题我们上周已经解决了";
+        assert_eq!(remove_text_overlap(previous, current), current);
+
+        let previous = "这次发布主要是修复登录问题";
+        let current = "登录问题之外还有支付的问题";
+        assert_eq!(remove_text_overlap(previous, current), current);
+    }
+
+    #[test]
+    fn keeps_chinese_overlap_made_of_fillers() {
+        let previous = "这个方案的成本太高了，我觉得就是说";
+        let current = "我觉得就是说我们可以先做个试点";
+        assert_eq!(remove_text_overlap(previous, current), current);
+    }
+
+    #[test]
+    fn removes_long_chinese_and_japanese_overlap() {
+        assert_eq!(
+            remove_text_overlap("下一步我们需要确认上线时间表", "确认上线时间表和预算分配"),
+            "和预算分配"
+        );
+        assert_eq!(
+            remove_text_overlap("来週のミーティングで予算を決めます", "予算を決めますので資料をお願いします"),
+            "ので資料をお願いします"
+        );
+    }
+
+    #[test]
+    fn removes_full_duplicate_segment() {
+        let previous = "we should align on launch timeline";
+        let current = "launch timeline";
+        assert_eq!(remove_text_overlap(previous, current), "");
+    }
+
+    #[test]
+    fn keeps_text_when_no_overlap() {
+        let previous = "budget approved yesterday";
+        let current = "design review starts tomorrow";
+        assert_eq!(
+            remove_text_overlap(previous, current),
+            "design review starts tomorrow"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+// audio/transcription/websocket_sink.rs
+//
+// Optional local websocket server that mirrors live transcript events for
+// external tools (OBS captions, a second screen, scripts).
+//
+// Every connected client receives one JSON text frame per event:
+//
+//   { "type": "transcript-update",  "payload": <TranscriptUpdate> }
+//   { "type": "transcript-partial", "payload": { "chunk_id", "text", "chunk_start_time",
+//                                                "audio_start_time", "audio_end_time" } }
+//
+// `TranscriptUpdate` is serialized exactly as the `transcript-update` Tauri event.
+// Incoming client messages are ignored. The server binds to 127.0.0.1 unless a
+// non-loopback address is requested with `allow_remote`.
+
+use super::sink::{self, TranscriptSink};
+use super::worker::TranscriptUpdate;
+use futures_util::{SinkExt, StreamExt};
+use log::{info, warn};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_util::sync::CancellationToken;
+
+const SINK_NAME: &str = "websocket";
+const DEFAULT_BIND_ADDR: &str = "127.0.0.1:8765";
+// Slow clients that fall this far behind skip ahead instead of stalling others
+const BROADCAST_CAPACITY: usize = 256;
+
+struct WebSocketSink {
+    sender: broadcast::Sender<String>,
+}
+
+impl WebSocketSink {
+    fn broadcast(&self, event_type: &str, payload: serde_json::Value) {
+        let message = serde_json::json!({ "type": event_type, "payload": payload }).to_string();
+        // Err only means no client is connected right now
+        let _ = self.sender.send(message);
+    }
+}
+
+impl TranscriptSink for WebSocketSink {
+    fn name(&self) -> &'static str {
+        SINK_NAME
+    }
+
+    fn send_update(&self, update: &TranscriptUpdate) {
+        match serde_json::to_value(update) {
+            Ok(payload) => self.broadcast("transcript-update", payload),
+            Err(e) => warn!("Failed to serialize transcript update for websocket: {}", e),
+        }
+    }
+
+    fn send_partial(&self, partial: &serde_json::Value) {
+        self.broadcast("transcript-partial", partial.clone());
+    }
+}
+
+struct RunningServer {
+    addr: SocketAddr,
+    cancel: CancellationToken,
+}
+
+static SERVER: Mutex<Option<RunningServer>> = Mutex::new(None);
+
+async fn handle_client(
+    stream: TcpStream,
+    peer: SocketAddr,
+    mut receiver: broadcast::Receiver<String>,
+    cancel: CancellationToken,
+) {
+    let mut ws = match tokio_tungstenite::accept_async(stream).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            warn!("Transcript websocket handshake with {} failed: {}", peer, e);
+            return;
+        }
+    };
+    info!("Transcript websocket client connected: {}", peer);
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                let _ = ws.close(None).await;
+                break;
+            }
+            message = receiver.recv() => match message {
+                Ok(text) => {
+                    if ws.send(Message::Text(text)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("Transcript websocket client {} lagged, skipped {} events", peer, skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            incoming = ws.next() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {} // Clients are read-only
+            },
+        }
+    }
+
+    info!("Transcript websocket client disconnected: {}", peer);
+}
+
+/// Start the transcript websocket server. Returns the bound address.
+#[tauri::command]
+pub async fn start_transcript_websocket(
+    bind_addr: Option<String>,
+    allow_remote: Option<bool>,
+) -> Result<String, String> {
+    if let Some(server) = SERVER.lock().unwrap().as_ref() {
+        return Err(format!(
+            "Transcript websocket already running on {}",
+            server.addr
+        ));
+    }
+
+    let bind_addr = bind_addr.unwrap_or_else(|| DEFAULT_BIND_ADDR.to_string());
+    let addr: SocketAddr = bind_addr
+        .parse()
+        .map_err(|e| format!("Invalid websocket bind address '{}': {}", bind_addr, e))?;
+
+    if !addr.ip().is_loopback() && !allow_remote.unwrap_or(false) {
+        return Err(format!(
+            "Refusing to expose transcripts on non-loopback address {} without allow_remote",
+            addr
+        ));
+    }
+
+    let listener = TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Failed to bind transcript websocket on {}: {}", addr, e))?;
+    let addr = listener.local_addr().map_err(|e| e.to_string())?;
+
+    let (sender, _) = broadcast::channel(BROADCAST_CAPACITY);
+    let cancel = CancellationToken::new();
+
+    {
+        let mut server = SERVER.lock().unwrap();
+        if server.is_some() {
+            return Err("Transcript websocket was started concurrently".to_string());
+        }
+        *server = Some(RunningServer {
+            addr,
+            cancel: cancel.clone(),
+        });
+    }
+
+    sink::register_sink(Arc::new(WebSocketSink {
+        sender: sender.clone(),
+    }));
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, peer)) => {
+                        tokio::spawn(handle_client(stream, peer, sender.subscribe(), cancel.clone()));
+                    }
+                    Err(e) => warn!("Transcript websocket accept failed: {}", e),
+                },
+            }
+        }
+        info!("Transcript websocket server on {} stopped", addr);
+    });
+
+    info!("Transcript websocket server listening on ws://{}", addr);
+    Ok(addr.to_string())
+}
+
+/// Stop the transcript websocket server and disconnect all clients
+#[tauri::command]
+pub async fn stop_transcript_websocket() -> Result<(), String> {
+    let server = SERVER.lock().unwrap().take();
+    match server {
+        Some(server) => {
+            sink::unregister_sink(SINK_NAME);
+            server.cancel.cancel();
+            Ok(())
+        }
+        None => Err("Transcript websocket is not running".to_string()),
+    }
+}
+
+/// Get the address the transcript websocket is listening on, if running
+#[tauri::command]
+pub async fn get_transcript_websocket_status() -> Result<Option<String>, String> {
+    Ok(SERVER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|server| server.addr.to_string()))
+}
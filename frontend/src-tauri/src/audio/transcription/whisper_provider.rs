@@ -29,11 +29,9 @@ impl TranscriptionProvider for WhisperProvider {
             .transcribe_audio_with_confidence(audio, language)
             .await
         {
-            Ok((text, confidence, is_partial)) => Ok(TranscriptResult {
-                text: text.trim().to_string(),
-                confidence: Some(confidence),
-                is_partial,
-            }),
+            Ok((text, confidence, is_partial)) => {
+                Ok(TranscriptResult::from_text(&text, Some(confidence), is_partial))
+            }
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
     }
@@ -49,4 +47,8 @@ impl TranscriptionProvider for WhisperProvider {
     fn provider_name(&self) -> &'static str {
         "Whisper"
     }
+
+    fn supports_translation(&self) -> bool {
+        true
+    }
 }
@@ -3,12 +3,18 @@
 // Parallel transcription worker pool and chunk processing logic.
 
 use super::engine::TranscriptionEngine;
-use super::provider::TranscriptionError;
-use crate::audio::AudioChunk;
+use super::metrics::{self, DropReason};
+use super::provider::{resolve_language, TranscriptResult, TranscriptionError, WordTiming};
+use super::text_normalize::{detect_qwen_language, normalize_for_language};
+use super::text_overlap::{append_transcript, is_cjk_char, remove_text_overlap};
+use crate::audio::{AudioChunk, ChunkPriority, RecordingDeviceType};
+use crate::qwen_asr_engine::{clean_qwen_asr_output, QwenAsrTranscript, QwenDecoderContext};
+use futures_util::FutureExt;
 use log::{error, info, warn};
-use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::collections::{BTreeMap, VecDeque};
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, LazyLock};
 use tauri::{AppHandle, Emitter, Runtime};
 
@@ -19,6 +25,7 @@ static SEQUENCE_COUNTER: AtomicU64 = AtomicU64::new(0);
 struct LastTranscriptState {
     text: String,
     audio_end_time: Option<f64>,
+    words: Option<Vec<WordTiming>>, // Recording-relative word timings, when available
 }
 
 // Track the last emitted transcript for overlap deduplication.
@@ -29,12 +36,495 @@ static LAST_TRANSCRIPT_STATE: LazyLock<std::sync::Mutex<LastTranscriptState>> =
 // Speech detection flag - reset per recording session
 static SPEECH_DETECTED_EMITTED: AtomicBool = AtomicBool::new(false);
 
+// Minimum words a finalized segment needs to be emitted (0 = filter disabled)
+static MIN_WORDS_TO_EMIT: AtomicUsize = AtomicUsize::new(0);
+
+// CJK text has no spaces between words; count this many characters as one word
+const CJK_CHARS_PER_WORD: usize = 2;
+
+// Emulated streaming for batch providers: re-transcribe growing prefixes of a chunk
+// to emit partials. Off by default since every partial is an extra API call.
+static EMULATED_STREAMING_ENABLED: AtomicBool = AtomicBool::new(false);
+const EMULATED_STREAMING_WINDOW_SAMPLES: usize = 16000 * 3; // 3s steps at 16kHz
+const EMULATED_STREAMING_MAX_PARTIALS: usize = 4;
+
+/// Enable emulated partials for providers without native streaming (multiplies API calls)
+#[tauri::command]
+pub async fn set_emulated_streaming_enabled(enabled: bool) -> Result<(), String> {
+    EMULATED_STREAMING_ENABLED.store(enabled, Ordering::SeqCst);
+    info!("Emulated streaming for batch providers {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_emulated_streaming_enabled() -> Result<bool, String> {
+    Ok(EMULATED_STREAMING_ENABLED.load(Ordering::SeqCst))
+}
+
+// Make sure the model has run its warmup decode before the first chunk, so that
+// chunk isn't slowed by buffer allocation. Only Qwen ASR has a warmup step.
+static PREWARM_MODEL_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Run the model's warmup decode while workers start, before the first chunk arrives
+#[tauri::command]
+pub async fn set_transcription_prewarm_enabled(enabled: bool) -> Result<(), String> {
+    PREWARM_MODEL_ENABLED.store(enabled, Ordering::SeqCst);
+    info!("Model prewarm before the first chunk {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_transcription_prewarm_enabled() -> Result<bool, String> {
+    Ok(PREWARM_MODEL_ENABLED.load(Ordering::SeqCst))
+}
+
+// Warn about segment timing that would break playback sync (on by default in debug builds)
+static TIMING_VALIDATION_ENABLED: AtomicBool = AtomicBool::new(cfg!(debug_assertions));
+const TIMING_OVERLAP_TOLERANCE_SECS: f64 = 0.2;
+const TIMING_MAX_GAP_SECS: f64 = 60.0;
+
+/// Enable warnings for overlapping or widely separated segment timestamps
+#[tauri::command]
+pub async fn set_transcript_timing_validation(enabled: bool) -> Result<(), String> {
+    TIMING_VALIDATION_ENABLED.store(enabled, Ordering::SeqCst);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_transcript_timing_validation() -> Result<bool, String> {
+    Ok(TIMING_VALIDATION_ENABLED.load(Ordering::SeqCst))
+}
+
+/// A timing inconsistency between a new final segment and the previous one
+#[derive(Debug, PartialEq)]
+enum TimingIssue {
+    Overlap(f64), // Seconds the new segment starts before the previous one ended
+    Gap(f64),     // Seconds between the previous end and the new start
+}
+
+fn check_segment_timing(previous_end: Option<f64>, start: f64, is_refinement: bool) -> Option<TimingIssue> {
+    // Refinements re-cover earlier audio on purpose
+    if is_refinement {
+        return None;
+    }
+    let gap = start - previous_end?;
+    if gap < -TIMING_OVERLAP_TOLERANCE_SECS {
+        Some(TimingIssue::Overlap(-gap))
+    } else if gap > TIMING_MAX_GAP_SECS {
+        Some(TimingIssue::Gap(gap))
+    } else {
+        None
+    }
+}
+
+/// Settings captured once when a recording's transcription starts. Chunks read
+/// these instead of the live globals, so changing a setting mid-recording
+/// doesn't change how the rest of that recording is transcribed or filtered.
+#[derive(Debug, Clone)]
+struct SessionConfig {
+    provider: String,
+    model: Option<String>,
+    language: Option<String>,
+    confidence_threshold: f32,
+    min_words: usize,
+    emulated_streaming: bool,
+    timing_validation: bool,
+    dry_run: bool,
+    live_refinement: LiveRefinementSettings,
+    max_workers: usize,
+    prewarm: bool,
+    /// Rolling Qwen decoder prompt shared by this recording's workers
+    qwen_decoder_context: Arc<QwenDecoderContext>,
+}
+
+impl SessionConfig {
+    async fn snapshot(engine: &TranscriptionEngine) -> Self {
+        let confidence_threshold = match engine {
+            TranscriptionEngine::Whisper(_)
+            | TranscriptionEngine::QwenAsr(_)
+            | TranscriptionEngine::Provider(_) => 0.3,
+            TranscriptionEngine::Parakeet(_) => 0.0, // Parakeet has no confidence, accept all
+        };
+        Self {
+            provider: engine.provider_name().to_string(),
+            model: engine.get_current_model().await,
+            language: resolve_language(
+                crate::get_language_preference_internal(),
+                engine.supports_translation(),
+            ),
+            confidence_threshold,
+            min_words: MIN_WORDS_TO_EMIT.load(Ordering::SeqCst),
+            emulated_streaming: EMULATED_STREAMING_ENABLED.load(Ordering::SeqCst),
+            timing_validation: TIMING_VALIDATION_ENABLED.load(Ordering::SeqCst),
+            dry_run: DRY_RUN_ENABLED.load(Ordering::SeqCst),
+            live_refinement: live_refinement_settings(),
+            max_workers: MAX_TRANSCRIPTION_WORKERS.load(Ordering::SeqCst),
+            prewarm: PREWARM_MODEL_ENABLED.load(Ordering::SeqCst),
+            qwen_decoder_context: Arc::new(QwenDecoderContext::default()),
+        }
+    }
+}
+
+/// End offsets (in samples) of the growing prefixes transcribed for emulated partials.
+/// The full chunk is excluded since the final transcription covers it.
+fn emulated_partial_windows(total_samples: usize, window_samples: usize, max_partials: usize) -> Vec<usize> {
+    if window_samples == 0 {
+        return Vec::new();
+    }
+    (1..=max_partials)
+        .map(|i| i * window_samples)
+        .take_while(|&end| end < total_samples)
+        .collect()
+}
+
+// How long a QwenASR decode may keep running after the recording's input ended
+// before it is stopped and its result discarded, so one stuck chunk can't hold up
+// shutdown. Builds whose decoder can't be interrupted still wait for it to finish.
+const SHUTDOWN_DISCARD_GRACE_SECS: u64 = 10;
+// How long shutdown waits for the workers once the recording's input ended. Longer
+// than any single decode, so it only trips when a worker can no longer finish.
+const WORKER_SHUTDOWN_TIMEOUT_SECS: u64 = 120;
+
+// Audio dispatched to the workers but not yet picked up, in milliseconds
+static BUFFERED_AUDIO_MS: AtomicU64 = AtomicU64::new(0);
+// Audio sent by the pipeline but not yet taken by the dispatcher, in milliseconds
+static PENDING_AUDIO_MS: AtomicU64 = AtomicU64::new(0);
+// Chunks the pipeline dropped because the backlog was full, this recording
+static DROPPED_BACKLOG_CHUNKS: AtomicU64 = AtomicU64::new(0);
+
+// Cap on BUFFERED_AUDIO_MS in seconds (0 = unlimited). At the cap the dispatcher stops
+// taking chunks until the workers catch up, so the worker queues stay bounded. Chunks
+// then wait in the recording channel, which the producer bounds in turn: see
+// `admit_transcription_chunk`.
+static MAX_BUFFERED_AUDIO_SECS: AtomicU64 = AtomicU64::new(600);
+const MAX_BUFFERED_AUDIO_LIMIT_SECS: u64 = 4 * 60 * 60;
+// Total backlog (channel + worker queues) at which live chunks are dropped, as a
+// multiple of the cap. Background chunks are dropped as soon as the cap is reached.
+const LIVE_BACKLOG_CAP_FACTOR: u64 = 2;
+// Fraction of the cap at which the frontend is warned that transcription is falling behind
+const BUFFERED_AUDIO_WARN_RATIO: f64 = 0.8;
+const BUFFER_FULL_POLL_MS: u64 = 100;
+
+/// Set the cap on audio queued for transcription, in seconds (0 = unlimited)
+#[tauri::command]
+pub async fn set_transcription_buffer_max_seconds(seconds: u64) -> Result<(), String> {
+    if seconds > MAX_BUFFERED_AUDIO_LIMIT_SECS {
+        return Err(format!(
+            "Transcription buffer cap must be at most {} seconds",
+            MAX_BUFFERED_AUDIO_LIMIT_SECS
+        ));
+    }
+    MAX_BUFFERED_AUDIO_SECS.store(seconds, Ordering::SeqCst);
+    info!("Transcription buffer cap set to {}s", seconds);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_transcription_buffer_max_seconds() -> Result<u64, String> {
+    Ok(MAX_BUFFERED_AUDIO_SECS.load(Ordering::SeqCst))
+}
+
+// Chunks transcribed concurrently. Results are still emitted in dispatch order, so
+// more than one only helps engines that can decode in parallel.
+static MAX_TRANSCRIPTION_WORKERS: AtomicUsize = AtomicUsize::new(1);
+const MAX_TRANSCRIPTION_WORKERS_LIMIT: usize = 8;
+
+/// Set how many chunks may be transcribed at once (takes effect on the next recording)
+#[tauri::command]
+pub async fn set_transcription_max_workers(max_workers: usize) -> Result<(), String> {
+    if !(1..=MAX_TRANSCRIPTION_WORKERS_LIMIT).contains(&max_workers) {
+        return Err(format!(
+            "Transcription workers must be between 1 and {}",
+            MAX_TRANSCRIPTION_WORKERS_LIMIT
+        ));
+    }
+    MAX_TRANSCRIPTION_WORKERS.store(max_workers, Ordering::SeqCst);
+    info!("Transcription workers set to {}", max_workers);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_transcription_max_workers() -> Result<usize, String> {
+    Ok(MAX_TRANSCRIPTION_WORKERS.load(Ordering::SeqCst))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BufferLevel {
+    Normal,
+    NearCap,
+    Full,
+}
+
+fn buffer_level(buffered_ms: u64, max_secs: u64) -> BufferLevel {
+    if max_secs == 0 {
+        return BufferLevel::Normal;
+    }
+    let max_ms = max_secs * 1000;
+    if buffered_ms >= max_ms {
+        BufferLevel::Full
+    } else if buffered_ms as f64 >= max_ms as f64 * BUFFERED_AUDIO_WARN_RATIO {
+        BufferLevel::NearCap
+    } else {
+        BufferLevel::Normal
+    }
+}
+
+fn chunk_duration_ms(chunk: &AudioChunk) -> u64 {
+    if chunk.sample_rate == 0 {
+        return 0;
+    }
+    chunk.data.len() as u64 * 1000 / chunk.sample_rate as u64
+}
+
+/// Whether a chunk of `chunk_ms` still fits in the backlog of `backlog_ms`. Live
+/// chunks may fill the recording channel up to `LIVE_BACKLOG_CAP_FACTOR` caps while
+/// the dispatcher is paused; background chunks only fit below one cap.
+fn backlog_has_room(backlog_ms: u64, chunk_ms: u64, max_secs: u64, priority: ChunkPriority) -> bool {
+    if max_secs == 0 {
+        return true;
+    }
+    let limit_ms = match priority {
+        ChunkPriority::Live => max_secs * 1000 * LIVE_BACKLOG_CAP_FACTOR,
+        ChunkPriority::Background => max_secs * 1000,
+    };
+    backlog_ms + chunk_ms <= limit_ms
+}
+
+/// Producer-side cap, called by the audio pipeline before it sends a chunk for
+/// transcription. Returns false (and counts the drop) when the backlog is full, so a
+/// transcriber that can't keep up loses transcript for the overflow instead of
+/// growing memory without bound. The recording itself is saved separately.
+pub fn admit_transcription_chunk(chunk: &AudioChunk) -> bool {
+    let chunk_ms = chunk_duration_ms(chunk);
+    let max_secs = MAX_BUFFERED_AUDIO_SECS.load(Ordering::SeqCst);
+    let backlog_ms = PENDING_AUDIO_MS.load(Ordering::SeqCst) + BUFFERED_AUDIO_MS.load(Ordering::SeqCst);
+    if !backlog_has_room(backlog_ms, chunk_ms, max_secs, chunk.priority) {
+        if DROPPED_BACKLOG_CHUNKS.fetch_add(1, Ordering::SeqCst) == 0 {
+            warn!(
+                "⚠️ Transcription backlog full ({:.1}s buffered, cap {}s), dropping chunks until it drains",
+                backlog_ms as f64 / 1000.0,
+                max_secs
+            );
+        }
+        return false;
+    }
+    PENDING_AUDIO_MS.fetch_add(chunk_ms, Ordering::SeqCst);
+    true
+}
+
+/// Clear the backlog counters for a new recording, before its channel exists
+pub fn reset_transcription_backlog() {
+    PENDING_AUDIO_MS.store(0, Ordering::SeqCst);
+    BUFFERED_AUDIO_MS.store(0, Ordering::SeqCst);
+    DROPPED_BACKLOG_CHUNKS.store(0, Ordering::SeqCst);
+}
+
+fn take_pending_audio(chunk: &AudioChunk) {
+    let duration_ms = chunk_duration_ms(chunk);
+    let _ = PENDING_AUDIO_MS.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |ms| {
+        Some(ms.saturating_sub(duration_ms))
+    });
+}
+
+fn release_buffered_audio(chunk: &AudioChunk) {
+    let duration_ms = chunk_duration_ms(chunk);
+    let _ = BUFFERED_AUDIO_MS.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |ms| {
+        Some(ms.saturating_sub(duration_ms))
+    });
+}
+
+/// Dispatcher backpressure: warn once as the backlog nears the cap, and wait
+/// while it is over the cap.
+async fn wait_for_buffer_space<R: Runtime>(app: &AppHandle<R>, warned: &mut bool) {
+    let mut paused = false;
+    loop {
+        let max_secs = MAX_BUFFERED_AUDIO_SECS.load(Ordering::SeqCst);
+        let buffered_ms = BUFFERED_AUDIO_MS.load(Ordering::SeqCst);
+        let level = buffer_level(buffered_ms, max_secs);
+
+        if level != BufferLevel::Normal && !*warned {
+            warn!(
+                "⚠️ Transcription is falling behind: {:.1}s of audio buffered (cap {}s)",
+                buffered_ms as f64 / 1000.0,
+                max_secs
+            );
+            let _ = app.emit(
+                "transcription-backlog-warning",
+                serde_json::json!({
+                    "bufferedSeconds": buffered_ms as f64 / 1000.0,
+                    "maxSeconds": max_secs,
+                    "paused": level == BufferLevel::Full,
+                    "droppedChunks": DROPPED_BACKLOG_CHUNKS.load(Ordering::SeqCst),
+                }),
+            );
+            *warned = true;
+        } else if level == BufferLevel::Normal {
+            *warned = false;
+        }
+
+        if level != BufferLevel::Full {
+            if paused {
+                info!("📥 Transcription backlog below cap, resuming dispatch");
+            }
+            return;
+        }
+        if !paused {
+            warn!("⏸️ Transcription buffer cap reached, pausing dispatch until workers catch up");
+            paused = true;
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(BUFFER_FULL_POLL_MS)).await;
+    }
+}
+
+// Live refinement: while recording, re-transcribe the most recent audio window on an
+// interval so later context can correct the live text. Refinement chunks use the
+// background lane, so they only run while no live chunk is waiting.
+static LIVE_REFINEMENT_ENABLED: AtomicBool = AtomicBool::new(false);
+static LIVE_REFINEMENT_INTERVAL_SECS: AtomicU64 = AtomicU64::new(30);
+static LIVE_REFINEMENT_WINDOW_SECS: AtomicU64 = AtomicU64::new(60);
+const MIN_LIVE_REFINEMENT_INTERVAL_SECS: u64 = 5;
+const MAX_LIVE_REFINEMENT_INTERVAL_SECS: u64 = 10 * 60;
+// Shorter windows give the model no more context than the live chunks had
+const MIN_LIVE_REFINEMENT_WINDOW_SECS: u64 = 10;
+const MAX_LIVE_REFINEMENT_WINDOW_SECS: u64 = 5 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LiveRefinementSettings {
+    pub enabled: bool,
+    pub interval_secs: u64, // How often the recent window is re-transcribed
+    pub window_secs: u64,   // How much recent audio each refinement covers
+}
+
+fn live_refinement_settings() -> LiveRefinementSettings {
+    LiveRefinementSettings {
+        enabled: LIVE_REFINEMENT_ENABLED.load(Ordering::SeqCst),
+        interval_secs: LIVE_REFINEMENT_INTERVAL_SECS.load(Ordering::SeqCst),
+        window_secs: LIVE_REFINEMENT_WINDOW_SECS.load(Ordering::SeqCst),
+    }
+}
+
+/// Configure scheduled refinement; takes effect from the next recording
+#[tauri::command]
+pub async fn set_live_refinement_settings(settings: LiveRefinementSettings) -> Result<(), String> {
+    if !(MIN_LIVE_REFINEMENT_INTERVAL_SECS..=MAX_LIVE_REFINEMENT_INTERVAL_SECS).contains(&settings.interval_secs) {
+        return Err(format!(
+            "Refinement interval must be between {} and {} seconds",
+            MIN_LIVE_REFINEMENT_INTERVAL_SECS, MAX_LIVE_REFINEMENT_INTERVAL_SECS
+        ));
+    }
+    if !(MIN_LIVE_REFINEMENT_WINDOW_SECS..=MAX_LIVE_REFINEMENT_WINDOW_SECS).contains(&settings.window_secs) {
+        return Err(format!(
+            "Refinement window must be between {} and {} seconds",
+            MIN_LIVE_REFINEMENT_WINDOW_SECS, MAX_LIVE_REFINEMENT_WINDOW_SECS
+        ));
+    }
+    LIVE_REFINEMENT_ENABLED.store(settings.enabled, Ordering::SeqCst);
+    LIVE_REFINEMENT_INTERVAL_SECS.store(settings.interval_secs, Ordering::SeqCst);
+    LIVE_REFINEMENT_WINDOW_SECS.store(settings.window_secs, Ordering::SeqCst);
+    info!(
+        "Live refinement {} (every {}s over the last {}s)",
+        if settings.enabled { "enabled" } else { "disabled" },
+        settings.interval_secs,
+        settings.window_secs
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_live_refinement_settings() -> Result<LiveRefinementSettings, String> {
+    Ok(live_refinement_settings())
+}
+
+// Debug builds only: run chunk preprocessing and gating, then report the verdict as a
+// `transcription-dry-run` event instead of decoding
+static DRY_RUN_ENABLED: AtomicBool = AtomicBool::new(false);
+// Shortest chunk providers accept (100ms at 16kHz)
+const MIN_TRANSCRIBE_SAMPLES: usize = 1600;
+// Mean energy under which a chunk is flagged as near-silent (about -60 dBFS); not a gate
+const NEAR_SILENT_ENERGY: f32 = 1e-6;
+
+/// Enable dry-run transcription (debug builds only)
+#[tauri::command]
+pub async fn set_transcription_dry_run(enabled: bool) -> Result<(), String> {
+    if enabled && !cfg!(debug_assertions) {
+        return Err("Dry-run transcription is only available in debug builds".to_string());
+    }
+    DRY_RUN_ENABLED.store(enabled, Ordering::SeqCst);
+    info!("Transcription dry run {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_transcription_dry_run() -> Result<bool, String> {
+    Ok(DRY_RUN_ENABLED.load(Ordering::SeqCst))
+}
+
+/// What transcription would do with a preprocessed chunk
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct DryRunReport {
+    chunk_id: u64,
+    source_sample_rate: u32,
+    samples: usize,
+    duration_secs: f64,
+    energy: f32,
+    accepted: bool,
+    reason: Option<&'static str>, // Why the chunk would be dropped
+    near_silent: bool,            // Likely to decode to nothing even if accepted
+}
+
+/// Why a preprocessed chunk would not be decoded. The one gate for both the real
+/// and the dry-run path, so a dry run reports exactly what decoding would drop.
+fn chunk_rejection(samples: &[f32]) -> Option<&'static str> {
+    if samples.is_empty() {
+        Some("empty")
+    } else if samples.len() < MIN_TRANSCRIBE_SAMPLES {
+        Some("tooShort")
+    } else {
+        None
+    }
+}
+
+fn dry_run_report(chunk_id: u64, source_sample_rate: u32, samples: &[f32]) -> DryRunReport {
+    let energy = if samples.is_empty() {
+        0.0
+    } else {
+        samples.iter().map(|&x| x * x).sum::<f32>() / samples.len() as f32
+    };
+    let reason = chunk_rejection(samples);
+    DryRunReport {
+        chunk_id,
+        source_sample_rate,
+        samples: samples.len(),
+        duration_secs: samples.len() as f64 / 16000.0,
+        energy,
+        accepted: reason.is_none(),
+        reason,
+        near_silent: energy < NEAR_SILENT_ENERGY,
+    }
+}
+
+/// Set the minimum word count for finalized segments (0 disables the filter)
+#[tauri::command]
+pub async fn set_transcript_min_words(min_words: usize) -> Result<(), String> {
+    MIN_WORDS_TO_EMIT.store(min_words, Ordering::SeqCst);
+    info!("Minimum words per transcript segment set to {}", min_words);
+    Ok(())
+}
+
+/// Get the minimum word count for finalized segments
+#[tauri::command]
+pub async fn get_transcript_min_words() -> Result<usize, String> {
+    Ok(MIN_WORDS_TO_EMIT.load(Ordering::SeqCst))
+}
+
 /// Reset the speech detected flag and transcript dedup state for a new recording session
 pub fn reset_speech_detected_flag() {
     SPEECH_DETECTED_EMITTED.store(false, Ordering::SeqCst);
     if let Ok(mut last) = LAST_TRANSCRIPT_STATE.lock() {
         last.text.clear();
         last.audio_end_time = None;
+        last.words = None;
     }
     info!(
         "🔍 SPEECH_DETECTED_EMITTED reset to: {}",
@@ -42,6 +532,374 @@ pub fn reset_speech_detected_flag() {
     );
 }
 
+/// Per-priority work queues shared by the transcription workers
+struct ChunkLanes {
+    live: tokio::sync::mpsc::UnboundedReceiver<AudioChunk>,
+    background: tokio::sync::mpsc::UnboundedReceiver<AudioChunk>,
+}
+
+impl ChunkLanes {
+    /// Next chunk to transcribe; background chunks only run while the live lane is empty.
+    /// Returns None once both lanes are closed and drained.
+    async fn recv(&mut self) -> Option<AudioChunk> {
+        tokio::select! {
+            biased;
+            Some(chunk) = self.live.recv() => Some(chunk),
+            Some(chunk) = self.background.recv() => Some(chunk),
+            else => None,
+        }
+    }
+}
+
+/// Holds results that finish ahead of an earlier chunk and releases them in
+/// dispatch order, so parallel workers still emit transcripts chronologically.
+struct ReorderBuffer<T> {
+    next_ticket: u64,
+    pending: BTreeMap<u64, T>,
+}
+
+impl<T> ReorderBuffer<T> {
+    fn new() -> Self {
+        Self {
+            next_ticket: 0,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Store the result for `ticket` and return every result that is now in order.
+    fn push(&mut self, ticket: u64, item: T) -> Vec<T> {
+        self.pending.insert(ticket, item);
+        let mut ready = Vec::new();
+        while let Some(item) = self.pending.remove(&self.next_ticket) {
+            ready.push(item);
+            self.next_ticket += 1;
+        }
+        ready
+    }
+}
+
+/// A chunk's transcription result plus the chunk details needed to emit it.
+struct DecodedChunk {
+    chunk_id: u64,
+    chunk_timestamp: f64,
+    scheduled_refinement: bool,
+    leading_overlap_secs: f64,
+    chunk_duration: f64,
+    should_log_this_chunk: bool,
+    result: Result<TranscriptResult, TranscriptionError>,
+}
+
+/// What happens to a decoded chunk once it reaches the front of the reorder buffer
+enum ChunkOutcome {
+    /// A transcript segment to emit, with the engine's confidence
+    Emit(TranscriptUpdate, Option<f32>),
+    /// Nothing to emit. Quiet drops count as completed without a progress event.
+    Dropped { reason: DropReason, quiet: bool },
+    /// The transcription failed and the user is warned
+    Failed(TranscriptionError),
+}
+
+/// Dedup, filter and sequence one decoded chunk. Runs in dispatch order, so sequence
+/// ids and the overlap state follow the audio timeline whichever worker finished first.
+fn finalize_decoded_chunk(decoded: DecodedChunk, config: &SessionConfig, worker_id: usize) -> ChunkOutcome {
+    let DecodedChunk {
+        chunk_id,
+        chunk_timestamp,
+        scheduled_refinement,
+        leading_overlap_secs,
+        chunk_duration,
+        should_log_this_chunk,
+        result: transcribe_result,
+    } = decoded;
+
+    let TranscriptResult {
+        text: transcript,
+        confidence: confidence_opt,
+        is_partial,
+        words,
+    } = match transcribe_result {
+        Ok(result) if result.is_no_speech() => {
+            // Nothing recognized: counted as completed, not a failure
+            return ChunkOutcome::Dropped { reason: DropReason::Empty, quiet: false };
+        }
+        Ok(result) => result,
+        Err(e @ TranscriptionError::AudioTooShort { .. }) => {
+            // Skip silently, this is expected for very short chunks
+            info!("Worker {}: {}", worker_id, e);
+            return ChunkOutcome::Dropped { reason: DropReason::TooShort, quiet: true };
+        }
+        Err(TranscriptionError::ModelNotLoaded) => {
+            warn!("Worker {}: Model unloaded during transcription", worker_id);
+            return ChunkOutcome::Dropped { reason: DropReason::ModelNotLoaded, quiet: true };
+        }
+        Err(e) => return ChunkOutcome::Failed(e),
+    };
+
+    // Provider-aware confidence threshold
+    let confidence_threshold = config.confidence_threshold;
+
+    let confidence_str = match confidence_opt {
+        Some(c) => format!("{:.2}", c),
+        None => "N/A".to_string(),
+    };
+
+    info!("🔍 Worker {} transcription result: text='{}', confidence={}, partial={}, threshold={:.2}",
+          worker_id, transcript, confidence_str, is_partial, confidence_threshold);
+
+    // Check confidence threshold (or accept if no confidence provided)
+    let meets_threshold = confidence_opt.map_or(true, |c| c >= confidence_threshold);
+
+    if !meets_threshold {
+        // PERFORMANCE: Only log low-confidence results occasionally
+        if should_log_this_chunk {
+            if let Some(c) = confidence_opt {
+                info!("Worker {} low-confidence transcription (confidence: {:.2}), skipping", worker_id, c);
+            }
+        }
+        return ChunkOutcome::Dropped { reason: DropReason::LowConfidence, quiet: false };
+    }
+
+    // PERFORMANCE: Only log transcription results, not every processing step
+    info!("✅ Worker {} transcribed: {} (confidence: {}, partial: {})",
+          worker_id, transcript, confidence_str, is_partial);
+
+    // Generate sequence ID and calculate timestamps FIRST
+    let sequence_id = SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let audio_start_time = chunk_timestamp; // Already in seconds from recording start
+    let audio_end_time = chunk_timestamp + chunk_duration;
+
+    // Save structured transcript segment to recording manager (only final results)
+    // Save ALL segments (partial and final) to ensure complete JSON
+    // Create structured segment with full timestamp data
+    // NOTE: This is now handled via the transcript-update event emission below
+    // The recording_commands module listens to these events and saves them
+    // This decouples the transcription worker from direct RECORDING_MANAGER access
+
+    // Detect refinement segments: a segment whose start time is
+    // significantly before the last emitted segment's end time.
+    // This happens when VAD force-splits continuous speech and then
+    // emits the full speech run at SpeechEnd.
+    let is_refinement = scheduled_refinement || {
+        let last = LAST_TRANSCRIPT_STATE
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        last.audio_end_time.map_or(false, |last_end| {
+            // Refinement: starts >2s before last segment ended
+            // and has substantial duration (>4s)
+            audio_start_time < last_end - 2.0
+                && chunk_duration > 4.0
+        })
+    };
+
+    if is_refinement {
+        info!(
+            "📝 Detected refinement segment: audio=[{:.1}s, {:.1}s] (duration={:.1}s) overlaps previous segments",
+            audio_start_time, audio_end_time, chunk_duration
+        );
+    }
+
+    if !is_partial && config.timing_validation {
+        let previous_end = LAST_TRANSCRIPT_STATE
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .audio_end_time;
+        match check_segment_timing(previous_end, audio_start_time, is_refinement) {
+            Some(TimingIssue::Overlap(secs)) => warn!(
+                "⏱️ Chunk {} starts at {:.2}s, {:.2}s before the previous segment ended ({:.2}s); check VAD/chunk timestamps",
+                chunk_id, audio_start_time, secs, previous_end.unwrap_or(0.0)
+            ),
+            Some(TimingIssue::Gap(secs)) => warn!(
+                "⏱️ Chunk {} starts {:.1}s after the previous segment ended ({:.2}s); unexpected unless the speaker was silent",
+                chunk_id, secs, previous_end.unwrap_or(0.0)
+            ),
+            None => {}
+        }
+    }
+
+    // Remove overlapping text with the previous transcript segment
+    let deduped_transcript = if !is_partial {
+        // Only apply overlap dedup when segments are near-adjacent in time.
+        // After pause/resume or mode/device changes, aggressive dedup can
+        // incorrectly suppress valid new utterances.
+        const MAX_DEDUP_GAP_SEC: f64 = 1.5;
+        const MAX_NEGATIVE_DRIFT_SEC: f64 = 0.2;
+
+        let mut last = LAST_TRANSCRIPT_STATE
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        // Skip dedup for refinement segments — they intentionally
+        // re-transcribe the same audio range at higher quality.
+        let should_dedup = !is_refinement &&
+            last.audio_end_time.map_or(false, |last_end| {
+                let gap = audio_start_time - last_end;
+                gap >= -MAX_NEGATIVE_DRIFT_SEC
+                    && gap <= MAX_DEDUP_GAP_SEC
+            });
+
+        // Shift word timings onto the recording timeline; the
+        // decoded audio starts at the overlap, not at the segment
+        let decode_start = audio_start_time - leading_overlap_secs;
+        let words: Option<Vec<WordTiming>> = words.map(|words| {
+            words
+                .into_iter()
+                .map(|w| WordTiming {
+                    start: w.start + decode_start,
+                    end: w.end + decode_start,
+                    text: w.text,
+                })
+                .collect()
+        });
+
+        let deduped = if should_dedup {
+            // Prefer timestamps when both segments carry word
+            // timings; fall back to text matching otherwise.
+            match (last.words.as_deref(), words.as_deref()) {
+                (Some(prev_words), Some(curr_words))
+                    if !prev_words.is_empty() && !curr_words.is_empty() =>
+                {
+                    remove_timed_overlap(prev_words, curr_words, &transcript)
+                }
+                _ => remove_text_overlap(&last.text, &transcript),
+            }
+        } else {
+            transcript.clone()
+        };
+
+        // Always refresh last state for next segment decision.
+        // For refinement segments, update end time to the max
+        // to avoid deduping the next real segment against
+        // a stale earlier end time.
+        last.text = transcript;
+        last.words = words;
+        let new_end = if is_refinement {
+            Some(audio_end_time.max(last.audio_end_time.unwrap_or(0.0)))
+        } else {
+            Some(audio_end_time)
+        };
+        last.audio_end_time = new_end;
+        deduped
+    } else {
+        transcript
+    };
+
+    // Skip if dedup removed all content
+    if deduped_transcript.trim().is_empty() {
+        info!("📝 Transcript fully overlapped with previous, skipping");
+        return ChunkOutcome::Dropped { reason: DropReason::Overlap, quiet: true };
+    }
+
+    // Drop filler-only finals ("uh", "mm") below the minimum word count
+    let min_words = config.min_words;
+    if !is_partial && !meets_min_words(&deduped_transcript, min_words) {
+        info!(
+            "📝 Segment '{}' has fewer than {} words, skipping",
+            deduped_transcript, min_words
+        );
+        return ChunkOutcome::Dropped { reason: DropReason::TooFewWords, quiet: true };
+    }
+
+    // Transcript update with NEW recording-relative timestamps
+    let update = TranscriptUpdate {
+        text: deduped_transcript,
+        timestamp: format_current_timestamp(), // Wall-clock for reference
+        source: "Audio".to_string(),
+        sequence_id,
+        chunk_start_time: chunk_timestamp, // Legacy compatibility
+        is_partial,
+        confidence: confidence_opt.unwrap_or(0.85), // Default for providers without confidence
+        // NEW: Recording-relative timestamps for sync
+        audio_start_time,
+        audio_end_time,
+        duration: chunk_duration,
+        is_refinement,
+        replaces_range: is_refinement
+            .then_some((audio_start_time, audio_end_time)),
+    };
+
+    ChunkOutcome::Emit(update, confidence_opt)
+}
+
+/// Recent live audio kept for scheduled refinement, trimmed to the refinement window.
+/// Chunks are stored without their leading overlap, keyed by recording-relative start.
+struct RecentAudio {
+    window_secs: f64,
+    sample_rate: u32,
+    segments: VecDeque<(f64, Vec<f32>)>,
+    last_chunk: Option<(u64, RecordingDeviceType)>,
+    refined_until: f64, // End of the last window handed out, so idle ticks don't repeat it
+}
+
+impl RecentAudio {
+    fn new(window_secs: u64) -> Self {
+        Self {
+            window_secs: window_secs as f64,
+            sample_rate: 0,
+            segments: VecDeque::new(),
+            last_chunk: None,
+            refined_until: 0.0,
+        }
+    }
+
+    fn push(&mut self, chunk: &AudioChunk) {
+        if chunk.sample_rate != self.sample_rate {
+            self.segments.clear();
+            self.sample_rate = chunk.sample_rate;
+        }
+        let overlap = ((chunk.leading_overlap_secs * chunk.sample_rate as f64).round() as usize).min(chunk.data.len());
+        self.segments.push_back((chunk.timestamp, chunk.data[overlap..].to_vec()));
+        self.last_chunk = Some((chunk.chunk_id, chunk.device_type.clone()));
+
+        let cutoff = self.end_time().unwrap_or(0.0) - self.window_secs;
+        while let Some((start, data)) = self.segments.front() {
+            if start + data.len() as f64 / self.sample_rate as f64 > cutoff {
+                break;
+            }
+            self.segments.pop_front();
+        }
+    }
+
+    fn end_time(&self) -> Option<f64> {
+        self.segments
+            .back()
+            .map(|(start, data)| start + data.len() as f64 / self.sample_rate as f64)
+    }
+
+    /// The last `window_secs` of audio as one background chunk, with silence filling the
+    /// gaps VAD cut out so timestamps stay on the recording timeline. None when nothing
+    /// new arrived since the previous refinement.
+    fn refinement_chunk(&mut self) -> Option<AudioChunk> {
+        let end = self.end_time()?;
+        if end <= self.refined_until {
+            return None;
+        }
+        let (first_start, _) = self.segments.front()?;
+        let start = first_start.max(end - self.window_secs);
+        let rate = self.sample_rate as f64;
+
+        let mut data = vec![0.0; ((end - start) * rate).round() as usize];
+        for (seg_start, seg) in &self.segments {
+            let offset = ((seg_start - start) * rate).round() as isize;
+            let skip = (-offset).max(0) as usize;
+            let dst = offset.max(0) as usize;
+            let len = seg.len().saturating_sub(skip).min(data.len().saturating_sub(dst));
+            data[dst..dst + len].copy_from_slice(&seg[skip..skip + len]);
+        }
+
+        self.refined_until = end;
+        let (chunk_id, device_type) = self.last_chunk.clone()?;
+        Some(AudioChunk {
+            data,
+            sample_rate: self.sample_rate,
+            timestamp: start,
+            chunk_id,
+            device_type,
+            leading_overlap_secs: 0.0,
+            priority: ChunkPriority::Background,
+        })
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TranscriptUpdate {
     pub text: String,
@@ -56,15 +914,22 @@ pub struct TranscriptUpdate {
     pub audio_end_time: f64,   // Seconds from recording start (e.g., 128.6)
     pub duration: f64,         // Segment duration in seconds (e.g., 3.3)
     pub is_refinement: bool,   // True for full-run refinement segments that should replace chunks
+    // Audio range (start, end) this refinement supersedes; segments inside it should be removed
+    #[serde(default)]
+    pub replaces_range: Option<(f64, f64)>,
 }
 
 // NOTE: get_transcript_history and get_recording_meeting_name functions
 // have been moved to recording_commands.rs where they have access to RECORDING_MANAGER
 
-/// Optimized parallel transcription task ensuring ZERO chunk loss
+/// Optimized parallel transcription task ensuring ZERO chunk loss.
+///
+/// When all chunks are drained, emits `transcription-session-complete` with
+/// `recording_id` so the summary pipeline can auto-trigger.
 pub fn start_transcription_task<R: Runtime>(
     app: AppHandle<R>,
     transcription_receiver: tokio::sync::mpsc::UnboundedReceiver<AudioChunk>,
+    recording_id: Option<String>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         info!("🚀 Starting optimized parallel transcription task - guaranteeing zero chunk loss");
@@ -84,25 +949,41 @@ pub fn start_transcription_task<R: Runtime>(
             }
         };
 
+        let session_config = Arc::new(SessionConfig::snapshot(&transcription_engine).await);
+        info!("📋 Transcription session config: {:?}", session_config);
+
+        metrics::begin_recording(
+            transcription_engine.provider_name(),
+            session_config.model.clone(),
+        );
+
         // Create parallel workers for faster processing while preserving ALL chunks
-        const NUM_WORKERS: usize = 1; // Serial processing ensures transcripts emit in chronological order
-        let (work_sender, work_receiver) = tokio::sync::mpsc::unbounded_channel::<AudioChunk>();
-        let work_receiver = Arc::new(tokio::sync::Mutex::new(work_receiver));
+        let num_workers = session_config.max_workers;
+        // Live and background chunks get separate lanes so re-transcription never delays live text
+        let (live_sender, live_receiver) = tokio::sync::mpsc::unbounded_channel::<AudioChunk>();
+        let (background_sender, background_receiver) = tokio::sync::mpsc::unbounded_channel::<AudioChunk>();
+        let work_receiver = Arc::new(tokio::sync::Mutex::new(ChunkLanes {
+            live: live_receiver,
+            background: background_receiver,
+        }));
 
         // Track completion: AtomicU64 for chunks queued, AtomicU64 for chunks completed
         let chunks_queued = Arc::new(AtomicU64::new(0));
         let chunks_completed = Arc::new(AtomicU64::new(0));
         let input_finished = Arc::new(AtomicBool::new(false));
+        // Chunks are ticketed as workers take them; results are emitted in ticket order
+        let dispatch_tickets = Arc::new(AtomicU64::new(0));
+        let reorder_buffer = Arc::new(tokio::sync::Mutex::new(ReorderBuffer::<DecodedChunk>::new()));
 
         info!(
-            "📊 Starting {} transcription worker{} (serial mode for ordered emission)",
-            NUM_WORKERS,
-            if NUM_WORKERS == 1 { "" } else { "s" }
+            "📊 Starting {} transcription worker{} (results emitted in dispatch order)",
+            num_workers,
+            if num_workers == 1 { "" } else { "s" }
         );
 
         // Spawn worker tasks
         let mut worker_handles = Vec::new();
-        for worker_id in 0..NUM_WORKERS {
+        for worker_id in 0..num_workers {
             let engine_clone = match &transcription_engine {
                 TranscriptionEngine::Whisper(e) => TranscriptionEngine::Whisper(e.clone()),
                 TranscriptionEngine::Parakeet(e) => TranscriptionEngine::Parakeet(e.clone()),
@@ -114,6 +995,9 @@ pub fn start_transcription_task<R: Runtime>(
             let chunks_completed_clone = chunks_completed.clone();
             let input_finished_clone = input_finished.clone();
             let chunks_queued_clone = chunks_queued.clone();
+            let session_config_clone = session_config.clone();
+            let dispatch_tickets_clone = dispatch_tickets.clone();
+            let reorder_buffer_clone = reorder_buffer.clone();
 
             let worker_handle = tokio::spawn(async move {
                 info!("👷 Worker {} started", worker_id);
@@ -128,6 +1012,9 @@ pub fn start_transcription_task<R: Runtime>(
                 let engine_name = engine_clone.provider_name();
 
                 if initial_model_loaded {
+                    if session_config_clone.prewarm && !engine_clone.warm_up().await {
+                        warn!("⚠️ Worker {} pre-validation: {} warmup failed", worker_id, engine_name);
+                    }
                     info!(
                         "✅ Worker {} pre-validation: {} model '{}' is loaded and ready",
                         worker_id, engine_name, current_model
@@ -142,12 +1029,17 @@ pub fn start_transcription_task<R: Runtime>(
                 loop {
                     // Try to get a chunk to process
                     let chunk = {
-                        let mut receiver = work_receiver_clone.lock().await;
-                        receiver.recv().await
+                        let mut lanes = work_receiver_clone.lock().await;
+                        lanes
+                            .recv()
+                            .await
+                            .map(|chunk| (dispatch_tickets_clone.fetch_add(1, Ordering::SeqCst), chunk))
                     };
 
                     match chunk {
-                        Some(chunk) => {
+                        Some((ticket, chunk)) => {
+                            release_buffered_audio(&chunk);
+
                             // PERFORMANCE OPTIMIZATION: Reduce logging in hot path
                             // Only log every 10th chunk per worker to reduce I/O overhead
                             let should_log_this_chunk = chunk.chunk_id % 10 == 0;
@@ -161,56 +1053,76 @@ pub fn start_transcription_task<R: Runtime>(
                                 );
                             }
 
-                            // Check if model is still loaded before processing
-                            if !engine_clone.is_model_loaded().await {
-                                warn!("⚠️ Worker {}: Model unloaded, but continuing to preserve chunk {}", worker_id, chunk.chunk_id);
-                                // Still count as completed even if we can't process
-                                chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
-                                continue;
-                            }
-
+                            let chunk_id = chunk.chunk_id;
                             let chunk_timestamp = chunk.timestamp;
-                            let chunk_duration = chunk.data.len() as f64 / chunk.sample_rate as f64;
-
-                            info!("📊 Chunk {} details: timestamp={:.2}s, duration={:.2}s, samples={}, sample_rate={}, time_range=[{:.2}s - {:.2}s]",
-                                  chunk.chunk_id, chunk_timestamp, chunk_duration,
-                                  chunk.data.len(), chunk.sample_rate,
-                                  chunk_timestamp, chunk_timestamp + chunk_duration);
-
-                            // Transcribe with provider-agnostic approach
-                            match transcribe_chunk_with_provider(&engine_clone, chunk, &app_clone)
+                            // Scheduled refinements replace whatever live text covers their window
+                            let scheduled_refinement = chunk.priority == ChunkPriority::Background;
+                            // Any prepended overlap belongs to the previous segment
+                            let leading_overlap_secs = chunk.leading_overlap_secs;
+                            let chunk_duration = chunk.data.len() as f64 / chunk.sample_rate as f64
+                                - leading_overlap_secs;
+
+                            // Check if model is still loaded before processing. The chunk still
+                            // goes through the reorder buffer so later results aren't held back.
+                            let transcribe_result = if !engine_clone.is_model_loaded().await {
+                                warn!("⚠️ Worker {}: Model unloaded, but continuing to preserve chunk {}", worker_id, chunk.chunk_id);
+                                Err(TranscriptionError::ModelNotLoaded)
+                            } else {
+                                info!("📊 Chunk {} details: timestamp={:.2}s, duration={:.2}s, samples={}, sample_rate={}, time_range=[{:.2}s - {:.2}s]",
+                                      chunk.chunk_id, chunk_timestamp, chunk_duration,
+                                      chunk.data.len(), chunk.sample_rate,
+                                      chunk_timestamp, chunk_timestamp + chunk_duration);
+
+                                // Transcribe with provider-agnostic approach. A panicking engine
+                                // still completes the ticket (as a failed chunk), or every later
+                                // result would wait behind it in the reorder buffer.
+                                let transcribe_started = std::time::Instant::now();
+                                let result = AssertUnwindSafe(transcribe_chunk_with_provider(
+                                    &engine_clone,
+                                    chunk,
+                                    &session_config_clone,
+                                    &app_clone,
+                                ))
+                                .catch_unwind()
                                 .await
-                            {
-                                Ok((transcript, confidence_opt, is_partial)) => {
-                                    // Provider-aware confidence threshold
-                                    let confidence_threshold = match &engine_clone {
-                                        TranscriptionEngine::Whisper(_)
-                                        | TranscriptionEngine::Provider(_) => 0.3,
-                                        TranscriptionEngine::Parakeet(_) => 0.0, // Parakeet has no confidence, accept all
-                                        TranscriptionEngine::QwenAsr(_) => 0.0, // QwenASR has no confidence, accept all
-                                    };
-
-                                    let confidence_str = match confidence_opt {
-                                        Some(c) => format!("{:.2}", c),
-                                        None => "N/A".to_string(),
-                                    };
-
-                                    info!("🔍 Worker {} transcription result: text='{}', confidence={}, partial={}, threshold={:.2}",
-                                          worker_id, transcript, confidence_str, is_partial, confidence_threshold);
-
-                                    // Check confidence threshold (or accept if no confidence provided)
-                                    let meets_threshold =
-                                        confidence_opt.map_or(true, |c| c >= confidence_threshold);
-
-                                    if !transcript.trim().is_empty() && meets_threshold {
-                                        // PERFORMANCE: Only log transcription results, not every processing step
-                                        info!("✅ Worker {} transcribed: {} (confidence: {}, partial: {})",
-                                              worker_id, transcript, confidence_str, is_partial);
+                                .unwrap_or_else(|_| {
+                                    error!("❌ Worker {}: transcription of chunk {} panicked", worker_id, chunk_id);
+                                    Err(TranscriptionError::EngineFailed(
+                                        "Transcription engine panicked".to_string(),
+                                    ))
+                                });
+                                // Dry runs decode nothing, so they stay out of the metrics
+                                if !session_config_clone.dry_run {
+                                    metrics::record_chunk(
+                                        chunk_duration + leading_overlap_secs,
+                                        transcribe_started.elapsed().as_secs_f64(),
+                                    );
+                                }
+                                result
+                            };
 
+                            // Dedup, sequence ids and emission run on results in dispatch
+                            // order, whichever worker finished first
+                            let mut reorder = reorder_buffer_clone.lock().await;
+                            let ready = reorder.push(
+                                ticket,
+                                DecodedChunk {
+                                    chunk_id,
+                                    chunk_timestamp,
+                                    scheduled_refinement,
+                                    leading_overlap_secs,
+                                    chunk_duration,
+                                    should_log_this_chunk,
+                                    result: transcribe_result,
+                                },
+                            );
+                            for decoded in ready {
+                                let should_log_this_chunk = decoded.should_log_this_chunk;
+                                match finalize_decoded_chunk(decoded, &session_config_clone, worker_id) {
+                                    ChunkOutcome::Emit(update, confidence_opt) => {
                                         // Emit speech-detected event for frontend UX (only on first detection per session)
                                         // This is lightweight and provides better user feedback
-                                        let current_flag =
-                                            SPEECH_DETECTED_EMITTED.load(Ordering::SeqCst);
+                                        let current_flag = SPEECH_DETECTED_EMITTED.load(Ordering::SeqCst);
                                         info!("🔍 Checking speech-detected flag: current={}, will_emit={}", current_flag, !current_flag);
 
                                         if !current_flag {
@@ -225,184 +1137,58 @@ pub fn start_transcription_task<R: Runtime>(
                                             info!("🔍 Speech already detected in this session, not re-emitting");
                                         }
 
-                                        // Generate sequence ID and calculate timestamps FIRST
-                                        let sequence_id =
-                                            SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst);
-                                        let audio_start_time = chunk_timestamp; // Already in seconds from recording start
-                                        let audio_end_time = chunk_timestamp + chunk_duration;
-
-                                        // Save structured transcript segment to recording manager (only final results)
-                                        // Save ALL segments (partial and final) to ensure complete JSON
-                                        // Create structured segment with full timestamp data
-                                        // NOTE: This is now handled via the transcript-update event emission below
-                                        // The recording_commands module listens to these events and saves them
-                                        // This decouples the transcription worker from direct RECORDING_MANAGER access
-
-                                        // Detect refinement segments: a segment whose start time is
-                                        // significantly before the last emitted segment's end time.
-                                        // This happens when VAD force-splits continuous speech and then
-                                        // emits the full speech run at SpeechEnd.
-                                        let is_refinement = {
-                                            let last = LAST_TRANSCRIPT_STATE
-                                                .lock()
-                                                .unwrap_or_else(|e| e.into_inner());
-                                            last.audio_end_time.map_or(false, |last_end| {
-                                                // Refinement: starts >2s before last segment ended
-                                                // and has substantial duration (>4s)
-                                                audio_start_time < last_end - 2.0
-                                                    && chunk_duration > 4.0
-                                            })
-                                        };
-
-                                        if is_refinement {
-                                            info!(
-                                                "📝 Detected refinement segment: audio=[{:.1}s, {:.1}s] (duration={:.1}s) overlaps previous segments",
-                                                audio_start_time, audio_end_time, chunk_duration
-                                            );
-                                        }
-
-                                        // Remove overlapping text with the previous transcript segment
-                                        let deduped_transcript = if !is_partial {
-                                            // Only apply overlap dedup when segments are near-adjacent in time.
-                                            // After pause/resume or mode/device changes, aggressive dedup can
-                                            // incorrectly suppress valid new utterances.
-                                            const MAX_DEDUP_GAP_SEC: f64 = 1.5;
-                                            const MAX_NEGATIVE_DRIFT_SEC: f64 = 0.2;
-
-                                            let mut last = LAST_TRANSCRIPT_STATE
-                                                .lock()
-                                                .unwrap_or_else(|e| e.into_inner());
-
-                                            // Skip dedup for refinement segments — they intentionally
-                                            // re-transcribe the same audio range at higher quality.
-                                            let should_dedup = !is_refinement &&
-                                                last.audio_end_time.map_or(false, |last_end| {
-                                                    let gap = audio_start_time - last_end;
-                                                    gap >= -MAX_NEGATIVE_DRIFT_SEC
-                                                        && gap <= MAX_DEDUP_GAP_SEC
-                                                });
-
-                                            let deduped = if should_dedup {
-                                                remove_text_overlap(&last.text, &transcript)
-                                            } else {
-                                                transcript.clone()
-                                            };
-
-                                            // Always refresh last state for next segment decision.
-                                            // For refinement segments, update end time to the max
-                                            // to avoid deduping the next real segment against
-                                            // a stale earlier end time.
-                                            last.text = transcript;
-                                            let new_end = if is_refinement {
-                                                Some(audio_end_time.max(last.audio_end_time.unwrap_or(0.0)))
-                                            } else {
-                                                Some(audio_end_time)
-                                            };
-                                            last.audio_end_time = new_end;
-                                            deduped
-                                        } else {
-                                            transcript
-                                        };
-
-                                        // Skip if dedup removed all content
-                                        if deduped_transcript.trim().is_empty() {
-                                            info!("📝 Transcript fully overlapped with previous, skipping");
-                                            chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
-                                            continue;
-                                        }
-
-                                        // Emit transcript update with NEW recording-relative timestamps
-
-                                        let update = TranscriptUpdate {
-                                            text: deduped_transcript,
-                                            timestamp: format_current_timestamp(), // Wall-clock for reference
-                                            source: "Audio".to_string(),
-                                            sequence_id,
-                                            chunk_start_time: chunk_timestamp, // Legacy compatibility
-                                            is_partial,
-                                            confidence: confidence_opt.unwrap_or(0.85), // Default for providers without confidence
-                                            // NEW: Recording-relative timestamps for sync
-                                            audio_start_time,
-                                            audio_end_time,
-                                            duration: chunk_duration,
-                                            is_refinement,
-                                        };
-
-                                        if let Err(e) = app_clone.emit("transcript-update", &update)
-                                        {
-                                            error!(
-                                                "Worker {}: Failed to emit transcript update: {}",
-                                                worker_id, e
-                                            );
-                                        }
-                                        // PERFORMANCE: Removed verbose logging of every emission
-                                    } else if !transcript.trim().is_empty() && should_log_this_chunk
-                                    {
-                                        // PERFORMANCE: Only log low-confidence results occasionally
-                                        if let Some(c) = confidence_opt {
-                                            info!("Worker {} low-confidence transcription (confidence: {:.2}), skipping", worker_id, c);
+                                        if let Err(e) = super::sink::emit_transcript_update(&app_clone, &update) {
+                                            error!("Worker {}: Failed to emit transcript update: {}", worker_id, e);
                                         }
+                                        metrics::record_emitted(confidence_opt);
                                     }
-                                }
-                                Err(e) => {
-                                    // Improved error handling with specific cases
-                                    match e {
-                                        TranscriptionError::AudioTooShort { .. } => {
-                                            // Skip silently, this is expected for very short chunks
-                                            info!("Worker {}: {}", worker_id, e);
-                                            chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
-                                            continue;
+                                    ChunkOutcome::Dropped { reason, quiet } => {
+                                        if !session_config_clone.dry_run {
+                                            metrics::record_drop(reason);
                                         }
-                                        TranscriptionError::ModelNotLoaded => {
-                                            warn!(
-                                                "Worker {}: Model unloaded during transcription",
-                                                worker_id
-                                            );
+                                        if quiet {
                                             chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
                                             continue;
                                         }
-                                        _ => {
-                                            warn!(
-                                                "Worker {}: Transcription failed: {}",
-                                                worker_id, e
-                                            );
-                                            let _ = app_clone
-                                                .emit("transcription-warning", e.to_string());
-                                        }
+                                    }
+                                    ChunkOutcome::Failed(e) => {
+                                        warn!("Worker {}: Transcription failed: {}", worker_id, e);
+                                        metrics::record_drop(DropReason::Error);
+                                        let _ = app_clone.emit("transcription-warning", e.to_string());
                                     }
                                 }
-                            }
 
-                            // Mark chunk as completed
-                            let completed =
-                                chunks_completed_clone.fetch_add(1, Ordering::SeqCst) + 1;
-                            let queued = chunks_queued_clone.load(Ordering::SeqCst);
-
-                            // PERFORMANCE: Only log progress every 5th chunk to reduce I/O overhead
-                            if completed % 5 == 0 || should_log_this_chunk {
-                                info!(
-                                    "Worker {}: Progress {}/{} chunks ({:.1}%)",
-                                    worker_id,
-                                    completed,
-                                    queued,
-                                    (completed as f64 / queued.max(1) as f64 * 100.0)
-                                );
-                            }
+                                // Mark chunk as completed
+                                let completed =
+                                    chunks_completed_clone.fetch_add(1, Ordering::SeqCst) + 1;
+                                let queued = chunks_queued_clone.load(Ordering::SeqCst);
 
-                            // Emit progress event for frontend
-                            let progress_percentage = if queued > 0 {
-                                (completed as f64 / queued as f64 * 100.0) as u32
-                            } else {
-                                100
-                            };
+                                // PERFORMANCE: Only log progress every 5th chunk to reduce I/O overhead
+                                if completed % 5 == 0 || should_log_this_chunk {
+                                    info!(
+                                        "Worker {}: Progress {}/{} chunks ({:.1}%)",
+                                        worker_id,
+                                        completed,
+                                        queued,
+                                        (completed as f64 / queued.max(1) as f64 * 100.0)
+                                    );
+                                }
 
-                            let _ = app_clone.emit("transcription-progress", serde_json::json!({
-                                "worker_id": worker_id,
-                                "chunks_completed": completed,
-                                "chunks_queued": queued,
-                                "progress_percentage": progress_percentage,
-                                "message": format!("Worker {} processing... ({}/{})", worker_id, completed, queued)
-                            }));
+                                // Emit progress event for frontend
+                                let progress_percentage = if queued > 0 {
+                                    (completed as f64 / queued as f64 * 100.0) as u32
+                                } else {
+                                    100
+                                };
+
+                                let _ = app_clone.emit("transcription-progress", serde_json::json!({
+                                    "worker_id": worker_id,
+                                    "chunks_completed": completed,
+                                    "chunks_queued": queued,
+                                    "progress_percentage": progress_percentage,
+                                    "message": format!("Worker {} processing... ({}/{})", worker_id, completed, queued)
+                                }));
+                            }
                         }
                         None => {
                             // No more chunks available
@@ -436,16 +1222,48 @@ pub fn start_transcription_task<R: Runtime>(
             worker_handles.push(worker_handle);
         }
 
-        // Main dispatcher: receive chunks and distribute to workers
+        // Main dispatcher: receive chunks and distribute to workers, interleaving scheduled
+        // refinements of the recent audio when enabled
         let mut receiver = transcription_receiver;
-        while let Some(chunk) = receiver.recv().await {
+        let mut backlog_warned = false;
+        let refinement = session_config.live_refinement;
+        let mut recent_audio = RecentAudio::new(refinement.window_secs);
+        let refinement_period = tokio::time::Duration::from_secs(refinement.interval_secs);
+        let mut refinement_ticker =
+            tokio::time::interval_at(tokio::time::Instant::now() + refinement_period, refinement_period);
+        loop {
+            let chunk = tokio::select! {
+                received = receiver.recv() => match received {
+                    Some(chunk) => {
+                        take_pending_audio(&chunk);
+                        chunk
+                    }
+                    None => break,
+                },
+                _ = refinement_ticker.tick(), if refinement.enabled => {
+                    match recent_audio.refinement_chunk() {
+                        Some(chunk) => chunk,
+                        None => continue,
+                    }
+                }
+            };
+            if refinement.enabled && chunk.priority == ChunkPriority::Live {
+                recent_audio.push(&chunk);
+            }
+
+            wait_for_buffer_space(&app, &mut backlog_warned).await;
+            BUFFERED_AUDIO_MS.fetch_add(chunk_duration_ms(&chunk), Ordering::SeqCst);
             let queued = chunks_queued.fetch_add(1, Ordering::SeqCst) + 1;
             info!(
-                "📥 Dispatching chunk {} to workers (total queued: {})",
-                chunk.chunk_id, queued
+                "📥 Dispatching {:?} chunk {} to workers (total queued: {})",
+                chunk.priority, chunk.chunk_id, queued
             );
 
-            if let Err(_) = work_sender.send(chunk) {
+            let sender = match chunk.priority {
+                ChunkPriority::Live => &live_sender,
+                ChunkPriority::Background => &background_sender,
+            };
+            if let Err(_) = sender.send(chunk) {
                 error!("❌ Failed to send chunk to workers - this should not happen!");
                 break;
             }
@@ -453,11 +1271,13 @@ pub fn start_transcription_task<R: Runtime>(
 
         // Signal that input is finished
         input_finished.store(true, Ordering::SeqCst);
-        drop(work_sender); // Close the channel to signal workers
+        // Close both lanes to signal workers
+        drop(live_sender);
+        drop(background_sender);
 
         let total_chunks_queued = chunks_queued.load(Ordering::SeqCst);
         info!("📭 Input finished with {} total chunks queued. Waiting for all {} workers to complete...",
-              total_chunks_queued, NUM_WORKERS);
+              total_chunks_queued, num_workers);
 
         // Emit final chunk count to frontend
         let _ = app.emit("transcription-queue-complete", serde_json::json!({
@@ -465,17 +1285,47 @@ pub fn start_transcription_task<R: Runtime>(
             "message": format!("{} chunks queued for processing - waiting for completion", total_chunks_queued)
         }));
 
-        // Wait for all workers to complete
-        for (worker_id, handle) in worker_handles.into_iter().enumerate() {
-            if let Err(e) = handle.await {
-                error!("❌ Worker {} panicked: {:?}", worker_id, e);
-            } else {
-                info!("✅ Worker {} completed successfully", worker_id);
+        // The discarded chunk fails like any engine error and still counts as completed
+        let discard_timer = match &transcription_engine {
+            TranscriptionEngine::QwenAsr(engine) => {
+                let engine = engine.clone();
+                Some(tokio::spawn(async move {
+                    tokio::time::sleep(tokio::time::Duration::from_secs(SHUTDOWN_DISCARD_GRACE_SECS)).await;
+                    if engine.discard_running_transcription() {
+                        warn!(
+                            "⏱️ QwenASR still decoding {}s after input finished, its result will be discarded",
+                            SHUTDOWN_DISCARD_GRACE_SECS
+                        );
+                    }
+                }))
+            }
+            _ => None,
+        };
+
+        // Wait for all workers to complete, but not forever: a worker that died outside
+        // a transcription leaves its ticket open and the rest waiting for it
+        let shutdown_deadline =
+            tokio::time::Instant::now() + tokio::time::Duration::from_secs(WORKER_SHUTDOWN_TIMEOUT_SECS);
+        for (worker_id, mut handle) in worker_handles.into_iter().enumerate() {
+            match tokio::time::timeout_at(shutdown_deadline, &mut handle).await {
+                Ok(Ok(())) => info!("✅ Worker {} completed successfully", worker_id),
+                Ok(Err(e)) => error!("❌ Worker {} panicked: {:?}", worker_id, e),
+                Err(_) => {
+                    error!(
+                        "❌ Worker {} still running {}s after input finished, abandoning it",
+                        worker_id, WORKER_SHUTDOWN_TIMEOUT_SECS
+                    );
+                    handle.abort();
+                }
             }
         }
+        if let Some(timer) = discard_timer {
+            timer.abort();
+        }
 
         // Final verification with retry logic to catch any stragglers
         let mut verification_attempts = 0;
+        let mut chunk_loss_detected = false;
         const MAX_VERIFICATION_ATTEMPTS: u32 = 10;
 
         loop {
@@ -511,21 +1361,41 @@ pub fn start_transcription_task<R: Runtime>(
                         "message": "Some transcript chunks may have been lost during shutdown"
                     }),
                 );
+                chunk_loss_detected = true;
                 break;
             }
         }
 
+        // Lets the summary pipeline auto-trigger; it should skip incomplete transcripts
+        let _ = app.emit(
+            "transcription-session-complete",
+            serde_json::json!({
+                "recording_id": recording_id,
+                "chunks_queued": chunks_queued.load(Ordering::SeqCst),
+                "chunks_completed": chunks_completed.load(Ordering::SeqCst),
+                "chunk_loss_detected": chunk_loss_detected,
+            }),
+        );
+
+        let summary =
+            metrics::finish_recording().summary(recording_id, chunks_queued.load(Ordering::SeqCst));
+        let _ = app.emit("transcription-session-summary", &summary);
+
         info!("✅ Parallel transcription task completed - all workers finished, ready for model unload");
     })
 }
 
 /// Transcribe audio chunk using the appropriate provider (Whisper, Parakeet, or trait-based)
-/// Returns: (text, confidence Option, is_partial)
+/// Returns `TranscriptResult::no_speech()` when nothing was recognized.
+///
+/// `config` is the recording's settings snapshot; it also carries the
+/// cross-chunk decoder context (Qwen ASR only).
 async fn transcribe_chunk_with_provider<R: Runtime>(
     engine: &TranscriptionEngine,
     chunk: AudioChunk,
+    config: &SessionConfig,
     app: &AppHandle<R>,
-) -> std::result::Result<(String, Option<f32>, bool), TranscriptionError> {
+) -> std::result::Result<TranscriptResult, TranscriptionError> {
     // Convert to 16kHz mono for transcription
     let transcription_data = if chunk.sample_rate != 16000 {
         crate::audio::audio_processing::resample_audio(&chunk.data, chunk.sample_rate, 16000)
@@ -536,15 +1406,23 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
     // Skip VAD processing here since the pipeline already extracted speech using VAD
     let speech_samples = transcription_data;
 
-    // Check for empty samples - improved error handling
-    if speech_samples.is_empty() {
+    if config.dry_run {
+        let report = dry_run_report(chunk.chunk_id, chunk.sample_rate, &speech_samples);
+        info!("🧪 Dry run for chunk {}: {:?}", chunk.chunk_id, report);
+        let _ = app.emit("transcription-dry-run", &report);
+        return Ok(TranscriptResult::no_speech());
+    }
+
+    if let Some(reason) = chunk_rejection(&speech_samples) {
         warn!(
-            "Audio chunk {} is empty, skipping transcription",
-            chunk.chunk_id
+            "Audio chunk {} rejected ({}, {} samples), skipping transcription",
+            chunk.chunk_id,
+            reason,
+            speech_samples.len()
         );
         return Err(TranscriptionError::AudioTooShort {
-            samples: 0,
-            minimum: 1600, // 100ms at 16kHz
+            samples: speech_samples.len(),
+            minimum: MIN_TRANSCRIBE_SAMPLES,
         });
     }
 
@@ -559,27 +1437,18 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
     );
 
     // Transcribe using the appropriate engine (with improved error handling)
-    match engine {
+    let result = match engine {
         TranscriptionEngine::Whisper(whisper_engine) => {
-            // Get language preference from global state
-            let language = crate::get_language_preference_internal();
+            // Language preference as of recording start
+            let language = config.language.clone();
 
             match whisper_engine
-                .transcribe_audio_with_confidence(speech_samples, language)
+                .transcribe_audio_with_confidence(speech_samples, language.clone())
                 .await
             {
                 Ok((text, confidence, is_partial)) => {
-                    let cleaned_text = text.trim().to_string();
-                    if cleaned_text.is_empty() {
-                        return Ok((String::new(), Some(confidence), is_partial));
-                    }
-
-                    info!(
-                        "Whisper transcription complete for chunk {}: '{}' (confidence: {:.2}, partial: {})",
-                        chunk.chunk_id, cleaned_text, confidence, is_partial
-                    );
-
-                    Ok((cleaned_text, Some(confidence), is_partial))
+                    let cleaned_text = normalize_for_language(text.trim(), language.as_deref());
+                    Ok(TranscriptResult::from_text(&cleaned_text, Some(confidence), is_partial))
                 }
                 Err(e) => {
                     error!(
@@ -604,18 +1473,10 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
         TranscriptionEngine::Parakeet(parakeet_engine) => {
             match parakeet_engine.transcribe_audio(speech_samples).await {
                 Ok(text) => {
-                    let cleaned_text = text.trim().to_string();
-                    if cleaned_text.is_empty() {
-                        return Ok((String::new(), None, false));
-                    }
-
-                    info!(
-                        "Parakeet transcription complete for chunk {}: '{}'",
-                        chunk.chunk_id, cleaned_text
-                    );
-
+                    let language = config.language.clone();
+                    let cleaned_text = normalize_for_language(text.trim(), language.as_deref());
                     // Parakeet doesn't provide confidence or partial results
-                    Ok((cleaned_text, None, false))
+                    Ok(TranscriptResult::from_text(&cleaned_text, None, false))
                 }
                 Err(e) => {
                     error!(
@@ -645,14 +1506,14 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
             let app_for_streaming = app.clone();
             let chunk_id = chunk.chunk_id;
             let chunk_ts = chunk.timestamp;
-            let chunk_dur = speech_samples.len() as f64 / 16000.0;
+            let chunk_dur = speech_samples.len() as f64 / 16000.0 - chunk.leading_overlap_secs;
             let partial_buffer = std::sync::Arc::new(std::sync::Mutex::new(String::new()));
             let partial_buffer_clone = partial_buffer.clone();
             let token_count = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
             let token_count_clone = token_count.clone();
 
             let on_token = move |token: &str| -> bool {
-                let mut buf = partial_buffer_clone.lock().unwrap();
+                let mut buf = partial_buffer_clone.lock().unwrap_or_else(|e| e.into_inner());
                 buf.push_str(token);
                 let count = token_count_clone.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
@@ -660,10 +1521,10 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                 // Uses a dedicated "transcript-partial" event so it doesn't pollute
                 // the sequence_id-ordered "transcript-update" stream.
                 if count % 5 == 4 {
-                    let partial_text = clean_qwen_asr_output(buf.as_str());
+                    let partial_text = clean_qwen_asr_output(complete_words_prefix(buf.as_str()));
                     if !partial_text.is_empty() {
-                        let _ = app_for_streaming.emit(
-                            "transcript-partial",
+                        super::sink::emit_transcript_partial(
+                            &app_for_streaming,
                             serde_json::json!({
                                 "chunk_id": chunk_id,
                                 "text": partial_text,
@@ -677,28 +1538,49 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                 true // continue decoding
             };
 
-            match qwen_engine
-                .transcribe_audio_streaming(speech_samples, on_token)
-                .await
-            {
-                Ok(text) => {
-                    info!("QwenASR raw output for chunk {}: '{}'", chunk_id, text);
-                    let cleaned_text = clean_qwen_asr_output(&text);
-                    if cleaned_text.is_empty() {
-                        info!(
-                            "QwenASR chunk {} cleaned to empty (raw was '{}'), skipping",
-                            chunk_id, text
-                        );
-                        return Ok((String::new(), None, false));
-                    }
-
-                    info!(
-                        "QwenASR transcription complete for chunk {}: '{}'",
-                        chunk_id, cleaned_text
+            let retry_samples = speech_samples.clone();
+            let result = qwen_engine
+                .transcribe_audio_with_context(speech_samples, &config.qwen_decoder_context, on_token)
+                .await;
+            // Garbled streaming output is retried once in batch mode, which returns
+            // the whole transcript in one piece instead of token by token
+            let result = match result {
+                Ok(transcript) if is_garbled_qwen_output(&transcript.text) => {
+                    warn!(
+                        "QwenASR output for chunk {} is full of replacement characters, retrying in batch mode: '{}'",
+                        chunk_id, transcript.text
                     );
-
-                    // Final result (non-partial)
-                    Ok((cleaned_text, None, false))
+                    metrics::record_retry();
+                    match qwen_engine.transcribe_audio_with_confidence(retry_samples).await {
+                        Ok(retried) if !is_garbled_qwen_output(&retried.text) => Ok(retried),
+                        _ => {
+                            warn!("QwenASR retry for chunk {} still garbled, dropping replacement characters", chunk_id);
+                            let text = transcript.text.replace(char::REPLACEMENT_CHARACTER, "");
+                            // The timings still describe the garbled tokens
+                            Ok(QwenAsrTranscript { text, words: Vec::new(), ..transcript })
+                        }
+                    }
+                }
+                other => other,
+            };
+            match result {
+                Ok(transcript) => {
+                    let confidence = transcript.confidence();
+                    let words = super::qwen_asr_provider::qwen_word_timings(transcript.words);
+                    let text = transcript.text;
+                    info!("QwenASR raw output for chunk {}: '{}'", chunk_id, text);
+                    // Prefer the language Qwen detected over the configured one
+                    let language = detect_qwen_language(&text)
+                        .or_else(|| config.language.clone());
+                    let cleaned_text =
+                        normalize_for_language(&clean_qwen_asr_output(&text), language.as_deref());
+                    // Final result (non-partial); word timings are chunk-relative and
+                    // offset by the caller like any provider's
+                    let result = TranscriptResult::from_text(&cleaned_text, Some(confidence), false);
+                    Ok(TranscriptResult {
+                        words: if result.text.is_empty() { None } else { words },
+                        ..result
+                    })
                 }
                 Err(e) => {
                     error!("QwenASR transcription failed for chunk {}: {}", chunk_id, e);
@@ -719,30 +1601,65 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
         }
         TranscriptionEngine::Provider(provider) => {
             // Trait-based provider (clean, unified interface)
-            let language = crate::get_language_preference_internal();
+            let language = config.language.clone();
 
-            match provider.transcribe(speech_samples, language).await {
-                Ok(result) => {
-                    let cleaned_text = result.text.trim().to_string();
-                    if cleaned_text.is_empty() {
-                        return Ok((String::new(), result.confidence, result.is_partial));
+            // Partials run alongside the final request and stop once it returns
+            let final_done = Arc::new(AtomicBool::new(false));
+            if config.emulated_streaming
+                && !provider.supports_streaming()
+                && chunk.priority == ChunkPriority::Live
+            {
+                let windows = emulated_partial_windows(
+                    speech_samples.len(),
+                    EMULATED_STREAMING_WINDOW_SAMPLES,
+                    EMULATED_STREAMING_MAX_PARTIALS,
+                );
+                let provider = provider.clone();
+                let app = app.clone();
+                let samples = speech_samples.clone();
+                let language = language.clone();
+                let final_done = final_done.clone();
+                let chunk_id = chunk.chunk_id;
+                let chunk_ts = chunk.timestamp;
+                let overlap = chunk.leading_overlap_secs;
+                tokio::spawn(async move {
+                    for end in windows {
+                        if final_done.load(Ordering::SeqCst) {
+                            break;
+                        }
+                        let Ok(partial) = provider.transcribe(samples[..end].to_vec(), language.clone()).await
+                        else {
+                            break;
+                        };
+                        let text = normalize_for_language(partial.text.trim(), language.as_deref());
+                        if text.is_empty() || final_done.load(Ordering::SeqCst) {
+                            continue;
+                        }
+                        super::sink::emit_transcript_partial(
+                            &app,
+                            serde_json::json!({
+                                "chunk_id": chunk_id,
+                                "text": text,
+                                "chunk_start_time": chunk_ts,
+                                "audio_start_time": chunk_ts,
+                                "audio_end_time": chunk_ts + end as f64 / 16000.0 - overlap,
+                            }),
+                        );
                     }
+                });
+            }
 
-                    let confidence_str = match result.confidence {
-                        Some(c) => format!("confidence: {:.2}", c),
-                        None => "no confidence".to_string(),
-                    };
+            let transcription = provider.transcribe(speech_samples, language.clone()).await;
+            final_done.store(true, Ordering::SeqCst);
 
-                    info!(
-                        "{} transcription complete for chunk {}: '{}' ({}, partial: {})",
-                        provider.provider_name(),
-                        chunk.chunk_id,
-                        cleaned_text,
-                        confidence_str,
-                        result.is_partial
-                    );
-
-                    Ok((cleaned_text, result.confidence, result.is_partial))
+            match transcription {
+                Ok(result) => {
+                    let cleaned_text =
+                        normalize_for_language(result.text.trim(), language.as_deref());
+                    Ok(TranscriptResult {
+                        words: result.words,
+                        ..TranscriptResult::from_text(&cleaned_text, result.confidence, result.is_partial)
+                    })
                 }
                 Err(e) => {
                     error!(
@@ -765,146 +1682,438 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                 }
             }
         }
+    }?;
+
+    // Every engine reports "no speech" the same way; the caller skips it as completed
+    if result.is_no_speech() {
+        info!("No speech recognized in chunk {}, skipping", chunk.chunk_id);
+        return Ok(TranscriptResult::no_speech());
     }
+
+    let confidence_str = match result.confidence {
+        Some(c) => format!("confidence: {:.2}", c),
+        None => "no confidence".to_string(),
+    };
+    info!(
+        "{} transcription complete for chunk {}: '{}' ({}, partial: {})",
+        engine.provider_name(),
+        chunk.chunk_id,
+        result.text,
+        confidence_str,
+        result.is_partial
+    );
+
+    Ok(result)
 }
 
-/// Remove overlapping text between consecutive transcript segments.
-///
-/// When VAD splits continuous speech, adjacent chunks can produce overlapping transcriptions.
-/// This function finds the longest suffix of `previous` that is a prefix of `current`
-/// and returns `current` with that overlap removed.
-fn remove_text_overlap(previous: &str, current: &str) -> String {
-    let previous = previous.trim();
-    let current = current.trim_start();
+/// The part of a streaming buffer that ends on a complete word, so partials never show
+/// a half-decoded word. Whitespace, punctuation, and CJK characters (each one complete
+/// on its own) all end a word; the trailing in-progress word stays hidden.
+fn complete_words_prefix(text: &str) -> &str {
+    match text
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace() || c.is_ascii_punctuation() || is_cjk_char(*c))
+    {
+        Some((i, c)) => &text[..i + c.len_utf8()],
+        None => "",
+    }
+}
 
-    if previous.is_empty() || current.is_empty() {
-        return current.to_string();
+/// Whether a finalized segment is long enough to emit.
+///
+/// Segments ending in terminal punctuation are treated as complete short
+/// utterances ("No.", "Yes!") and always pass. Space-delimited words are counted
+/// normally; CJK characters count as one word per `CJK_CHARS_PER_WORD`.
+fn meets_min_words(text: &str, min_words: usize) -> bool {
+    if min_words == 0 {
+        return true;
     }
 
-    // Find the longest suffix of `previous` that matches a prefix of `current`.
-    // We compare character-by-character using a sliding window.
-    let prev_chars: Vec<char> = previous.chars().collect();
-    let curr_chars: Vec<char> = current.chars().collect();
+    let text = text.trim();
+    if text.ends_with(['.', '!', '?', '。', '！', '？']) {
+        return true;
+    }
 
-    let mut best_overlap = 0;
+    let cjk_chars = text.chars().filter(|&c| is_cjk_char(c)).count();
+    let other_words = text
+        .split(|c: char| c.is_whitespace() || is_cjk_char(c))
+        .filter(|w| w.chars().any(|c| c.is_alphanumeric()))
+        .count();
+    let words = other_words + cjk_chars.div_ceil(CJK_CHARS_PER_WORD);
 
-    // Only check overlaps of at least 4 characters to avoid false positives
-    let min_overlap = 4;
-    // IMPORTANT: we must allow overlap to exceed half of the current text.
-    // In continuous speech, next segment can be mostly repeated context with
-    // only a few new trailing words.
-    let max_check = curr_chars.len().min(prev_chars.len());
+    words >= min_words
+}
 
-    for overlap_len in min_overlap..=max_check {
-        let prev_suffix_start = prev_chars.len() - overlap_len;
-        let prev_suffix = &prev_chars[prev_suffix_start..];
-        let curr_prefix = &curr_chars[..overlap_len];
+/// Remove overlap between consecutive segments using word timestamps.
+///
+/// Words in `current` whose midpoint falls inside the time range already covered by
+/// `previous` are dropped. Unlike text matching, this neither removes a word that is
+/// legitimately said twice nor misses an overlap the model transcribed differently.
+/// Both slices must use the same (recording-relative) timeline.
+///
+/// `current_text` is the segment's transcript. It is returned as is when nothing
+/// overlaps, and cut after the dropped words when they lead it, so punctuation and
+/// spacing survive; otherwise the kept words are rejoined.
+fn remove_timed_overlap(previous: &[WordTiming], current: &[WordTiming], current_text: &str) -> String {
+    let emitted_end = previous
+        .iter()
+        .map(|w| w.end)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let is_kept = |w: &WordTiming| (w.start + w.end) / 2.0 >= emitted_end;
+
+    let dropped = current.iter().filter(|w| !is_kept(w)).count();
+    if dropped == 0 {
+        return current_text.to_string();
+    }
+    info!(
+        "📝 Removed {} words of timestamp overlap between consecutive segments",
+        dropped
+    );
 
-        if prev_suffix == curr_prefix {
-            best_overlap = overlap_len;
-        }
+    let leading = current.iter().take_while(|w| !is_kept(w)).count();
+    let cut = (leading == dropped)
+        .then(|| end_of_words(current_text, &current[..leading]))
+        .flatten();
+    if let Some(end) = cut {
+        return current_text[end..]
+            .trim_start_matches(|c: char| !c.is_alphanumeric())
+            .to_string();
     }
 
-    if best_overlap >= min_overlap {
-        let deduped: String = curr_chars[best_overlap..].iter().collect();
-        info!(
-            "📝 Removed {} chars of text overlap between consecutive segments",
-            best_overlap
-        );
-        deduped.trim_start().to_string()
-    } else {
-        current.to_string()
+    let mut kept = String::new();
+    for word in current.iter().filter(|w| is_kept(w)) {
+        append_transcript(&mut kept, &word.text);
     }
+    kept
+}
+
+/// Byte offset in `text` just past `words`, found in order; `None` if one is missing.
+fn end_of_words(text: &str, words: &[WordTiming]) -> Option<usize> {
+    words.iter().try_fold(0, |pos, word| {
+        let word = word.text.trim();
+        text[pos..].find(word).map(|i| pos + i + word.len())
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::remove_text_overlap;
+    use super::{
+        backlog_has_room, buffer_level, check_segment_timing, chunk_rejection, clean_qwen_asr_output, complete_words_prefix,
+        dry_run_report, emulated_partial_windows, finalize_decoded_chunk, is_garbled_qwen_output, meets_min_words,
+        remove_timed_overlap, BufferLevel, ChunkLanes, ChunkOutcome, DecodedChunk, LiveRefinementSettings, RecentAudio,
+        ReorderBuffer, SessionConfig, TimingIssue, TranscriptResult, TranscriptionError, WordTiming,
+    };
+    use crate::audio::{AudioChunk, ChunkPriority, RecordingDeviceType};
+
+    fn words(items: &[(&str, f64, f64)]) -> Vec<WordTiming> {
+        items
+            .iter()
+            .map(|(text, start, end)| WordTiming {
+                text: text.to_string(),
+                start: *start,
+                end: *end,
+            })
+            .collect()
+    }
 
     #[test]
-    fn removes_overlap_larger_than_half_of_current() {
-        let previous = "let's review the roadmap for q2 and q3";
-        let current = "roadmap for q2 and q3 plus hiring plan";
-        assert_eq!(remove_text_overlap(previous, current), "plus hiring plan");
+    fn segment_timing_flags_overlap_beyond_drift() {
+        assert_eq!(check_segment_timing(Some(10.0), 9.9, false), None);
+        assert_eq!(check_segment_timing(Some(10.0), 9.0, false), Some(TimingIssue::Overlap(1.0)));
+        assert_eq!(check_segment_timing(Some(10.0), 9.0, true), None);
+        assert_eq!(check_segment_timing(None, 0.0, false), None);
     }
 
     #[test]
-    fn removes_full_duplicate_segment() {
-        let previous = "we should align on launch timeline";
-        let current = "launch timeline";
-        assert_eq!(remove_text_overlap(previous, current), "");
+    fn segment_timing_flags_large_gap() {
+        assert_eq!(check_segment_timing(Some(10.0), 40.0, false), None);
+        assert_eq!(check_segment_timing(Some(10.0), 80.0, false), Some(TimingIssue::Gap(70.0)));
     }
 
     #[test]
-    fn keeps_text_when_no_overlap() {
-        let previous = "budget approved yesterday";
-        let current = "design review starts tomorrow";
-        assert_eq!(
-            remove_text_overlap(previous, current),
-            "design review starts tomorrow"
-        );
+    fn emulated_partial_windows_grow_and_exclude_full_chunk() {
+        assert_eq!(emulated_partial_windows(10, 3, 4), vec![3, 6, 9]);
+        assert_eq!(emulated_partial_windows(9, 3, 4), vec![3, 6]);
+        assert_eq!(emulated_partial_windows(100, 3, 2), vec![3, 6]);
+        assert!(emulated_partial_windows(3, 3, 4).is_empty());
+        assert!(emulated_partial_windows(10, 0, 4).is_empty());
     }
-}
 
-/// Remove QwenASR language-prefix artifacts.
-///
-/// Qwen3-ASR prepends a language tag directly before the transcript with NO separator:
-///   - `language EnglishWhat's your name?`
-///   - `language Chinese吃吃吃。`
-///   - `language None Hello`
-///
-/// We match the known language names exactly to avoid eating transcript content.
-fn clean_qwen_asr_output(text: &str) -> String {
-    // Known Qwen3-ASR language names (case-insensitive).
-    // These are directly concatenated to the transcript without any separator.
-    static LANGUAGE_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
-        Regex::new(concat!(
-            r"(?im)^\s*language\s+(?:",
-            r"English|Chinese|Japanese|Korean|French|German|Spanish|",
-            r"Portuguese|Russian|Italian|Dutch|Turkish|Arabic|Polish|",
-            r"Swedish|Norwegian|Danish|Finnish|Hungarian|Czech|Romanian|",
-            r"Bulgarian|Greek|Serbian|Croatian|Slovak|Slovenian|",
-            r"Ukrainian|Catalan|Vietnamese|Thai|Indonesian|Malay|",
-            r"Hindi|Tamil|Telugu|Bengali|Urdu|Persian|Hebrew|",
-            r"Cantonese|Yue|None|null",
-            r")[:：]?\s*"
-        ))
-        .expect("valid regex")
-    });
-    static LANGUAGE_SENTENCE_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
-        Regex::new(concat!(
-            r"(?i)([。！？.!?]\s*)language\s+(?:",
-            r"English|Chinese|Japanese|Korean|French|German|Spanish|",
-            r"Portuguese|Russian|Italian|Dutch|Turkish|Arabic|Polish|",
-            r"Swedish|Norwegian|Danish|Finnish|Hungarian|Czech|Romanian|",
-            r"Bulgarian|Greek|Serbian|Croatian|Slovak|Slovenian|",
-            r"Ukrainian|Catalan|Vietnamese|Thai|Indonesian|Malay|",
-            r"Hindi|Tamil|Telugu|Bengali|Urdu|Persian|Hebrew|",
-            r"Cantonese|Yue|None|null",
-            r")[:：]?\s*"
-        ))
-        .expect("valid regex")
-    });
-    static MULTISPACE_RE: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"[ \t]{2,}").expect("valid regex"));
+    #[test]
+    fn timed_overlap_drops_words_inside_previous_range() {
+        let previous = words(&[("ship", 10.0, 10.4), ("it", 10.4, 10.6), ("friday", 10.6, 11.2)]);
+        let current = words(&[("friday", 10.7, 11.1), ("then", 11.3, 11.5), ("retro", 11.5, 12.0)]);
+        assert_eq!(remove_timed_overlap(&previous, &current, "friday, then retro."), "then retro.");
+    }
 
-    let mut cleaned = text.trim().to_string();
-    if cleaned.is_empty() {
-        return cleaned;
+    #[test]
+    fn timed_overlap_keeps_repeated_words_said_later() {
+        // Text matching would strip the second "thank you"
+        let previous = words(&[("thank", 5.0, 5.3), ("you", 5.3, 5.5)]);
+        let current = words(&[("thank", 6.0, 6.3), ("you", 6.3, 6.5), ("all", 6.5, 6.8)]);
+        assert_eq!(remove_timed_overlap(&previous, &current, "Thank you all!"), "Thank you all!");
     }
 
-    cleaned = LANGUAGE_PREFIX_RE.replace_all(&cleaned, "").into_owned();
-    loop {
-        let next = LANGUAGE_SENTENCE_PREFIX_RE
-            .replace_all(&cleaned, "$1")
-            .into_owned();
-        if next == cleaned {
-            break;
+    #[test]
+    fn timed_overlap_handles_reworded_overlap() {
+        // Same audio transcribed differently: text matching would miss it
+        let previous = words(&[("gonna", 2.0, 2.4), ("start", 2.4, 2.8)]);
+        let current = words(&[("going", 2.0, 2.2), ("to", 2.2, 2.4), ("start", 2.4, 2.8), ("now", 3.0, 3.3)]);
+        assert_eq!(remove_timed_overlap(&previous, &current, "going to start now"), "now");
+    }
+
+    #[test]
+    fn timed_overlap_keeps_cjk_text_unspaced() {
+        let previous = words(&[("我们", 1.0, 1.3), ("今天", 1.3, 1.6)]);
+        let current = words(&[("今天", 1.3, 1.6), ("讨论", 1.7, 2.0), ("预算", 2.0, 2.3)]);
+        assert_eq!(remove_timed_overlap(&previous, &current, "今天讨论预算。"), "讨论预算。");
+
+        // Words missing from the text are rejoined without spaces between CJK words
+        assert_eq!(remove_timed_overlap(&previous, &current, "今日は予算"), "讨论预算");
+    }
+
+    #[test]
+    fn min_words_disabled_by_default() {
+        assert!(meets_min_words("uh", 0));
+    }
+
+    #[test]
+    fn min_words_drops_filler() {
+        assert!(!meets_min_words("uh", 2));
+        assert!(!meets_min_words("mm, okay", 3));
+        assert!(meets_min_words("let's move on", 3));
+    }
+
+    #[test]
+    fn min_words_keeps_complete_short_utterance() {
+        assert!(meets_min_words("No.", 3));
+        assert!(meets_min_words("好的。", 3));
+    }
+
+    #[test]
+    fn min_words_counts_cjk_characters() {
+        assert!(!meets_min_words("嗯", 2));
+        assert!(meets_min_words("我们开始吧", 2));
+        assert!(meets_min_words("ok 好的", 2));
+    }
+
+    #[test]
+    fn garbled_output_detects_dense_replacement_chars() {
+        assert!(is_garbled_qwen_output("language Chinese\u{FFFD}\u{FFFD}好\u{FFFD}"));
+        assert!(is_garbled_qwen_output("\u{FFFD}\u{FFFD} \u{FFFD}"));
+        // One stray bad token in a long sentence is not worth a retry
+        assert!(!is_garbled_qwen_output("we should ship the release on friday\u{FFFD}"));
+        assert!(!is_garbled_qwen_output("我们开始吧"));
+        assert!(!is_garbled_qwen_output(""));
+    }
+
+    #[test]
+    fn buffer_level_warns_before_the_cap() {
+        assert_eq!(buffer_level(10_000_000, 0), BufferLevel::Normal);
+        assert_eq!(buffer_level(79_000, 100), BufferLevel::Normal);
+        assert_eq!(buffer_level(80_000, 100), BufferLevel::NearCap);
+        assert_eq!(buffer_level(100_000, 100), BufferLevel::Full);
+    }
+
+    #[test]
+    fn producer_drops_chunks_past_the_backlog_cap() {
+        // A stalled transcriber: the producer keeps sending 1s chunks and nothing drains
+        let max_secs = 10;
+        let mut backlog_ms = 0;
+        let mut dropped = 0;
+        for _ in 0..1000 {
+            if backlog_has_room(backlog_ms, 1000, max_secs, ChunkPriority::Live) {
+                backlog_ms += 1000;
+            } else {
+                dropped += 1;
+            }
+        }
+        assert_eq!(backlog_ms, 20_000);
+        assert_eq!(dropped, 980);
+
+        // Background work is refused as soon as the cap is reached
+        assert!(backlog_has_room(9_000, 1000, max_secs, ChunkPriority::Background));
+        assert!(!backlog_has_room(10_000, 1000, max_secs, ChunkPriority::Background));
+        // Once the workers drain some audio, live chunks are admitted again
+        assert!(backlog_has_room(backlog_ms - 1000, 1000, max_secs, ChunkPriority::Live));
+        // 0 = unlimited
+        assert!(backlog_has_room(u64::MAX / 2, 1000, 0, ChunkPriority::Live));
+    }
+
+    #[test]
+    fn dry_run_report_explains_rejections() {
+        let report = dry_run_report(1, 48000, &[]);
+        assert_eq!((report.accepted, report.reason), (false, Some("empty")));
+
+        let report = dry_run_report(2, 16000, &[0.1; 800]);
+        assert_eq!((report.accepted, report.reason), (false, Some("tooShort")));
+
+        let report = dry_run_report(3, 16000, &[0.0; 16000]);
+        assert!(report.accepted && report.near_silent);
+
+        let report = dry_run_report(4, 16000, &[0.1; 16000]);
+        assert!(report.accepted && !report.near_silent);
+        assert_eq!(report.duration_secs, 1.0);
+
+        // The real path rejects the same chunks
+        assert_eq!(chunk_rejection(&[0.1; 800]), Some("tooShort"));
+        assert_eq!(chunk_rejection(&[0.1; 1600]), None);
+    }
+
+    fn chunk(chunk_id: u64, priority: ChunkPriority) -> AudioChunk {
+        AudioChunk {
+            data: vec![0.0; 160],
+            sample_rate: 16000,
+            timestamp: chunk_id as f64,
+            chunk_id,
+            device_type: RecordingDeviceType::Microphone,
+            leading_overlap_secs: 0.0,
+            priority,
         }
-        cleaned = next;
     }
-    cleaned = MULTISPACE_RE.replace_all(&cleaned, " ").into_owned();
-    cleaned.trim().to_string()
+
+    #[test]
+    fn partials_stop_at_the_last_complete_word() {
+        assert_eq!(complete_words_prefix("hello wor"), "hello ");
+        assert_eq!(complete_words_prefix("hello world."), "hello world.");
+        assert_eq!(complete_words_prefix("hello world "), "hello world ");
+        assert_eq!(complete_words_prefix("partial"), "");
+        // CJK characters are complete as soon as they arrive
+        assert_eq!(complete_words_prefix("你好世界"), "你好世界");
+        assert_eq!(complete_words_prefix("你好 wor"), "你好 ");
+        assert_eq!(complete_words_prefix("你好wor"), "你好");
+    }
+
+    #[test]
+    fn qwen_language_tag_alone_is_no_speech() {
+        let result = TranscriptResult::from_text(&clean_qwen_asr_output("language None"), None, false);
+        assert!(result.is_no_speech());
+        let result = TranscriptResult::from_text(&clean_qwen_asr_output("language English Hi."), None, false);
+        assert_eq!(result.text, "Hi.");
+    }
+
+    #[test]
+    fn recent_audio_refines_the_window_with_gaps_as_silence() {
+        let mut recent = RecentAudio::new(10);
+        for (chunk_id, timestamp) in [(1, 0.0), (2, 6.0), (3, 12.0)] {
+            recent.push(&AudioChunk {
+                data: vec![1.0; 16000 * 2],
+                timestamp,
+                ..chunk(chunk_id, ChunkPriority::Live)
+            });
+        }
+
+        // Audio ends at 14s; the 0-2s chunk fell out of the 10s window
+        let refinement = recent.refinement_chunk().unwrap();
+        assert_eq!(refinement.priority, ChunkPriority::Background);
+        assert_eq!(refinement.timestamp, 6.0);
+        assert_eq!(refinement.chunk_id, 3);
+        assert_eq!(refinement.data.len(), 16000 * 8);
+        assert_eq!(refinement.data[16000], 1.0);
+        assert_eq!(refinement.data[16000 * 3], 0.0); // 8-12s was silence
+        assert_eq!(refinement.data[16000 * 7], 1.0);
+
+        // Nothing new since the last refinement
+        assert!(recent.refinement_chunk().is_none());
+    }
+
+    #[tokio::test]
+    async fn chunk_lanes_prefer_live_chunks() {
+        let (live_tx, live) = tokio::sync::mpsc::unbounded_channel();
+        let (background_tx, background) = tokio::sync::mpsc::unbounded_channel();
+        let mut lanes = ChunkLanes { live, background };
+
+        background_tx.send(chunk(1, ChunkPriority::Background)).unwrap();
+        live_tx.send(chunk(2, ChunkPriority::Live)).unwrap();
+        background_tx.send(chunk(3, ChunkPriority::Background)).unwrap();
+        live_tx.send(chunk(4, ChunkPriority::Live)).unwrap();
+        drop(live_tx);
+        drop(background_tx);
+
+        let mut order = Vec::new();
+        while let Some(chunk) = lanes.recv().await {
+            order.push(chunk.chunk_id);
+        }
+        assert_eq!(order, vec![2, 4, 1, 3]);
+    }
+
+    #[test]
+    fn reorder_buffer_emits_updates_in_dispatch_order() {
+        let config = SessionConfig {
+            provider: "test".to_string(),
+            model: None,
+            language: None,
+            confidence_threshold: 0.0,
+            min_words: 0,
+            emulated_streaming: false,
+            timing_validation: false,
+            dry_run: false,
+            live_refinement: LiveRefinementSettings { enabled: false, interval_secs: 30, window_secs: 60 },
+            max_workers: 4,
+            prewarm: false,
+            qwen_decoder_context: Arc::new(QwenDecoderContext::default()),
+        };
+        // Chunks far enough apart that overlap dedup never applies
+        let decoded = |ticket: u64, result: Result<TranscriptResult, TranscriptionError>| DecodedChunk {
+            chunk_id: ticket,
+            chunk_timestamp: 1000.0 + ticket as f64 * 10.0,
+            scheduled_refinement: false,
+            leading_overlap_secs: 0.0,
+            chunk_duration: 1.0,
+            should_log_this_chunk: false,
+            result,
+        };
+        let text = |t: &str| Ok(TranscriptResult::from_text(t, None, false));
+
+        let mut reorder = ReorderBuffer::new();
+        let mut updates = Vec::new();
+        // Workers finish out of order; ticket 1's engine panicked and completes as a failure
+        let finished = [
+            (2, text("two")),
+            (0, text("zero")),
+            (3, text("three")),
+            (1, Err(TranscriptionError::EngineFailed("Transcription engine panicked".to_string()))),
+            (4, text("four")),
+        ];
+        for (ticket, result) in finished {
+            let ready = reorder.push(ticket, decoded(ticket, result));
+            if ticket == 2 {
+                assert!(ready.is_empty(), "held until earlier chunks finish");
+            }
+            for chunk in ready {
+                match finalize_decoded_chunk(chunk, &config, 0) {
+                    ChunkOutcome::Emit(update, _) => updates.push(update),
+                    ChunkOutcome::Failed(_) => {}
+                    ChunkOutcome::Dropped { reason, .. } => panic!("unexpected drop: {:?}", reason),
+                }
+            }
+        }
+
+        let texts: Vec<&str> = updates.iter().map(|u| u.text.as_str()).collect();
+        assert_eq!(texts, vec!["zero", "two", "three", "four"]);
+        assert!(updates.windows(2).all(|pair| {
+            pair[0].sequence_id < pair[1].sequence_id && pair[0].audio_start_time < pair[1].audio_start_time
+        }));
+        assert!(reorder.pending.is_empty());
+    }
+}
+
+/// Share of U+FFFD among non-whitespace characters above which a Qwen result is
+/// treated as garbled rather than as a stray bad token.
+const GARBLED_REPLACEMENT_RATIO: f64 = 0.1;
+
+/// Whether `text` is dense with U+FFFD replacement characters. That happens when
+/// multi-byte (e.g. CJK) tokens are split while decoding, and the regexes in
+/// `clean_qwen_asr_output` then run on corrupted text.
+pub(crate) fn is_garbled_qwen_output(text: &str) -> bool {
+    let (replacements, total) = text
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .fold((0usize, 0usize), |(replacements, total), c| {
+            (replacements + usize::from(c == char::REPLACEMENT_CHARACTER), total + 1)
+        });
+    replacements > 0 && replacements as f64 / total as f64 > GARBLED_REPLACEMENT_RATIO
 }
 
 /// Format current timestamp (wall-clock time)
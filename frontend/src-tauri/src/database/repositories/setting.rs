@@ -1,4 +1,5 @@
 use crate::database::models::{Setting, TranscriptSetting};
+use crate::qwen_asr_engine::QwenAsrConfig;
 use crate::summary::CustomOpenAIConfig;
 use sqlx::SqlitePool;
 
@@ -180,6 +181,7 @@ impl SettingsRepository {
         let api_key_column = match provider {
             "localWhisper" => "whisperApiKey",
             "parakeet" | "qwenAsr" => return Ok(()), // Local engines don't need an API key
+            "openaiCompatible" => "openaiCompatibleApiKey",
             "deepgram" => "deepgramApiKey",
             "elevenLabs" => "elevenLabsApiKey",
             "groq" => "groqApiKey",
@@ -205,6 +207,49 @@ impl SettingsRepository {
         Ok(())
     }
 
+    /// Gets the Qwen ASR decoding config (GPU device, threads, temperature) from JSON
+    pub async fn get_qwen_asr_config(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<QwenAsrConfig>, sqlx::Error> {
+        use sqlx::Row;
+
+        let row = sqlx::query("SELECT qwenAsrConfig FROM transcript_settings WHERE id = '1' LIMIT 1")
+            .fetch_optional(pool)
+            .await?;
+
+        let config_json: Option<String> = row.and_then(|record| record.get("qwenAsrConfig"));
+        config_json
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in qwenAsrConfig: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the Qwen ASR decoding config as JSON
+    pub async fn save_qwen_asr_config(
+        pool: &SqlitePool,
+        config: &QwenAsrConfig,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let config_json = serde_json::to_string(config).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize config to JSON: {}", e).into())
+        })?;
+
+        let query = format!(
+            r#"
+            INSERT INTO transcript_settings (id, provider, model, qwenAsrConfig)
+            VALUES ('1', 'parakeet', '{}', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                qwenAsrConfig = excluded.qwenAsrConfig
+            "#,
+            crate::config::DEFAULT_PARAKEET_MODEL
+        );
+        sqlx::query(&query).bind(config_json).execute(pool).await?;
+
+        Ok(())
+    }
+
     pub async fn get_transcript_api_key(
         pool: &SqlitePool,
         provider: &str,
@@ -212,6 +257,7 @@ impl SettingsRepository {
         let api_key_column = match provider {
             "localWhisper" => "whisperApiKey",
             "parakeet" | "qwenAsr" => return Ok(None), // Local engines don't need an API key
+            "openaiCompatible" => "openaiCompatibleApiKey",
             "deepgram" => "deepgramApiKey",
             "elevenLabs" => "elevenLabsApiKey",
             "groq" => "groqApiKey",
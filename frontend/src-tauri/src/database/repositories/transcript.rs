@@ -10,17 +10,31 @@ impl TranscriptsRepository {
     /// Saves a new meeting and its associated transcript segments.
     /// This function uses a transaction to ensure that either both the meeting
     /// and all its transcripts are saved, or none of them are.
+    ///
+    /// `meeting_id` is the id assigned when recording started; a fresh one is
+    /// generated when it is missing or already taken.
     pub async fn save_transcript(
         pool: &SqlitePool,
         meeting_title: &str,
         transcripts: &[TranscriptSegment],
         folder_path: Option<String>,
+        meeting_id: Option<String>,
     ) -> Result<String, SqlxError> {
-        let meeting_id = format!("meeting-{}", Uuid::new_v4());
-
         let mut conn = pool.acquire().await?;
         let mut transaction = conn.begin().await?;
 
+        let assigned = match meeting_id {
+            Some(id) => {
+                let taken: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM meetings WHERE id = ?")
+                    .bind(&id)
+                    .fetch_one(&mut *transaction)
+                    .await?;
+                (taken == 0).then_some(id)
+            }
+            None => None,
+        };
+        let meeting_id = assigned.unwrap_or_else(|| format!("meeting-{}", Uuid::new_v4()));
+
         let now = Utc::now();
 
         // 1. Create the new meeting
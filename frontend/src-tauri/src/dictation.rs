@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
-use regex::Regex;
+use regex::{NoExpand, Regex, RegexBuilder};
+use std::collections::VecDeque;
 use std::io::Write;
 use std::process::{Command, Stdio};
-use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU32, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{LazyLock, Mutex as StdMutex};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
@@ -10,6 +11,7 @@ use crate::audio::audio_processing::{audio_to_mono, resample_audio};
 use crate::audio::extract_speech_16k;
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use tauri::{AppHandle, Emitter, Manager, Runtime, WebviewUrl, WebviewWindowBuilder};
+use tauri_plugin_store::StoreExt;
 #[cfg(target_os = "macos")]
 use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
 #[cfg(target_os = "macos")]
@@ -28,11 +30,64 @@ use core_graphics::event::{
 const DICTATION_WIDGET_LABEL: &str = "dictation-widget";
 const DICTATION_WIDGET_WIDTH: f64 = 400.0;
 const DICTATION_WIDGET_HEIGHT: f64 = 128.0;
-const MAX_DICTATION_SECONDS: usize = 60;
+const DEFAULT_MAX_DICTATION_SECONDS: u64 = 60;
+const MAX_DICTATION_SECONDS_LIMIT: u64 = 600;
+const MIN_DICTATION_SECONDS_LIMIT: u64 = 5;
+/// The widget warns this long before a dictation reaches its max length
+const MAX_LENGTH_WARNING_SECONDS: u64 = 10;
 const DICTATION_LOW_LATENCY_BUFFER_TARGET_FRAMES: u32 = 256;
 const DICTATION_CAPTURE_WARMUP_TIMEOUT_MS: u64 = 300;
+/// How long start_dictation waits for the first audio callback before giving up
+const DICTATION_CAPTURE_LIVE_TIMEOUT_MS: u64 = 2_000;
+/// Pause after capture goes live before showing "Listening", so the first word isn't clipped
+const DEFAULT_READY_DELAY_MS: u64 = 150;
+const MAX_READY_DELAY_MS: u64 = 1_000;
+/// Longest gap between the two presses of a double-tap hotkey
+const DEFAULT_DOUBLE_TAP_WINDOW_MS: u64 = 300;
+const MIN_DOUBLE_TAP_WINDOW_MS: u64 = 100;
+const MAX_DOUBLE_TAP_WINDOW_MS: u64 = 1_000;
+const DEFAULT_PREROLL_MS: u64 = 500;
+const MAX_PREROLL_MS: u64 = 2_000;
+// Buffer headroom past MAX_DICTATION_SECONDS, so audio isn't evicted while a session
+// that hit the limit is being stopped
+const DICTATION_WATCHDOG_GRACE_SECONDS: u64 = 5;
+const MIN_CAPTURE_SAMPLE_RATE: u32 = 8_000;
+const MAX_CAPTURE_SAMPLE_RATE: u32 = 192_000;
+/// Shortest clip (in ms) worth transcribing; applied both before and after resampling
+const DEFAULT_MIN_DICTATION_MS: u64 = 200;
+const MAX_MIN_DICTATION_MS: u64 = 5_000;
+/// Audio dropped from each end of a clip to skip the hotkey click or a breath (0 = off)
+const MAX_EDGE_TRIM_MS: u64 = 300;
+/// Edges are only trimmed when at least this much audio remains, so short words survive
+const MIN_TRIMMED_CLIP_MS: u64 = 1_000;
+/// Resampling may shave a few samples off the end, so the 16 kHz check allows this much slack
+const RESAMPLE_TOLERANCE_MS: f64 = 5.0;
+/// Saved dictation recordings are pruned oldest-first past either limit
+const SAVED_RECORDINGS_DIR: &str = "dictation-recordings";
+const MAX_SAVED_RECORDINGS: usize = 100;
+const MAX_SAVED_RECORDINGS_BYTES: u64 = 200 * 1024 * 1024;
+/// fn never reaches apps outside macOS, so other platforms default to ctrl+shift+space
+#[cfg(target_os = "macos")]
 const DEFAULT_HOTKEY: &str = "fn+space";
+#[cfg(not(target_os = "macos"))]
+const DEFAULT_HOTKEY: &str = "ctrl+shift+space";
+/// Most UTF-16 units one synthesized keyboard event can carry
+const TYPE_CHUNK_UTF16_UNITS: usize = 20;
+/// Pause between typed chunks so slower apps don't drop characters
+const TYPE_CHUNK_DELAY_MS: u64 = 4;
 const DEBUG_EVENT_LIMIT: usize = 50;
+/// Recent transcripts kept for re-pasting; longer ones are truncated in the history only
+const DICTATION_HISTORY_LIMIT: usize = 20;
+const MAX_HISTORY_ENTRY_CHARS: usize = 10_000;
+/// How often the widget's level meter is updated while recording
+const LEVEL_METER_INTERVAL_MS: u64 = 100;
+/// RMS at or below this reads as an empty meter; 0 dBFS reads as full
+const LEVEL_METER_FLOOR_DB: f32 = -60.0;
+/// Bounds on the user's replacement list. The regex crate never backtracks, so the
+/// remaining risk is a pattern that compiles to a huge automaton; cap its size too.
+const MAX_REPLACEMENTS: usize = 200;
+const MAX_REPLACEMENT_PATTERN_CHARS: usize = 200;
+const REPLACEMENT_REGEX_SIZE_LIMIT: usize = 1 << 20;
 const KEY_RETURN: u16 = 0x24;
 const KEY_TAB: u16 = 0x30;
 const KEY_SPACE: u16 = 0x31;
@@ -106,18 +161,61 @@ const KEY_F20: u16 = 0x5A;
 static DICTATION_ACTIVE: AtomicBool = AtomicBool::new(false);
 static DICTATION_PROCESSING: AtomicBool = AtomicBool::new(false);
 static DICTATION_PREWARMING: AtomicBool = AtomicBool::new(false);
+// Incremented on every successful start so the watchdog only stops its own session
+static DICTATION_SESSION: AtomicU64 = AtomicU64::new(0);
 static HOTKEY_HELD: AtomicBool = AtomicBool::new(false);
+// While set, hotkey matches are only reported (settings UI), never start dictation
+static HOTKEY_TEST_MODE: AtomicBool = AtomicBool::new(false);
+/// Warn once per capture about misaligned buffers instead of on every callback
+static MISALIGNED_CAPTURE_WARNED: AtomicBool = AtomicBool::new(false);
+// Loudest RMS and peak seen since the level meter last read them, stored as f32 bits.
+// Non-negative floats order the same as their bits, so the capture callback can use
+// fetch_max without locking.
+static LEVEL_RMS_BITS: AtomicU32 = AtomicU32::new(0);
+static LEVEL_PEAK_BITS: AtomicU32 = AtomicU32::new(0);
+static READY_DELAY_MS: AtomicU64 = AtomicU64::new(DEFAULT_READY_DELAY_MS);
+/// Always-on pre-roll keeps the microphone open between dictations, so it is strictly opt-in
+static PREROLL_ENABLED: AtomicBool = AtomicBool::new(false);
+static PREROLL_MS: AtomicU64 = AtomicU64::new(DEFAULT_PREROLL_MS);
+static MIN_DICTATION_MS: AtomicU64 = AtomicU64::new(DEFAULT_MIN_DICTATION_MS);
+static MAX_DICTATION_SECONDS: AtomicU64 = AtomicU64::new(DEFAULT_MAX_DICTATION_SECONDS);
+static EDGE_TRIM_MS: AtomicU64 = AtomicU64::new(0);
+/// Keep a WAV of each dictation's audio for troubleshooting (local only, opt-in)
+static SAVE_RECORDINGS: AtomicBool = AtomicBool::new(false);
 static FN_HELD: AtomicBool = AtomicBool::new(false);
 static CMD_HELD: AtomicBool = AtomicBool::new(false);
 static CTRL_HELD: AtomicBool = AtomicBool::new(false);
 static ALT_HELD: AtomicBool = AtomicBool::new(false);
 static SHIFT_HELD: AtomicBool = AtomicBool::new(false);
 static HOTKEY_KEY_CODE: AtomicU16 = AtomicU16::new(KEY_SPACE);
-static HOTKEY_REQUIRE_FN: AtomicBool = AtomicBool::new(true);
-static HOTKEY_REQUIRE_CONTROL: AtomicBool = AtomicBool::new(false);
+static HOTKEY_REQUIRE_FN: AtomicBool = AtomicBool::new(cfg!(target_os = "macos"));
+static HOTKEY_REQUIRE_CONTROL: AtomicBool = AtomicBool::new(!cfg!(target_os = "macos"));
 static HOTKEY_REQUIRE_COMMAND: AtomicBool = AtomicBool::new(false);
 static HOTKEY_REQUIRE_OPTION: AtomicBool = AtomicBool::new(false);
-static HOTKEY_REQUIRE_SHIFT: AtomicBool = AtomicBool::new(false);
+static HOTKEY_REQUIRE_SHIFT: AtomicBool = AtomicBool::new(!cfg!(target_os = "macos"));
+static HOTKEY_CONSUME: AtomicBool = AtomicBool::new(true);
+static HOTKEY_DOUBLE_TAP: AtomicBool = AtomicBool::new(false);
+static DOUBLE_TAP_WINDOW_MS: AtomicU64 = AtomicU64::new(DEFAULT_DOUBLE_TAP_WINDOW_MS);
+// Time of a pending first tap (0 = none) and whether the tapped key is currently down,
+// so OS key repeat isn't mistaken for a second tap
+static DOUBLE_TAP_FIRST_MS: AtomicU64 = AtomicU64::new(0);
+static DOUBLE_TAP_KEY_DOWN: AtomicBool = AtomicBool::new(false);
+// The second tap's KeyDown was swallowed, so its repeats and KeyUp are too
+#[cfg(target_os = "macos")]
+static DOUBLE_TAP_KEY_CONSUMED: AtomicBool = AtomicBool::new(false);
+// Toggle mode: one hotkey press starts, the next stops. Kept as an atomic because the
+// event tap callback must never block.
+static DICTATION_TOGGLE_MODE: AtomicBool = AtomicBool::new(false);
+static DICTATION_TYPE_OUTPUT: AtomicBool = AtomicBool::new(false);
+// rdev::listen can't be interrupted, so stopping the listener only disables it; the
+// thread keeps running and is re-enabled on the next start.
+#[cfg(not(target_os = "macos"))]
+static RDEV_LISTENER_RUNNING: AtomicBool = AtomicBool::new(false);
+#[cfg(not(target_os = "macos"))]
+static RDEV_LISTENER_ENABLED: AtomicBool = AtomicBool::new(false);
+// Key repeat re-sends KeyPress while a key is held; set from the main key's press to its release
+#[cfg(not(target_os = "macos"))]
+static HOTKEY_KEY_DOWN: AtomicBool = AtomicBool::new(false);
 #[cfg(target_os = "macos")]
 static ACCESSIBILITY_PROMPTED_THIS_SESSION: AtomicBool = AtomicBool::new(false);
 #[cfg(target_os = "macos")]
@@ -128,10 +226,102 @@ static DICTATION_PERMISSION_WARNING_NOTIFIED_THIS_SESSION: AtomicBool = AtomicBo
 static DICTATION_PERMISSION_WARNING_DIALOG_SHOWN_THIS_SESSION: AtomicBool = AtomicBool::new(false);
 
 static LAST_TRANSCRIPT: LazyLock<StdMutex<Option<String>>> = LazyLock::new(|| StdMutex::new(None));
+static LAST_LATENCY: LazyLock<StdMutex<Option<DictationLatency>>> = LazyLock::new(|| StdMutex::new(None));
+/// Newest first
+static DICTATION_HISTORY: LazyLock<StdMutex<VecDeque<DictationHistoryEntry>>> =
+    LazyLock::new(|| StdMutex::new(VecDeque::with_capacity(DICTATION_HISTORY_LIMIT)));
 static HOTKEY_CONFIG: LazyLock<StdMutex<DictationHotkeyConfig>> =
     LazyLock::new(|| StdMutex::new(DictationHotkeyConfig::default()));
 static DICTATION_DEBUG_STATE: LazyLock<StdMutex<DictationDebugState>> =
     LazyLock::new(|| StdMutex::new(DictationDebugState::default()));
+static DICTATION_REPLACEMENTS: LazyLock<StdMutex<Vec<CompiledReplacement>>> =
+    LazyLock::new(|| StdMutex::new(Vec::new()));
+/// Dictation-only language; None follows the global (meeting) language preference
+static DICTATION_LANGUAGE: LazyLock<StdMutex<Option<String>>> = LazyLock::new(|| StdMutex::new(None));
+
+/// One entry of the user's vocabulary list, e.g. "meet lee" -> "Meetily". Entries run
+/// in order, each on the previous one's output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DictationReplacement {
+    pattern: String,
+    replacement: String,
+    /// Treat `pattern` as a regex (with `$1`-style groups in `replacement`) instead of literal text
+    #[serde(default)]
+    regex: bool,
+    #[serde(default)]
+    case_sensitive: bool,
+}
+
+struct CompiledReplacement {
+    entry: DictationReplacement,
+    regex: Regex,
+}
+
+impl CompiledReplacement {
+    /// Validate and compile one entry. Literal patterns only match whole words at
+    /// their word-character edges, so "ai" doesn't rewrite "said".
+    fn compile(entry: DictationReplacement) -> Result<Self, String> {
+        if entry.pattern.trim().is_empty() {
+            return Err("Pattern cannot be empty".to_string());
+        }
+        if entry.pattern.chars().count() > MAX_REPLACEMENT_PATTERN_CHARS {
+            return Err(format!(
+                "Pattern is longer than {} characters",
+                MAX_REPLACEMENT_PATTERN_CHARS
+            ));
+        }
+
+        let source = if entry.regex {
+            entry.pattern.clone()
+        } else {
+            let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+            format!(
+                "{}{}{}",
+                if is_word(entry.pattern.chars().next()) { r"\b" } else { "" },
+                regex::escape(&entry.pattern),
+                if is_word(entry.pattern.chars().last()) { r"\b" } else { "" },
+            )
+        };
+        let regex = RegexBuilder::new(&source)
+            .case_insensitive(!entry.case_sensitive)
+            .size_limit(REPLACEMENT_REGEX_SIZE_LIMIT)
+            .dfa_size_limit(REPLACEMENT_REGEX_SIZE_LIMIT)
+            .build()
+            .map_err(|e| format!("Invalid pattern '{}': {e}", entry.pattern))?;
+        // A pattern matching nothing at all would insert the replacement between every character
+        if regex.is_match("") {
+            return Err(format!("Pattern '{}' matches empty text", entry.pattern));
+        }
+
+        Ok(Self { entry, regex })
+    }
+
+    fn apply(&self, text: &str) -> String {
+        if self.entry.regex {
+            self.regex.replace_all(text, self.entry.replacement.as_str()).into_owned()
+        } else {
+            self.regex.replace_all(text, NoExpand(&self.entry.replacement)).into_owned()
+        }
+    }
+}
+
+/// Compile a whole list, naming the first bad entry (1-based) on failure
+fn compile_replacements(entries: Vec<DictationReplacement>) -> Result<Vec<CompiledReplacement>, String> {
+    if entries.len() > MAX_REPLACEMENTS {
+        return Err(format!("At most {} replacements are supported", MAX_REPLACEMENTS));
+    }
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| CompiledReplacement::compile(entry).map_err(|e| format!("Replacement {}: {e}", i + 1)))
+        .collect()
+}
+
+fn apply_replacements(text: &str, replacements: &[CompiledReplacement]) -> String {
+    replacements
+        .iter()
+        .fold(text.to_string(), |acc, replacement| replacement.apply(&acc))
+}
 
 #[derive(Debug, Clone, Serialize)]
 struct WidgetPayload {
@@ -140,16 +330,85 @@ struct WidgetPayload {
     #[serde(skip_serializing_if = "Option::is_none")]
     transcript: Option<String>,
     hotkey: String,
+    mode: DictationMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+/// `dictation-level` payload: `level` is RMS on a dB scale, `peak` the raw sample peak,
+/// both 0.0-1.0
+#[derive(Debug, Clone, Copy, Serialize)]
+struct DictationLevel {
+    level: f32,
+    peak: f32,
+}
+
+/// How the hotkey controls a dictation session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DictationMode {
+    /// Record while the hotkey is held, stop on release
+    PushToTalk,
+    /// One press starts recording, the next press stops it
+    Toggle,
+}
+
+/// How a finished transcript reaches the focused app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DictationOutputMode {
+    /// Put the text on the clipboard, press Cmd+V, then restore the clipboard
+    Paste,
+    /// Synthesize keyboard events carrying the text, leaving the clipboard alone
+    Type,
+}
+
+fn dictation_output_mode() -> DictationOutputMode {
+    if DICTATION_TYPE_OUTPUT.load(Ordering::SeqCst) {
+        DictationOutputMode::Type
+    } else {
+        DictationOutputMode::Paste
+    }
+}
+
+fn dictation_mode() -> DictationMode {
+    if DICTATION_TOGGLE_MODE.load(Ordering::SeqCst) {
+        DictationMode::Toggle
+    } else {
+        DictationMode::PushToTalk
+    }
+}
+
+/// `action` recorded for a hotkey debug event. HOTKEY_HELD tracks the key being held
+/// in push-to-talk mode and the recording being switched on in toggle mode.
+fn hotkey_debug_action(test_mode: bool, mode: DictationMode, held_before: bool, held_after: bool) -> &'static str {
+    match (test_mode, mode, held_before, held_after) {
+        (true, _, _, _) => "test",
+        (false, DictationMode::PushToTalk, false, true) => "start",
+        (false, DictationMode::PushToTalk, true, false) => "stop",
+        (false, DictationMode::Toggle, false, true) => "toggle-start",
+        (false, DictationMode::Toggle, true, false) => "toggle-stop",
+        _ => "none",
+    }
 }
 
 #[derive(Debug, Clone)]
 struct DictationHotkeyConfig {
+    /// macOS virtual keycode of the main key. It identifies the key on every platform;
+    /// other listeners map it through the key's name in HOTKEY_KEYS. For a double tap
+    /// this may be a modifier's keycode, and either side of that modifier counts.
     key_code: u16,
     require_fn: bool,
     require_control: bool,
     require_command: bool,
     require_option: bool,
     require_shift: bool,
+    /// Swallow the hotkey's key events in Filter mode; when false they pass through to the
+    /// focused app as well
+    consume: bool,
+    /// Trigger on two presses of `key_code` within DOUBLE_TAP_WINDOW_MS instead of a
+    /// modifier chord; a single press reaches the focused app as usual
+    double_tap: bool,
     display: String,
 }
 
@@ -193,6 +452,72 @@ impl Default for DictationDebugState {
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct DictationCaptureDebugSample {
+    device_sample_rate: u32,
+    device_sample_count: usize,
+    sample_rate: u32,
+    sample_count: usize,
+    duration_seconds: f64,
+    peak: f32,
+    rms: f32,
+    speech_seconds: f64,
+    wav_path: Option<String>,
+}
+
+/// How a history entry's text reached the focused app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DictationDelivery {
+    Pasted,
+    Typed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DictationHistoryEntry {
+    timestamp_ms: u64,
+    text: String,
+    /// `text` was cut to MAX_HISTORY_ENTRY_CHARS
+    truncated: bool,
+    delivery: DictationDelivery,
+    delivery_error: Option<String>,
+}
+
+impl DictationHistoryEntry {
+    fn new(text: &str, delivered: &Result<DictationOutputMode, String>) -> Self {
+        let (delivery, delivery_error) = match delivered {
+            Ok(DictationOutputMode::Paste) => (DictationDelivery::Pasted, None),
+            Ok(DictationOutputMode::Type) => (DictationDelivery::Typed, None),
+            Err(e) => (DictationDelivery::Failed, Some(e.clone())),
+        };
+        let truncated = text.chars().count() > MAX_HISTORY_ENTRY_CHARS;
+        Self {
+            timestamp_ms: now_millis(),
+            text: text.chars().take(MAX_HISTORY_ENTRY_CHARS).collect(),
+            truncated,
+            delivery,
+            delivery_error,
+        }
+    }
+}
+
+/// Add an entry at the front, dropping the oldest past DICTATION_HISTORY_LIMIT
+fn push_history_entry(history: &mut VecDeque<DictationHistoryEntry>, entry: DictationHistoryEntry) {
+    history.push_front(entry);
+    history.truncate(DICTATION_HISTORY_LIMIT);
+}
+
+/// Time from hotkey release to paste, split by phase
+#[derive(Debug, Clone, Serialize)]
+pub struct DictationLatency {
+    extract_speech_ms: u64,
+    transcribe_ms: u64,
+    paste_ms: u64,
+    total_ms: u64,
+    speech_seconds: f64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct DictationDebugSnapshot {
     listener_running: bool,
@@ -204,6 +529,8 @@ pub struct DictationDebugSnapshot {
     input_monitoring_granted: bool,
     current_hotkey: String,
     current_keycode: u16,
+    double_tap: bool,
+    double_tap_window_ms: u64,
     require_fn: bool,
     require_control: bool,
     require_command: bool,
@@ -211,6 +538,7 @@ pub struct DictationDebugSnapshot {
     require_shift: bool,
     dictation_active: bool,
     dictation_processing: bool,
+    hotkey_test_mode: bool,
     hotkey_held: bool,
     fn_held: bool,
     cmd_held: bool,
@@ -224,11 +552,13 @@ impl Default for DictationHotkeyConfig {
     fn default() -> Self {
         Self {
             key_code: KEY_SPACE,
-            require_fn: true,
-            require_control: false,
+            require_fn: cfg!(target_os = "macos"),
+            require_control: !cfg!(target_os = "macos"),
             require_command: false,
             require_option: false,
-            require_shift: false,
+            require_shift: !cfg!(target_os = "macos"),
+            consume: true,
+            double_tap: false,
             display: DEFAULT_HOTKEY.to_string(),
         }
     }
@@ -244,6 +574,9 @@ struct DictationRecorder {
     stream: cpal::Stream,
     sample_rate: u32,
     buffer: std::sync::Arc<StdMutex<Vec<f32>>>,
+    // Rolling cap on buffered samples: the pre-roll length while idle, the full limit while dictating
+    sample_limit: std::sync::Arc<AtomicUsize>,
+    live: std::sync::Arc<AtomicBool>,
 }
 
 // SAFETY: cpal::Stream is used only through synchronized access in this module.
@@ -270,7 +603,7 @@ enum EventTapMode {
 }
 
 #[cfg(target_os = "macos")]
-fn check_accessibility_permission() -> bool {
+pub(crate) fn check_accessibility_permission() -> bool {
     #[link(name = "CoreGraphics", kind = "framework")]
     extern "C" {
         fn CGPreflightPostEventAccess() -> bool;
@@ -434,7 +767,7 @@ fn maybe_prompt_missing_dictation_permissions<R: Runtime>(app: &AppHandle<R>) {
 }
 
 #[cfg(not(target_os = "macos"))]
-fn check_accessibility_permission() -> bool {
+pub(crate) fn check_accessibility_permission() -> bool {
     false
 }
 
@@ -451,6 +784,8 @@ fn hotkey_config_from_atoms() -> DictationHotkeyConfig {
         require_command: HOTKEY_REQUIRE_COMMAND.load(Ordering::SeqCst),
         require_option: HOTKEY_REQUIRE_OPTION.load(Ordering::SeqCst),
         require_shift: HOTKEY_REQUIRE_SHIFT.load(Ordering::SeqCst),
+        consume: HOTKEY_CONSUME.load(Ordering::SeqCst),
+        double_tap: HOTKEY_DOUBLE_TAP.load(Ordering::SeqCst),
         display: String::new(),
     }
 }
@@ -462,6 +797,9 @@ fn sync_hotkey_atoms(cfg: &DictationHotkeyConfig) {
     HOTKEY_REQUIRE_COMMAND.store(cfg.require_command, Ordering::SeqCst);
     HOTKEY_REQUIRE_OPTION.store(cfg.require_option, Ordering::SeqCst);
     HOTKEY_REQUIRE_SHIFT.store(cfg.require_shift, Ordering::SeqCst);
+    HOTKEY_CONSUME.store(cfg.consume, Ordering::SeqCst);
+    HOTKEY_DOUBLE_TAP.store(cfg.double_tap, Ordering::SeqCst);
+    DOUBLE_TAP_FIRST_MS.store(0, Ordering::SeqCst);
 }
 
 fn current_hotkey_display() -> String {
@@ -478,75 +816,48 @@ fn now_millis() -> u64 {
         .unwrap_or(0)
 }
 
+/// Names for the modifier keys' macOS virtual keycodes, which HOTKEY_KEYS doesn't list
+const MODIFIER_KEYCODES: &[(u16, &str)] = &[
+    (KEY_FUNCTION, "fn"),
+    (KEY_LEFT_COMMAND, "cmd"),
+    (KEY_RIGHT_COMMAND, "cmd"),
+    (KEY_LEFT_CONTROL, "ctrl"),
+    (KEY_RIGHT_CONTROL, "ctrl"),
+    (KEY_LEFT_OPTION, "option"),
+    (KEY_RIGHT_OPTION, "option"),
+    (KEY_LEFT_SHIFT, "shift"),
+    (KEY_RIGHT_SHIFT, "shift"),
+];
+
+/// Logical name of a hotkey's main key
+fn hotkey_key_name(keycode: u16) -> Option<&'static str> {
+    HOTKEY_KEYS.iter().find(|k| k.mac_code == keycode).map(|k| k.name)
+}
+
+/// Logical name of any known key, modifiers included; both sides of a modifier share one
+fn key_name(keycode: u16) -> Option<&'static str> {
+    hotkey_key_name(keycode).or_else(|| {
+        MODIFIER_KEYCODES
+            .iter()
+            .find(|(code, _)| *code == keycode)
+            .map(|(_, name)| *name)
+    })
+}
+
+/// Keycode for a logical key name, the left-hand one for modifiers
+fn keycode_for_name(name: &str) -> Option<u16> {
+    parse_keycode(name).or_else(|| {
+        MODIFIER_KEYCODES
+            .iter()
+            .find(|(_, modifier)| *modifier == name)
+            .map(|(code, _)| *code)
+    })
+}
+
 fn keycode_to_name(keycode: u16) -> String {
-    match keycode {
-        KEY_SPACE => "space".to_string(),
-        KEY_RETURN => "enter".to_string(),
-        KEY_TAB => "tab".to_string(),
-        KEY_ESCAPE => "esc".to_string(),
-        KEY_FUNCTION => "fn".to_string(),
-        KEY_LEFT_COMMAND | KEY_RIGHT_COMMAND => "cmd".to_string(),
-        KEY_LEFT_CONTROL | KEY_RIGHT_CONTROL => "ctrl".to_string(),
-        KEY_LEFT_OPTION | KEY_RIGHT_OPTION => "option".to_string(),
-        KEY_LEFT_SHIFT | KEY_RIGHT_SHIFT => "shift".to_string(),
-        KEY_A => "a".to_string(),
-        KEY_B => "b".to_string(),
-        KEY_C => "c".to_string(),
-        KEY_D => "d".to_string(),
-        KEY_E => "e".to_string(),
-        KEY_F => "f".to_string(),
-        KEY_G => "g".to_string(),
-        KEY_H => "h".to_string(),
-        KEY_I => "i".to_string(),
-        KEY_J => "j".to_string(),
-        KEY_K => "k".to_string(),
-        KEY_L => "l".to_string(),
-        KEY_M => "m".to_string(),
-        KEY_N => "n".to_string(),
-        KEY_O => "o".to_string(),
-        KEY_P => "p".to_string(),
-        KEY_Q => "q".to_string(),
-        KEY_R => "r".to_string(),
-        KEY_S => "s".to_string(),
-        KEY_T => "t".to_string(),
-        KEY_U => "u".to_string(),
-        KEY_V => "v".to_string(),
-        KEY_W => "w".to_string(),
-        KEY_X => "x".to_string(),
-        KEY_Y => "y".to_string(),
-        KEY_Z => "z".to_string(),
-        KEY_0 => "0".to_string(),
-        KEY_1 => "1".to_string(),
-        KEY_2 => "2".to_string(),
-        KEY_3 => "3".to_string(),
-        KEY_4 => "4".to_string(),
-        KEY_5 => "5".to_string(),
-        KEY_6 => "6".to_string(),
-        KEY_7 => "7".to_string(),
-        KEY_8 => "8".to_string(),
-        KEY_9 => "9".to_string(),
-        KEY_F1 => "f1".to_string(),
-        KEY_F2 => "f2".to_string(),
-        KEY_F3 => "f3".to_string(),
-        KEY_F4 => "f4".to_string(),
-        KEY_F5 => "f5".to_string(),
-        KEY_F6 => "f6".to_string(),
-        KEY_F7 => "f7".to_string(),
-        KEY_F8 => "f8".to_string(),
-        KEY_F9 => "f9".to_string(),
-        KEY_F10 => "f10".to_string(),
-        KEY_F11 => "f11".to_string(),
-        KEY_F12 => "f12".to_string(),
-        KEY_F13 => "f13".to_string(),
-        KEY_F14 => "f14".to_string(),
-        KEY_F15 => "f15".to_string(),
-        KEY_F16 => "f16".to_string(),
-        KEY_F17 => "f17".to_string(),
-        KEY_F18 => "f18".to_string(),
-        KEY_F19 => "f19".to_string(),
-        KEY_F20 => "f20".to_string(),
-        other => format!("keycode:{other}"),
-    }
+    key_name(keycode)
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("keycode:{keycode}"))
 }
 
 #[cfg(target_os = "macos")]
@@ -628,6 +939,8 @@ fn emit_widget_state<R: Runtime>(
         message: message.to_string(),
         transcript,
         hotkey: current_hotkey_display(),
+        mode: dictation_mode(),
+        language: dictation_language(),
     };
 
     let _ = app.emit("dictation-widget-update", payload);
@@ -675,19 +988,147 @@ fn hide_widget_after_delay<R: Runtime>(app: AppHandle<R>, ms: u64) {
     });
 }
 
+/// Modifier keys currently held down
+#[derive(Debug, Clone, Copy, Default)]
+struct HeldModifiers {
+    fn_key: bool,
+    control: bool,
+    command: bool,
+    option: bool,
+    shift: bool,
+}
+
+/// True once any modifier the hotkey requires is no longer held. KeyUp for the main
+/// key can be missed, so releasing a required modifier also ends the dictation.
+fn required_modifier_released(held: HeldModifiers, cfg: &DictationHotkeyConfig) -> bool {
+    (cfg.require_fn && !held.fn_key)
+        || (cfg.require_control && !held.control)
+        || (cfg.require_command && !held.command)
+        || (cfg.require_option && !held.option)
+        || (cfg.require_shift && !held.shift)
+}
+
 #[cfg(target_os = "macos")]
-fn modifiers_match(flags: CGEventFlags, cfg: &DictationHotkeyConfig) -> bool {
-    let has_fn = flags.contains(CGEventFlags::CGEventFlagSecondaryFn) || FN_HELD.load(Ordering::SeqCst);
-    let has_ctrl = flags.contains(CGEventFlags::CGEventFlagControl) || CTRL_HELD.load(Ordering::SeqCst);
-    let has_cmd = flags.contains(CGEventFlags::CGEventFlagCommand) || CMD_HELD.load(Ordering::SeqCst);
-    let has_alt = flags.contains(CGEventFlags::CGEventFlagAlternate) || ALT_HELD.load(Ordering::SeqCst);
-    let has_shift = flags.contains(CGEventFlags::CGEventFlagShift) || SHIFT_HELD.load(Ordering::SeqCst);
+fn held_modifiers(flags: CGEventFlags) -> HeldModifiers {
+    HeldModifiers {
+        fn_key: flags.contains(CGEventFlags::CGEventFlagSecondaryFn) || FN_HELD.load(Ordering::SeqCst),
+        control: flags.contains(CGEventFlags::CGEventFlagControl) || CTRL_HELD.load(Ordering::SeqCst),
+        command: flags.contains(CGEventFlags::CGEventFlagCommand) || CMD_HELD.load(Ordering::SeqCst),
+        option: flags.contains(CGEventFlags::CGEventFlagAlternate) || ALT_HELD.load(Ordering::SeqCst),
+        shift: flags.contains(CGEventFlags::CGEventFlagShift) || SHIFT_HELD.load(Ordering::SeqCst),
+    }
+}
+
+/// Whether the named modifier is held; false for non-modifier names
+fn modifier_held(held: HeldModifiers, name: &str) -> bool {
+    match name {
+        "fn" => held.fn_key,
+        "ctrl" => held.control,
+        "cmd" => held.command,
+        "option" => held.option,
+        "shift" => held.shift,
+        _ => false,
+    }
+}
+
+/// True when a double-tap's second press lands within the window of a pending first
+fn completes_double_tap(first_tap_ms: u64, now_ms: u64, window_ms: u64) -> bool {
+    first_tap_ms != 0 && now_ms.saturating_sub(first_tap_ms) <= window_ms
+}
+
+/// Feed one key press or release to the double-tap detector, starting or stopping
+/// dictation like a chord hotkey would. Shared by every platform's listener; returns
+/// true when this press completed a double tap.
+fn handle_double_tap_key<R: Runtime>(
+    app: &AppHandle<R>,
+    key: &str,
+    pressed: bool,
+    held: HeldModifiers,
+    cfg: &DictationHotkeyConfig,
+) -> bool {
+    let is_tap_key = key_name(cfg.key_code) == Some(key);
+
+    if !pressed {
+        if !is_tap_key {
+            return false;
+        }
+        DOUBLE_TAP_KEY_DOWN.store(false, Ordering::SeqCst);
+        // Releasing the second tap ends a push-to-talk session; toggle waits for the next double tap
+        let test_mode = HOTKEY_TEST_MODE.load(Ordering::SeqCst);
+        let ends_hold = test_mode || dictation_mode() == DictationMode::PushToTalk;
+        if ends_hold && HOTKEY_HELD.swap(false, Ordering::SeqCst) && !test_mode {
+            let app_clone = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let _ = stop_dictation(app_clone).await;
+            });
+        }
+        return false;
+    }
+
+    // Any other key, or the tap key pressed as part of a shortcut, cancels a pending tap
+    let other_modifier_held = HOTKEY_MODIFIERS
+        .iter()
+        .any(|(name, _)| *name != key && modifier_held(held, name));
+    if !is_tap_key || other_modifier_held {
+        DOUBLE_TAP_FIRST_MS.store(0, Ordering::SeqCst);
+        return false;
+    }
+    if DOUBLE_TAP_KEY_DOWN.swap(true, Ordering::SeqCst) {
+        return false;
+    }
+
+    let now = now_millis();
+    let first = DOUBLE_TAP_FIRST_MS.swap(now, Ordering::SeqCst);
+    if !completes_double_tap(first, now, DOUBLE_TAP_WINDOW_MS.load(Ordering::Relaxed)) {
+        return false;
+    }
+    DOUBLE_TAP_FIRST_MS.store(0, Ordering::SeqCst);
+
+    if HOTKEY_TEST_MODE.load(Ordering::SeqCst) {
+        HOTKEY_HELD.store(true, Ordering::SeqCst);
+        return true;
+    }
+    let app_clone = app.clone();
+    if dictation_mode() == DictationMode::Toggle {
+        if HOTKEY_HELD.fetch_xor(true, Ordering::SeqCst) {
+            tauri::async_runtime::spawn(async move {
+                let _ = stop_dictation(app_clone).await;
+            });
+        } else {
+            spawn_toggle_start(app_clone);
+        }
+    } else {
+        HOTKEY_HELD.store(true, Ordering::SeqCst);
+        tauri::async_runtime::spawn(async move {
+            let _ = start_dictation(app_clone).await;
+        });
+    }
+    true
+}
+
+/// Start the session a toggle press just latched on. HOTKEY_HELD is set before the
+/// start so a second press while the microphone opens stops it; a start that fails
+/// clears it again, otherwise the next press would stop a session that never ran.
+fn spawn_toggle_start<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        if start_dictation(app).await.is_err() {
+            HOTKEY_HELD.store(false, Ordering::SeqCst);
+        }
+    });
+}
+
+/// Exactly the hotkey's modifiers are held
+fn held_modifiers_match(held: HeldModifiers, cfg: &DictationHotkeyConfig) -> bool {
+    held.fn_key == cfg.require_fn
+        && held.control == cfg.require_control
+        && held.command == cfg.require_command
+        && held.option == cfg.require_option
+        && held.shift == cfg.require_shift
+}
 
-    has_fn == cfg.require_fn
-        && has_ctrl == cfg.require_control
-        && has_cmd == cfg.require_command
-        && has_alt == cfg.require_option
-        && has_shift == cfg.require_shift
+#[cfg(target_os = "macos")]
+fn modifiers_match(flags: CGEventFlags, cfg: &DictationHotkeyConfig) -> bool {
+    held_modifiers_match(held_modifiers(flags), cfg)
 }
 
 fn push_audio_chunk(
@@ -696,16 +1137,32 @@ fn push_audio_chunk(
     channels: u16,
     max_samples: usize,
 ) {
-    if data.is_empty() {
+    if data.is_empty() || channels == 0 {
         return;
     }
 
+    // A device that renegotiated its layout can deliver partial frames; downmixing
+    // those would shift every following frame, so drop the trailing remainder.
+    let remainder = data.len() % channels as usize;
+    if remainder != 0 && !MISALIGNED_CAPTURE_WARNED.swap(true, Ordering::Relaxed) {
+        log::warn!(
+            "Dictation capture buffer of {} samples is not a multiple of {} channels; dropping partial frames",
+            data.len(),
+            channels
+        );
+    }
+    let data = &data[..data.len() - remainder];
+
     let mono = if channels > 1 {
         audio_to_mono(data, channels)
     } else {
         data.to_vec()
     };
 
+    let (rms, peak) = chunk_levels(&mono);
+    LEVEL_RMS_BITS.fetch_max(rms.to_bits(), Ordering::Relaxed);
+    LEVEL_PEAK_BITS.fetch_max(peak.to_bits(), Ordering::Relaxed);
+
     if let Ok(mut buffer) = shared.lock() {
         if buffer.len() + mono.len() > max_samples {
             let overflow = (buffer.len() + mono.len()) - max_samples;
@@ -716,6 +1173,29 @@ fn push_audio_chunk(
     }
 }
 
+/// RMS and absolute peak of a mono buffer
+fn chunk_levels(samples: &[f32]) -> (f32, f32) {
+    if samples.is_empty() {
+        return (0.0, 0.0);
+    }
+    let mut sum_squares = 0.0f32;
+    let mut peak = 0.0f32;
+    for &sample in samples {
+        sum_squares += sample * sample;
+        peak = peak.max(sample.abs());
+    }
+    ((sum_squares / samples.len() as f32).sqrt(), peak.min(1.0))
+}
+
+/// Map RMS onto 0.0-1.0 in dB, so normal speech fills a useful part of the meter
+fn meter_level(rms: f32) -> f32 {
+    if rms <= 0.0 {
+        return 0.0;
+    }
+    let db = 20.0 * rms.log10();
+    ((db - LEVEL_METER_FLOOR_DB) / -LEVEL_METER_FLOOR_DB).clamp(0.0, 1.0)
+}
+
 fn choose_dictation_buffer_size(supported: &cpal::SupportedStreamConfig) -> cpal::BufferSize {
     match supported.buffer_size() {
         cpal::SupportedBufferSize::Range { min, max } => {
@@ -726,12 +1206,83 @@ fn choose_dictation_buffer_size(supported: &cpal::SupportedStreamConfig) -> cpal
     }
 }
 
+/// Reject configs from misbehaving (often virtual) devices before they reach the stream
+fn validate_capture_config(sample_rate: u32, channels: u16) -> Result<(), String> {
+    if !(MIN_CAPTURE_SAMPLE_RATE..=MAX_CAPTURE_SAMPLE_RATE).contains(&sample_rate) {
+        return Err(format!(
+            "Microphone reported an unsupported sample rate of {sample_rate} Hz (expected {MIN_CAPTURE_SAMPLE_RATE}-{MAX_CAPTURE_SAMPLE_RATE} Hz)"
+        ));
+    }
+    if channels == 0 {
+        return Err("Microphone reported 0 input channels".to_string());
+    }
+    Ok(())
+}
+
+fn preroll_sample_limit(sample_rate: u32) -> usize {
+    (sample_rate as u64 * PREROLL_MS.load(Ordering::Relaxed) / 1000) as usize
+}
+
+/// Buffer cap while dictating: the max length plus any pre-roll already buffered and
+/// the stop grace period, so a session is stopped before its start is evicted
+fn dictation_sample_limit(sample_rate: u32) -> usize {
+    let secs = MAX_DICTATION_SECONDS.load(Ordering::Relaxed) + DICTATION_WATCHDOG_GRACE_SECONDS;
+    (sample_rate as u64 * secs) as usize + preroll_sample_limit(sample_rate)
+}
+
+/// Seconds into a dictation of `max_secs` at which the widget warns that the limit is near
+fn length_warning_after_secs(max_secs: u64) -> u64 {
+    max_secs - MAX_LENGTH_WARNING_SECONDS.min(max_secs / 2)
+}
+
 fn start_microphone_capture() -> Result<(), String> {
+    open_microphone_capture(false)
+}
+
+/// Keep a short rolling buffer of mic audio so the next dictation starts with pre-roll.
+/// No-op unless the user opted in.
+fn resume_preroll_capture() {
+    if !PREROLL_ENABLED.load(Ordering::SeqCst)
+        || DICTATION_ACTIVE.load(Ordering::SeqCst)
+        || DICTATION_PROCESSING.load(Ordering::SeqCst)
+    {
+        return;
+    }
+    if let Err(err) = open_microphone_capture(true) {
+        log::warn!("Dictation pre-roll capture failed to start: {}", err);
+    }
+}
+
+fn capture_has_audio() -> bool {
+    ACTIVE_RECORDER
+        .lock()
+        .map(|guard| {
+            guard
+                .as_ref()
+                .and_then(|r| r.buffer.lock().ok().map(|b| !b.is_empty()))
+                .unwrap_or(false)
+        })
+        .unwrap_or(false)
+}
+
+fn capture_is_live() -> bool {
+    ACTIVE_RECORDER
+        .lock()
+        .map(|guard| guard.as_ref().is_some_and(|r| r.live.load(Ordering::Relaxed)))
+        .unwrap_or(false)
+}
+
+fn open_microphone_capture(preroll_only: bool) -> Result<(), String> {
     let mut guard = ACTIVE_RECORDER
         .lock()
         .map_err(|e| format!("Failed to lock recorder state: {e}"))?;
 
-    if guard.is_some() {
+    if let Some(recorder) = guard.as_ref() {
+        if !preroll_only {
+            recorder
+                .sample_limit
+                .store(dictation_sample_limit(recorder.sample_rate), Ordering::Relaxed);
+        }
         return Ok(());
     }
 
@@ -746,6 +1297,7 @@ fn start_microphone_capture() -> Result<(), String> {
 
     let sample_rate = supported.sample_rate().0;
     let channels = supported.channels();
+    validate_capture_config(sample_rate, channels)?;
     let stream_config = cpal::StreamConfig {
         channels,
         sample_rate: cpal::SampleRate(sample_rate),
@@ -753,7 +1305,12 @@ fn start_microphone_capture() -> Result<(), String> {
         buffer_size: choose_dictation_buffer_size(&supported),
     };
 
-    let max_samples = (sample_rate as usize) * MAX_DICTATION_SECONDS;
+    let sample_limit = std::sync::Arc::new(AtomicUsize::new(if preroll_only {
+        preroll_sample_limit(sample_rate)
+    } else {
+        dictation_sample_limit(sample_rate)
+    }));
+    MISALIGNED_CAPTURE_WARNED.store(false, Ordering::Relaxed);
     let shared_buffer = std::sync::Arc::new(StdMutex::new(Vec::<f32>::new()));
     let first_callback_received = std::sync::Arc::new(AtomicBool::new(false));
 
@@ -764,6 +1321,7 @@ fn start_microphone_capture() -> Result<(), String> {
     let stream = match supported.sample_format() {
         cpal::SampleFormat::F32 => {
             let shared = shared_buffer.clone();
+            let limit = sample_limit.clone();
             let first_ready = first_callback_received.clone();
             device
                 .build_input_stream(
@@ -772,7 +1330,7 @@ fn start_microphone_capture() -> Result<(), String> {
                         if !data.is_empty() {
                             first_ready.store(true, Ordering::Relaxed);
                         }
-                        push_audio_chunk(&shared, data, channels, max_samples);
+                        push_audio_chunk(&shared, data, channels, limit.load(Ordering::Relaxed));
                     },
                     err_fn,
                     None,
@@ -781,6 +1339,7 @@ fn start_microphone_capture() -> Result<(), String> {
         }
         cpal::SampleFormat::I16 => {
             let shared = shared_buffer.clone();
+            let limit = sample_limit.clone();
             let first_ready = first_callback_received.clone();
             device
                 .build_input_stream(
@@ -793,7 +1352,7 @@ fn start_microphone_capture() -> Result<(), String> {
                             .iter()
                             .map(|&sample| sample as f32 / i16::MAX as f32)
                             .collect();
-                        push_audio_chunk(&shared, &f32_data, channels, max_samples);
+                        push_audio_chunk(&shared, &f32_data, channels, limit.load(Ordering::Relaxed));
                     },
                     err_fn,
                     None,
@@ -802,6 +1361,7 @@ fn start_microphone_capture() -> Result<(), String> {
         }
         cpal::SampleFormat::U16 => {
             let shared = shared_buffer.clone();
+            let limit = sample_limit.clone();
             let first_ready = first_callback_received.clone();
             device
                 .build_input_stream(
@@ -814,7 +1374,7 @@ fn start_microphone_capture() -> Result<(), String> {
                             .iter()
                             .map(|&sample| (sample as f32 / u16::MAX as f32) * 2.0 - 1.0)
                             .collect();
-                        push_audio_chunk(&shared, &f32_data, channels, max_samples);
+                        push_audio_chunk(&shared, &f32_data, channels, limit.load(Ordering::Relaxed));
                     },
                     err_fn,
                     None,
@@ -823,6 +1383,7 @@ fn start_microphone_capture() -> Result<(), String> {
         }
         cpal::SampleFormat::I32 => {
             let shared = shared_buffer.clone();
+            let limit = sample_limit.clone();
             let first_ready = first_callback_received.clone();
             device
                 .build_input_stream(
@@ -835,7 +1396,7 @@ fn start_microphone_capture() -> Result<(), String> {
                             .iter()
                             .map(|&sample| sample as f32 / i32::MAX as f32)
                             .collect();
-                        push_audio_chunk(&shared, &f32_data, channels, max_samples);
+                        push_audio_chunk(&shared, &f32_data, channels, limit.load(Ordering::Relaxed));
                     },
                     err_fn,
                     None,
@@ -844,6 +1405,7 @@ fn start_microphone_capture() -> Result<(), String> {
         }
         cpal::SampleFormat::I8 => {
             let shared = shared_buffer.clone();
+            let limit = sample_limit.clone();
             let first_ready = first_callback_received.clone();
             device
                 .build_input_stream(
@@ -856,7 +1418,7 @@ fn start_microphone_capture() -> Result<(), String> {
                             .iter()
                             .map(|&sample| sample as f32 / i8::MAX as f32)
                             .collect();
-                        push_audio_chunk(&shared, &f32_data, channels, max_samples);
+                        push_audio_chunk(&shared, &f32_data, channels, limit.load(Ordering::Relaxed));
                     },
                     err_fn,
                     None,
@@ -883,6 +1445,8 @@ fn start_microphone_capture() -> Result<(), String> {
         stream,
         sample_rate,
         buffer: shared_buffer,
+        sample_limit,
+        live: first_callback_received,
     });
 
     Ok(())
@@ -924,12 +1488,29 @@ fn stop_microphone_capture() -> Result<CapturedAudio, String> {
     })
 }
 
+fn audio_duration_ms(n_samples: usize, sample_rate: u32) -> f64 {
+    if sample_rate == 0 {
+        return 0.0;
+    }
+    n_samples as f64 * 1000.0 / sample_rate as f64
+}
+
+fn meets_min_duration(n_samples: usize, sample_rate: u32, min_ms: u64, tolerance_ms: f64) -> bool {
+    audio_duration_ms(n_samples, sample_rate) + tolerance_ms >= min_ms as f64
+}
+
 fn normalize_and_extract_speech(captured: CapturedAudio) -> Vec<f32> {
     let audio_16k = if captured.sample_rate != 16_000 {
         resample_audio(&captured.samples, captured.sample_rate, 16_000)
     } else {
         captured.samples
     };
+    let audio_16k = trim_clip_edges(
+        audio_16k,
+        16_000,
+        EDGE_TRIM_MS.load(Ordering::Relaxed),
+        MIN_TRIMMED_CLIP_MS.max(MIN_DICTATION_MS.load(Ordering::Relaxed)),
+    );
 
     // For push-to-talk dictation, preserve the full utterance to avoid clipping
     // leading words. VAD is used only to detect true no-speech input.
@@ -939,6 +1520,17 @@ fn normalize_and_extract_speech(captured: CapturedAudio) -> Vec<f32> {
     }
 }
 
+/// Drop `trim_ms` from both ends of a clip. Clips that would end up shorter than
+/// `min_remaining_ms` are returned untouched rather than risk cutting real speech.
+fn trim_clip_edges(samples: Vec<f32>, sample_rate: u32, trim_ms: u64, min_remaining_ms: u64) -> Vec<f32> {
+    let trim = (sample_rate as u64 * trim_ms / 1000) as usize;
+    let min_remaining = (sample_rate as u64 * min_remaining_ms / 1000) as usize;
+    if trim == 0 || samples.len() < min_remaining + 2 * trim {
+        return samples;
+    }
+    samples[trim..samples.len() - trim].to_vec()
+}
+
 fn clean_qwen_asr_output(text: &str) -> String {
     static LANGUAGE_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
         Regex::new(concat!(
@@ -996,27 +1588,66 @@ fn normalize_transcript(provider: &str, text: &str) -> String {
     } else {
         text.to_string()
     };
-    normalized.trim().to_string()
+    let replaced = match DICTATION_REPLACEMENTS.lock() {
+        Ok(replacements) => apply_replacements(&normalized, &replacements),
+        Err(_) => normalized,
+    };
+    replaced.trim().to_string()
 }
 
-async fn transcribe_audio<R: Runtime>(app: &AppHandle<R>, samples_16k: Vec<f32>) -> Result<String, String> {
-    crate::audio::transcription::engine::validate_transcription_model_ready(app).await?;
+/// Trim a language code and map "" to None. Codes are short ASCII tags like "en",
+/// "zh" or "auto".
+fn normalize_language_code(language: Option<String>) -> Result<Option<String>, String> {
+    let Some(code) = language.map(|l| l.trim().to_string()).filter(|l| !l.is_empty()) else {
+        return Ok(None);
+    };
+    if code.len() > 16 || !code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(format!("Invalid language code '{code}'"));
+    }
+    Ok(Some(code))
+}
 
-    let transcript_config = crate::api::api::api_get_transcript_config(app.clone(), app.state(), None)
-        .await
-        .ok()
-        .flatten();
-
-    let provider = transcript_config
-        .as_ref()
-        .map(|cfg| cfg.provider.as_str())
-        .unwrap_or("parakeet");
-
-    let result = match provider {
-        "localWhisper" => crate::whisper_engine::commands::whisper_transcribe_audio(samples_16k.clone()).await,
-        "qwenAsr" => crate::qwen_asr_engine::commands::qwen_asr_transcribe_audio(samples_16k.clone()).await,
-        "parakeet" => crate::parakeet_engine::commands::parakeet_transcribe_audio(samples_16k.clone()).await,
-        _ => crate::parakeet_engine::commands::parakeet_transcribe_audio(samples_16k.clone()).await,
+fn resolve_language(dictation: Option<String>, global: Option<String>) -> Option<String> {
+    dictation.or(global)
+}
+
+/// Language dictation transcribes in: its own override, else the global preference
+fn dictation_language() -> Option<String> {
+    let dictation = DICTATION_LANGUAGE.lock().ok().and_then(|l| l.clone());
+    resolve_language(dictation, crate::get_language_preference_internal())
+}
+
+async fn transcribe_audio<R: Runtime>(app: &AppHandle<R>, samples_16k: Vec<f32>) -> Result<String, String> {
+    let readiness = crate::audio::transcription::engine::transcription_model_readiness(app).await;
+    let provider = readiness.provider.as_str();
+    // Whisper and QwenASR decode in this language; Parakeet always auto-detects
+    let language = dictation_language();
+
+    // A provider with no downloaded model can't recover by retrying; go straight to fallbacks
+    let result = match readiness.error.clone() {
+        Some(e) if !readiness.downloaded => {
+            log::info!("Dictation: '{}' has no downloaded model, trying fallbacks", provider);
+            Err(e)
+        }
+        Some(e) => return Err(e),
+        None => match provider {
+            "localWhisper" => {
+                crate::whisper_engine::commands::whisper_transcribe_audio_with_language(
+                    samples_16k.clone(),
+                    language.clone(),
+                )
+                .await
+            }
+            "qwenAsr" => {
+                crate::qwen_asr_engine::commands::qwen_asr_transcribe_audio_with_language(
+                    samples_16k.clone(),
+                    language.clone(),
+                )
+                .await
+            }
+            "parakeet" => crate::parakeet_engine::commands::parakeet_transcribe_audio(samples_16k.clone()).await,
+            _ => crate::parakeet_engine::commands::parakeet_transcribe_audio(samples_16k.clone()).await,
+        },
     };
 
     match result {
@@ -1028,20 +1659,43 @@ async fn transcribe_audio<R: Runtime>(app: &AppHandle<R>, samples_16k: Vec<f32>)
             Err("Transcription returned empty text".to_string())
         }
         Err(primary_err) => {
-            // Fallback sequence for robustness
-            let fallback_qwen = crate::qwen_asr_engine::commands::qwen_asr_transcribe_audio(samples_16k.clone()).await;
-            if let Ok(text) = fallback_qwen {
-                let cleaned = normalize_transcript("qwenAsr", &text);
-                if !cleaned.is_empty() {
-                    return Ok(cleaned);
-                }
-            }
-
-            let fallback_whisper = crate::whisper_engine::commands::whisper_transcribe_audio(samples_16k.clone()).await;
-            if let Ok(text) = fallback_whisper {
-                let cleaned = normalize_transcript("localWhisper", &text);
-                if !cleaned.is_empty() {
-                    return Ok(cleaned);
+            // Fall back through the shared provider priority (ready providers only)
+            let fallbacks =
+                crate::audio::transcription::engine::ordered_ready_providers(app, Some(provider)).await;
+            for fallback in fallbacks.iter().filter(|p| p.as_str() != provider) {
+                let fallback_result = match fallback.as_str() {
+                    "localWhisper" => {
+                        crate::whisper_engine::commands::whisper_transcribe_audio_with_language(
+                            samples_16k.clone(),
+                            language.clone(),
+                        )
+                        .await
+                    }
+                    "qwenAsr" => {
+                        crate::qwen_asr_engine::commands::qwen_asr_transcribe_audio_with_language(
+                            samples_16k.clone(),
+                            language.clone(),
+                        )
+                        .await
+                    }
+                    "parakeet" => crate::parakeet_engine::commands::parakeet_transcribe_audio(samples_16k.clone()).await,
+                    other => {
+                        log::debug!("Dictation: provider '{}' not supported for fallback, skipping", other);
+                        continue;
+                    }
+                };
+                if let Ok(text) = fallback_result {
+                    let cleaned = normalize_transcript(fallback, &text);
+                    if !cleaned.is_empty() {
+                        log::warn!("Dictation: '{}' failed ({}), used fallback '{}'", provider, primary_err, fallback);
+                        crate::audio::transcription::engine::emit_provider_fallback(
+                            app,
+                            provider,
+                            fallback,
+                            &primary_err,
+                        );
+                        return Ok(cleaned);
+                    }
                 }
             }
 
@@ -1124,27 +1778,117 @@ fn paste_via_temporary_clipboard(_text: &str) -> Result<(), String> {
     Err("Auto-paste currently supports macOS only".to_string())
 }
 
+/// Split text into pieces of at most TYPE_CHUNK_UTF16_UNITS UTF-16 units, never
+/// splitting a character's surrogate pair across events
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn type_chunks(text: &str) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut units = 0;
+    for ch in text.chars() {
+        if units + ch.len_utf16() > TYPE_CHUNK_UTF16_UNITS {
+            chunks.push(std::mem::take(&mut current));
+            units = 0;
+        }
+        current.push(ch);
+        units += ch.len_utf16();
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Type the text into the focused app as Unicode keyboard events. The events carry
+/// the characters themselves rather than keycodes, so any script types correctly
+/// regardless of the keyboard layout. Needs Accessibility permission.
+#[cfg(target_os = "macos")]
+fn type_text_via_key_events(text: &str) -> Result<(), String> {
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .map_err(|_| "Failed to create keyboard event source".to_string())?;
+    for chunk in type_chunks(text) {
+        for key_down in [true, false] {
+            let event = CGEvent::new_keyboard_event(source.clone(), 0, key_down)
+                .map_err(|_| "Failed to create keyboard event".to_string())?;
+            // The hotkey's modifiers may still be down; don't let them apply to the text
+            event.set_flags(CGEventFlags::CGEventFlagNull);
+            event.set_string(&chunk);
+            event.post(CGEventTapLocation::HID);
+        }
+        std::thread::sleep(Duration::from_millis(TYPE_CHUNK_DELAY_MS));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "macos"))]
+fn type_text_via_key_events(_text: &str) -> Result<(), String> {
+    Err("Typing dictation text currently supports macOS only".to_string())
+}
+
+/// Deliver a transcript in the configured output mode, returning the mode actually
+/// used. Typing falls back to pasting when posting keyboard events isn't permitted.
+fn deliver_dictation_text(text: &str) -> Result<DictationOutputMode, String> {
+    if dictation_output_mode() == DictationOutputMode::Type {
+        if check_accessibility_permission() {
+            return type_text_via_key_events(text).map(|()| DictationOutputMode::Type);
+        }
+        log::warn!("Dictation: Accessibility permission missing, pasting instead of typing");
+    }
+    paste_via_temporary_clipboard(text).map(|()| DictationOutputMode::Paste)
+}
+
 async fn finish_dictation<R: Runtime>(app: AppHandle<R>, captured: CapturedAudio) {
     let process_result = async {
-        if captured.samples.len() < (captured.sample_rate as usize / 5) {
+        let started = Instant::now();
+        let min_ms = MIN_DICTATION_MS.load(Ordering::Relaxed);
+        if !meets_min_duration(captured.samples.len(), captured.sample_rate, min_ms, 0.0) {
             return Err("Audio too short, please hold the hotkey longer".to_string());
         }
 
+        // Same minimum at 16 kHz: a short result here means VAD found no speech
         let speech = normalize_and_extract_speech(captured);
-        if speech.len() < 2_400 {
+        let extract_speech = started.elapsed();
+        if !meets_min_duration(speech.len(), 16_000, min_ms, RESAMPLE_TOLERANCE_MS) {
             return Err("No clear speech detected".to_string());
         }
+        let speech_seconds = speech.len() as f64 / 16_000.0;
+
+        if SAVE_RECORDINGS.load(Ordering::Relaxed) {
+            let app = app.clone();
+            let audio = speech.clone();
+            tauri::async_runtime::spawn_blocking(move || {
+                if let Err(e) = save_dictation_recording(&app, &audio) {
+                    log::warn!("Failed to save dictation recording: {}", e);
+                }
+            });
+        }
 
+        let transcribe_started = Instant::now();
         let text = transcribe_audio(&app, speech).await?;
+        let transcribe = transcribe_started.elapsed();
 
         if let Ok(mut last) = LAST_TRANSCRIPT.lock() {
             *last = Some(text.clone());
         }
 
-        match paste_via_temporary_clipboard(&text) {
-            Ok(_) => {
+        let paste_started = Instant::now();
+        let delivered_text = text.clone();
+        let delivered = tauri::async_runtime::spawn_blocking(move || deliver_dictation_text(&delivered_text))
+            .await
+            .unwrap_or_else(|e| Err(format!("output task failed: {e}")));
+        let paste = paste_started.elapsed();
+        if let Ok(mut history) = DICTATION_HISTORY.lock() {
+            push_history_entry(&mut history, DictationHistoryEntry::new(&text, &delivered));
+        }
+        match delivered {
+            Ok(DictationOutputMode::Paste) => {
                 emit_widget_state(&app, "success", "Transcribed and pasted", Some(text.clone()));
             }
+            Ok(DictationOutputMode::Type) => {
+                emit_widget_state(&app, "success", "Transcribed and typed", Some(text.clone()));
+            }
             Err(e) => {
                 emit_widget_state(
                     &app,
@@ -1155,6 +1899,26 @@ async fn finish_dictation<R: Runtime>(app: AppHandle<R>, captured: CapturedAudio
             }
         }
 
+        let latency = DictationLatency {
+            extract_speech_ms: extract_speech.as_millis() as u64,
+            transcribe_ms: transcribe.as_millis() as u64,
+            paste_ms: paste.as_millis() as u64,
+            total_ms: started.elapsed().as_millis() as u64,
+            speech_seconds,
+        };
+        log::info!(
+            "Dictation latency: extract {}ms, transcribe {}ms, paste {}ms, total {}ms for {:.1}s of speech",
+            latency.extract_speech_ms,
+            latency.transcribe_ms,
+            latency.paste_ms,
+            latency.total_ms,
+            latency.speech_seconds
+        );
+        let _ = app.emit("dictation-latency", &latency);
+        if let Ok(mut last) = LAST_LATENCY.lock() {
+            *last = Some(latency);
+        }
+
         Ok::<(), String>(())
     }
     .await;
@@ -1164,6 +1928,7 @@ async fn finish_dictation<R: Runtime>(app: AppHandle<R>, captured: CapturedAudio
     }
 
     DICTATION_PROCESSING.store(false, Ordering::SeqCst);
+    resume_preroll_capture();
     hide_widget_after_delay(app, 2000);
 }
 
@@ -1178,14 +1943,35 @@ pub async fn start_dictation<R: Runtime>(app: AppHandle<R>) -> Result<(), String
 
     DICTATION_PREWARMING.store(false, Ordering::SeqCst);
 
-    match start_microphone_capture() {
-        Ok(_) => {
-            ensure_widget_window(&app);
-            emit_widget_state(&app, "recording", "Listening... release hotkey to transcribe", None);
+    // Pre-roll or prewarm audio already covers the first word; otherwise give a brief get-ready pause
+    let has_preroll = capture_has_audio();
+
+    let started = match start_microphone_capture() {
+        Ok(()) => wait_until_capture_ready(&app, has_preroll).await,
+        Err(e) => Err(e),
+    };
+
+    match started {
+        Ok(()) => {
+            let session = DICTATION_SESSION.fetch_add(1, Ordering::SeqCst) + 1;
+            spawn_dictation_length_watchdog(app.clone(), session);
+            spawn_dictation_level_meter(app.clone(), session);
+            // The hotkey may have been released while waiting for the microphone
+            if DICTATION_ACTIVE.load(Ordering::SeqCst) {
+                let message = match dictation_mode() {
+                    DictationMode::PushToTalk => "Listening... release hotkey to transcribe",
+                    DictationMode::Toggle => "Listening... press hotkey again to transcribe",
+                };
+                emit_widget_state(&app, "recording", message, None);
+            }
             Ok(())
         }
         Err(e) => {
             DICTATION_ACTIVE.store(false, Ordering::SeqCst);
+            if let Err(err) = abort_microphone_capture() {
+                log::debug!("Dictation microphone abort failed: {}", err);
+            }
+            resume_preroll_capture();
             ensure_widget_window(&app);
             emit_widget_state(&app, "error", &e, None);
             hide_widget_after_delay(app, 1800);
@@ -1194,23 +1980,196 @@ pub async fn start_dictation<R: Runtime>(app: AppHandle<R>) -> Result<(), String
     }
 }
 
-pub async fn stop_dictation<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
-    if !DICTATION_ACTIVE.swap(false, Ordering::SeqCst) {
-        return Ok(());
+/// Write the 16 kHz audio sent for transcription to the recordings folder,
+/// then prune the folder back under its limits
+fn save_dictation_recording<R: Runtime>(app: &AppHandle<R>, audio_16k: &[f32]) -> Result<(), String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {e}"))?
+        .join(SAVED_RECORDINGS_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+
+    let path = dir.join(format!(
+        "dictation-{}.wav",
+        chrono::Local::now().format("%Y%m%d-%H%M%S%.3f")
+    ));
+    std::fs::write(&path, crate::audio::common::pcm16_wav_bytes(audio_16k, 16_000))
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    log::info!("Saved dictation recording to {}", path.display());
+
+    let entries = std::fs::read_dir(&dir)
+        .map_err(|e| format!("Failed to list {}: {e}", dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "wav"))
+        .filter_map(|entry| Some((entry.path(), entry.metadata().ok()?.len())))
+        .collect();
+    for old in recordings_to_prune(entries, MAX_SAVED_RECORDINGS, MAX_SAVED_RECORDINGS_BYTES) {
+        if let Err(e) = std::fs::remove_file(&old) {
+            log::warn!("Failed to prune dictation recording {}: {}", old.display(), e);
+        }
     }
-
-    DICTATION_PROCESSING.store(true, Ordering::SeqCst);
-    DICTATION_PREWARMING.store(false, Ordering::SeqCst);
-    emit_widget_state(&app, "processing", "Transcribing...", None);
-
-    let captured = stop_microphone_capture()?;
-    tauri::async_runtime::spawn(finish_dictation(app, captured));
-
     Ok(())
 }
 
-fn maybe_start_dictation_prewarm() {
-    if DICTATION_ACTIVE.load(Ordering::SeqCst) || DICTATION_PROCESSING.load(Ordering::SeqCst) {
+/// Oldest recordings to delete so at most `max_files` totalling `max_bytes`
+/// remain. Filenames are timestamps, so name order is age order.
+fn recordings_to_prune(
+    mut files: Vec<(std::path::PathBuf, u64)>,
+    max_files: usize,
+    max_bytes: u64,
+) -> Vec<std::path::PathBuf> {
+    files.sort_by(|a, b| b.0.cmp(&a.0));
+    let mut kept_bytes = 0;
+    let mut prune = Vec::new();
+    for (index, (path, size)) in files.into_iter().enumerate() {
+        kept_bytes += size;
+        if index >= max_files || kept_bytes > max_bytes {
+            prune.push(path);
+        }
+    }
+    prune
+}
+
+/// Show "preparing" until the first audio callback arrives, then hold for the get-ready delay
+async fn wait_until_capture_ready<R: Runtime>(app: &AppHandle<R>, has_preroll: bool) -> Result<(), String> {
+    ensure_widget_window(app);
+    if has_preroll {
+        return Ok(());
+    }
+
+    emit_widget_state(app, "preparing", "Starting microphone...", None);
+
+    let deadline = Instant::now() + Duration::from_millis(DICTATION_CAPTURE_LIVE_TIMEOUT_MS);
+    while !capture_is_live() {
+        // Stopped or cancelled before the microphone came up; the caller skips "Listening"
+        if !DICTATION_ACTIVE.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            return Err("Microphone did not start, please try again".to_string());
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let delay_ms = READY_DELAY_MS.load(Ordering::Relaxed);
+    if delay_ms > 0 {
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    }
+    Ok(())
+}
+
+pub async fn stop_dictation<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    if !DICTATION_ACTIVE.swap(false, Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    DICTATION_PROCESSING.store(true, Ordering::SeqCst);
+    DICTATION_PREWARMING.store(false, Ordering::SeqCst);
+    emit_widget_state(&app, "processing", "Transcribing...", None);
+
+    let captured = stop_microphone_capture()?;
+    tauri::async_runtime::spawn(finish_dictation(app, captured));
+
+    Ok(())
+}
+
+/// Escape cancels while recording, unless Escape is itself the hotkey's key
+fn escape_cancels_dictation(cfg: &DictationHotkeyConfig) -> bool {
+    cfg.key_code != KEY_ESCAPE && DICTATION_ACTIVE.load(Ordering::SeqCst)
+}
+
+/// Abort the recording without transcribing or pasting: the captured audio is dropped
+/// and the widget shows "cancelled". Separate from stop_dictation, which always
+/// hands the audio to finish_dictation.
+pub async fn cancel_dictation<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    if !DICTATION_ACTIVE.swap(false, Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    log::info!("Dictation: cancelled, discarding captured audio");
+    // The hotkey may still be down (push-to-talk) or latched on (toggle); its next
+    // release or press must not stop or restart anything
+    HOTKEY_HELD.store(false, Ordering::SeqCst);
+    DICTATION_PREWARMING.store(false, Ordering::SeqCst);
+    DICTATION_PROCESSING.store(false, Ordering::SeqCst);
+
+    let aborted = abort_microphone_capture();
+    resume_preroll_capture();
+    emit_widget_state(&app, "cancelled", "Dictation cancelled", None);
+    hide_widget_after_delay(app, 1200);
+    aborted
+}
+
+/// Emit `dictation-level` every LEVEL_METER_INTERVAL_MS while the session records, with
+/// the loudest audio since the previous update, then a final zero so the meter empties
+/// on stop. Only reads what the capture callback records; the buffer is untouched.
+fn spawn_dictation_level_meter<R: Runtime>(app: AppHandle<R>, session: u64) {
+    LEVEL_RMS_BITS.store(0, Ordering::Relaxed);
+    LEVEL_PEAK_BITS.store(0, Ordering::Relaxed);
+    tauri::async_runtime::spawn(async move {
+        let session_active = || {
+            DICTATION_ACTIVE.load(Ordering::SeqCst) && DICTATION_SESSION.load(Ordering::SeqCst) == session
+        };
+        let mut interval = tokio::time::interval(Duration::from_millis(LEVEL_METER_INTERVAL_MS));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            interval.tick().await;
+            if !session_active() {
+                break;
+            }
+            let rms = f32::from_bits(LEVEL_RMS_BITS.swap(0, Ordering::Relaxed));
+            let peak = f32::from_bits(LEVEL_PEAK_BITS.swap(0, Ordering::Relaxed));
+            let _ = app.emit("dictation-level", DictationLevel { level: meter_level(rms), peak });
+        }
+        let _ = app.emit("dictation-level", DictationLevel { level: 0.0, peak: 0.0 });
+    });
+}
+
+/// Warn in the widget shortly before a session reaches the max dictation length, then
+/// stop it at the limit so whatever was captured gets transcribed instead of the
+/// buffer dropping its start. This also recovers sessions whose hotkey KeyUp was
+/// missed in ListenOnly mode, which would otherwise leave the recorder running forever.
+fn spawn_dictation_length_watchdog<R: Runtime>(app: AppHandle<R>, session: u64) {
+    tauri::async_runtime::spawn(async move {
+        let limit_secs = MAX_DICTATION_SECONDS.load(Ordering::Relaxed);
+        let session_active = || {
+            DICTATION_ACTIVE.load(Ordering::SeqCst) && DICTATION_SESSION.load(Ordering::SeqCst) == session
+        };
+
+        let warn_after = length_warning_after_secs(limit_secs);
+        tokio::time::sleep(Duration::from_secs(warn_after)).await;
+        if !session_active() {
+            return;
+        }
+        emit_widget_state(
+            &app,
+            "recording",
+            &format!("Approaching max length, {}s left", limit_secs - warn_after),
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_secs(limit_secs - warn_after)).await;
+        if session_active() {
+            log::warn!(
+                "Dictation: session reached the {}s max length (or the hotkey release was missed); stopping",
+                limit_secs
+            );
+            HOTKEY_HELD.store(false, Ordering::SeqCst);
+            if let Err(e) = stop_dictation(app).await {
+                log::error!("Dictation: failed to stop session at max length: {}", e);
+            }
+        }
+    });
+}
+
+fn maybe_start_dictation_prewarm() {
+    // Pre-roll capture already keeps the microphone warm
+    if PREROLL_ENABLED.load(Ordering::SeqCst)
+        || DICTATION_ACTIVE.load(Ordering::SeqCst)
+        || DICTATION_PROCESSING.load(Ordering::SeqCst)
+    {
         return;
     }
 
@@ -1250,6 +2209,148 @@ pub async fn dictation_stop_manual<R: Runtime>(app: AppHandle<R>) -> Result<(),
     stop_dictation(app).await
 }
 
+#[tauri::command]
+pub async fn dictation_cancel_manual<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    cancel_dictation(app).await
+}
+
+/// Set the pause between the microphone going live and "Listening" being shown
+#[tauri::command]
+pub async fn dictation_set_ready_delay_ms(delay_ms: u64) -> Result<(), String> {
+    if delay_ms > MAX_READY_DELAY_MS {
+        return Err(format!("Ready delay must be at most {} ms", MAX_READY_DELAY_MS));
+    }
+    READY_DELAY_MS.store(delay_ms, Ordering::Relaxed);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dictation_get_ready_delay_ms() -> Result<u64, String> {
+    Ok(READY_DELAY_MS.load(Ordering::Relaxed))
+}
+
+/// Longest gap between the two presses of a double-tap hotkey such as "doubletap+shift".
+#[tauri::command]
+pub async fn dictation_set_double_tap_window_ms(window_ms: u64) -> Result<(), String> {
+    if !(MIN_DOUBLE_TAP_WINDOW_MS..=MAX_DOUBLE_TAP_WINDOW_MS).contains(&window_ms) {
+        return Err(format!(
+            "Double-tap window must be between {} and {} ms",
+            MIN_DOUBLE_TAP_WINDOW_MS, MAX_DOUBLE_TAP_WINDOW_MS
+        ));
+    }
+    DOUBLE_TAP_WINDOW_MS.store(window_ms, Ordering::Relaxed);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dictation_get_double_tap_window_ms() -> Result<u64, String> {
+    Ok(DOUBLE_TAP_WINDOW_MS.load(Ordering::Relaxed))
+}
+
+/// Opt in to (or out of) always-on pre-roll. While enabled the microphone stays open
+/// and the last `preroll_ms` of audio is kept in memory only, then prepended to the
+/// next dictation. Nothing is written to disk.
+#[tauri::command]
+pub async fn dictation_set_preroll(enabled: bool, preroll_ms: Option<u64>) -> Result<(), String> {
+    if let Some(ms) = preroll_ms {
+        if ms == 0 || ms > MAX_PREROLL_MS {
+            return Err(format!("Pre-roll must be between 1 and {} ms", MAX_PREROLL_MS));
+        }
+        PREROLL_MS.store(ms, Ordering::Relaxed);
+    }
+
+    let was_enabled = PREROLL_ENABLED.swap(enabled, Ordering::SeqCst);
+    let idle = !DICTATION_ACTIVE.load(Ordering::SeqCst) && !DICTATION_PROCESSING.load(Ordering::SeqCst);
+    if enabled && idle {
+        resume_preroll_capture();
+    } else if was_enabled && !enabled && idle {
+        abort_microphone_capture()?;
+    }
+
+    log::info!("Dictation pre-roll {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DictationPrerollSettings {
+    enabled: bool,
+    preroll_ms: u64,
+}
+
+#[tauri::command]
+pub async fn dictation_get_preroll() -> Result<DictationPrerollSettings, String> {
+    Ok(DictationPrerollSettings {
+        enabled: PREROLL_ENABLED.load(Ordering::SeqCst),
+        preroll_ms: PREROLL_MS.load(Ordering::Relaxed),
+    })
+}
+
+/// Save each dictation's audio as a 16 kHz WAV in the app data folder
+#[tauri::command]
+pub async fn dictation_set_save_recordings(enabled: bool) -> Result<(), String> {
+    SAVE_RECORDINGS.store(enabled, Ordering::Relaxed);
+    log::info!("Dictation recording saving {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dictation_get_save_recordings() -> Result<bool, String> {
+    Ok(SAVE_RECORDINGS.load(Ordering::Relaxed))
+}
+
+/// Set the shortest dictation clip (in ms) that will be transcribed
+#[tauri::command]
+pub async fn dictation_set_min_duration_ms(min_ms: u64) -> Result<(), String> {
+    if min_ms == 0 || min_ms > MAX_MIN_DICTATION_MS {
+        return Err(format!(
+            "Minimum dictation duration must be between 1 and {} ms",
+            MAX_MIN_DICTATION_MS
+        ));
+    }
+    MIN_DICTATION_MS.store(min_ms, Ordering::Relaxed);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dictation_get_min_duration_ms() -> Result<u64, String> {
+    Ok(MIN_DICTATION_MS.load(Ordering::Relaxed))
+}
+
+/// Set the longest a single dictation may run, in seconds. The widget warns shortly
+/// before the limit and the session is stopped and transcribed when it is reached.
+#[tauri::command]
+pub async fn dictation_set_max_seconds(secs: u64) -> Result<(), String> {
+    if !(MIN_DICTATION_SECONDS_LIMIT..=MAX_DICTATION_SECONDS_LIMIT).contains(&secs) {
+        return Err(format!(
+            "Max dictation length must be between {} and {} seconds",
+            MIN_DICTATION_SECONDS_LIMIT, MAX_DICTATION_SECONDS_LIMIT
+        ));
+    }
+    MAX_DICTATION_SECONDS.store(secs, Ordering::Relaxed);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dictation_get_max_seconds() -> Result<u64, String> {
+    Ok(MAX_DICTATION_SECONDS.load(Ordering::Relaxed))
+}
+
+/// Trim this many ms from the start and end of each dictation clip before
+/// transcription, so a hotkey click or breath isn't transcribed (0 disables)
+#[tauri::command]
+pub async fn dictation_set_edge_trim_ms(trim_ms: u64) -> Result<(), String> {
+    if trim_ms > MAX_EDGE_TRIM_MS {
+        return Err(format!("Edge trim must be at most {} ms", MAX_EDGE_TRIM_MS));
+    }
+    EDGE_TRIM_MS.store(trim_ms, Ordering::Relaxed);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dictation_get_edge_trim_ms() -> Result<u64, String> {
+    Ok(EDGE_TRIM_MS.load(Ordering::Relaxed))
+}
+
 #[tauri::command]
 pub async fn dictation_get_last_transcript() -> Result<Option<String>, String> {
     LAST_TRANSCRIPT
@@ -1258,6 +2359,113 @@ pub async fn dictation_get_last_transcript() -> Result<Option<String>, String> {
         .map_err(|e| format!("Failed to read last transcript: {e}"))
 }
 
+/// Phase timings of the last completed dictation, if any
+#[tauri::command]
+pub async fn dictation_get_last_latency() -> Result<Option<DictationLatency>, String> {
+    LAST_LATENCY
+        .lock()
+        .map(|v| v.clone())
+        .map_err(|e| format!("Failed to read last latency: {e}"))
+}
+
+fn save_replacements_to_store<R: Runtime>(app: &AppHandle<R>, entries: &[DictationReplacement]) -> Result<(), String> {
+    let store = app
+        .store(PREFERENCES_STORE)
+        .map_err(|e| format!("Preferences store unavailable: {e}"))?;
+    if entries.is_empty() {
+        store.delete(REPLACEMENTS_STORE_KEY);
+    } else {
+        let value = serde_json::to_value(entries).map_err(|e| format!("Failed to serialize replacements: {e}"))?;
+        store.set(REPLACEMENTS_STORE_KEY, value);
+    }
+    store.save().map_err(|e| format!("Failed to save replacements: {e}"))
+}
+
+/// Replace the vocabulary list applied to every dictation transcript. The whole list is
+/// validated first; on any bad entry nothing changes.
+#[tauri::command]
+pub async fn dictation_set_replacements<R: Runtime>(
+    app: AppHandle<R>,
+    replacements: Vec<DictationReplacement>,
+) -> Result<(), String> {
+    let compiled = compile_replacements(replacements.clone())?;
+    save_replacements_to_store(&app, &replacements)?;
+    *DICTATION_REPLACEMENTS
+        .lock()
+        .map_err(|e| format!("Failed to lock replacements: {e}"))? = compiled;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dictation_get_replacements() -> Result<Vec<DictationReplacement>, String> {
+    DICTATION_REPLACEMENTS
+        .lock()
+        .map(|list| list.iter().map(|r| r.entry.clone()).collect())
+        .map_err(|e| format!("Failed to read replacements: {e}"))
+}
+
+#[tauri::command]
+pub async fn dictation_clear_replacements<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    save_replacements_to_store(&app, &[])?;
+    DICTATION_REPLACEMENTS
+        .lock()
+        .map_err(|e| format!("Failed to lock replacements: {e}"))?
+        .clear();
+    Ok(())
+}
+
+/// Set the language used for dictation only; None or "" follows the global language
+/// preference again. Meeting transcription is unaffected.
+#[tauri::command]
+pub async fn dictation_set_language(language: Option<String>) -> Result<(), String> {
+    let language = normalize_language_code(language)?;
+    log::info!("Dictation: language set to {}", language.as_deref().unwrap_or("(global preference)"));
+    *DICTATION_LANGUAGE
+        .lock()
+        .map_err(|e| format!("Failed to set dictation language: {e}"))? = language;
+    Ok(())
+}
+
+/// The dictation-only language, or None when it follows the global preference
+#[tauri::command]
+pub async fn dictation_get_language() -> Result<Option<String>, String> {
+    DICTATION_LANGUAGE
+        .lock()
+        .map(|l| l.clone())
+        .map_err(|e| format!("Failed to read dictation language: {e}"))
+}
+
+/// Load the saved replacement list at startup. Entries that no longer compile are
+/// dropped with a warning rather than losing the whole list.
+pub fn restore_replacements<R: Runtime>(app: &AppHandle<R>) {
+    let Some(value) = app.store(PREFERENCES_STORE).ok().and_then(|s| s.get(REPLACEMENTS_STORE_KEY)) else {
+        return;
+    };
+    let entries: Vec<DictationReplacement> = match serde_json::from_value(value) {
+        Ok(entries) => entries,
+        Err(e) => {
+            log::warn!("Dictation: ignoring unreadable saved replacements: {}", e);
+            return;
+        }
+    };
+
+    let compiled: Vec<CompiledReplacement> = entries
+        .into_iter()
+        .take(MAX_REPLACEMENTS)
+        .filter_map(|entry| match CompiledReplacement::compile(entry) {
+            Ok(compiled) => Some(compiled),
+            Err(e) => {
+                log::warn!("Dictation: dropping saved replacement: {}", e);
+                None
+            }
+        })
+        .collect();
+    log::info!("Dictation: restored {} replacement(s)", compiled.len());
+    if let Ok(mut list) = DICTATION_REPLACEMENTS.lock() {
+        *list = compiled;
+    }
+}
+
 #[tauri::command]
 pub async fn dictation_paste_last_transcript() -> Result<(), String> {
     let text = LAST_TRANSCRIPT
@@ -1269,6 +2477,66 @@ pub async fn dictation_paste_last_transcript() -> Result<(), String> {
     paste_via_temporary_clipboard(&text)
 }
 
+/// Recent dictations, newest first, with how each was delivered
+#[tauri::command]
+pub async fn dictation_get_history() -> Result<Vec<DictationHistoryEntry>, String> {
+    DICTATION_HISTORY
+        .lock()
+        .map(|history| history.iter().cloned().collect())
+        .map_err(|e| format!("Failed to read dictation history: {e}"))
+}
+
+/// Paste a history entry again; `index` 0 is the newest, as returned by dictation_get_history
+#[tauri::command]
+pub async fn dictation_paste_from_history(index: usize) -> Result<(), String> {
+    let text = DICTATION_HISTORY
+        .lock()
+        .map_err(|e| format!("Failed to lock dictation history: {e}"))?
+        .get(index)
+        .map(|entry| entry.text.clone())
+        .ok_or_else(|| format!("No dictation history entry at index {index}"))?;
+
+    paste_via_temporary_clipboard(&text)
+}
+
+/// Choose whether transcripts are pasted through the clipboard or typed as keyboard
+/// events. Typing leaves the clipboard untouched and works in apps that block paste.
+#[tauri::command]
+pub async fn dictation_set_output_mode(mode: DictationOutputMode) -> Result<(), String> {
+    DICTATION_TYPE_OUTPUT.store(mode == DictationOutputMode::Type, Ordering::SeqCst);
+    log::info!("Dictation output mode set to {:?}", mode);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dictation_get_output_mode() -> Result<DictationOutputMode, String> {
+    Ok(dictation_output_mode())
+}
+
+/// Switch between push-to-talk (hold the hotkey) and toggle (press once to start,
+/// again to stop). Takes effect from the next hotkey press.
+#[tauri::command]
+pub async fn dictation_set_mode(mode: DictationMode) -> Result<(), String> {
+    if DICTATION_ACTIVE.load(Ordering::SeqCst) {
+        return Err("Cannot change the dictation mode while dictating".to_string());
+    }
+    DICTATION_TOGGLE_MODE.store(mode == DictationMode::Toggle, Ordering::SeqCst);
+    HOTKEY_HELD.store(false, Ordering::SeqCst);
+    log::info!("Dictation mode set to {:?}", mode);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dictation_get_mode() -> Result<DictationMode, String> {
+    Ok(dictation_mode())
+}
+
+/// The hotkey "Reset to default" restores on this platform
+#[tauri::command]
+pub async fn dictation_get_default_hotkey() -> Result<String, String> {
+    Ok(DEFAULT_HOTKEY.to_string())
+}
+
 #[tauri::command]
 pub async fn dictation_get_hotkey() -> Result<String, String> {
     HOTKEY_CONFIG
@@ -1299,6 +2567,8 @@ pub async fn dictation_get_debug_state() -> Result<DictationDebugSnapshot, Strin
         input_monitoring_granted: check_input_monitoring_permission(),
         current_hotkey: cfg.display,
         current_keycode: cfg.key_code,
+        double_tap: cfg.double_tap,
+        double_tap_window_ms: DOUBLE_TAP_WINDOW_MS.load(Ordering::Relaxed),
         require_fn: cfg.require_fn,
         require_control: cfg.require_control,
         require_command: cfg.require_command,
@@ -1306,6 +2576,7 @@ pub async fn dictation_get_debug_state() -> Result<DictationDebugSnapshot, Strin
         require_shift: cfg.require_shift,
         dictation_active: DICTATION_ACTIVE.load(Ordering::SeqCst),
         dictation_processing: DICTATION_PROCESSING.load(Ordering::SeqCst),
+        hotkey_test_mode: HOTKEY_TEST_MODE.load(Ordering::SeqCst),
         hotkey_held: HOTKEY_HELD.load(Ordering::SeqCst),
         fn_held: FN_HELD.load(Ordering::SeqCst),
         cmd_held: CMD_HELD.load(Ordering::SeqCst),
@@ -1316,6 +2587,30 @@ pub async fn dictation_get_debug_state() -> Result<DictationDebugSnapshot, Strin
     })
 }
 
+/// Enter hotkey test mode: matched hotkey presses are recorded in the debug buffer
+/// and reported as `dictation-hotkey-test` events, without starting the microphone
+/// or pasting anything.
+#[tauri::command]
+pub async fn dictation_start_hotkey_test_mode() -> Result<(), String> {
+    if DICTATION_ACTIVE.load(Ordering::SeqCst) || DICTATION_PROCESSING.load(Ordering::SeqCst) {
+        return Err("Cannot test the hotkey while dictation is running".to_string());
+    }
+    maybe_cancel_dictation_prewarm();
+    HOTKEY_HELD.store(false, Ordering::SeqCst);
+    HOTKEY_TEST_MODE.store(true, Ordering::SeqCst);
+    log::info!("Dictation: hotkey test mode started");
+    Ok(())
+}
+
+/// Leave hotkey test mode; the hotkey starts dictation again.
+#[tauri::command]
+pub async fn dictation_stop_hotkey_test_mode() -> Result<(), String> {
+    HOTKEY_TEST_MODE.store(false, Ordering::SeqCst);
+    HOTKEY_HELD.store(false, Ordering::SeqCst);
+    log::info!("Dictation: hotkey test mode stopped");
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn dictation_clear_debug_events() -> Result<(), String> {
     let mut debug = DICTATION_DEBUG_STATE
@@ -1326,6 +2621,92 @@ pub async fn dictation_clear_debug_events() -> Result<(), String> {
     Ok(())
 }
 
+/// Record a short sample through the dictation capture path so bug reports can include
+/// exactly what the transcriber would have received.
+#[tauri::command]
+pub async fn dictation_capture_debug_sample(
+    seconds: u32,
+    save_wav: Option<bool>,
+) -> Result<DictationCaptureDebugSample, String> {
+    let max_seconds = MAX_DICTATION_SECONDS.load(Ordering::Relaxed);
+    if seconds == 0 || seconds as u64 > max_seconds {
+        return Err(format!(
+            "Debug sample length must be between 1 and {max_seconds} seconds"
+        ));
+    }
+    if DICTATION_ACTIVE.load(Ordering::SeqCst)
+        || DICTATION_PROCESSING
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+    {
+        return Err("Dictation is in progress, try again when it finishes".to_string());
+    }
+
+    let captured = async {
+        // A prewarmed recorder may hold stale audio from before this request
+        if DICTATION_PREWARMING.swap(false, Ordering::SeqCst) {
+            abort_microphone_capture()?;
+        }
+        start_microphone_capture()?;
+        tokio::time::sleep(Duration::from_secs(seconds as u64)).await;
+        stop_microphone_capture()
+    }
+    .await;
+    DICTATION_PROCESSING.store(false, Ordering::SeqCst);
+    resume_preroll_capture();
+    let captured = captured?;
+
+    let device_sample_rate = captured.sample_rate;
+    let device_sample_count = captured.samples.len();
+    let audio_16k = if device_sample_rate != 16_000 {
+        resample_audio(&captured.samples, device_sample_rate, 16_000)
+    } else {
+        captured.samples
+    };
+
+    let peak = audio_16k.iter().fold(0.0f32, |max, s| max.max(s.abs()));
+    let rms = if audio_16k.is_empty() {
+        0.0
+    } else {
+        (audio_16k.iter().map(|s| s * s).sum::<f32>() / audio_16k.len() as f32).sqrt()
+    };
+    let speech_seconds = match extract_speech_16k(&audio_16k) {
+        Ok(speech) => audio_duration_ms(speech.len(), 16_000) / 1000.0,
+        Err(e) => {
+            log::warn!("Dictation debug sample VAD failed: {}", e);
+            0.0
+        }
+    };
+
+    let wav_path = if save_wav.unwrap_or(false) {
+        let path = std::env::temp_dir().join(format!(
+            "dictation-debug-{}.wav",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0)
+        ));
+        std::fs::write(&path, crate::audio::common::pcm16_wav_bytes(&audio_16k, 16_000))
+            .map_err(|e| format!("Failed to write debug WAV: {e}"))?;
+        log::info!("Saved dictation debug sample to {}", path.display());
+        Some(path.to_string_lossy().to_string())
+    } else {
+        None
+    };
+
+    Ok(DictationCaptureDebugSample {
+        device_sample_rate,
+        device_sample_count,
+        sample_rate: 16_000,
+        sample_count: audio_16k.len(),
+        duration_seconds: audio_duration_ms(audio_16k.len(), 16_000) / 1000.0,
+        peak,
+        rms,
+        speech_seconds,
+        wav_path,
+    })
+}
+
 /// Restart the global hotkey listener. Useful after granting Accessibility permission
 /// so the app can re-create the event tap in Filter mode.
 #[tauri::command]
@@ -1341,6 +2722,53 @@ pub async fn dictation_restart_listener<R: Runtime>(app: AppHandle<R>) -> Result
     Ok(debug.listener_mode)
 }
 
+/// Frontend store holding the user's saved hotkey, re-applied on startup
+const PREFERENCES_STORE: &str = "preferences.json";
+const HOTKEY_STORE_KEY: &str = "dictation_hotkey";
+const REPLACEMENTS_STORE_KEY: &str = "dictation_replacements";
+
+/// Put dictation back in a clean state: stop the listener, drop any capture and stuck
+/// flags, restore the default hotkey (also in the preferences store), clear the debug
+/// events, and start the listener again. Returns the resulting debug state.
+#[tauri::command]
+pub async fn dictation_reset_all<R: Runtime>(app: AppHandle<R>) -> Result<DictationDebugSnapshot, String> {
+    log::info!("Dictation: resetting all state to defaults");
+    stop_global_hotkey_listener();
+
+    maybe_cancel_dictation_prewarm();
+    if let Err(e) = abort_microphone_capture() {
+        log::warn!("Dictation reset: failed to stop capture: {}", e);
+    }
+    DICTATION_ACTIVE.store(false, Ordering::SeqCst);
+    DICTATION_PROCESSING.store(false, Ordering::SeqCst);
+    DICTATION_PREWARMING.store(false, Ordering::SeqCst);
+    HOTKEY_HELD.store(false, Ordering::SeqCst);
+    HOTKEY_TEST_MODE.store(false, Ordering::SeqCst);
+
+    let default_cfg = DictationHotkeyConfig::default();
+    sync_hotkey_atoms(&default_cfg);
+    *HOTKEY_CONFIG
+        .lock()
+        .map_err(|e| format!("Failed to lock hotkey config: {e}"))? = default_cfg;
+    match app.store(PREFERENCES_STORE) {
+        Ok(store) => {
+            store.delete(HOTKEY_STORE_KEY);
+            if let Err(e) = store.save() {
+                log::warn!("Dictation reset: failed to save preferences: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Dictation reset: preferences store unavailable: {}", e),
+    }
+
+    *DICTATION_DEBUG_STATE
+        .lock()
+        .map_err(|e| format!("Failed to lock debug state: {e}"))? = DictationDebugState::default();
+
+    start_global_hotkey_listener(&app)?;
+    resume_preroll_capture();
+    dictation_get_debug_state().await
+}
+
 /// Check current accessibility permission status.
 #[tauri::command]
 pub async fn dictation_check_accessibility() -> Result<bool, String> {
@@ -1382,74 +2810,149 @@ pub async fn dictation_request_input_monitoring() -> Result<bool, String> {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SetHotkeyResponse {
     pub hotkey: String,
+    pub consume: bool,
 }
 
-fn parse_keycode(key: &str) -> Option<u16> {
-    match key {
-        "a" => Some(KEY_A),
-        "b" => Some(KEY_B),
-        "c" => Some(KEY_C),
-        "d" => Some(KEY_D),
-        "e" => Some(KEY_E),
-        "f" => Some(KEY_F),
-        "g" => Some(KEY_G),
-        "h" => Some(KEY_H),
-        "i" => Some(KEY_I),
-        "j" => Some(KEY_J),
-        "k" => Some(KEY_K),
-        "l" => Some(KEY_L),
-        "m" => Some(KEY_M),
-        "n" => Some(KEY_N),
-        "o" => Some(KEY_O),
-        "p" => Some(KEY_P),
-        "q" => Some(KEY_Q),
-        "r" => Some(KEY_R),
-        "s" => Some(KEY_S),
-        "t" => Some(KEY_T),
-        "u" => Some(KEY_U),
-        "v" => Some(KEY_V),
-        "w" => Some(KEY_W),
-        "x" => Some(KEY_X),
-        "y" => Some(KEY_Y),
-        "z" => Some(KEY_Z),
-        "0" => Some(KEY_0),
-        "1" => Some(KEY_1),
-        "2" => Some(KEY_2),
-        "3" => Some(KEY_3),
-        "4" => Some(KEY_4),
-        "5" => Some(KEY_5),
-        "6" => Some(KEY_6),
-        "7" => Some(KEY_7),
-        "8" => Some(KEY_8),
-        "9" => Some(KEY_9),
-        "space" => Some(KEY_SPACE),
-        "enter" | "return" => Some(KEY_RETURN),
-        "tab" => Some(KEY_TAB),
-        "esc" | "escape" => Some(KEY_ESCAPE),
-        "f1" => Some(KEY_F1),
-        "f2" => Some(KEY_F2),
-        "f3" => Some(KEY_F3),
-        "f4" => Some(KEY_F4),
-        "f5" => Some(KEY_F5),
-        "f6" => Some(KEY_F6),
-        "f7" => Some(KEY_F7),
-        "f8" => Some(KEY_F8),
-        "f9" => Some(KEY_F9),
-        "f10" => Some(KEY_F10),
-        "f11" => Some(KEY_F11),
-        "f12" => Some(KEY_F12),
-        "f13" => Some(KEY_F13),
-        "f14" => Some(KEY_F14),
-        "f15" => Some(KEY_F15),
-        "f16" => Some(KEY_F16),
-        "f17" => Some(KEY_F17),
-        "f18" => Some(KEY_F18),
-        "f19" => Some(KEY_F19),
-        "f20" => Some(KEY_F20),
-        _ => None,
+/// A key accepted as the non-modifier part of a dictation hotkey
+struct HotkeyKey {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    /// macOS virtual keycode, stored as DictationHotkeyConfig::key_code
+    mac_code: u16,
+}
+
+/// Every key `parse_keycode` accepts; `dictation_get_supported_keys` reads the same table
+const HOTKEY_KEYS: &[HotkeyKey] = &[
+    HotkeyKey { name: "a", aliases: &[], mac_code: KEY_A },
+    HotkeyKey { name: "b", aliases: &[], mac_code: KEY_B },
+    HotkeyKey { name: "c", aliases: &[], mac_code: KEY_C },
+    HotkeyKey { name: "d", aliases: &[], mac_code: KEY_D },
+    HotkeyKey { name: "e", aliases: &[], mac_code: KEY_E },
+    HotkeyKey { name: "f", aliases: &[], mac_code: KEY_F },
+    HotkeyKey { name: "g", aliases: &[], mac_code: KEY_G },
+    HotkeyKey { name: "h", aliases: &[], mac_code: KEY_H },
+    HotkeyKey { name: "i", aliases: &[], mac_code: KEY_I },
+    HotkeyKey { name: "j", aliases: &[], mac_code: KEY_J },
+    HotkeyKey { name: "k", aliases: &[], mac_code: KEY_K },
+    HotkeyKey { name: "l", aliases: &[], mac_code: KEY_L },
+    HotkeyKey { name: "m", aliases: &[], mac_code: KEY_M },
+    HotkeyKey { name: "n", aliases: &[], mac_code: KEY_N },
+    HotkeyKey { name: "o", aliases: &[], mac_code: KEY_O },
+    HotkeyKey { name: "p", aliases: &[], mac_code: KEY_P },
+    HotkeyKey { name: "q", aliases: &[], mac_code: KEY_Q },
+    HotkeyKey { name: "r", aliases: &[], mac_code: KEY_R },
+    HotkeyKey { name: "s", aliases: &[], mac_code: KEY_S },
+    HotkeyKey { name: "t", aliases: &[], mac_code: KEY_T },
+    HotkeyKey { name: "u", aliases: &[], mac_code: KEY_U },
+    HotkeyKey { name: "v", aliases: &[], mac_code: KEY_V },
+    HotkeyKey { name: "w", aliases: &[], mac_code: KEY_W },
+    HotkeyKey { name: "x", aliases: &[], mac_code: KEY_X },
+    HotkeyKey { name: "y", aliases: &[], mac_code: KEY_Y },
+    HotkeyKey { name: "z", aliases: &[], mac_code: KEY_Z },
+    HotkeyKey { name: "0", aliases: &[], mac_code: KEY_0 },
+    HotkeyKey { name: "1", aliases: &[], mac_code: KEY_1 },
+    HotkeyKey { name: "2", aliases: &[], mac_code: KEY_2 },
+    HotkeyKey { name: "3", aliases: &[], mac_code: KEY_3 },
+    HotkeyKey { name: "4", aliases: &[], mac_code: KEY_4 },
+    HotkeyKey { name: "5", aliases: &[], mac_code: KEY_5 },
+    HotkeyKey { name: "6", aliases: &[], mac_code: KEY_6 },
+    HotkeyKey { name: "7", aliases: &[], mac_code: KEY_7 },
+    HotkeyKey { name: "8", aliases: &[], mac_code: KEY_8 },
+    HotkeyKey { name: "9", aliases: &[], mac_code: KEY_9 },
+    HotkeyKey { name: "space", aliases: &[], mac_code: KEY_SPACE },
+    HotkeyKey { name: "enter", aliases: &["return"], mac_code: KEY_RETURN },
+    HotkeyKey { name: "tab", aliases: &[], mac_code: KEY_TAB },
+    HotkeyKey { name: "esc", aliases: &["escape"], mac_code: KEY_ESCAPE },
+    HotkeyKey { name: "f1", aliases: &[], mac_code: KEY_F1 },
+    HotkeyKey { name: "f2", aliases: &[], mac_code: KEY_F2 },
+    HotkeyKey { name: "f3", aliases: &[], mac_code: KEY_F3 },
+    HotkeyKey { name: "f4", aliases: &[], mac_code: KEY_F4 },
+    HotkeyKey { name: "f5", aliases: &[], mac_code: KEY_F5 },
+    HotkeyKey { name: "f6", aliases: &[], mac_code: KEY_F6 },
+    HotkeyKey { name: "f7", aliases: &[], mac_code: KEY_F7 },
+    HotkeyKey { name: "f8", aliases: &[], mac_code: KEY_F8 },
+    HotkeyKey { name: "f9", aliases: &[], mac_code: KEY_F9 },
+    HotkeyKey { name: "f10", aliases: &[], mac_code: KEY_F10 },
+    HotkeyKey { name: "f11", aliases: &[], mac_code: KEY_F11 },
+    HotkeyKey { name: "f12", aliases: &[], mac_code: KEY_F12 },
+    HotkeyKey { name: "f13", aliases: &[], mac_code: KEY_F13 },
+    HotkeyKey { name: "f14", aliases: &[], mac_code: KEY_F14 },
+    HotkeyKey { name: "f15", aliases: &[], mac_code: KEY_F15 },
+    HotkeyKey { name: "f16", aliases: &[], mac_code: KEY_F16 },
+    HotkeyKey { name: "f17", aliases: &[], mac_code: KEY_F17 },
+    HotkeyKey { name: "f18", aliases: &[], mac_code: KEY_F18 },
+    HotkeyKey { name: "f19", aliases: &[], mac_code: KEY_F19 },
+    HotkeyKey { name: "f20", aliases: &[], mac_code: KEY_F20 },
+];
+
+/// Modifier names `parse_hotkey` accepts: canonical name first, then aliases
+const HOTKEY_MODIFIERS: &[(&str, &[&str])] = &[
+    ("fn", &["function"]),
+    ("ctrl", &["control"]),
+    ("cmd", &["command", "meta"]),
+    ("option", &["opt", "alt"]),
+    ("shift", &[]),
+];
+
+const HOTKEY_ERR_EMPTY: &str = "Hotkey cannot be empty";
+const HOTKEY_ERR_MULTIPLE_KEYS: &str = "Only one non-modifier key is supported";
+const HOTKEY_ERR_NO_KEY: &str = "Hotkey must include a key (e.g. space, f1)";
+const HOTKEY_ERR_NO_MODIFIER: &str = "At least one modifier is required";
+const HOTKEY_ERR_FN_UNSUPPORTED: &str = "The fn key can only be used as a modifier on macOS";
+const HOTKEY_ERR_DOUBLE_TAP: &str = "Double-tap hotkeys take exactly one key (e.g. doubletap+shift)";
+/// Hotkey prefix selecting a double tap of the following key instead of a chord
+const DOUBLE_TAP_TOKEN: &str = "doubletap";
+
+/// Whether this platform's listener can see the key. rdev has no F13-F20.
+fn hotkey_key_supported(name: &str) -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        let _ = name;
+        true
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        rdev_key(name).is_some()
     }
 }
 
+/// fn is handled by the keyboard firmware outside macOS and never reaches apps
+fn hotkey_modifier_supported(name: &str) -> bool {
+    cfg!(target_os = "macos") || name != "fn"
+}
+
+/// Reject a parsed hotkey this platform's listener could never match
+fn check_hotkey_supported(cfg: &DictationHotkeyConfig) -> Result<(), String> {
+    if cfg.require_fn && !hotkey_modifier_supported("fn") {
+        return Err(HOTKEY_ERR_FN_UNSUPPORTED.to_string());
+    }
+    let key = keycode_to_name(cfg.key_code);
+    let supported = if parse_modifier(&key).is_some() {
+        hotkey_modifier_supported(&key)
+    } else {
+        hotkey_key_supported(&key)
+    };
+    if !supported {
+        return Err(format!("{key} can't be used as a hotkey on this platform"));
+    }
+    Ok(())
+}
+
+fn parse_keycode(key: &str) -> Option<u16> {
+    HOTKEY_KEYS
+        .iter()
+        .find(|k| k.name == key || k.aliases.contains(&key))
+        .map(|k| k.mac_code)
+}
+
+/// Canonical modifier name for a token, if it is a modifier
+fn parse_modifier(token: &str) -> Option<&'static str> {
+    HOTKEY_MODIFIERS
+        .iter()
+        .find(|(name, aliases)| *name == token || aliases.contains(&token))
+        .map(|(name, _)| *name)
+}
+
 fn parse_hotkey(input: &str) -> Result<DictationHotkeyConfig, String> {
     let tokens: Vec<String> = input
         .split('+')
@@ -1458,7 +2961,10 @@ fn parse_hotkey(input: &str) -> Result<DictationHotkeyConfig, String> {
         .collect();
 
     if tokens.is_empty() {
-        return Err("Hotkey cannot be empty".to_string());
+        return Err(HOTKEY_ERR_EMPTY.to_string());
+    }
+    if tokens.iter().any(|t| t == DOUBLE_TAP_TOKEN) {
+        return parse_double_tap_hotkey(&tokens, input);
     }
 
     let mut require_fn = false;
@@ -1469,15 +2975,17 @@ fn parse_hotkey(input: &str) -> Result<DictationHotkeyConfig, String> {
     let mut key_code: Option<u16> = None;
 
     for token in &tokens {
-        match token.as_str() {
-            "fn" | "function" => require_fn = true,
-            "ctrl" | "control" => require_control = true,
-            "cmd" | "command" | "meta" => require_command = true,
-            "opt" | "option" | "alt" => require_option = true,
-            "shift" => require_shift = true,
-            key => {
+        match parse_modifier(token) {
+            Some("fn") => require_fn = true,
+            Some("ctrl") => require_control = true,
+            Some("cmd") => require_command = true,
+            Some("option") => require_option = true,
+            Some("shift") => require_shift = true,
+            Some(other) => return Err(format!("Unsupported modifier: {other}")),
+            None => {
+                let key = token.as_str();
                 if key_code.is_some() {
-                    return Err("Only one non-modifier key is supported".to_string());
+                    return Err(HOTKEY_ERR_MULTIPLE_KEYS.to_string());
                 }
                 key_code = parse_keycode(key);
                 if key_code.is_none() {
@@ -1487,26 +2995,104 @@ fn parse_hotkey(input: &str) -> Result<DictationHotkeyConfig, String> {
         }
     }
 
-    let key_code = key_code.ok_or_else(|| "Hotkey must include a key (e.g. space, f1)".to_string())?;
+    let key_code = key_code.ok_or_else(|| HOTKEY_ERR_NO_KEY.to_string())?;
 
     if !require_fn && !require_control && !require_command && !require_option && !require_shift {
-        return Err("At least one modifier is required".to_string());
+        return Err(HOTKEY_ERR_NO_MODIFIER.to_string());
     }
 
     Ok(DictationHotkeyConfig {
         key_code,
-        require_fn,
-        require_control,
-        require_command,
-        require_option,
-        require_shift,
+        require_fn,
+        require_control,
+        require_command,
+        require_option,
+        require_shift,
+        consume: true,
+        double_tap: false,
+        display: input.trim().to_string(),
+    })
+}
+
+/// "doubletap+<key>", where the key may also be a modifier (e.g. doubletap+shift)
+fn parse_double_tap_hotkey(tokens: &[String], input: &str) -> Result<DictationHotkeyConfig, String> {
+    let keys: Vec<&str> = tokens
+        .iter()
+        .map(String::as_str)
+        .filter(|t| *t != DOUBLE_TAP_TOKEN)
+        .collect();
+    let [key] = keys.as_slice() else {
+        return Err(HOTKEY_ERR_DOUBLE_TAP.to_string());
+    };
+    let key_code = parse_modifier(key)
+        .map_or_else(|| parse_keycode(key), keycode_for_name)
+        .ok_or_else(|| format!("Unsupported key: {key}"))?;
+
+    Ok(DictationHotkeyConfig {
+        key_code,
+        require_fn: false,
+        require_control: false,
+        require_command: false,
+        require_option: false,
+        require_shift: false,
+        consume: true,
+        double_tap: true,
         display: input.trim().to_string(),
     })
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct SupportedHotkeyName {
+    name: String,
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SupportedHotkeyKeys {
+    keys: Vec<SupportedHotkeyName>,
+    modifiers: Vec<SupportedHotkeyName>,
+    invalid_combinations: Vec<String>, // Rules a hotkey must satisfy, as reported by the parser
+}
+
+/// List the keys and modifiers `dictation_set_hotkey` accepts, so the picker can't offer
+/// a combination the parser rejects.
+#[tauri::command]
+pub async fn dictation_get_supported_keys() -> Result<SupportedHotkeyKeys, String> {
+    let to_names = |name: &str, aliases: &[&str]| SupportedHotkeyName {
+        name: name.to_string(),
+        aliases: aliases.iter().map(|a| a.to_string()).collect(),
+    };
+
+    Ok(SupportedHotkeyKeys {
+        keys: HOTKEY_KEYS
+            .iter()
+            .filter(|k| hotkey_key_supported(k.name))
+            .map(|k| to_names(k.name, k.aliases))
+            .collect(),
+        modifiers: HOTKEY_MODIFIERS
+            .iter()
+            .filter(|(name, _)| hotkey_modifier_supported(name))
+            .map(|(name, aliases)| to_names(name, aliases))
+            .collect(),
+        invalid_combinations: [
+            HOTKEY_ERR_NO_MODIFIER,
+            HOTKEY_ERR_NO_KEY,
+            HOTKEY_ERR_MULTIPLE_KEYS,
+            HOTKEY_ERR_DOUBLE_TAP,
+        ]
+            .iter()
+            .map(|e| e.to_string())
+            .collect(),
+    })
+}
+
+/// `consume` defaults to true; pass false to let the hotkey's keystrokes reach the
+/// focused app even when the event tap runs in Filter mode.
 #[tauri::command]
-pub async fn dictation_set_hotkey(hotkey: String) -> Result<SetHotkeyResponse, String> {
-    let parsed = parse_hotkey(&hotkey)?;
+pub async fn dictation_set_hotkey(hotkey: String, consume: Option<bool>) -> Result<SetHotkeyResponse, String> {
+    let mut parsed = parse_hotkey(&hotkey)?;
+    check_hotkey_supported(&parsed)?;
+    parsed.consume = consume.unwrap_or(true);
 
     let mut cfg = HOTKEY_CONFIG
         .lock()
@@ -1516,6 +3102,7 @@ pub async fn dictation_set_hotkey(hotkey: String) -> Result<SetHotkeyResponse, S
 
     Ok(SetHotkeyResponse {
         hotkey: hotkey.trim().to_string(),
+        consume: parsed.consume,
     })
 }
 
@@ -1523,6 +3110,17 @@ pub async fn dictation_set_hotkey(hotkey: String) -> Result<SetHotkeyResponse, S
 fn handle_hotkey_event<R: Runtime>(app: &AppHandle<R>, event_type: CGEventType, keycode: u16, flags: CGEventFlags, autorepeat: bool) {
     let cfg = hotkey_config_from_atoms();
 
+    if HOTKEY_TEST_MODE.load(Ordering::SeqCst) {
+        // Track held state so the matching key-up is consumed as usual, but never
+        // prewarm, record, or paste.
+        if matches!(event_type, CGEventType::KeyUp) && keycode == cfg.key_code {
+            HOTKEY_HELD.store(false, Ordering::SeqCst);
+        } else if is_keydown_hotkey_match(event_type, keycode, flags, autorepeat, &cfg) {
+            HOTKEY_HELD.store(true, Ordering::SeqCst);
+        }
+        return;
+    }
+
     if matches!(event_type, CGEventType::FlagsChanged) && !HOTKEY_HELD.load(Ordering::SeqCst) {
         if modifiers_match(flags, &cfg) {
             maybe_start_dictation_prewarm();
@@ -1531,6 +3129,23 @@ fn handle_hotkey_event<R: Runtime>(app: &AppHandle<R>, event_type: CGEventType,
         }
     }
 
+    // Toggle mode only reacts to the hotkey's KeyDown: KeyUp and modifier releases
+    // don't end the session, the next press does.
+    if dictation_mode() == DictationMode::Toggle {
+        if !is_keydown_hotkey_match(event_type, keycode, flags, autorepeat, &cfg) {
+            return;
+        }
+        let app_clone = app.clone();
+        if HOTKEY_HELD.fetch_xor(true, Ordering::SeqCst) {
+            tauri::async_runtime::spawn(async move {
+                let _ = stop_dictation(app_clone).await;
+            });
+        } else {
+            spawn_toggle_start(app_clone);
+        }
+        return;
+    }
+
     // KeyUp should only check key code and held state.
     if matches!(event_type, CGEventType::KeyUp) && keycode == cfg.key_code {
         if HOTKEY_HELD.swap(false, Ordering::SeqCst) {
@@ -1542,20 +3157,16 @@ fn handle_hotkey_event<R: Runtime>(app: &AppHandle<R>, event_type: CGEventType,
         return;
     }
 
-    // If fn was released before key-up, stop early.
-    if matches!(event_type, CGEventType::FlagsChanged)
-        && cfg.require_fn
-        && HOTKEY_HELD.load(Ordering::SeqCst)
-    {
-        let fn_active =
-            flags.contains(CGEventFlags::CGEventFlagSecondaryFn) || FN_HELD.load(Ordering::SeqCst);
-        if !fn_active {
-            if HOTKEY_HELD.swap(false, Ordering::SeqCst) {
-                let app_clone = app.clone();
-                tauri::async_runtime::spawn(async move {
-                    let _ = stop_dictation(app_clone).await;
-                });
-            }
+    // If a required modifier was released before key-up, stop early. The swap
+    // guarantees only one of this path and KeyUp stops the session.
+    if matches!(event_type, CGEventType::FlagsChanged) && HOTKEY_HELD.load(Ordering::SeqCst) {
+        if required_modifier_released(held_modifiers(flags), &cfg)
+            && HOTKEY_HELD.swap(false, Ordering::SeqCst)
+        {
+            let app_clone = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let _ = stop_dictation(app_clone).await;
+            });
         }
         return;
     }
@@ -1589,7 +3200,7 @@ fn should_consume_hotkey_key_event(
     flags: CGEventFlags,
     cfg: &DictationHotkeyConfig,
 ) -> bool {
-    if keycode != cfg.key_code {
+    if !cfg.consume || keycode != cfg.key_code {
         return false;
     }
 
@@ -1598,8 +3209,58 @@ fn should_consume_hotkey_key_event(
     }
 
     // Consume if current modifiers match, or if we are already in held state
-    // (covers key-up after modifier transitions).
-    modifiers_match(flags, cfg) || HOTKEY_HELD.load(Ordering::SeqCst)
+    // (covers key-up after modifier transitions). In toggle mode HOTKEY_HELD stays set
+    // for the whole recording, so it would swallow the bare key typed meanwhile.
+    modifiers_match(flags, cfg)
+        || (dictation_mode() == DictationMode::PushToTalk && HOTKEY_HELD.load(Ordering::SeqCst))
+}
+
+/// Feed an event tap event to the double-tap detector as a press or release. Modifier
+/// taps arrive as FlagsChanged, other keys as KeyDown/KeyUp.
+#[cfg(target_os = "macos")]
+fn handle_double_tap_cg_event<R: Runtime>(
+    app: &AppHandle<R>,
+    event_type: CGEventType,
+    keycode: u16,
+    flags: CGEventFlags,
+    autorepeat: bool,
+    cfg: &DictationHotkeyConfig,
+) -> bool {
+    let Some(key) = key_name(keycode) else {
+        return false;
+    };
+    let held = held_modifiers(flags);
+    let pressed = match event_type {
+        CGEventType::KeyDown if !autorepeat => true,
+        CGEventType::KeyUp => false,
+        CGEventType::FlagsChanged => modifier_held(held, key),
+        _ => return false,
+    };
+    handle_double_tap_key(app, key, pressed, held, cfg)
+}
+
+/// Swallow the second tap of a double-tap hotkey, with its repeats and KeyUp, so it
+/// doesn't type into the focused app. The first tap always passes through.
+#[cfg(target_os = "macos")]
+fn consume_double_tap_key_event(
+    event_type: CGEventType,
+    keycode: u16,
+    autorepeat: bool,
+    completed: bool,
+    cfg: &DictationHotkeyConfig,
+) -> bool {
+    if !cfg.consume || keycode != cfg.key_code {
+        return false;
+    }
+    match event_type {
+        CGEventType::KeyDown if completed => {
+            DOUBLE_TAP_KEY_CONSUMED.store(true, Ordering::SeqCst);
+            true
+        }
+        CGEventType::KeyDown => autorepeat && DOUBLE_TAP_KEY_CONSUMED.load(Ordering::SeqCst),
+        CGEventType::KeyUp => DOUBLE_TAP_KEY_CONSUMED.swap(false, Ordering::SeqCst),
+        _ => false,
+    }
 }
 
 #[cfg(target_os = "macos")]
@@ -1716,26 +3377,61 @@ pub fn start_global_hotkey_listener<R: Runtime>(app: &AppHandle<R>) -> Result<()
                     // Never block in event tap callback. Match logic uses atomics.
                     let cfg = hotkey_config_from_atoms();
 
-                    let held_before = HOTKEY_HELD.load(Ordering::SeqCst);
-                    let matches_hotkey =
-                        is_keydown_hotkey_match(event_type, keycode, flags, autorepeat, &cfg);
+                    // Escape aborts a running dictation; only swallowed while one is active
+                    if matches!(event_type, CGEventType::KeyDown)
+                        && keycode == KEY_ESCAPE
+                        && !autorepeat
+                        && escape_cancels_dictation(&cfg)
+                    {
+                        let app_for_cancel = app_handle_inner.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let _ = cancel_dictation(app_for_cancel).await;
+                        });
+                        if matches!(mode_inner, EventTapMode::Filter) {
+                            return Some(make_consumed_event_from_original(event));
+                        }
+                        return None;
+                    }
 
-                    handle_hotkey_event(&app_handle_inner, event_type, keycode, flags, autorepeat);
+                    let held_before = HOTKEY_HELD.load(Ordering::SeqCst);
+                    let matches_hotkey = if cfg.double_tap {
+                        handle_double_tap_cg_event(&app_handle_inner, event_type, keycode, flags, autorepeat, &cfg)
+                    } else {
+                        let matches =
+                            is_keydown_hotkey_match(event_type, keycode, flags, autorepeat, &cfg);
+                        handle_hotkey_event(&app_handle_inner, event_type, keycode, flags, autorepeat);
+                        matches
+                    };
 
                     let held_after = HOTKEY_HELD.load(Ordering::SeqCst);
                     let consume_candidate = matches!(mode_inner, EventTapMode::Filter)
-                        && should_consume_hotkey_key_event(event_type, keycode, flags, &cfg);
+                        && if cfg.double_tap {
+                            consume_double_tap_key_event(event_type, keycode, autorepeat, matches_hotkey, &cfg)
+                        } else {
+                            should_consume_hotkey_key_event(event_type, keycode, flags, &cfg)
+                        };
 
                     let modifiers_ok = modifiers_match(flags, &cfg);
+                    let test_mode = HOTKEY_TEST_MODE.load(Ordering::SeqCst);
+                    let test_match = test_mode && (matches_hotkey || (held_before && !held_after));
+
+                    if test_match {
+                        // Emit off the tap thread; the callback must never block.
+                        let app_for_test = app_handle_inner.clone();
+                        let payload = serde_json::json!({
+                            "eventType": format!("{event_type:?}"),
+                            "keycode": keycode,
+                            "key": keycode_to_name(keycode),
+                            "modifiers": format_flags(flags),
+                            "consumed": consume_candidate,
+                        });
+                        tauri::async_runtime::spawn(async move {
+                            let _ = app_for_test.emit("dictation-hotkey-test", payload);
+                        });
+                    }
 
-                    if should_trace_debug_event(keycode, &cfg) {
-                        let action = if !held_before && held_after {
-                            "start"
-                        } else if held_before && !held_after {
-                            "stop"
-                        } else {
-                            "none"
-                        };
+                    if should_trace_debug_event(keycode, &cfg) || test_match {
+                        let action = hotkey_debug_action(test_mode, dictation_mode(), held_before, held_after);
                         push_debug_event(DictationDebugEvent {
                             timestamp_ms: now_millis(),
                             event_type: format!("{event_type:?}"),
@@ -1908,9 +3604,305 @@ pub fn start_global_hotkey_listener<R: Runtime>(app: &AppHandle<R>) -> Result<()
     Ok(())
 }
 
+/// rdev's key for a hotkey key name; None for keys rdev can't report
+#[cfg(not(target_os = "macos"))]
+fn rdev_key(name: &str) -> Option<rdev::Key> {
+    use rdev::Key;
+
+    let key = match name {
+        "a" => Key::KeyA,
+        "b" => Key::KeyB,
+        "c" => Key::KeyC,
+        "d" => Key::KeyD,
+        "e" => Key::KeyE,
+        "f" => Key::KeyF,
+        "g" => Key::KeyG,
+        "h" => Key::KeyH,
+        "i" => Key::KeyI,
+        "j" => Key::KeyJ,
+        "k" => Key::KeyK,
+        "l" => Key::KeyL,
+        "m" => Key::KeyM,
+        "n" => Key::KeyN,
+        "o" => Key::KeyO,
+        "p" => Key::KeyP,
+        "q" => Key::KeyQ,
+        "r" => Key::KeyR,
+        "s" => Key::KeyS,
+        "t" => Key::KeyT,
+        "u" => Key::KeyU,
+        "v" => Key::KeyV,
+        "w" => Key::KeyW,
+        "x" => Key::KeyX,
+        "y" => Key::KeyY,
+        "z" => Key::KeyZ,
+        "0" => Key::Num0,
+        "1" => Key::Num1,
+        "2" => Key::Num2,
+        "3" => Key::Num3,
+        "4" => Key::Num4,
+        "5" => Key::Num5,
+        "6" => Key::Num6,
+        "7" => Key::Num7,
+        "8" => Key::Num8,
+        "9" => Key::Num9,
+        "space" => Key::Space,
+        "enter" => Key::Return,
+        "tab" => Key::Tab,
+        "esc" => Key::Escape,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Hotkey key name for an rdev key, the reverse of `rdev_key`
+#[cfg(not(target_os = "macos"))]
+fn rdev_key_name(key: rdev::Key) -> Option<&'static str> {
+    HOTKEY_KEYS
+        .iter()
+        .map(|k| k.name)
+        .find(|name| rdev_key(name) == Some(key))
+}
+
+/// Logical name and held-state flag of an rdev modifier key. cmd maps to the
+/// Windows/Super key.
+#[cfg(not(target_os = "macos"))]
+fn rdev_modifier(key: rdev::Key) -> Option<(&'static str, &'static AtomicBool)> {
+    use rdev::Key;
+
+    match key {
+        Key::ControlLeft | Key::ControlRight => Some(("ctrl", &CTRL_HELD)),
+        Key::MetaLeft | Key::MetaRight => Some(("cmd", &CMD_HELD)),
+        Key::Alt | Key::AltGr => Some(("option", &ALT_HELD)),
+        Key::ShiftLeft | Key::ShiftRight => Some(("shift", &SHIFT_HELD)),
+        _ => None,
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn held_modifiers_from_atoms() -> HeldModifiers {
+    HeldModifiers {
+        fn_key: false,
+        control: CTRL_HELD.load(Ordering::SeqCst),
+        command: CMD_HELD.load(Ordering::SeqCst),
+        option: ALT_HELD.load(Ordering::SeqCst),
+        shift: SHIFT_HELD.load(Ordering::SeqCst),
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn format_held_modifiers(held: HeldModifiers) -> String {
+    let tokens: Vec<&str> = [
+        (held.command, "cmd"),
+        (held.control, "ctrl"),
+        (held.option, "option"),
+        (held.shift, "shift"),
+    ]
+    .iter()
+    .filter(|(on, _)| *on)
+    .map(|(_, name)| *name)
+    .collect();
+    if tokens.is_empty() {
+        "none".to_string()
+    } else {
+        tokens.join("+")
+    }
+}
+
+/// rdev counterpart of the event tap callback: tracks modifiers, then starts or stops
+/// dictation the same way `handle_hotkey_event` does. rdev only observes events, so
+/// the hotkey always reaches the focused app as well and `consume` has no effect.
+#[cfg(not(target_os = "macos"))]
+fn handle_rdev_event<R: Runtime>(app: &AppHandle<R>, event_type: rdev::EventType) {
+    let (key, pressed) = match event_type {
+        rdev::EventType::KeyPress(key) => (key, true),
+        rdev::EventType::KeyRelease(key) => (key, false),
+        _ => return,
+    };
+
+    // Keep tracking modifiers while disabled so the state is right once re-enabled
+    let modifier = rdev_modifier(key);
+    if let Some((_, flag)) = modifier {
+        flag.store(pressed, Ordering::SeqCst);
+    }
+    if !RDEV_LISTENER_ENABLED.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let cfg = hotkey_config_from_atoms();
+    if pressed && key == rdev::Key::Escape && escape_cancels_dictation(&cfg) {
+        let app_for_cancel = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let _ = cancel_dictation(app_for_cancel).await;
+        });
+        return;
+    }
+
+    let held = held_modifiers_from_atoms();
+    let test_mode = HOTKEY_TEST_MODE.load(Ordering::SeqCst);
+    let mode = dictation_mode();
+    let held_before = HOTKEY_HELD.load(Ordering::SeqCst);
+    let key_name = modifier.map(|(name, _)| name).or_else(|| rdev_key_name(key));
+
+    let (matches_hotkey, modifiers_ok, autorepeat) = if cfg.double_tap {
+        let completed = key_name.is_some_and(|name| handle_double_tap_key(app, name, pressed, held, &cfg));
+        (completed, true, false)
+    } else {
+        handle_rdev_chord_event(app, key, pressed, modifier.is_some(), held, &cfg)
+    };
+
+    let held_after = HOTKEY_HELD.load(Ordering::SeqCst);
+    let Some(key_name) = key_name else {
+        return;
+    };
+    let keycode = keycode_for_name(key_name).unwrap_or_default();
+    let test_match = test_mode && (matches_hotkey || (held_before && !held_after));
+    if test_match {
+        let payload = serde_json::json!({
+            "eventType": format!("{event_type:?}"),
+            "keycode": keycode,
+            "key": key_name,
+            "modifiers": format_held_modifiers(held),
+            "consumed": false,
+        });
+        let app_for_test = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let _ = app_for_test.emit("dictation-hotkey-test", payload);
+        });
+    }
+
+    push_debug_event(DictationDebugEvent {
+        timestamp_ms: now_millis(),
+        event_type: format!("{event_type:?}"),
+        keycode,
+        expected_keycode: cfg.key_code,
+        key: key_name.to_string(),
+        flags: format_held_modifiers(held),
+        autorepeat,
+        matches_hotkey,
+        modifiers_ok,
+        consume_candidate: false,
+        hotkey_held_before: held_before,
+        hotkey_held_after: held_after,
+        action: hotkey_debug_action(test_mode, mode, held_before, held_after).to_string(),
+    });
+}
+
+/// Modifier-chord hotkeys on rdev. Returns (matches_hotkey, modifiers_ok, autorepeat)
+/// for the debug trace.
+#[cfg(not(target_os = "macos"))]
+fn handle_rdev_chord_event<R: Runtime>(
+    app: &AppHandle<R>,
+    key: rdev::Key,
+    pressed: bool,
+    is_modifier: bool,
+    held: HeldModifiers,
+    cfg: &DictationHotkeyConfig,
+) -> (bool, bool, bool) {
+    let is_hotkey_key = hotkey_key_name(cfg.key_code).and_then(rdev_key) == Some(key);
+    let autorepeat = is_hotkey_key && pressed && HOTKEY_KEY_DOWN.swap(true, Ordering::SeqCst);
+    if is_hotkey_key && !pressed {
+        HOTKEY_KEY_DOWN.store(false, Ordering::SeqCst);
+    }
+
+    let modifiers_ok = held_modifiers_match(held, cfg);
+    let matches_hotkey = is_hotkey_key && pressed && !autorepeat && modifiers_ok;
+
+    if HOTKEY_TEST_MODE.load(Ordering::SeqCst) {
+        if is_hotkey_key && !pressed {
+            HOTKEY_HELD.store(false, Ordering::SeqCst);
+        } else if matches_hotkey {
+            HOTKEY_HELD.store(true, Ordering::SeqCst);
+        }
+        return (matches_hotkey, modifiers_ok, autorepeat);
+    }
+
+    if is_modifier && !HOTKEY_HELD.load(Ordering::SeqCst) {
+        if modifiers_ok {
+            maybe_start_dictation_prewarm();
+        } else {
+            maybe_cancel_dictation_prewarm();
+        }
+    }
+
+    let app_clone = app.clone();
+    if dictation_mode() == DictationMode::Toggle {
+        if matches_hotkey {
+            if HOTKEY_HELD.fetch_xor(true, Ordering::SeqCst) {
+                tauri::async_runtime::spawn(async move {
+                    let _ = stop_dictation(app_clone).await;
+                });
+            } else {
+                spawn_toggle_start(app_clone);
+            }
+        }
+    } else if matches_hotkey {
+        if !HOTKEY_HELD.swap(true, Ordering::SeqCst) {
+            tauri::async_runtime::spawn(async move {
+                let _ = start_dictation(app_clone).await;
+            });
+        }
+    } else if !pressed
+        && (is_hotkey_key || (is_modifier && required_modifier_released(held, cfg)))
+        && HOTKEY_HELD.swap(false, Ordering::SeqCst)
+    {
+        tauri::async_runtime::spawn(async move {
+            let _ = stop_dictation(app_clone).await;
+        });
+    }
+
+    (matches_hotkey, modifiers_ok, autorepeat)
+}
+
+/// Windows and Linux listener built on rdev: a low-level keyboard hook on Windows,
+/// XRecord on Linux (X11 only; Wayland sessions don't expose global key events).
 #[cfg(not(target_os = "macos"))]
-pub fn start_global_hotkey_listener<R: Runtime>(_app: &AppHandle<R>) -> Result<(), String> {
-    set_listener_debug_state(false, "unsupported-platform", Some("Global dictation hotkey currently supports macOS only".to_string()));
+pub fn start_global_hotkey_listener<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    HOTKEY_HELD.store(false, Ordering::SeqCst);
+    HOTKEY_KEY_DOWN.store(false, Ordering::SeqCst);
+    RDEV_LISTENER_ENABLED.store(true, Ordering::SeqCst);
+
+    if RDEV_LISTENER_RUNNING.swap(true, Ordering::SeqCst) {
+        set_listener_debug_state(true, "rdev", None);
+        return Ok(());
+    }
+
+    set_listener_debug_state(false, "starting", None);
+    let app_handle = app.clone();
+    let spawned = std::thread::Builder::new()
+        .name("dictation-hotkey".to_string())
+        .spawn(move || {
+            set_listener_debug_state(true, "rdev", None);
+            log::info!("Dictation: rdev global hotkey listener started");
+            // Only returns if the hook could not be installed
+            let error = match rdev::listen(move |event| handle_rdev_event(&app_handle, event.event_type)) {
+                Ok(()) => "Global hotkey listener exited".to_string(),
+                Err(e) => format!("Global hotkey listener failed: {e:?}"),
+            };
+            log::error!("Dictation: {}", error);
+            RDEV_LISTENER_RUNNING.store(false, Ordering::SeqCst);
+            set_listener_debug_state(false, "failed", Some(error));
+        });
+
+    if let Err(e) = spawned {
+        RDEV_LISTENER_RUNNING.store(false, Ordering::SeqCst);
+        let error = format!("Failed to spawn hotkey listener thread: {e}");
+        set_listener_debug_state(false, "failed", Some(error.clone()));
+        return Err(error);
+    }
+
     Ok(())
 }
 
@@ -1933,5 +3925,357 @@ pub fn stop_global_hotkey_listener() {
 
 #[cfg(not(target_os = "macos"))]
 pub fn stop_global_hotkey_listener() {
+    RDEV_LISTENER_ENABLED.store(false, Ordering::SeqCst);
+    HOTKEY_HELD.store(false, Ordering::SeqCst);
+    HOTKEY_KEY_DOWN.store(false, Ordering::SeqCst);
     set_listener_debug_state(false, "stopped", None);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_recordings_prune_oldest_first() {
+        let files = (1..=5)
+            .map(|i| (std::path::PathBuf::from(format!("dictation-2026010{i}.wav")), 10))
+            .collect::<Vec<_>>();
+
+        let mut pruned = recordings_to_prune(files.clone(), 3, 1_000);
+        pruned.sort();
+        assert_eq!(pruned, vec![files[0].0.clone(), files[1].0.clone()]);
+
+        // Byte cap: only the newest two fit in 25 bytes
+        assert_eq!(recordings_to_prune(files.clone(), 10, 25).len(), 3);
+        assert!(recordings_to_prune(files, 10, 1_000).is_empty());
+    }
+
+    #[test]
+    fn edge_trim_keeps_the_middle_at_its_offsets() {
+        let samples: Vec<f32> = (0..32_000).map(|i| i as f32).collect();
+        let trimmed = trim_clip_edges(samples, 16_000, 150, MIN_TRIMMED_CLIP_MS);
+        // 150ms at 16kHz is 2,400 samples from each end
+        assert_eq!(trimmed.len(), 32_000 - 2 * 2_400);
+        assert_eq!(trimmed[0], 2_400.0);
+        assert_eq!(*trimmed.last().unwrap(), 29_599.0);
+
+        // Too short to trim safely, or trimming disabled
+        assert_eq!(trim_clip_edges(vec![0.0; 20_000], 16_000, 150, MIN_TRIMMED_CLIP_MS).len(), 20_000);
+        assert_eq!(trim_clip_edges(vec![0.0; 32_000], 16_000, 0, MIN_TRIMMED_CLIP_MS).len(), 32_000);
+    }
+
+    #[test]
+    fn clip_exactly_at_minimum_passes() {
+        assert!(meets_min_duration(3_200, 16_000, 200, 0.0));
+        assert!(meets_min_duration(8_820, 44_100, 200, 0.0));
+        assert!(meets_min_duration(9_600, 48_000, 200, 0.0));
+    }
+
+    #[test]
+    fn clip_one_sample_short_fails() {
+        assert!(!meets_min_duration(3_199, 16_000, 200, 0.0));
+        assert!(!meets_min_duration(8_819, 44_100, 200, 0.0));
+    }
+
+    #[test]
+    fn resampled_boundary_clip_passes_with_tolerance() {
+        // A 200 ms capture that lost a few samples in the resampler
+        assert!(meets_min_duration(3_190, 16_000, 200, RESAMPLE_TOLERANCE_MS));
+        assert!(!meets_min_duration(3_000, 16_000, 200, RESAMPLE_TOLERANCE_MS));
+    }
+
+    #[test]
+    fn push_audio_chunk_drops_partial_trailing_frame() {
+        let shared = std::sync::Arc::new(StdMutex::new(Vec::new()));
+        // Three stereo frames plus one stray sample
+        push_audio_chunk(&shared, &[0.2, 0.4, -0.2, -0.4, 1.0, 0.0, 0.9], 2, 100);
+
+        let buffer = shared.lock().unwrap();
+        assert_eq!(buffer.len(), 3);
+        assert!((buffer[0] - 0.3).abs() < 1e-6);
+        assert!((buffer[1] + 0.3).abs() < 1e-6);
+        assert!((buffer[2] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn history_keeps_the_newest_entries_first() {
+        let mut history = VecDeque::new();
+        for i in 0..DICTATION_HISTORY_LIMIT + 5 {
+            push_history_entry(&mut history, DictationHistoryEntry::new(&format!("entry {i}"), &Ok(DictationOutputMode::Paste)));
+        }
+        assert_eq!(history.len(), DICTATION_HISTORY_LIMIT);
+        assert_eq!(history[0].text, format!("entry {}", DICTATION_HISTORY_LIMIT + 4));
+        assert_eq!(history.back().unwrap().text, "entry 5");
+    }
+
+    #[test]
+    fn history_entries_record_delivery_and_cap_length() {
+        let failed = DictationHistoryEntry::new("hello", &Err("no permission".to_string()));
+        assert_eq!(failed.delivery, DictationDelivery::Failed);
+        assert_eq!(failed.delivery_error.as_deref(), Some("no permission"));
+        assert_eq!(DictationHistoryEntry::new("hi", &Ok(DictationOutputMode::Type)).delivery, DictationDelivery::Typed);
+
+        let long = DictationHistoryEntry::new(&"é".repeat(MAX_HISTORY_ENTRY_CHARS + 1), &Ok(DictationOutputMode::Paste));
+        assert!(long.truncated);
+        assert_eq!(long.text.chars().count(), MAX_HISTORY_ENTRY_CHARS);
+        assert!(!failed.truncated);
+    }
+
+    #[test]
+    fn level_meter_reads_rms_on_a_db_scale() {
+        assert_eq!(chunk_levels(&[]), (0.0, 0.0));
+        let (rms, peak) = chunk_levels(&[0.5, -0.5, 0.5, -0.5]);
+        assert!((rms - 0.5).abs() < 1e-6);
+        assert_eq!(peak, 0.5);
+
+        assert_eq!(meter_level(0.0), 0.0);
+        assert_eq!(meter_level(1.0), 1.0);
+        // -20 dBFS is two thirds of the way up a 60 dB meter
+        assert!((meter_level(0.1) - 2.0 / 3.0).abs() < 1e-5);
+        assert_eq!(meter_level(0.0001), 0.0);
+    }
+
+    #[test]
+    fn push_audio_chunk_ignores_buffer_shorter_than_a_frame() {
+        let shared = std::sync::Arc::new(StdMutex::new(Vec::new()));
+        push_audio_chunk(&shared, &[0.5, 0.5], 3, 100);
+        assert!(shared.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn parsed_hotkeys_consume_by_default() {
+        assert!(parse_hotkey("ctrl+space").unwrap().consume);
+        assert!(DictationHotkeyConfig::default().consume);
+    }
+
+    #[test]
+    fn ctrl_space_stops_when_ctrl_released_before_space() {
+        let cfg = parse_hotkey("ctrl+space").unwrap();
+        let holding = HeldModifiers { control: true, ..Default::default() };
+        assert!(!required_modifier_released(holding, &cfg));
+
+        // Ctrl comes up first while space is still down
+        assert!(required_modifier_released(HeldModifiers::default(), &cfg));
+    }
+
+    #[test]
+    fn typed_chunks_respect_the_event_limit_and_surrogate_pairs() {
+        assert!(type_chunks("").is_empty());
+        assert_eq!(type_chunks("hello"), vec!["hello"]);
+
+        let long = "a".repeat(45);
+        assert_eq!(type_chunks(&long).iter().map(|c| c.len()).collect::<Vec<_>>(), vec![20, 20, 5]);
+
+        // Each emoji is a surrogate pair; 19 units of text leave no room for one more
+        let text = format!("{}😀😀", "b".repeat(19));
+        let chunks = type_chunks(&text);
+        assert_eq!(chunks, vec!["b".repeat(19), "😀😀".to_string()]);
+        assert!(chunks.iter().all(|c| c.encode_utf16().count() <= TYPE_CHUNK_UTF16_UNITS));
+        assert_eq!(chunks.concat(), text);
+    }
+
+    #[test]
+    fn debug_action_names_toggle_transitions() {
+        let toggle = DictationMode::Toggle;
+        assert_eq!(hotkey_debug_action(false, toggle, false, true), "toggle-start");
+        assert_eq!(hotkey_debug_action(false, toggle, true, false), "toggle-stop");
+        assert_eq!(hotkey_debug_action(false, toggle, true, true), "none");
+        assert_eq!(hotkey_debug_action(false, DictationMode::PushToTalk, false, true), "start");
+        assert_eq!(hotkey_debug_action(false, DictationMode::PushToTalk, true, false), "stop");
+        assert_eq!(hotkey_debug_action(true, toggle, false, true), "test");
+    }
+
+    #[test]
+    fn extra_modifier_changes_do_not_stop_dictation() {
+        let cfg = parse_hotkey("ctrl+space").unwrap();
+        let with_shift = HeldModifiers { control: true, shift: true, ..Default::default() };
+        assert!(!required_modifier_released(with_shift, &cfg));
+    }
+
+    #[test]
+    fn any_required_modifier_release_stops_multi_modifier_hotkey() {
+        let cfg = parse_hotkey("cmd+shift+d").unwrap();
+        let both = HeldModifiers { command: true, shift: true, ..Default::default() };
+        assert!(!required_modifier_released(both, &cfg));
+        assert!(required_modifier_released(HeldModifiers { command: true, ..Default::default() }, &cfg));
+        assert!(required_modifier_released(HeldModifiers { shift: true, ..Default::default() }, &cfg));
+    }
+
+    #[test]
+    fn every_listed_key_and_alias_parses() {
+        for key in HOTKEY_KEYS {
+            assert_eq!(parse_keycode(key.name), Some(key.mac_code));
+            for alias in key.aliases {
+                assert_eq!(parse_keycode(alias), Some(key.mac_code));
+            }
+            assert!(parse_hotkey(&format!("ctrl+{}", key.name)).is_ok());
+        }
+        for (name, aliases) in HOTKEY_MODIFIERS {
+            for modifier in std::iter::once(name).chain(aliases.iter()) {
+                assert!(parse_hotkey(&format!("{modifier}+space")).is_ok(), "{modifier}");
+            }
+        }
+    }
+
+    #[test]
+    fn key_names_round_trip_through_keycodes() {
+        for key in HOTKEY_KEYS {
+            assert_eq!(keycode_to_name(key.mac_code), key.name);
+        }
+        assert_eq!(keycode_to_name(KEY_RIGHT_OPTION), "option");
+        assert_eq!(keycode_to_name(0x7F), "keycode:127");
+    }
+
+    #[test]
+    fn default_hotkey_is_usable_on_this_platform() {
+        let parsed = parse_hotkey(DEFAULT_HOTKEY).unwrap();
+        let default = DictationHotkeyConfig::default();
+        assert!(check_hotkey_supported(&default).is_ok());
+        assert_eq!(parsed.key_code, default.key_code);
+        assert!(held_modifiers_match(
+            HeldModifiers {
+                fn_key: parsed.require_fn,
+                control: parsed.require_control,
+                command: parsed.require_command,
+                option: parsed.require_option,
+                shift: parsed.require_shift,
+            },
+            &default
+        ));
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn rdev_keys_map_back_to_hotkey_names() {
+        for key in HOTKEY_KEYS.iter().filter(|k| hotkey_key_supported(k.name)) {
+            assert_eq!(rdev_key_name(rdev_key(key.name).unwrap()), Some(key.name));
+        }
+        assert!(!hotkey_key_supported("f13"));
+        assert_eq!(check_hotkey_supported(&parse_hotkey("fn+space").unwrap()).unwrap_err(), HOTKEY_ERR_FN_UNSUPPORTED);
+        assert!(check_hotkey_supported(&parse_hotkey("ctrl+f13").unwrap()).is_err());
+        assert!(check_hotkey_supported(&parse_hotkey("cmd+shift+d").unwrap()).is_ok());
+        assert!(check_hotkey_supported(&parse_hotkey("doubletap+shift").unwrap()).is_ok());
+        assert!(check_hotkey_supported(&parse_hotkey("doubletap+fn").unwrap()).is_err());
+    }
+
+    #[test]
+    fn double_tap_hotkeys_parse_keys_and_modifiers() {
+        let cfg = parse_hotkey("doubletap+shift").unwrap();
+        assert!(cfg.double_tap);
+        assert_eq!(cfg.key_code, KEY_LEFT_SHIFT);
+        assert_eq!(key_name(KEY_RIGHT_SHIFT), key_name(cfg.key_code));
+        assert!(!cfg.require_shift);
+        assert_eq!(cfg.display, "doubletap+shift");
+
+        assert_eq!(parse_hotkey("DoubleTap+F5").unwrap().key_code, KEY_F5);
+        assert!(!parse_hotkey("ctrl+space").unwrap().double_tap);
+        assert_eq!(parse_hotkey("doubletap").unwrap_err(), HOTKEY_ERR_DOUBLE_TAP);
+        assert_eq!(parse_hotkey("doubletap+ctrl+space").unwrap_err(), HOTKEY_ERR_DOUBLE_TAP);
+        assert!(parse_hotkey("doubletap+nope").is_err());
+    }
+
+    #[test]
+    fn second_tap_must_land_inside_the_window() {
+        assert!(completes_double_tap(1_000, 1_300, 300));
+        assert!(!completes_double_tap(1_000, 1_301, 300));
+        // No pending first tap
+        assert!(!completes_double_tap(0, 200, 300));
+    }
+
+    #[test]
+    fn listed_invalid_combinations_are_rejected() {
+        assert_eq!(parse_hotkey("space").unwrap_err(), HOTKEY_ERR_NO_MODIFIER);
+        assert_eq!(parse_hotkey("ctrl+shift").unwrap_err(), HOTKEY_ERR_NO_KEY);
+        assert_eq!(parse_hotkey("ctrl+a+b").unwrap_err(), HOTKEY_ERR_MULTIPLE_KEYS);
+    }
+
+    fn replacement(pattern: &str, replacement: &str, regex: bool, case_sensitive: bool) -> DictationReplacement {
+        DictationReplacement {
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            regex,
+            case_sensitive,
+        }
+    }
+
+    #[test]
+    fn literal_replacements_match_whole_words_case_insensitively() {
+        let list = compile_replacements(vec![
+            replacement("meet lee", "Meetily", false, false),
+            replacement("ai", "AI", false, false),
+            replacement("$5", "five dollars", false, false),
+        ])
+        .unwrap();
+        assert_eq!(
+            apply_replacements("Meet Lee said ai costs $5", &list),
+            "Meetily said AI costs five dollars"
+        );
+
+        let exact = compile_replacements(vec![replacement("Go", "Golang", false, true)]).unwrap();
+        assert_eq!(apply_replacements("go Go", &exact), "go Golang");
+    }
+
+    #[test]
+    fn regex_replacements_expand_groups() {
+        let list = compile_replacements(vec![replacement(r"v(\d+) point (\d+)", "v$1.$2", true, false)]).unwrap();
+        assert_eq!(apply_replacements("ship V2 point 5 today", &list), "ship v2.5 today");
+    }
+
+    #[test]
+    fn replacements_apply_in_order() {
+        let list = compile_replacements(vec![
+            replacement("cube control", "kubectl", false, false),
+            replacement("kubectl", "`kubectl`", false, true),
+        ])
+        .unwrap();
+        assert_eq!(apply_replacements("run cube control get pods", &list), "run `kubectl` get pods");
+
+        let reversed = compile_replacements(vec![
+            replacement("kubectl", "`kubectl`", false, true),
+            replacement("cube control", "kubectl", false, false),
+        ])
+        .unwrap();
+        assert_eq!(apply_replacements("run cube control get pods", &reversed), "run kubectl get pods");
+    }
+
+    #[test]
+    fn invalid_replacements_are_rejected_by_position() {
+        let err = compile_replacements(vec![
+            replacement("ok", "fine", false, false),
+            replacement("(unclosed", "x", true, false),
+        ])
+        .err()
+        .unwrap();
+        assert!(err.starts_with("Replacement 2:"), "{err}");
+        assert!(compile_replacements(vec![replacement("a*", "x", true, false)]).is_err());
+        assert!(compile_replacements(vec![replacement("  ", "x", false, false)]).is_err());
+        assert!(compile_replacements(vec![replacement(r"\w{1000}{1000}", "x", true, false)]).is_err());
+    }
+
+    #[test]
+    fn max_length_warning_leaves_time_to_finish() {
+        assert_eq!(length_warning_after_secs(60), 50);
+        assert_eq!(length_warning_after_secs(600), 590);
+        assert_eq!(length_warning_after_secs(5), 3);
+        assert!(dictation_sample_limit(16_000) >= 16_000 * DEFAULT_MAX_DICTATION_SECONDS as usize);
+    }
+
+    #[test]
+    fn zero_sample_rate_is_never_long_enough() {
+        assert_eq!(audio_duration_ms(16_000, 0), 0.0);
+        assert!(!meets_min_duration(16_000, 0, 200, 0.0));
+    }
+
+    #[test]
+    fn dictation_language_overrides_global_preference() {
+        assert_eq!(normalize_language_code(Some(" de ".to_string())), Ok(Some("de".to_string())));
+        assert_eq!(normalize_language_code(Some("".to_string())), Ok(None));
+        assert_eq!(normalize_language_code(None), Ok(None));
+        assert!(normalize_language_code(Some("en; rm".to_string())).is_err());
+
+        let global = Some("en".to_string());
+        assert_eq!(resolve_language(Some("de".to_string()), global.clone()), Some("de".to_string()));
+        assert_eq!(resolve_language(None, global), Some("en".to_string()));
+        assert_eq!(resolve_language(None, None), None);
+    }
+}
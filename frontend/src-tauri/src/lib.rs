@@ -418,6 +418,7 @@ pub fn run() {
             if let Err(e) = dictation::start_global_hotkey_listener(&_app.handle()) {
                 log::warn!("Failed to start dictation hotkey listener: {}", e);
             }
+            dictation::restore_replacements(_app.handle());
 
             // Initialize notification system with proper defaults
             log::info!("Initializing notification system...");
@@ -465,15 +466,9 @@ pub fn run() {
                 }
             });
 
-            // Set Qwen ASR models directory
+            // Set Qwen ASR models directory (the engine is initialized once the database is ready)
             qwen_asr_engine::commands::set_models_directory(&_app.handle());
-
-            // Initialize Qwen ASR engine on startup
-            tauri::async_runtime::spawn(async {
-                if let Err(e) = qwen_asr_engine::commands::qwen_asr_init().await {
-                    log::error!("Failed to initialize Qwen ASR engine on startup: {}", e);
-                }
-            });
+            qwen_asr_engine::commands::start_idle_unload_watcher(_app.handle().clone());
 
             // Initialize ModelManager for summary engine (async, non-blocking)
             let app_handle_for_model_manager = _app.handle().clone();
@@ -503,6 +498,17 @@ pub fn run() {
             })
             .expect("Failed to initialize database");
 
+            // Initialize Qwen ASR engine on startup and apply its saved config,
+            // which lives in the database
+            let app_for_qwen_config = _app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = qwen_asr_engine::commands::qwen_asr_init().await {
+                    log::error!("Failed to initialize Qwen ASR engine on startup: {}", e);
+                    return;
+                }
+                qwen_asr_engine::commands::restore_config(&app_for_qwen_config).await;
+            });
+
             // Start meeting app detection loop
             meeting_detector::start_detection_loop(_app.handle().clone());
 
@@ -581,16 +587,77 @@ pub fn run() {
             qwen_asr_engine::commands::qwen_asr_init,
             qwen_asr_engine::commands::qwen_asr_get_available_models,
             qwen_asr_engine::commands::qwen_asr_load_model,
+            qwen_asr_engine::commands::qwen_asr_unload_model,
+            qwen_asr_engine::commands::qwen_asr_preload_model,
             qwen_asr_engine::commands::qwen_asr_get_current_model,
             qwen_asr_engine::commands::qwen_asr_is_model_loaded,
             qwen_asr_engine::commands::qwen_asr_has_available_models,
             qwen_asr_engine::commands::qwen_asr_validate_model_ready,
             qwen_asr_engine::commands::qwen_asr_transcribe_audio,
+            qwen_asr_engine::commands::qwen_asr_transcribe_file_with_progress,
+            qwen_asr_engine::commands::qwen_asr_cancel_file_transcription,
+            qwen_asr_engine::commands::qwen_asr_set_temperature,
+            qwen_asr_engine::commands::qwen_asr_set_config,
+            qwen_asr_engine::commands::qwen_asr_get_config,
+            qwen_asr_engine::commands::qwen_asr_get_gpu_device_count,
+            qwen_asr_engine::commands::qwen_asr_set_repetition_threshold,
+            qwen_asr_engine::commands::qwen_asr_set_decoder_context,
+            qwen_asr_engine::commands::qwen_asr_get_decoder_context,
+            qwen_asr_engine::commands::qwen_asr_set_idle_unload_minutes,
+            qwen_asr_engine::commands::qwen_asr_get_idle_unload_minutes,
+            qwen_asr_engine::commands::qwen_asr_set_idle_unload_secs,
+            qwen_asr_engine::commands::qwen_asr_get_idle_unload_secs,
+            qwen_asr_engine::commands::qwen_asr_set_model_pinned,
+            qwen_asr_engine::commands::qwen_asr_is_model_pinned,
+            qwen_asr_engine::commands::qwen_asr_set_transcript_cache_size,
+            qwen_asr_engine::commands::qwen_asr_get_transcript_cache_size,
+            qwen_asr_engine::commands::qwen_asr_set_download_retries,
+            qwen_asr_engine::commands::qwen_asr_get_download_retries,
+            qwen_asr_engine::commands::qwen_asr_set_download_connections,
+            qwen_asr_engine::commands::qwen_asr_get_download_connections,
+            qwen_asr_engine::commands::qwen_asr_get_download_history,
+            qwen_asr_engine::commands::qwen_asr_set_mel_backend,
+            qwen_asr_engine::commands::qwen_asr_get_mel_backend,
+            qwen_asr_engine::commands::qwen_asr_get_build_info,
             qwen_asr_engine::commands::qwen_asr_get_models_directory,
             qwen_asr_engine::commands::qwen_asr_download_model,
             qwen_asr_engine::commands::qwen_asr_cancel_download,
             qwen_asr_engine::commands::qwen_asr_delete_model,
+            qwen_asr_engine::commands::qwen_asr_verify_model,
+            qwen_asr_engine::commands::qwen_asr_import_model,
             qwen_asr_engine::commands::qwen_asr_open_models_folder,
+            // Transcription provider readiness
+            audio::transcription::engine::list_ready_providers,
+            audio::transcription::engine::get_transcription_provider_priority,
+            audio::transcription::engine::set_transcription_provider_priority,
+            audio::transcription::engine::get_openai_compatible_transcription_config,
+            audio::transcription::engine::set_openai_compatible_transcription_config,
+            audio::transcription::engine::get_transcription_model_readiness,
+            audio::transcription::engine::run_transcription_preflight,
+            audio::transcription::metrics::get_last_recording_metrics,
+            audio::transcription::worker::set_transcript_min_words,
+            audio::transcription::worker::get_transcript_min_words,
+            audio::transcription::worker::set_emulated_streaming_enabled,
+            audio::transcription::worker::get_emulated_streaming_enabled,
+            audio::transcription::worker::set_transcription_prewarm_enabled,
+            audio::transcription::worker::get_transcription_prewarm_enabled,
+            audio::transcription::worker::set_transcript_timing_validation,
+            audio::transcription::worker::get_transcript_timing_validation,
+            audio::transcription::worker::set_transcription_buffer_max_seconds,
+            audio::transcription::worker::get_transcription_buffer_max_seconds,
+            audio::transcription::worker::set_transcription_max_workers,
+            audio::transcription::worker::get_transcription_max_workers,
+            audio::transcription::worker::set_transcription_dry_run,
+            audio::transcription::worker::get_transcription_dry_run,
+            audio::transcription::worker::set_live_refinement_settings,
+            audio::transcription::worker::get_live_refinement_settings,
+            audio::transcription::text_normalize::set_normalize_spelled_numbers,
+            audio::transcription::text_normalize::get_normalize_spelled_numbers,
+            audio::pipeline::set_transcription_chunk_overlap_ms,
+            audio::pipeline::get_transcription_chunk_overlap_ms,
+            audio::transcription::websocket_sink::start_transcript_websocket,
+            audio::transcription::websocket_sink::stop_transcript_websocket,
+            audio::transcription::websocket_sink::get_transcript_websocket_status,
             // Parallel processing commands
             whisper_engine::parallel_commands::initialize_parallel_processor,
             whisper_engine::parallel_commands::start_parallel_processing,
@@ -701,8 +768,20 @@ pub fn run() {
             // Meeting detection commands
             meeting_detector::set_meeting_detection_enabled,
             meeting_detector::get_meeting_detection_enabled,
+            meeting_detector::set_meeting_alert_delivery,
+            meeting_detector::get_meeting_alert_delivery,
+            meeting_detector::set_meeting_auto_start_enabled,
+            meeting_detector::get_meeting_auto_start_enabled,
+            meeting_detector::set_meeting_auto_start_countdown,
+            meeting_detector::get_meeting_auto_start_countdown,
+            meeting_detector::set_meeting_detection_interval,
+            meeting_detector::get_meeting_detection_interval,
             meeting_detector::dismiss_meeting_banner,
             meeting_detector::accept_meeting_banner,
+            meeting_detector::get_active_meetings,
+            meeting_detector::get_custom_meeting_apps,
+            meeting_detector::add_custom_meeting_app,
+            meeting_detector::remove_custom_meeting_app,
             // Notification system commands
             notifications::commands::get_notification_settings,
             notifications::commands::set_notification_settings,
@@ -744,13 +823,46 @@ pub fn run() {
             // Dictation (push-to-talk) commands
             dictation::dictation_start_manual,
             dictation::dictation_stop_manual,
+            dictation::dictation_cancel_manual,
             dictation::dictation_get_last_transcript,
             dictation::dictation_paste_last_transcript,
+            dictation::dictation_get_last_latency,
+            dictation::dictation_get_history,
+            dictation::dictation_paste_from_history,
+            dictation::dictation_set_language,
+            dictation::dictation_get_language,
+            dictation::dictation_set_replacements,
+            dictation::dictation_get_replacements,
+            dictation::dictation_clear_replacements,
             dictation::dictation_get_hotkey,
+            dictation::dictation_get_default_hotkey,
+            dictation::dictation_set_mode,
+            dictation::dictation_set_output_mode,
+            dictation::dictation_get_output_mode,
+            dictation::dictation_get_mode,
             dictation::dictation_set_hotkey,
             dictation::dictation_get_debug_state,
             dictation::dictation_clear_debug_events,
+            dictation::dictation_get_supported_keys,
+            dictation::dictation_capture_debug_sample,
+            dictation::dictation_start_hotkey_test_mode,
+            dictation::dictation_stop_hotkey_test_mode,
+            dictation::dictation_set_min_duration_ms,
+            dictation::dictation_get_min_duration_ms,
+            dictation::dictation_set_edge_trim_ms,
+            dictation::dictation_get_edge_trim_ms,
+            dictation::dictation_set_max_seconds,
+            dictation::dictation_get_max_seconds,
+            dictation::dictation_set_ready_delay_ms,
+            dictation::dictation_get_ready_delay_ms,
+            dictation::dictation_set_double_tap_window_ms,
+            dictation::dictation_get_double_tap_window_ms,
+            dictation::dictation_set_preroll,
+            dictation::dictation_get_preroll,
+            dictation::dictation_set_save_recordings,
+            dictation::dictation_get_save_recordings,
             dictation::dictation_restart_listener,
+            dictation::dictation_reset_all,
             dictation::dictation_check_accessibility,
             dictation::dictation_request_accessibility,
             dictation::dictation_check_input_monitoring,
@@ -767,6 +879,8 @@ pub fn run() {
             audio::retranscription::start_retranscription_command,
             audio::retranscription::cancel_retranscription_command,
             audio::retranscription::is_retranscription_in_progress_command,
+            audio::retranscription::set_stereo_mic_channel,
+            audio::retranscription::get_stereo_mic_channel,
             // Import audio commands
             audio::import::select_and_validate_audio_command,
             audio::import::validate_audio_file_command,
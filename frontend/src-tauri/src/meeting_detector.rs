@@ -1,9 +1,15 @@
-use log::{info, warn};
+use crate::notifications::{self, commands::NotificationManagerState};
+use log::{debug, info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+use std::sync::Mutex;
 use sysinfo::System;
 use tauri::{AppHandle, Emitter, Manager, Runtime, WebviewUrl, WebviewWindowBuilder};
+use tauri_plugin_store::StoreExt;
 
 /// Meeting detection rules.
 ///
@@ -13,66 +19,233 @@ use tauri::{AppHandle, Emitter, Manager, Runtime, WebviewUrl, WebviewWindowBuild
 /// - `meeting_indicators`: processes that only appear during an active meeting/call.
 ///   If empty, the app process itself is treated as the indicator (for apps
 ///   where the process only launches when joining a call).
+/// - `window_title_pattern`: regex over a lowercased window title that means a
+///   meeting is open, for meetings running in a browser tab with no process of
+///   their own. Checked when the process rules don't find a meeting.
+/// - `window_title_processes`: processes that can show such a window. Titles are
+///   only listed while one of them runs, since listing them spawns a command.
 struct MeetingApp {
     display_name: &'static str,
     app_processes: &'static [&'static str],
     meeting_indicators: &'static [&'static str],
+    window_title_pattern: Option<&'static str>,
+    window_title_processes: &'static [&'static str],
 }
 
+const BROWSER_PROCESSES: &[&str] = &[
+    "chrome", "chromium", "msedge", "microsoft edge", "firefox", "safari", "brave", "opera", "vivaldi",
+];
+
 const MEETING_APPS: &[MeetingApp] = &[
     MeetingApp {
         display_name: "Zoom",
         app_processes: &["zoom.us"],
         meeting_indicators: &["cpthost"],
+        window_title_pattern: None,
+        window_title_processes: &[],
     },
     MeetingApp {
         display_name: "Feishu",
         app_processes: &["feishu", "lark"],
         meeting_indicators: &["feishu_vc", "lark_vc", "byteaudiod"],
+        window_title_pattern: None,
+        window_title_processes: &[],
     },
     MeetingApp {
         display_name: "Tencent Meeting",
         app_processes: &["wemeet"],
         meeting_indicators: &["wemeetapp"],
+        window_title_pattern: None,
+        window_title_processes: &[],
     },
     MeetingApp {
         display_name: "VooV Meeting",
         app_processes: &["voov"],
         meeting_indicators: &[],
+        window_title_pattern: None,
+        window_title_processes: &[],
     },
     MeetingApp {
         display_name: "Microsoft Teams",
         app_processes: &["microsoft teams", "ms-teams", "teams"],
         meeting_indicators: &[],
+        // "Meeting with Alex | Microsoft Teams", not the chat or calendar pages
+        window_title_pattern: Some(r"^(meeting|call) (with|in) [^|]+\| microsoft teams\b"),
+        window_title_processes: BROWSER_PROCESSES,
+    },
+    MeetingApp {
+        display_name: "Google Meet",
+        app_processes: &[],
+        meeting_indicators: &[],
+        // A joined meeting shows its code, "Meet - abc-defg-hij"; the landing page doesn't
+        window_title_pattern: Some(r"^meet [-–] [a-z]{3}-[a-z]{4}-[a-z]{3}\b"),
+        window_title_processes: BROWSER_PROCESSES,
     },
     MeetingApp {
         display_name: "Discord",
         app_processes: &["discord"],
         meeting_indicators: &[],
+        window_title_pattern: None,
+        window_title_processes: &[],
     },
     MeetingApp {
         display_name: "Webex",
         app_processes: &["webex", "webexmta"],
         meeting_indicators: &["ciscocollabhost"],
+        window_title_pattern: None,
+        window_title_processes: &[],
     },
 ];
 
+const PREFERENCES_STORE: &str = "preferences.json";
+const CUSTOM_APPS_STORE_KEY: &str = "custom_meeting_apps";
+const MAX_CUSTOM_APPS: usize = 50;
+const MAX_DISPLAY_NAME_CHARS: usize = 64;
+
+/// Seconds between scans; the default is 5s and user values are clamped to MIN..=MAX
+pub const DEFAULT_SCAN_INTERVAL_SECS: u64 = 5;
+pub const MIN_SCAN_INTERVAL_SECS: u64 = 2;
+pub const MAX_SCAN_INTERVAL_SECS: u64 = 60;
+/// Floor on the interval while recording, when scans are skipped anyway
+const RECORDING_SCAN_INTERVAL_SECS: u64 = 30;
+
+fn clamp_scan_interval(secs: u64) -> u64 {
+    secs.clamp(MIN_SCAN_INTERVAL_SECS, MAX_SCAN_INTERVAL_SECS)
+}
+
+/// Countdown shown on the banner before an auto-start begins recording
+pub const DEFAULT_AUTO_START_COUNTDOWN_SECS: u64 = 5;
+pub const MIN_AUTO_START_COUNTDOWN_SECS: u64 = 1;
+pub const MAX_AUTO_START_COUNTDOWN_SECS: u64 = 30;
+
+/// A user-defined detection rule, checked after `MEETING_APPS`. Same matching as the
+/// built-ins, minus window titles.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomMeetingApp {
+    pub display_name: String,
+    pub app_processes: Vec<String>,
+    #[serde(default)]
+    pub meeting_indicators: Vec<String>,
+}
+
+impl CustomMeetingApp {
+    fn validate(&self) -> Result<(), String> {
+        let name = self.display_name.trim();
+        if name.is_empty() {
+            return Err("Display name must not be empty".to_string());
+        }
+        if name.chars().count() > MAX_DISPLAY_NAME_CHARS {
+            return Err(format!("Display name is longer than {MAX_DISPLAY_NAME_CHARS} characters"));
+        }
+        if MEETING_APPS
+            .iter()
+            .any(|app| app.display_name.eq_ignore_ascii_case(name))
+        {
+            return Err(format!("'{name}' is already a built-in meeting app"));
+        }
+        if self.app_processes.is_empty() {
+            return Err("At least one app process is required".to_string());
+        }
+        for pattern in self.app_processes.iter().chain(&self.meeting_indicators) {
+            validate_process_pattern(pattern)?;
+        }
+        Ok(())
+    }
+}
+
+/// Process patterns are matched as substrings of lowercased process names, so anything
+/// with uppercase or surrounding whitespace could never match.
+fn validate_process_pattern(pattern: &str) -> Result<(), String> {
+    if pattern.trim().is_empty() {
+        return Err("Process patterns must not be empty".to_string());
+    }
+    if pattern.trim() != pattern {
+        return Err(format!("Process pattern '{pattern}' has leading or trailing whitespace"));
+    }
+    if pattern.to_lowercase() != pattern {
+        return Err(format!("Process pattern '{pattern}' must be lowercase"));
+    }
+    Ok(())
+}
+
+/// How a detected meeting is announced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MeetingAlertDelivery {
+    /// Floating always-on-top banner window (the default)
+    Banner,
+    /// System notification, with the start action offered in the main window. An
+    /// auto-start countdown still shows the banner so it can be cancelled.
+    Notification,
+    Both,
+}
+
+impl MeetingAlertDelivery {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Notification,
+            2 => Self::Both,
+            _ => Self::Banner,
+        }
+    }
+
+    fn as_u8(self) -> u8 {
+        match self {
+            Self::Banner => 0,
+            Self::Notification => 1,
+            Self::Both => 2,
+        }
+    }
+
+    fn shows_banner(self) -> bool {
+        matches!(self, Self::Banner | Self::Both)
+    }
+
+    fn shows_notification(self) -> bool {
+        matches!(self, Self::Notification | Self::Both)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MeetingAppDetected {
     pub app_name: String,
+    /// Seconds until recording starts on its own; None for a plain banner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countdown_secs: Option<u64>,
 }
 
 pub struct MeetingDetectionState {
     enabled: AtomicBool,
+    scan_interval_secs: AtomicU64,
+    delivery: AtomicU8,
+    auto_start: AtomicBool,
+    auto_start_countdown_secs: AtomicU64,
+    /// Bumped whenever a countdown is scheduled or the banner is answered; a pending
+    /// auto-start only fires if the generation it captured is still current
+    auto_start_generation: AtomicU64,
+    custom_apps: Mutex<Vec<CustomMeetingApp>>,
 }
 
 impl MeetingDetectionState {
     pub fn new() -> Self {
         Self {
             enabled: AtomicBool::new(true),
+            scan_interval_secs: AtomicU64::new(DEFAULT_SCAN_INTERVAL_SECS),
+            delivery: AtomicU8::new(MeetingAlertDelivery::Banner.as_u8()),
+            auto_start: AtomicBool::new(false),
+            auto_start_countdown_secs: AtomicU64::new(DEFAULT_AUTO_START_COUNTDOWN_SECS),
+            auto_start_generation: AtomicU64::new(0),
+            custom_apps: Mutex::new(Vec::new()),
         }
     }
 
+    pub fn custom_apps(&self) -> Vec<CustomMeetingApp> {
+        self.custom_apps
+            .lock()
+            .map(|apps| apps.clone())
+            .unwrap_or_default()
+    }
+
     pub fn is_enabled(&self) -> bool {
         self.enabled.load(Ordering::Relaxed)
     }
@@ -80,34 +253,229 @@ impl MeetingDetectionState {
     pub fn set_enabled(&self, enabled: bool) {
         self.enabled.store(enabled, Ordering::Relaxed);
     }
+
+    pub fn scan_interval_secs(&self) -> u64 {
+        self.scan_interval_secs.load(Ordering::Relaxed)
+    }
+
+    /// Store the interval clamped to `MIN_SCAN_INTERVAL_SECS..=MAX_SCAN_INTERVAL_SECS`
+    /// and return the value actually used
+    pub fn set_scan_interval_secs(&self, secs: u64) -> u64 {
+        let secs = clamp_scan_interval(secs);
+        self.scan_interval_secs.store(secs, Ordering::Relaxed);
+        secs
+    }
+
+    pub fn delivery(&self) -> MeetingAlertDelivery {
+        MeetingAlertDelivery::from_u8(self.delivery.load(Ordering::Relaxed))
+    }
+
+    pub fn set_delivery(&self, delivery: MeetingAlertDelivery) {
+        self.delivery.store(delivery.as_u8(), Ordering::Relaxed);
+    }
+
+    pub fn is_auto_start_enabled(&self) -> bool {
+        self.auto_start.load(Ordering::Relaxed)
+    }
+
+    pub fn set_auto_start_enabled(&self, enabled: bool) {
+        self.auto_start.store(enabled, Ordering::Relaxed);
+        if !enabled {
+            self.cancel_pending_auto_start();
+        }
+    }
+
+    pub fn auto_start_countdown_secs(&self) -> u64 {
+        self.auto_start_countdown_secs.load(Ordering::Relaxed)
+    }
+
+    /// Store the countdown clamped to the MIN/MAX_AUTO_START_COUNTDOWN_SECS range and
+    /// return the value actually used
+    pub fn set_auto_start_countdown_secs(&self, secs: u64) -> u64 {
+        let secs = secs.clamp(MIN_AUTO_START_COUNTDOWN_SECS, MAX_AUTO_START_COUNTDOWN_SECS);
+        self.auto_start_countdown_secs.store(secs, Ordering::Relaxed);
+        secs
+    }
+
+    /// Start a new countdown, superseding any pending one; returns its generation
+    fn schedule_auto_start(&self) -> u64 {
+        self.auto_start_generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    fn cancel_pending_auto_start(&self) {
+        self.auto_start_generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn auto_start_still_pending(&self, generation: u64) -> bool {
+        self.is_auto_start_enabled() && self.auto_start_generation.load(Ordering::SeqCst) == generation
+    }
 }
 
-fn has_process(system: &System, patterns: &[&str]) -> bool {
-    for process in system.processes().values() {
-        let name = process.name().to_string_lossy().to_lowercase();
-        for &p in patterns {
-            if name.contains(p) {
-                return true;
-            }
+fn has_process(processes: &[String], patterns: &[impl AsRef<str>]) -> bool {
+    processes
+        .iter()
+        .any(|name| patterns.iter().any(|p| name.contains(p.as_ref())))
+}
+
+/// Whether an app's process rule says a meeting is running (see `MeetingApp`)
+fn process_rule_matches(
+    processes: &[String],
+    app_processes: &[impl AsRef<str>],
+    meeting_indicators: &[impl AsRef<str>],
+) -> bool {
+    !app_processes.is_empty()
+        && has_process(processes, app_processes)
+        && (meeting_indicators.is_empty() || has_process(processes, meeting_indicators))
+}
+
+/// Compiled `window_title_pattern` of each entry in `MEETING_APPS`, by index
+static WINDOW_TITLE_PATTERNS: Lazy<Vec<Option<Regex>>> = Lazy::new(|| {
+    MEETING_APPS
+        .iter()
+        .map(|app| {
+            app.window_title_pattern
+                .map(|pattern| Regex::new(pattern).expect("Invalid window title pattern"))
+        })
+        .collect()
+});
+
+fn has_window_title(titles: &[String], pattern: &Regex) -> bool {
+    titles.iter().any(|title| pattern.is_match(title))
+}
+
+/// Lowercased titles of open windows, or `None` when they can't be read
+/// (missing permission or tool). Callers fall back to process matching only.
+#[cfg(target_os = "macos")]
+fn list_window_titles() -> Option<Vec<String>> {
+    // System Events only reports window names to apps with Accessibility access
+    if !crate::dictation::check_accessibility_permission() {
+        return None;
+    }
+    let script = r#"set out to ""
+tell application "System Events"
+    repeat with p in (every process whose background only is false)
+        try
+            repeat with w in (every window of p)
+                set out to out & (name of w as text) & linefeed
+            end repeat
+        end try
+    end repeat
+end tell
+return out"#;
+    run_title_command(Command::new("osascript").arg("-e").arg(script))
+}
+
+#[cfg(target_os = "windows")]
+fn list_window_titles() -> Option<Vec<String>> {
+    use std::os::windows::process::CommandExt;
+    // Hide the console window powershell would otherwise flash on every scan
+    const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+    // Only each process's main window; browsers report the active tab's title there
+    let mut command = Command::new("powershell");
+    command.creation_flags(CREATE_NO_WINDOW).args([
+        "-NoProfile",
+        "-Command",
+        "Get-Process | Where-Object { $_.MainWindowTitle } | ForEach-Object { $_.MainWindowTitle }",
+    ]);
+    run_title_command(&mut command)
+}
+
+#[cfg(target_os = "linux")]
+fn list_window_titles() -> Option<Vec<String>> {
+    // X11 only; `wmctrl -l` prints "<id> <desktop> <host> <title>"
+    let lines = run_title_command(Command::new("wmctrl").arg("-l"))?;
+    Some(
+        lines
+            .into_iter()
+            .filter_map(|line| {
+                line.splitn(4, char::is_whitespace)
+                    .nth(3)
+                    .map(|title| title.trim().to_string())
+            })
+            .collect(),
+    )
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn list_window_titles() -> Option<Vec<String>> {
+    None
+}
+
+#[cfg_attr(
+    not(any(target_os = "macos", target_os = "windows", target_os = "linux")),
+    allow(dead_code)
+)]
+fn run_title_command(command: &mut Command) -> Option<Vec<String>> {
+    match command.output() {
+        Ok(output) if output.status.success() => Some(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(|line| line.trim().to_lowercase())
+                .filter(|line| !line.is_empty())
+                .collect(),
+        ),
+        Ok(output) => {
+            debug!(
+                "Window title listing failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            None
+        }
+        Err(e) => {
+            debug!("Window title listing unavailable: {}", e);
+            None
         }
     }
-    false
 }
 
-fn scan_active_meetings(system: &mut System) -> HashSet<String> {
+fn scan_active_meetings(system: &mut System, custom_apps: &[CustomMeetingApp]) -> HashSet<String> {
     system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+    let processes: Vec<String> = system
+        .processes()
+        .values()
+        .map(|process| process.name().to_string_lossy().to_lowercase())
+        .collect();
+    detect_active_meetings(&processes, custom_apps, list_window_titles)
+}
 
+/// Match lowercased process names (and window titles, fetched lazily) against the
+/// built-in and custom rules.
+fn detect_active_meetings(
+    processes: &[String],
+    custom_apps: &[CustomMeetingApp],
+    list_titles: impl Fn() -> Option<Vec<String>>,
+) -> HashSet<String> {
     let mut active: HashSet<String> = HashSet::new();
-    for app in MEETING_APPS {
-        if !has_process(system, app.app_processes) {
+    // Listed at most once per scan, and only if a process rule came up empty
+    // while a process that could show the meeting window is running
+    let mut window_titles: Option<Option<Vec<String>>> = None;
+    for (app, title_pattern) in MEETING_APPS.iter().zip(WINDOW_TITLE_PATTERNS.iter()) {
+        if process_rule_matches(processes, app.app_processes, app.meeting_indicators) {
+            active.insert(app.display_name.to_string());
             continue;
         }
-        if app.meeting_indicators.is_empty() {
-            active.insert(app.display_name.to_string());
-        } else if has_process(system, app.meeting_indicators) {
+
+        let Some(title_pattern) = title_pattern else {
+            continue;
+        };
+        if !has_process(processes, app.window_title_processes) {
+            continue;
+        }
+        let titles = window_titles.get_or_insert_with(&list_titles);
+        if titles
+            .as_deref()
+            .is_some_and(|titles| has_window_title(titles, title_pattern))
+        {
             active.insert(app.display_name.to_string());
         }
     }
+
+    for app in custom_apps {
+        if process_rule_matches(processes, &app.app_processes, &app.meeting_indicators) {
+            active.insert(app.display_name.clone());
+        }
+    }
     active
 }
 
@@ -115,20 +483,25 @@ const BANNER_WINDOW_LABEL: &str = "meeting-banner";
 const BANNER_WIDTH: f64 = 420.0;
 const BANNER_HEIGHT: f64 = 64.0;
 
-/// Show the floating banner window for a detected meeting app.
-fn show_banner_window<R: Runtime>(app_handle: &AppHandle<R>, app_name: &str) {
+/// Show the floating banner window for a detected meeting app. With `countdown_secs`
+/// the banner counts down to an automatic recording start instead of offering a button.
+fn show_banner_window<R: Runtime>(app_handle: &AppHandle<R>, app_name: &str, countdown_secs: Option<u64>) {
     // If the banner window already exists, just update & show it
     if let Some(win) = app_handle.get_webview_window(BANNER_WINDOW_LABEL) {
         let _ = win.emit("meeting-app-detected", MeetingAppDetected {
             app_name: app_name.to_string(),
+            countdown_secs,
         });
         let _ = win.show();
         let _ = win.set_focus();
         return;
     }
 
-    // Build the URL with the app name as a query parameter
-    let url_str = format!("/meeting-banner?app={}", urlencoded(app_name));
+    // Build the URL with the app name (and countdown, if any) as query parameters
+    let mut url_str = format!("/meeting-banner?app={}", urlencoded(app_name));
+    if let Some(secs) = countdown_secs {
+        url_str.push_str(&format!("&countdown={secs}"));
+    }
     let url = WebviewUrl::App(url_str.into());
 
     // Get primary monitor to center the window horizontally at top
@@ -159,16 +532,199 @@ fn show_banner_window<R: Runtime>(app_handle: &AppHandle<R>, app_name: &str) {
     }
 }
 
+/// Post a system notification for a detected meeting through the notification manager
+/// (so consent, permission and Do Not Disturb apply) and tell the main window, which
+/// offers the start (and cancel) actions: desktop notifications can't carry buttons.
+fn show_meeting_notification<R: Runtime>(app_handle: &AppHandle<R>, app_name: &str, countdown_secs: Option<u64>) {
+    let app = app_handle.clone();
+    let name = app_name.to_string();
+    tauri::async_runtime::spawn(async move {
+        let Some(manager_state) = app.try_state::<NotificationManagerState<R>>() else {
+            warn!("Notification manager unavailable, skipping meeting notification");
+            return;
+        };
+        if let Err(e) = notifications::commands::show_meeting_detected_notification(
+            &app,
+            manager_state.inner(),
+            &name,
+            countdown_secs,
+        )
+        .await
+        {
+            warn!("Failed to show meeting notification: {}", e);
+        }
+    });
+
+    let _ = app_handle.emit_to(
+        "main",
+        "meeting-detected-notification",
+        MeetingAppDetected {
+            app_name: app_name.to_string(),
+            countdown_secs,
+        },
+    );
+}
+
+/// Announce a detected meeting through the banner, a notification, or both, per the
+/// user's delivery setting
+fn announce_meeting<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    state: &MeetingDetectionState,
+    app_name: &str,
+    countdown_secs: Option<u64>,
+) {
+    let delivery = state.delivery();
+    // A countdown can only be reliably cancelled from the banner, so it always shows one
+    if delivery.shows_banner() || countdown_secs.is_some() {
+        show_banner_window(app_handle, app_name, countdown_secs);
+    }
+    if delivery.shows_notification() {
+        show_meeting_notification(app_handle, app_name, countdown_secs);
+        // Without a banner the start action is only in the main window; show it
+        // without taking focus from the meeting
+        if !delivery.shows_banner() && countdown_secs.is_none() {
+            if let Some(main_win) = app_handle.get_webview_window("main") {
+                let _ = main_win.unminimize();
+                let _ = main_win.show();
+            }
+        }
+    }
+}
+
 /// Simple percent-encoding for the app name in query string.
 fn urlencoded(s: &str) -> String {
     s.replace(' ', "%20")
 }
 
+fn save_custom_apps<R: Runtime>(app: &AppHandle<R>, apps: &[CustomMeetingApp]) -> Result<(), String> {
+    let store = app
+        .store(PREFERENCES_STORE)
+        .map_err(|e| format!("Preferences store unavailable: {e}"))?;
+    let value = serde_json::to_value(apps).map_err(|e| format!("Failed to serialize custom meeting apps: {e}"))?;
+    store.set(CUSTOM_APPS_STORE_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save custom meeting apps: {e}"))
+}
+
+/// Load saved custom apps into the state. Entries that no longer validate are dropped.
+fn restore_custom_apps<R: Runtime>(app: &AppHandle<R>, state: &MeetingDetectionState) {
+    let Some(value) = app
+        .store(PREFERENCES_STORE)
+        .ok()
+        .and_then(|s| s.get(CUSTOM_APPS_STORE_KEY))
+    else {
+        return;
+    };
+    let saved: Vec<CustomMeetingApp> = match serde_json::from_value(value) {
+        Ok(saved) => saved,
+        Err(e) => {
+            warn!("Ignoring unreadable custom meeting apps: {}", e);
+            return;
+        }
+    };
+
+    let apps: Vec<CustomMeetingApp> = saved
+        .into_iter()
+        .take(MAX_CUSTOM_APPS)
+        .filter(|app| match app.validate() {
+            Ok(()) => true,
+            Err(e) => {
+                warn!("Dropping saved custom meeting app '{}': {}", app.display_name, e);
+                false
+            }
+        })
+        .collect();
+    info!("Restored {} custom meeting app(s)", apps.len());
+    if let Ok(mut custom) = state.custom_apps.lock() {
+        *custom = apps;
+    }
+}
+
+/// Close the banner and send the main window to the home page with the recording
+/// auto-start flag set, the same as clicking "Start transcribing".
+fn start_recording_from_detection<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(win) = app.get_webview_window(BANNER_WINDOW_LABEL) {
+        let _ = win.close();
+    }
+
+    // Focus main window and trigger recording start
+    if let Some(main_win) = app.get_webview_window("main") {
+        let _ = main_win.unminimize();
+        let _ = main_win.show();
+        let _ = main_win.set_focus();
+        // Set the auto-start flag and navigate to home
+        let _ = main_win.eval("sessionStorage.setItem('autoStartRecording', 'true')");
+        let _ = main_win.eval("window.location.assign('/')");
+    }
+}
+
+/// Show the countdown banner for `app_name` and start recording when it runs out,
+/// unless the banner is dismissed or accepted first, auto-start is turned off, or a
+/// recording has started in the meantime.
+fn start_auto_start_countdown<R: Runtime>(app_handle: &AppHandle<R>, state: &MeetingDetectionState, app_name: &str) {
+    let countdown = state.auto_start_countdown_secs();
+    let generation = state.schedule_auto_start();
+    announce_meeting(app_handle, state, app_name, Some(countdown));
+
+    let app_handle = app_handle.clone();
+    let app_name = app_name.to_string();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_secs(countdown)).await;
+
+        let Some(state) = app_handle.try_state::<MeetingDetectionState>() else {
+            return;
+        };
+        if !state.auto_start_still_pending(generation) {
+            debug!("Auto-start for {} was cancelled", app_name);
+            return;
+        }
+        if crate::audio::recording_commands::is_recording().await {
+            info!("Auto-start for {} skipped: already recording", app_name);
+            if let Some(win) = app_handle.get_webview_window(BANNER_WINDOW_LABEL) {
+                let _ = win.close();
+            }
+            return;
+        }
+
+        info!("Auto-starting recording for: {}", app_name);
+        start_recording_from_detection(&app_handle);
+    });
+}
+
+/// Scan on the blocking pool: refreshing processes and listing window titles (which
+/// runs osascript, powershell or wmctrl) would otherwise stall the async runtime.
+/// Hands the `System` back so its process table is reused by the next scan.
+async fn scan_active_meetings_blocking(
+    mut system: System,
+    custom_apps: Vec<CustomMeetingApp>,
+) -> (System, HashSet<String>) {
+    match tauri::async_runtime::spawn_blocking(move || {
+        let active = scan_active_meetings(&mut system, &custom_apps);
+        (system, active)
+    })
+    .await
+    {
+        Ok(scanned) => scanned,
+        Err(e) => {
+            warn!("Meeting scan failed: {}", e);
+            (System::new(), HashSet::new())
+        }
+    }
+}
+
 pub fn start_detection_loop<R: Runtime>(app_handle: AppHandle<R>) {
     tauri::async_runtime::spawn(async move {
-        let mut system = System::new();
+        let system = System::new();
 
-        let mut known_meetings = scan_active_meetings(&mut system);
+        let custom_apps = match app_handle.try_state::<MeetingDetectionState>() {
+            Some(state) => {
+                restore_custom_apps(&app_handle, &state);
+                state.custom_apps()
+            }
+            None => Vec::new(),
+        };
+        let (mut system, mut known_meetings) = scan_active_meetings_blocking(system, custom_apps).await;
         if !known_meetings.is_empty() {
             info!(
                 "Meetings already active at startup (will not notify): {:?}",
@@ -177,29 +733,47 @@ pub fn start_detection_loop<R: Runtime>(app_handle: AppHandle<R>) {
         }
 
         let mut notified: HashSet<String> = HashSet::new();
+        let mut recording = false;
 
         loop {
-            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            // Re-read every iteration so interval changes apply without a restart
+            let mut interval = app_handle
+                .try_state::<MeetingDetectionState>()
+                .map(|s| s.scan_interval_secs())
+                .unwrap_or(DEFAULT_SCAN_INTERVAL_SECS);
+            if recording {
+                interval = interval.max(RECORDING_SCAN_INTERVAL_SECS);
+            }
+            tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
 
             let state: tauri::State<'_, MeetingDetectionState> = match app_handle.try_state() {
                 Some(s) => s,
                 None => continue,
             };
+            // Checked before any process refresh so a disabled detector costs nothing
             if !state.is_enabled() {
+                recording = false;
                 continue;
             }
 
-            if crate::audio::recording_commands::is_recording().await {
+            recording = crate::audio::recording_commands::is_recording().await;
+            if recording {
                 continue;
             }
 
-            let currently_active = scan_active_meetings(&mut system);
+            let custom_apps = state.custom_apps();
+            let (scanned_system, currently_active) = scan_active_meetings_blocking(system, custom_apps).await;
+            system = scanned_system;
 
             for app in &currently_active {
                 if !known_meetings.contains(app) && !notified.contains(app) {
                     info!("Meeting started in: {}", app);
                     notified.insert(app.clone());
-                    show_banner_window(&app_handle, app);
+                    if state.is_auto_start_enabled() {
+                        start_auto_start_countdown(&app_handle, &state, app);
+                    } else {
+                        announce_meeting(&app_handle, &state, app, None);
+                    }
                 }
             }
 
@@ -211,9 +785,13 @@ pub fn start_detection_loop<R: Runtime>(app_handle: AppHandle<R>) {
     });
 }
 
-/// Close the banner popup window (called from the banner UI).
+/// Close the banner popup window (called from the banner UI). Also cancels a pending
+/// auto-start countdown.
 #[tauri::command]
 pub async fn dismiss_meeting_banner<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    if let Some(state) = app.try_state::<MeetingDetectionState>() {
+        state.cancel_pending_auto_start();
+    }
     if let Some(win) = app.get_webview_window(BANNER_WINDOW_LABEL) {
         win.close().map_err(|e| e.to_string())?;
     }
@@ -223,20 +801,11 @@ pub async fn dismiss_meeting_banner<R: Runtime>(app: AppHandle<R>) -> Result<(),
 /// Close banner and bring main window to front to start recording.
 #[tauri::command]
 pub async fn accept_meeting_banner<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
-    // Close banner
-    if let Some(win) = app.get_webview_window(BANNER_WINDOW_LABEL) {
-        let _ = win.close();
-    }
-
-    // Focus main window and trigger recording start
-    if let Some(main_win) = app.get_webview_window("main") {
-        let _ = main_win.unminimize();
-        let _ = main_win.show();
-        let _ = main_win.set_focus();
-        // Set the auto-start flag and navigate to home
-        let _ = main_win.eval("sessionStorage.setItem('autoStartRecording', 'true')");
-        let _ = main_win.eval("window.location.assign('/')");
+    // A countdown must not start a second recording after this one
+    if let Some(state) = app.try_state::<MeetingDetectionState>() {
+        state.cancel_pending_auto_start();
     }
+    start_recording_from_detection(&app);
     Ok(())
 }
 
@@ -262,3 +831,325 @@ pub async fn get_meeting_detection_enabled<R: Runtime>(
         .ok_or("MeetingDetectionState not initialized")?;
     Ok(state.is_enabled())
 }
+
+/// Choose how detected meetings are announced: banner (default), notification, or both.
+#[tauri::command]
+pub async fn set_meeting_alert_delivery<R: Runtime>(
+    app: AppHandle<R>,
+    delivery: MeetingAlertDelivery,
+) -> Result<(), String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    state.set_delivery(delivery);
+    info!("Meeting alert delivery set to: {:?}", delivery);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_meeting_alert_delivery<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<MeetingAlertDelivery, String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    Ok(state.delivery())
+}
+
+/// Opt in to starting a recording automatically when a meeting is detected, after a
+/// cancellable countdown banner. Turning it off cancels a pending countdown.
+#[tauri::command]
+pub async fn set_meeting_auto_start_enabled<R: Runtime>(
+    app: AppHandle<R>,
+    enabled: bool,
+) -> Result<(), String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    state.set_auto_start_enabled(enabled);
+    info!("Meeting auto-start recording set to: {}", enabled);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_meeting_auto_start_enabled<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<bool, String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    Ok(state.is_auto_start_enabled())
+}
+
+/// Set the auto-start countdown (clamped to 1-30s, default 5s). Returns the value
+/// actually applied.
+#[tauri::command]
+pub async fn set_meeting_auto_start_countdown<R: Runtime>(
+    app: AppHandle<R>,
+    seconds: u64,
+) -> Result<u64, String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    let applied = state.set_auto_start_countdown_secs(seconds);
+    info!("Meeting auto-start countdown set to: {}s", applied);
+    Ok(applied)
+}
+
+#[tauri::command]
+pub async fn get_meeting_auto_start_countdown<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<u64, String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    Ok(state.auto_start_countdown_secs())
+}
+
+/// Set the seconds between background scans (clamped to 2-60s, default 5s). Returns
+/// the interval actually applied; it takes effect after the current wait.
+#[tauri::command]
+pub async fn set_meeting_detection_interval<R: Runtime>(
+    app: AppHandle<R>,
+    seconds: u64,
+) -> Result<u64, String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    let applied = state.set_scan_interval_secs(seconds);
+    info!("Meeting detection interval set to: {}s", applied);
+    Ok(applied)
+}
+
+#[tauri::command]
+pub async fn get_meeting_detection_interval<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<u64, String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    Ok(state.scan_interval_secs())
+}
+
+/// Run a fresh scan and return the display names of meetings active right now, in
+/// `MEETING_APPS` order followed by custom apps. Empty while detection is disabled.
+#[tauri::command]
+pub async fn get_active_meetings<R: Runtime>(app: AppHandle<R>) -> Result<Vec<String>, String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    if !state.is_enabled() {
+        return Ok(Vec::new());
+    }
+
+    let custom_apps = state.custom_apps();
+    // Process refresh and window-title listing block, so keep them off the async runtime
+    let scan_apps = custom_apps.clone();
+    let active = tauri::async_runtime::spawn_blocking(move || scan_active_meetings(&mut System::new(), &scan_apps))
+        .await
+        .map_err(|e| format!("Meeting scan failed: {e}"))?;
+    Ok(MEETING_APPS
+        .iter()
+        .map(|app| app.display_name)
+        .chain(custom_apps.iter().map(|app| app.display_name.as_str()))
+        .filter(|name| active.contains(*name))
+        .map(str::to_string)
+        .collect())
+}
+
+#[tauri::command]
+pub async fn get_custom_meeting_apps<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<CustomMeetingApp>, String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+    Ok(state.custom_apps())
+}
+
+/// Add a custom meeting app, replacing any custom entry with the same display name.
+/// Returns the updated list.
+#[tauri::command]
+pub async fn add_custom_meeting_app<R: Runtime>(
+    app: AppHandle<R>,
+    entry: CustomMeetingApp,
+) -> Result<Vec<CustomMeetingApp>, String> {
+    entry.validate()?;
+    let entry = CustomMeetingApp {
+        display_name: entry.display_name.trim().to_string(),
+        ..entry
+    };
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+
+    let mut apps = state.custom_apps();
+    match apps
+        .iter_mut()
+        .find(|a| a.display_name.eq_ignore_ascii_case(&entry.display_name))
+    {
+        Some(existing) => *existing = entry,
+        None if apps.len() >= MAX_CUSTOM_APPS => {
+            return Err(format!("At most {MAX_CUSTOM_APPS} custom meeting apps are supported"));
+        }
+        None => apps.push(entry),
+    }
+
+    save_custom_apps(&app, &apps)?;
+    *state
+        .custom_apps
+        .lock()
+        .map_err(|e| format!("Failed to lock custom meeting apps: {e}"))? = apps.clone();
+    Ok(apps)
+}
+
+/// Remove a custom meeting app by display name. Returns the updated list.
+#[tauri::command]
+pub async fn remove_custom_meeting_app<R: Runtime>(
+    app: AppHandle<R>,
+    display_name: String,
+) -> Result<Vec<CustomMeetingApp>, String> {
+    let state = app
+        .try_state::<MeetingDetectionState>()
+        .ok_or("MeetingDetectionState not initialized")?;
+
+    let mut apps = state.custom_apps();
+    let before = apps.len();
+    apps.retain(|a| !a.display_name.eq_ignore_ascii_case(display_name.trim()));
+    if apps.len() == before {
+        return Err(format!("No custom meeting app named '{}'", display_name.trim()));
+    }
+
+    save_custom_apps(&app, &apps)?;
+    *state
+        .custom_apps
+        .lock()
+        .map_err(|e| format!("Failed to lock custom meeting apps: {e}"))? = apps.clone();
+    info!("Removed custom meeting app: {}", display_name.trim());
+    Ok(apps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn processes(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn custom(name: &str, app_processes: &[&str], meeting_indicators: &[&str]) -> CustomMeetingApp {
+        CustomMeetingApp {
+            display_name: name.to_string(),
+            app_processes: app_processes.iter().map(|p| p.to_string()).collect(),
+            meeting_indicators: meeting_indicators.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn custom_app_is_detected_alongside_built_ins() {
+        let apps = [custom("Jitsi", &["jitsi meet"], &["jitsi-call"])];
+        let running = processes(&["launchd", "zoom.us", "cpthost", "jitsi meet", "jitsi-call helper"]);
+
+        let active = detect_active_meetings(&running, &apps, || None);
+        assert!(active.contains("Jitsi"));
+        assert!(active.contains("Zoom"));
+
+        // App open but no call in progress
+        let idle = processes(&["jitsi meet"]);
+        assert!(detect_active_meetings(&idle, &apps, || None).is_empty());
+    }
+
+    #[test]
+    fn browser_meetings_are_matched_by_window_title() {
+        let browser = processes(&["firefox"]);
+        let titles = |titles: &[&str]| {
+            let titles: Vec<String> = titles.iter().map(|t| t.to_string()).collect();
+            move || Some(titles.clone())
+        };
+
+        let meet = detect_active_meetings(&browser, &[], titles(&["meet - abc-defg-hij - mozilla firefox"]));
+        assert!(meet.contains("Google Meet"));
+        let teams = detect_active_meetings(&browser, &[], titles(&["meeting with alex | microsoft teams"]));
+        assert!(teams.contains("Microsoft Teams"));
+
+        // Landing pages and non-meeting Teams pages
+        let idle = titles(&["google meet", "meet - new meeting", "chat | microsoft teams", "calendar | microsoft teams"]);
+        assert!(detect_active_meetings(&browser, &[], idle).is_empty());
+    }
+
+    #[test]
+    fn window_titles_are_only_listed_while_a_browser_runs() {
+        let listed = std::cell::Cell::new(0);
+        let list = || {
+            listed.set(listed.get() + 1);
+            Some(vec!["meet - abc-defg-hij".to_string()])
+        };
+
+        assert!(detect_active_meetings(&processes(&["launchd", "zoom.us"]), &[], list).is_empty());
+        assert_eq!(listed.get(), 0);
+
+        // Listed once, even though both Teams and Meet have a title rule
+        assert!(detect_active_meetings(&processes(&["google chrome"]), &[], list).contains("Google Meet"));
+        assert_eq!(listed.get(), 1);
+    }
+
+    #[test]
+    fn custom_app_validation_rejects_unmatchable_patterns() {
+        assert!(custom("Jitsi", &["jitsi"], &[]).validate().is_ok());
+        assert!(custom("  ", &["jitsi"], &[]).validate().is_err());
+        assert!(custom("Jitsi", &[], &[]).validate().is_err());
+        assert!(custom("Jitsi", &["Jitsi"], &[]).validate().is_err());
+        assert!(custom("Jitsi", &["jitsi"], &[""]).validate().is_err());
+        assert!(custom("Jitsi", &[" jitsi"], &[]).validate().is_err());
+        assert!(custom("zoom", &["zoom"], &[]).validate().is_err());
+    }
+
+    #[test]
+    fn scan_interval_is_clamped() {
+        let state = MeetingDetectionState::new();
+        assert_eq!(state.scan_interval_secs(), DEFAULT_SCAN_INTERVAL_SECS);
+        assert_eq!(state.set_scan_interval_secs(0), MIN_SCAN_INTERVAL_SECS);
+        assert_eq!(state.set_scan_interval_secs(3600), MAX_SCAN_INTERVAL_SECS);
+        assert_eq!(state.set_scan_interval_secs(10), 10);
+        assert_eq!(state.scan_interval_secs(), 10);
+    }
+
+    #[test]
+    fn alert_delivery_defaults_to_banner_and_round_trips() {
+        let state = MeetingDetectionState::new();
+        assert_eq!(state.delivery(), MeetingAlertDelivery::Banner);
+        for delivery in [
+            MeetingAlertDelivery::Banner,
+            MeetingAlertDelivery::Notification,
+            MeetingAlertDelivery::Both,
+        ] {
+            state.set_delivery(delivery);
+            assert_eq!(state.delivery(), delivery);
+        }
+        assert!(MeetingAlertDelivery::Both.shows_banner() && MeetingAlertDelivery::Both.shows_notification());
+        assert!(!MeetingAlertDelivery::Notification.shows_banner());
+        assert!(!MeetingAlertDelivery::Banner.shows_notification());
+    }
+
+    #[test]
+    fn auto_start_countdown_is_superseded_and_cancelled() {
+        let state = MeetingDetectionState::new();
+        assert!(!state.is_auto_start_enabled());
+        assert_eq!(state.set_auto_start_countdown_secs(0), MIN_AUTO_START_COUNTDOWN_SECS);
+        assert_eq!(state.set_auto_start_countdown_secs(120), MAX_AUTO_START_COUNTDOWN_SECS);
+
+        state.set_auto_start_enabled(true);
+        let first = state.schedule_auto_start();
+        assert!(state.auto_start_still_pending(first));
+        let second = state.schedule_auto_start();
+        assert!(!state.auto_start_still_pending(first));
+        assert!(state.auto_start_still_pending(second));
+
+        state.cancel_pending_auto_start();
+        assert!(!state.auto_start_still_pending(second));
+
+        let third = state.schedule_auto_start();
+        state.set_auto_start_enabled(false);
+        assert!(!state.auto_start_still_pending(third));
+    }
+}
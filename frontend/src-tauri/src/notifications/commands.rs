@@ -359,6 +359,29 @@ pub async fn show_recording_started_notification<R: Runtime>(
     }
 }
 
+/// Show a meeting detected notification (internal use). Always goes through the
+/// manager so consent, permission and Do Not Disturb apply; there's no direct fallback.
+pub async fn show_meeting_detected_notification<R: Runtime>(
+    app_handle: &tauri::AppHandle<R>,
+    manager_state: &NotificationManagerState<R>,
+    app_name: &str,
+    countdown_secs: Option<u64>,
+) -> Result<()> {
+    let notification = Notification::meeting_detected(app_name, countdown_secs);
+    if let Some(manager) = manager_state.read().await.as_ref() {
+        return manager.show_notification(notification).await;
+    }
+
+    log_info!("Notification manager not initialized for meeting detection, initializing now...");
+    let manager = initialize_notification_manager(app_handle.clone()).await?;
+    let result = manager.show_notification(notification).await;
+    let mut state_lock = manager_state.write().await;
+    if state_lock.is_none() {
+        *state_lock = Some(manager);
+    }
+    result
+}
+
 /// Show recording stopped notification (internal use)
 pub async fn show_recording_stopped_notification<R: Runtime>(
     app_handle: &tauri::AppHandle<R>,
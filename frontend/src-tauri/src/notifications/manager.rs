@@ -299,6 +299,7 @@ impl<R: Runtime> NotificationManager<R> {
             NotificationType::RecordingResumed => settings.notification_preferences.show_recording_resumed,
             NotificationType::TranscriptionComplete => settings.notification_preferences.show_transcription_complete,
             NotificationType::MeetingReminder(_) => settings.notification_preferences.show_meeting_reminders,
+            NotificationType::MeetingDetected => true, // Gated by the meeting detection settings
             NotificationType::SystemError(_) => settings.notification_preferences.show_system_errors,
             NotificationType::Test => true, // Always show test notifications
         }
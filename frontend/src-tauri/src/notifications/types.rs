@@ -21,6 +21,7 @@ pub enum NotificationType {
     RecordingResumed,
     TranscriptionComplete,
     MeetingReminder(u64), // Duration in minutes
+    MeetingDetected,
     SystemError(String),
     Test, // For testing notifications
 }
@@ -187,6 +188,20 @@ impl Notification {
         .with_timeout(NotificationTimeout::Never)
     }
 
+    pub fn meeting_detected(app_name: &str, countdown_secs: Option<u64>) -> Self {
+        let body = match countdown_secs {
+            Some(secs) => format!(
+                "Meeting detected in {} — recording starts in {}s. Cancel it from the banner.",
+                app_name, secs
+            ),
+            None => format!("Meeting detected in {} — start recording from Meetily.", app_name),
+        };
+
+        Notification::new("Meeting Detected", body, NotificationType::MeetingDetected)
+            .with_priority(NotificationPriority::High)
+            .with_timeout(NotificationTimeout::Seconds(10))
+    }
+
     pub fn test_notification() -> Self {
         Notification::new(
             "Meetily",
@@ -1,6 +1,9 @@
-use crate::qwen_asr_engine::{ModelInfo, ModelStatus, QwenAsrEngine, DownloadProgress};
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+use crate::qwen_asr_engine::{gpu_device_count, preferred_model, BuildInfo, ModelInfo, MelBackend, QwenAsrConfig, QwenAsrEngine, DownloadProgress, DownloadRecord, WindowProgress, MAX_DOWNLOAD_CONNECTIONS, MAX_DOWNLOAD_RETRIES, MAX_TRANSCRIPT_CACHE_ENTRIES};
 use std::path::PathBuf;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
 use std::sync::Arc;
 use tauri::{command, Emitter, AppHandle, Manager, Runtime};
 
@@ -10,6 +13,20 @@ pub static QWEN_ASR_ENGINE: Mutex<Option<Arc<QwenAsrEngine>>> = Mutex::new(None)
 // Global models directory path (set during app initialization)
 static MODELS_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
 
+// File transcription state (one file at a time)
+static FILE_TRANSCRIPTION_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+static FILE_TRANSCRIPTION_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// Lock a global, recovering the value if a panicking command poisoned the mutex.
+/// The guarded values are plain slots, so they are never left half-updated.
+pub(crate) fn lock_recovering<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| {
+        log::warn!("Recovering poisoned Qwen ASR global lock");
+        mutex.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
 /// Initialize the models directory path using app_data_dir.
 /// Should be called during app setup before qwen_asr_init.
 pub fn set_models_directory<R: Runtime>(app: &AppHandle<R>) {
@@ -27,36 +44,46 @@ pub fn set_models_directory<R: Runtime>(app: &AppHandle<R>) {
 
     log::info!("Qwen ASR models directory set to: {}", models_dir.display());
 
-    let mut guard = MODELS_DIR.lock().unwrap();
+    let mut guard = lock_recovering(&MODELS_DIR);
     *guard = Some(models_dir);
 }
 
 fn get_models_directory() -> Option<PathBuf> {
-    MODELS_DIR.lock().unwrap().clone()
+    lock_recovering(&MODELS_DIR).clone()
 }
 
 #[command]
 pub async fn qwen_asr_init() -> Result<(), String> {
     log::info!("qwen_asr_init called");
-    let mut guard = QWEN_ASR_ENGINE.lock().unwrap();
-    if guard.is_some() {
-        log::info!("qwen_asr_init: engine already initialized");
-        return Ok(());
-    }
+    let engine = {
+        let mut guard = lock_recovering(&QWEN_ASR_ENGINE);
+        if guard.is_some() {
+            log::info!("qwen_asr_init: engine already initialized");
+            return Ok(());
+        }
 
-    let models_dir = get_models_directory();
-    log::info!("qwen_asr_init: models_dir={:?}", models_dir);
-    let engine = QwenAsrEngine::new_with_models_dir(models_dir)
-        .map_err(|e| format!("Failed to initialize Qwen ASR engine: {}", e))?;
-    *guard = Some(Arc::new(engine));
+        let models_dir = get_models_directory();
+        log::info!("qwen_asr_init: models_dir={:?}", models_dir);
+        log::info!("qwen_asr_init: library build {:?}", BuildInfo::current());
+        let engine = QwenAsrEngine::new_with_models_dir(models_dir)
+            .map_err(|e| format!("Failed to initialize Qwen ASR engine: {}", e))?;
+        let engine = Arc::new(engine);
+        *guard = Some(engine.clone());
+        engine
+    };
     log::info!("qwen_asr_init: engine initialized successfully");
+
+    // Settle model files left behind by an interrupted download or crash
+    if let Err(e) = engine.discover_models().await {
+        log::warn!("qwen_asr_init: failed to discover models: {}", e);
+    }
     Ok(())
 }
 
 #[command]
 pub async fn qwen_asr_get_available_models() -> Result<Vec<ModelInfo>, String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -76,7 +103,7 @@ pub async fn qwen_asr_load_model<R: Runtime>(
     model_name: String,
 ) -> Result<(), String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -110,10 +137,82 @@ pub async fn qwen_asr_load_model<R: Runtime>(
     }
 }
 
+/// Preload a model in the background so a later load/switch to it is instant.
+/// Returns immediately; progress is reported through `qwen-asr-model-preload-*` events.
+#[command]
+pub async fn qwen_asr_preload_model<R: Runtime>(
+    app_handle: AppHandle<R>,
+    model_name: String,
+) -> Result<(), String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    let Some(engine) = engine else {
+        return Err("Qwen ASR engine not initialized".to_string());
+    };
+
+    tauri::async_runtime::spawn(async move {
+        let _ = app_handle.emit(
+            "qwen-asr-model-preload-started",
+            serde_json::json!({ "modelName": model_name }),
+        );
+
+        match engine.preload_model(&model_name).await {
+            Ok(loaded) => {
+                let _ = app_handle.emit(
+                    "qwen-asr-model-preload-completed",
+                    serde_json::json!({ "modelName": model_name, "alreadyLoaded": !loaded }),
+                );
+            }
+            Err(e) => {
+                log::warn!("Failed to preload Qwen ASR model {}: {}", model_name, e);
+                let _ = app_handle.emit(
+                    "qwen-asr-model-preload-failed",
+                    serde_json::json!({ "modelName": model_name, "error": e.to_string() }),
+                );
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Unload the current model to free its memory. Refused while a
+/// transcription is running.
+#[command]
+pub async fn qwen_asr_unload_model<R: Runtime>(app_handle: AppHandle<R>) -> Result<bool, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        if engine.is_transcribing() {
+            return Err("Cannot unload Qwen ASR model while a transcription is in progress".to_string());
+        }
+
+        let model_name = engine.get_current_model().await;
+        let unloaded = engine.unload_model().await;
+
+        if unloaded {
+            let _ = app_handle.emit(
+                "qwen-asr-model-unloaded",
+                serde_json::json!({ "modelName": model_name }),
+            );
+        }
+
+        Ok(unloaded)
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
 #[command]
 pub async fn qwen_asr_get_current_model() -> Result<Option<String>, String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -127,7 +226,7 @@ pub async fn qwen_asr_get_current_model() -> Result<Option<String>, String> {
 #[command]
 pub async fn qwen_asr_is_model_loaded() -> Result<bool, String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -141,21 +240,15 @@ pub async fn qwen_asr_is_model_loaded() -> Result<bool, String> {
 #[command]
 pub async fn qwen_asr_has_available_models() -> Result<bool, String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
     if let Some(engine) = engine {
-        let models = engine
-            .discover_models()
+        let available = engine
+            .has_available_models()
             .await
             .map_err(|e| format!("Failed to discover models: {}", e))?;
-
-        for model in &models {
-            log::info!("qwen_asr_has_available_models: model={}, status={:?}", model.name, model.status);
-        }
-
-        let available = models.iter().any(|m| matches!(m.status, ModelStatus::Available));
         log::info!("qwen_asr_has_available_models: returning {}", available);
         Ok(available)
     } else {
@@ -164,10 +257,20 @@ pub async fn qwen_asr_has_available_models() -> Result<bool, String> {
     }
 }
 
+/// Model to load when none is configured: the one the idle timer unloaded, else
+/// Q8_0 for speed. `available` must not be empty.
+fn fallback_model<'a>(engine: &QwenAsrEngine, available: &'a [ModelInfo]) -> &'a ModelInfo {
+    engine
+        .idle_unloaded_model()
+        .and_then(|name| available.iter().find(|m| m.name == name))
+        .or_else(|| preferred_model(available))
+        .expect("available models is not empty")
+}
+
 #[command]
 pub async fn qwen_asr_validate_model_ready() -> Result<String, String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -178,25 +281,16 @@ pub async fn qwen_asr_validate_model_ready() -> Result<String, String> {
             }
         }
 
-        let models = engine
-            .discover_models()
+        let available = engine
+            .available_models()
             .await
             .map_err(|e| format!("Failed to discover models: {}", e))?;
 
-        let available: Vec<_> = models
-            .iter()
-            .filter(|m| matches!(m.status, ModelStatus::Available))
-            .collect();
-
         if available.is_empty() {
             return Err("No Qwen ASR models available. Please download a model.".to_string());
         }
 
-        // Prefer Q8_0 for speed
-        let to_load = available.iter()
-            .find(|m| m.quantization == crate::qwen_asr_engine::QuantizationType::Q8_0)
-            .or_else(|| available.first())
-            .unwrap();
+        let to_load = fallback_model(&engine, &available);
 
         engine
             .load_model(&to_load.name)
@@ -214,7 +308,7 @@ pub async fn qwen_asr_validate_model_ready_with_config<R: tauri::Runtime>(
     app: &tauri::AppHandle<R>,
 ) -> Result<String, String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -246,16 +340,11 @@ pub async fn qwen_asr_validate_model_ready_with_config<R: tauri::Runtime>(
             _ => None,
         };
 
-        let models = engine
-            .discover_models()
+        let available = engine
+            .available_models()
             .await
             .map_err(|e| format!("Failed to discover models: {}", e))?;
 
-        let available: Vec<_> = models
-            .iter()
-            .filter(|m| matches!(m.status, ModelStatus::Available))
-            .collect();
-
         if available.is_empty() {
             return Err("No Qwen ASR models available. Please download a model.".to_string());
         }
@@ -265,20 +354,10 @@ pub async fn qwen_asr_validate_model_ready_with_config<R: tauri::Runtime>(
                 configured
             } else {
                 log::warn!("Configured model '{}' not available, using fallback", configured);
-                available.iter()
-                    .find(|m| m.quantization == crate::qwen_asr_engine::QuantizationType::Q8_0)
-                    .or_else(|| available.first())
-                    .unwrap()
-                    .name
-                    .clone()
+                fallback_model(&engine, &available).name.clone()
             }
         } else {
-            available.iter()
-                .find(|m| m.quantization == crate::qwen_asr_engine::QuantizationType::Q8_0)
-                .or_else(|| available.first())
-                .unwrap()
-                .name
-                .clone()
+            fallback_model(&engine, &available).name.clone()
         };
 
         engine
@@ -292,14 +371,24 @@ pub async fn qwen_asr_validate_model_ready_with_config<R: tauri::Runtime>(
     }
 }
 
+/// Reload a model (e.g. one the idle timer unloaded) before a transcription needs it
+async fn ensure_model_loaded(engine: &QwenAsrEngine) -> Result<(), String> {
+    if !engine.is_model_loaded().await {
+        let model = qwen_asr_validate_model_ready().await?;
+        log::info!("Reloaded Qwen ASR model '{}' for transcription", model);
+    }
+    Ok(())
+}
+
 #[command]
 pub async fn qwen_asr_transcribe_audio(audio_data: Vec<f32>) -> Result<String, String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
     if let Some(engine) = engine {
+        ensure_model_loaded(&engine).await?;
         engine
             .transcribe_audio(audio_data)
             .await
@@ -309,10 +398,527 @@ pub async fn qwen_asr_transcribe_audio(audio_data: Vec<f32>) -> Result<String, S
     }
 }
 
+/// Same as `qwen_asr_transcribe_audio`, but decoding in a fixed language
+/// (used by dictation's own language setting)
+pub async fn qwen_asr_transcribe_audio_with_language(
+    audio_data: Vec<f32>,
+    language: Option<String>,
+) -> Result<String, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        ensure_model_loaded(&engine).await?;
+        engine
+            .transcribe_audio_with_language(audio_data, language.as_deref())
+            .await
+            .map_err(|e| format!("Qwen ASR transcription failed: {}", e))
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+/// Transcribe an audio file of any length, emitting `qwen-asr-file-progress`
+/// after each window so the UI can show a progress bar.
+#[command]
+pub async fn qwen_asr_transcribe_file_with_progress<R: Runtime>(
+    app_handle: AppHandle<R>,
+    file_path: String,
+) -> Result<String, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    let engine = engine.ok_or_else(|| "Qwen ASR engine not initialized".to_string())?;
+    ensure_model_loaded(&engine).await?;
+
+    if FILE_TRANSCRIPTION_IN_PROGRESS.swap(true, Ordering::SeqCst) {
+        return Err("A file transcription is already in progress".to_string());
+    }
+    FILE_TRANSCRIPTION_CANCELLED.store(false, Ordering::SeqCst);
+
+    let path = PathBuf::from(&file_path);
+    let decoded = tokio::task::spawn_blocking(move || {
+        crate::audio::decode_audio_file(&path).map(|audio| audio.to_whisper_format())
+    })
+    .await
+    .map_err(|e| format!("Audio decoding task failed: {}", e))
+    .and_then(|r| r.map_err(|e| format!("Failed to decode audio file: {}", e)));
+
+    let samples = match decoded {
+        Ok(samples) => samples,
+        Err(e) => {
+            FILE_TRANSCRIPTION_IN_PROGRESS.store(false, Ordering::SeqCst);
+            return Err(e);
+        }
+    };
+
+    let app_clone = app_handle.clone();
+    let path_clone = file_path.clone();
+    let result = engine
+        .transcribe_audio_with_progress(samples, &FILE_TRANSCRIPTION_CANCELLED, move |progress: WindowProgress| {
+            let _ = app_clone.emit(
+                "qwen-asr-file-progress",
+                serde_json::json!({
+                    "filePath": path_clone,
+                    "window": progress.window,
+                    "totalWindows": progress.total_windows,
+                    "positionSeconds": progress.position_seconds,
+                    "totalSeconds": progress.total_seconds,
+                    "percent": (progress.window * 100 / progress.total_windows.max(1)) as u8,
+                    "text": progress.text,
+                }),
+            );
+        })
+        .await
+        .map_err(|e| format!("Qwen ASR file transcription failed: {}", e));
+
+    FILE_TRANSCRIPTION_IN_PROGRESS.store(false, Ordering::SeqCst);
+    result
+}
+
+/// Cancel the running file transcription (takes effect at the next window).
+#[command]
+pub async fn qwen_asr_cancel_file_transcription() -> Result<(), String> {
+    if !FILE_TRANSCRIPTION_IN_PROGRESS.load(Ordering::SeqCst) {
+        return Err("No file transcription in progress".to_string());
+    }
+    FILE_TRANSCRIPTION_CANCELLED.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Switch the mel spectrogram FFT ("auto", "accelerate" or "portable") without
+/// recompiling. The portable path is noticeably slower; use it only to check
+/// whether a transcription discrepancy comes from the FFT.
+#[command]
+pub async fn qwen_asr_set_mel_backend(backend: String) -> Result<String, String> {
+    let requested = MelBackend::parse(&backend)
+        .ok_or_else(|| format!("Unknown mel backend '{}'. Expected auto, accelerate or portable", backend))?;
+    requested.apply()?;
+
+    let active = MelBackend::current();
+    log::info!("Qwen ASR mel backend set to {} (active: {})", requested.as_str(), active.as_str());
+    Ok(active.as_str().to_string())
+}
+
+/// Get the active mel backend and the backends compiled into this build.
+#[command]
+pub async fn qwen_asr_get_mel_backend() -> Result<serde_json::Value, String> {
+    Ok(serde_json::json!({
+        "active": MelBackend::current().as_str(),
+        "available": MelBackend::available().iter().map(|b| b.as_str()).collect::<Vec<_>>(),
+    }))
+}
+
+/// Features the bundled qwen3-asr library was compiled with (vendor vs stub,
+/// GPU backends, mel FFT), for telling capability gaps apart from bugs.
+#[command]
+pub async fn qwen_asr_get_build_info() -> Result<serde_json::Value, String> {
+    let info = BuildInfo::current();
+    Ok(serde_json::json!({
+        "hasVendor": info.has_vendor,
+        "metal": info.metal,
+        "cuda": info.cuda,
+        "accelerate": info.accelerate,
+        "fftBackend": info.fft_backend.as_str(),
+        "interruptibleDecode": info.interruptible_decode,
+    }))
+}
+
+/// Set the decoding temperature (0.0 = greedy, deterministic).
+#[command]
+pub async fn qwen_asr_set_temperature(temperature: f32) -> Result<(), String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine
+            .set_temperature(temperature)
+            .await
+            .map_err(|e| format!("Failed to set temperature: {}", e))
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+/// Set the GPU device, thread count and temperature, and save them with the
+/// transcript settings so they survive a restart.
+#[command]
+pub async fn qwen_asr_set_config(
+    state: tauri::State<'_, AppState>,
+    config: QwenAsrConfig,
+) -> Result<(), String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    let Some(engine) = engine else {
+        return Err("Qwen ASR engine not initialized".to_string());
+    };
+    engine
+        .set_config(config)
+        .await
+        .map_err(|e| format!("Failed to set Qwen ASR config: {}", e))?;
+    SettingsRepository::save_qwen_asr_config(state.db_manager.pool(), &config)
+        .await
+        .map_err(|e| format!("Failed to save Qwen ASR config: {}", e))
+}
+
+#[command]
+pub async fn qwen_asr_get_config() -> Result<QwenAsrConfig, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    match engine {
+        Some(engine) => Ok(engine.get_config().await),
+        None => Err("Qwen ASR engine not initialized".to_string()),
+    }
+}
+
+/// Number of GPUs the Qwen ASR library can decode on (0 for CPU-only builds)
+#[command]
+pub async fn qwen_asr_get_gpu_device_count() -> Result<i32, String> {
+    Ok(gpu_device_count())
+}
+
+/// Apply the Qwen ASR config saved in the transcript settings. Called once on
+/// startup after the database is ready; a missing or unusable config leaves the defaults.
+pub async fn restore_config<R: Runtime>(app: &AppHandle<R>) {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+    let (Some(engine), Some(state)) = (engine, app.try_state::<AppState>()) else {
+        return;
+    };
+
+    match SettingsRepository::get_qwen_asr_config(state.db_manager.pool()).await {
+        Ok(Some(config)) => {
+            if let Err(e) = engine.set_config(config).await {
+                log::warn!("Ignoring saved Qwen ASR config: {}", e);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => log::warn!("Failed to load saved Qwen ASR config: {}", e),
+    }
+}
+
+/// Set how many consecutive repeats of a phrase abort streaming decoding
+/// (below 2 disables the check).
+#[command]
+pub async fn qwen_asr_set_repetition_threshold(threshold: usize) -> Result<(), String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine.set_repetition_threshold(threshold).await;
+        Ok(())
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+/// Longest configurable idle-unload delay (one day)
+const MAX_IDLE_UNLOAD_MINUTES: u64 = 24 * 60;
+
+/// How often the idle-unload watcher checks the engine
+const IDLE_CHECK_INTERVAL_SECS: u64 = 5;
+
+/// Background loop that unloads the Qwen ASR model once it has been idle for
+/// the configured time. Recording counts as activity, so silent
+/// stretches of a meeting never unload the model mid-session.
+pub fn start_idle_unload_watcher<R: Runtime>(app_handle: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(IDLE_CHECK_INTERVAL_SECS));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            interval.tick().await;
+
+            let engine = {
+                let guard = lock_recovering(&QWEN_ASR_ENGINE);
+                guard.as_ref().cloned()
+            };
+            let Some(engine) = engine else {
+                continue;
+            };
+
+            if crate::audio::recording_commands::is_recording().await {
+                engine.touch_activity();
+                continue;
+            }
+
+            if let Some(model_name) = engine.unload_if_idle().await {
+                let _ = app_handle.emit(
+                    "qwen-asr-model-unloaded",
+                    serde_json::json!({ "modelName": model_name, "reason": "idle" }),
+                );
+            }
+        }
+    });
+}
+
+/// Unload the model after this many minutes without a transcription (0 = never).
+#[command]
+pub async fn qwen_asr_set_idle_unload_minutes(minutes: u64) -> Result<(), String> {
+    if minutes > MAX_IDLE_UNLOAD_MINUTES {
+        return Err(format!(
+            "Idle unload must be at most {} minutes, got {}",
+            MAX_IDLE_UNLOAD_MINUTES, minutes
+        ));
+    }
+
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine.set_idle_unload_minutes(minutes);
+        Ok(())
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+/// Unload the model after this many seconds without a transcription (None = never).
+#[command]
+pub async fn qwen_asr_set_idle_unload_secs(secs: Option<u64>) -> Result<(), String> {
+    if secs.is_some_and(|secs| secs > MAX_IDLE_UNLOAD_MINUTES * 60) {
+        return Err(format!(
+            "Idle unload must be at most {} seconds, got {:?}",
+            MAX_IDLE_UNLOAD_MINUTES * 60,
+            secs
+        ));
+    }
+
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine.set_idle_unload_secs(secs);
+        Ok(())
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+#[command]
+pub async fn qwen_asr_get_idle_unload_secs() -> Result<Option<u64>, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    engine
+        .map(|engine| engine.get_idle_unload_secs())
+        .ok_or_else(|| "Qwen ASR engine not initialized".to_string())
+}
+
+#[command]
+pub async fn qwen_asr_get_idle_unload_minutes() -> Result<u64, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    engine
+        .map(|engine| engine.get_idle_unload_minutes())
+        .ok_or_else(|| "Qwen ASR engine not initialized".to_string())
+}
+
+/// Pin the loaded model so the idle timer never unloads it.
+#[command]
+pub async fn qwen_asr_set_model_pinned(pinned: bool) -> Result<(), String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine.set_model_pinned(pinned);
+        Ok(())
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+#[command]
+pub async fn qwen_asr_is_model_pinned() -> Result<bool, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    engine
+        .map(|engine| engine.is_model_pinned())
+        .ok_or_else(|| "Qwen ASR engine not initialized".to_string())
+}
+
+/// Feed the tail of the previous chunk's transcript to the decoder during
+/// live recording. Helps with names and split sentences, costs latency and
+/// can produce hallucinated continuations over silence.
+#[command]
+pub async fn qwen_asr_set_decoder_context(enabled: bool) -> Result<(), String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine.set_decoder_context_enabled(enabled);
+        Ok(())
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+#[command]
+pub async fn qwen_asr_get_decoder_context() -> Result<bool, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    engine
+        .map(|engine| engine.is_decoder_context_enabled())
+        .ok_or_else(|| "Qwen ASR engine not initialized".to_string())
+}
+
+/// Number of batch transcripts to keep for identical audio (0 disables the
+/// cache). Speeds up retries and re-transcribing unchanged ranges.
+#[command]
+pub async fn qwen_asr_set_transcript_cache_size(entries: usize) -> Result<(), String> {
+    if entries > MAX_TRANSCRIPT_CACHE_ENTRIES {
+        return Err(format!(
+            "Transcript cache size must be at most {} entries",
+            MAX_TRANSCRIPT_CACHE_ENTRIES
+        ));
+    }
+
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine.set_transcript_cache_entries(entries);
+        Ok(())
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+#[command]
+pub async fn qwen_asr_get_transcript_cache_size() -> Result<usize, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    engine
+        .map(|engine| engine.get_transcript_cache_entries())
+        .ok_or_else(|| "Qwen ASR engine not initialized".to_string())
+}
+
+/// How many times a stalled or dropped model download is resumed before failing
+#[command]
+pub async fn qwen_asr_set_download_retries(retries: u32) -> Result<(), String> {
+    if retries > MAX_DOWNLOAD_RETRIES {
+        return Err(format!("Download retries must be at most {}", MAX_DOWNLOAD_RETRIES));
+    }
+
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine.set_download_retries(retries);
+        Ok(())
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+#[command]
+pub async fn qwen_asr_get_download_retries() -> Result<u32, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    engine
+        .map(|engine| engine.get_download_retries())
+        .ok_or_else(|| "Qwen ASR engine not initialized".to_string())
+}
+
+/// Parallel Range connections for new model downloads (1 = a single stream)
+#[command]
+pub async fn qwen_asr_set_download_connections(connections: u8) -> Result<(), String> {
+    if !(1..=MAX_DOWNLOAD_CONNECTIONS).contains(&connections) {
+        return Err(format!(
+            "Download connections must be between 1 and {}",
+            MAX_DOWNLOAD_CONNECTIONS
+        ));
+    }
+
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine.set_download_connections(connections);
+        Ok(())
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+#[command]
+pub async fn qwen_asr_get_download_connections() -> Result<u8, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    engine
+        .map(|engine| engine.get_download_connections())
+        .ok_or_else(|| "Qwen ASR engine not initialized".to_string())
+}
+
+/// Past model download attempts (outcome, bytes reached, retries), oldest first
+#[command]
+pub async fn qwen_asr_get_download_history() -> Result<Vec<DownloadRecord>, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        Ok(engine.get_download_history().await)
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
 #[command]
 pub async fn qwen_asr_get_models_directory() -> Result<String, String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -330,7 +936,7 @@ pub async fn qwen_asr_download_model<R: Runtime>(
     model_name: String,
 ) -> Result<(), String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -355,7 +961,14 @@ pub async fn qwen_asr_download_model<R: Runtime>(
                     "downloaded_mb": progress.downloaded_mb,
                     "total_mb": progress.total_mb,
                     "speed_mbps": progress.speed_mbps,
-                    "status": if progress.percent == 100 { "completed" } else { "downloading" }
+                    "retry_attempt": progress.retry_attempt,
+                    "max_retries": progress.max_retries,
+                    "finalize_progress": progress.finalize_percent,
+                    "status": if progress.retry_attempt > 0 {
+                        "retrying"
+                    } else {
+                        progress.phase.as_str()
+                    }
                 }),
             );
         });
@@ -400,7 +1013,7 @@ pub async fn qwen_asr_cancel_download<R: Runtime>(
     model_name: String,
 ) -> Result<(), String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -429,7 +1042,7 @@ pub async fn qwen_asr_cancel_download<R: Runtime>(
 #[command]
 pub async fn qwen_asr_delete_model(model_name: String) -> Result<String, String> {
     let engine = {
-        let guard = QWEN_ASR_ENGINE.lock().unwrap();
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
         guard.as_ref().cloned()
     };
 
@@ -443,6 +1056,58 @@ pub async fn qwen_asr_delete_model(model_name: String) -> Result<String, String>
     }
 }
 
+/// Add a local Qwen3-ASR GGUF file as a model, announced like a finished download
+#[command]
+pub async fn qwen_asr_import_model<R: Runtime>(
+    app_handle: AppHandle<R>,
+    path: String,
+    name: String,
+) -> Result<ModelInfo, String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        // Populate the catalog first so name clashes with catalog models are caught
+        if let Err(e) = engine.discover_models().await {
+            log::warn!("Failed to discover models before import: {}", e);
+        }
+
+        let model = engine
+            .import_local_model(std::path::Path::new(&path), &name)
+            .await
+            .map_err(|e| format!("Failed to import model: {}", e))?;
+
+        let _ = app_handle.emit(
+            "qwen-asr-model-download-complete",
+            serde_json::json!({ "modelName": model.name }),
+        );
+        crate::tray::update_tray_menu(&app_handle);
+        Ok(model)
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
+/// Re-check a downloaded model against its published checksum; a corrupted file is deleted
+#[command]
+pub async fn qwen_asr_verify_model(model_name: String) -> Result<(), String> {
+    let engine = {
+        let guard = lock_recovering(&QWEN_ASR_ENGINE);
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine
+            .verify_model(&model_name)
+            .await
+            .map_err(|e| format!("Failed to verify model: {}", e))
+    } else {
+        Err("Qwen ASR engine not initialized".to_string())
+    }
+}
+
 #[command]
 pub async fn qwen_asr_open_models_folder() -> Result<(), String> {
     let models_dir = get_models_directory()
@@ -483,3 +1148,25 @@ pub async fn qwen_asr_open_models_folder() -> Result<(), String> {
     log::info!("Opened Qwen ASR models folder: {}", folder_path);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_recovering_survives_poisoned_mutex() {
+        static SLOT: Mutex<Option<PathBuf>> = Mutex::new(None);
+        *lock_recovering(&SLOT) = Some(PathBuf::from("models"));
+
+        let result = std::thread::spawn(|| {
+            let _guard = SLOT.lock().unwrap();
+            panic!("simulated command panic while holding the lock");
+        })
+        .join();
+        assert!(result.is_err());
+        assert!(SLOT.is_poisoned());
+
+        assert_eq!(lock_recovering(&SLOT).clone(), Some(PathBuf::from("models")));
+        assert!(!SLOT.is_poisoned());
+    }
+}
@@ -16,11 +16,14 @@
 //! - `qwen_asr_engine`: Main engine implementation (model management, download, transcription)
 //! - `model`: Safe FFI wrapper around qwen3-asr-sys
 //! - `commands`: Tauri command interface for frontend integration
+//! - `output`: Cleanup of raw decoder output (language tags)
 
 pub mod qwen_asr_engine;
 pub mod model;
 pub mod commands;
+pub mod output;
 
-pub use qwen_asr_engine::{QwenAsrEngine, QwenAsrEngineError, ModelInfo, ModelStatus, QuantizationType, DownloadProgress};
-pub use model::QwenAsrModel;
+pub use qwen_asr_engine::{preferred_model, QwenAsrConfig, QwenAsrEngine, QwenAsrEngineError, ModelInfo, ModelStatus, QuantizationType, QwenDecoderContext, DownloadOutcome, DownloadPhase, DownloadProgress, DownloadRecord, WindowProgress, MAX_DOWNLOAD_CONNECTIONS, MAX_DOWNLOAD_RETRIES, MAX_TRANSCRIPT_CACHE_ENTRIES};
+pub use output::clean_qwen_asr_output;
+pub use model::{gpu_device_count, BuildInfo, MelBackend, QwenAsrDiscardHandle, QwenAsrModel, QwenAsrParams, QwenAsrTranscript, QwenAsrWordTiming, RepetitionDetector};
 pub use commands::*;
@@ -5,11 +5,321 @@
 
 use std::ffi::{CStr, CString};
 use std::path::Path;
-use std::os::raw::{c_char, c_void};
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Decoding parameters for a single transcription call.
+///
+/// Built on top of `qwen3_asr_default_params()` so fields we don't expose
+/// keep the C library's defaults.
+///
+/// `use_gpu`, `gpu_device` and `n_threads` (0 = let the library pick) choose
+/// where decoding runs. They default to the C library's own choice: GPU 0
+/// with automatic threads.
+///
+/// `temperature` defaults to 0.0 (greedy decoding), which is deterministic:
+/// the same audio always yields the same text. A nonzero temperature trades
+/// that determinism for a chance of recovering on hard audio where greedy
+/// decoding gets stuck on a bad hypothesis. The C API has no top-p knob yet.
+///
+/// `repetition_threshold` is enforced on the Rust side: on the token stream
+/// while streaming, and on the finished text of every call, so batch decodes
+/// and builds that stream the text in one piece are cut back too. See
+/// [`RepetitionDetector`].
+///
+/// `decoder_prompt` is text the decoder sees before the audio, typically the
+/// tail of the previous chunk's transcript. It helps with proper nouns and
+/// sentences split across chunks, but makes the decoder more likely to
+/// continue the prompt on silence.
+///
+/// `max_new_tokens` caps how many tokens one call may decode (0 = no cap), so a
+/// decoder stuck in a loop on noisy audio stops instead of filling its context.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QwenAsrParams {
+    pub temperature: f32,
+    pub repetition_threshold: usize,
+    pub decoder_prompt: Option<String>,
+    pub max_new_tokens: usize,
+    pub use_gpu: bool,
+    pub gpu_device: i32,
+    pub n_threads: i32,
+}
+
+impl Default for QwenAsrParams {
+    fn default() -> Self {
+        Self {
+            temperature: 0.0,
+            repetition_threshold: DEFAULT_REPETITION_THRESHOLD,
+            decoder_prompt: None,
+            max_new_tokens: DEFAULT_MAX_NEW_TOKENS,
+            use_gpu: true,
+            gpu_device: 0,
+            n_threads: 0,
+        }
+    }
+}
+
+impl QwenAsrParams {
+    /// Set the sampling temperature (0.0 = greedy).
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    /// Set how many back-to-back repeats of the same n-gram abort decoding
+    /// (values below 2 disable the check).
+    pub fn with_repetition_threshold(mut self, threshold: usize) -> Self {
+        self.repetition_threshold = threshold;
+        self
+    }
+
+    /// Set the text fed to the decoder ahead of the audio (`None` = no prompt).
+    pub fn with_decoder_prompt(mut self, prompt: Option<String>) -> Self {
+        self.decoder_prompt = prompt.filter(|p| !p.trim().is_empty());
+        self
+    }
+
+    /// Set the most tokens one call may decode (0 = no cap).
+    pub fn with_max_new_tokens(mut self, max_new_tokens: usize) -> Self {
+        self.max_new_tokens = max_new_tokens;
+        self
+    }
+
+    /// The decoder prompt as a C string. Interior NULs can't cross the FFI
+    /// boundary, so they are stripped rather than failing the transcription.
+    fn prompt_cstring(&self) -> Option<CString> {
+        self.decoder_prompt
+            .as_deref()
+            .map(|p| CString::new(p.replace('\0', "")).expect("NULs were stripped"))
+    }
+
+    /// `prompt` and `language` must outlive every C call made with the returned params.
+    fn to_ffi(&self, prompt: Option<&CStr>, language: Option<&CStr>) -> qwen3_asr_sys::qwen3_asr_params {
+        let mut params = unsafe { qwen3_asr_sys::qwen3_asr_default_params() };
+        params.temperature = self.temperature;
+        params.decoder_prompt = prompt.map_or(std::ptr::null(), CStr::as_ptr);
+        params.language = language.map_or(std::ptr::null(), CStr::as_ptr);
+        params.max_new_tokens = self.max_new_tokens.min(i32::MAX as usize) as i32;
+        params.use_gpu = self.use_gpu;
+        params.gpu_device = self.gpu_device;
+        params.n_threads = self.n_threads.max(0);
+        with_valid_gpu_device(params, gpu_device_count())
+    }
+}
+
+/// Number of GPUs the linked library can decode on (0 for CPU-only builds).
+pub fn gpu_device_count() -> i32 {
+    unsafe { qwen3_asr_sys::qwen3_asr_gpu_device_count() }.max(0)
+}
+
+/// A forced language as a C string; blank means auto-detect.
+fn language_cstring(lang: Option<&str>) -> Option<CString> {
+    lang.map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| CString::new(l.replace('\0', "")).expect("NULs were stripped"))
+}
+
+/// FFI params for one decode call, together with the C strings they point
+/// into. The pointers stay valid for as long as this is alive, since moving a
+/// `CString` doesn't move its buffer.
+struct DecodeParams {
+    ffi: qwen3_asr_sys::qwen3_asr_params,
+    _prompt: Option<CString>,
+    _language: Option<CString>,
+}
+
+impl DecodeParams {
+    fn new(params: &QwenAsrParams, lang: Option<&str>) -> Self {
+        let prompt = params.prompt_cstring();
+        let language = language_cstring(lang);
+        Self {
+            ffi: params.to_ffi(prompt.as_deref(), language.as_deref()),
+            _prompt: prompt,
+            _language: language,
+        }
+    }
+}
+
+/// Text of one transcription and how sure the decoder was of it.
+///
+/// `avg_logprob` is the mean log-probability of the decoded tokens and
+/// `no_speech_prob` the chance the audio held no speech. Builds whose decoder
+/// doesn't report token scores (including the stub) give 0.0 for both, which
+/// reads as full confidence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QwenAsrTranscript {
+    pub text: String,
+    pub avg_logprob: f32,
+    pub no_speech_prob: f32,
+    /// Word timings of this decode; empty when the decoder doesn't report them or
+    /// the text was stitched from windows or truncated
+    pub words: Vec<QwenAsrWordTiming>,
+}
+
+/// Word-level timing reported by the decoder, in seconds from the start of the
+/// decoded audio
+#[derive(Debug, Clone, PartialEq)]
+pub struct QwenAsrWordTiming {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
+}
+
+impl QwenAsrTranscript {
+    fn new(text: String, result: &qwen3_asr_sys::qwen3_asr_result) -> Self {
+        Self {
+            text,
+            avg_logprob: result.avg_logprob,
+            no_speech_prob: result.no_speech_prob,
+            words: Vec::new(),
+        }
+    }
+
+    /// Geometric mean of the token probabilities, in 0.0..=1.0
+    pub fn confidence(&self) -> f32 {
+        self.avg_logprob.exp().clamp(0.0, 1.0)
+    }
+}
+
+/// Fall back to CPU when the requested GPU index doesn't exist (e.g. a stale
+/// saved setting after a hardware change) instead of letting the C side pick
+/// an invalid device mid-decode.
+fn with_valid_gpu_device(
+    mut params: qwen3_asr_sys::qwen3_asr_params,
+    device_count: i32,
+) -> qwen3_asr_sys::qwen3_asr_params {
+    if params.use_gpu && (params.gpu_device < 0 || params.gpu_device >= device_count) {
+        static WARNED: std::sync::Once = std::sync::Once::new();
+        WARNED.call_once(|| {
+            log::warn!(
+                "Qwen ASR GPU device {} not available ({} device(s) found), transcribing on CPU",
+                params.gpu_device,
+                device_count
+            );
+        });
+        params.use_gpu = false;
+    }
+    params
+}
+
+/// Default token cap per call, the same as Whisper's decoder limit.
+pub const DEFAULT_MAX_NEW_TOKENS: usize = 448;
+
+/// Default number of consecutive repeats that counts as a decoding loop.
+pub const DEFAULT_REPETITION_THRESHOLD: usize = 5;
+
+/// How long a decode may keep running after the callback asked it to stop
+/// before the context is cancelled explicitly. Only used on builds whose
+/// decode loop checks for a cancel (see [`BuildInfo::interruptible_decode`]).
+const ABORT_GRACE: Duration = Duration::from_secs(2);
+
+/// Longest n-gram (in tokens) checked for repetition.
+const MAX_REPEAT_NGRAM: usize = 4;
+
+/// Detects runaway repetition ("okay okay okay okay ...") in a token stream.
+///
+/// The decoder occasionally locks onto a short phrase and repeats it until it
+/// hits its token limit. Each pushed token is checked against the tail of the
+/// stream: if the last `threshold` n-grams (n = 1..=4 tokens) are identical,
+/// the stream is flagged and the text is cut back to the first occurrence.
+pub struct RepetitionDetector {
+    threshold: usize,
+    tokens: Vec<String>,
+    loop_onset: Option<usize>,
+}
+
+impl RepetitionDetector {
+    pub fn new(threshold: usize) -> Self {
+        Self {
+            threshold,
+            tokens: Vec::new(),
+            loop_onset: None,
+        }
+    }
+
+    /// Record a token. Returns `false` once a repetition loop is detected.
+    pub fn push(&mut self, token: &str) -> bool {
+        if self.loop_onset.is_some() {
+            return false;
+        }
+        self.tokens.push(token.to_string());
+
+        if self.threshold < 2 {
+            return true;
+        }
+
+        for n in 1..=MAX_REPEAT_NGRAM {
+            let span = n * self.threshold;
+            if self.tokens.len() < span {
+                break;
+            }
+            let window = &self.tokens[self.tokens.len() - span..];
+            let first = &window[..n];
+            if first.iter().all(|t| t.trim().is_empty()) {
+                continue;
+            }
+            let repeating = window
+                .chunks(n)
+                .all(|chunk| chunk.iter().zip(first).all(|(a, b)| a.trim() == b.trim()));
+            if repeating {
+                // Keep the first occurrence, drop the repeats
+                self.loop_onset = Some(self.tokens.len() - span + n);
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Whether a repetition loop has been detected.
+    pub fn loop_detected(&self) -> bool {
+        self.loop_onset.is_some()
+    }
+
+    /// Text accumulated so far, truncated at the loop onset if one was found.
+    pub fn text(&self) -> String {
+        let end = self.loop_onset.unwrap_or(self.tokens.len());
+        self.tokens[..end].concat()
+    }
+}
+
+/// Cut a finished transcript back to the onset of a repetition loop, checking
+/// it word by word. Returns `None` when the text doesn't loop.
+fn truncate_repetition(text: &str, threshold: usize) -> Option<String> {
+    let mut detector = RepetitionDetector::new(threshold);
+    let looped = text
+        .split_inclusive(char::is_whitespace)
+        .any(|word| !detector.push(word));
+    looped.then(|| detector.text().trim_end().to_string())
+}
 
 /// Safe wrapper around the qwen3-asr C context.
 pub struct QwenAsrModel {
     ctx: *mut qwen3_asr_sys::qwen3_asr_context,
+    // Same context for discard handles; nulled before the context is freed
+    discard_ctx: Arc<Mutex<ContextHandle>>,
+    // Set once a warmup decode has built the context's buffers
+    warmed_up: AtomicBool,
+}
+
+/// Discards the transcription running on a model from another thread, without
+/// the lock that transcription holds. Does nothing once the model is dropped.
+#[derive(Clone)]
+pub struct QwenAsrDiscardHandle(Arc<Mutex<ContextHandle>>);
+
+impl QwenAsrDiscardHandle {
+    /// Make the running transcribe call return an error instead of its text,
+    /// stopping its decode at the next token where the build supports it (see
+    /// [`BuildInfo::interruptible_decode`]). A call that hasn't started decoding
+    /// yet is not affected.
+    pub fn discard(&self) {
+        let ctx = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if !ctx.get().is_null() {
+            unsafe { qwen3_asr_sys::qwen3_asr_request_discard(ctx.get()) };
+        }
+    }
 }
 
 // SAFETY: The C library is designed for single-threaded access per context.
@@ -47,10 +357,47 @@ impl QwenAsrModel {
                 model_path.display()
             );
 
-            Ok(Self { ctx })
+            Ok(Self {
+                ctx,
+                discard_ctx: Arc::new(Mutex::new(ContextHandle(ctx))),
+                warmed_up: AtomicBool::new(false),
+            })
         }
     }
 
+    /// Discard the transcription currently running on this model (see
+    /// [`QwenAsrDiscardHandle::discard`]).
+    pub fn discard(&self) {
+        self.discard_handle().discard();
+    }
+
+    /// Handle for discarding transcriptions from code that can't borrow the model
+    pub fn discard_handle(&self) -> QwenAsrDiscardHandle {
+        QwenAsrDiscardHandle(self.discard_ctx.clone())
+    }
+
+    /// Run a short dummy decode so the first real transcription isn't slowed by
+    /// buffer allocation. Only the first successful call does any work.
+    pub fn warmup(&self, params: &QwenAsrParams) -> bool {
+        if self.warmed_up.load(Ordering::SeqCst) {
+            return true;
+        }
+        let started = std::time::Instant::now();
+        let ok = unsafe { qwen3_asr_sys::qwen3_asr_warmup(self.ctx, params.to_ffi(None, None)) };
+        if ok {
+            self.warmed_up.store(true, Ordering::SeqCst);
+            log::info!("Qwen3-ASR warmup took {:?}", started.elapsed());
+        } else {
+            log::warn!("Qwen3-ASR warmup failed after {:?}", started.elapsed());
+        }
+        ok
+    }
+
+    /// Whether [`Self::warmup`] has succeeded on this model.
+    pub fn is_warmed_up(&self) -> bool {
+        self.warmed_up.load(Ordering::SeqCst)
+    }
+
     /// Check if a model is loaded.
     pub fn is_model_loaded(&self) -> bool {
         unsafe { qwen3_asr_sys::qwen3_asr_is_model_loaded(self.ctx) }
@@ -59,36 +406,79 @@ impl QwenAsrModel {
     /// Transcribe audio samples (batch mode).
     ///
     /// Expects 16kHz mono f32 PCM audio.
-    pub fn transcribe(&self, samples: &[f32]) -> Result<String, String> {
+    pub fn transcribe(&self, samples: &[f32], params: &QwenAsrParams) -> Result<QwenAsrTranscript, String> {
+        self.transcribe_with_language(samples, params, None)
+    }
+
+    /// Transcribe audio samples (batch mode) in a fixed language.
+    ///
+    /// `lang` is the language name the model uses in its tags (e.g. "English",
+    /// "Chinese"); `None` auto-detects exactly like [`Self::transcribe`]. With a
+    /// forced language the output has no "language X" prefix, on vendor builds
+    /// that support it.
+    pub fn transcribe_with_language(
+        &self,
+        samples: &[f32],
+        params: &QwenAsrParams,
+        lang: Option<&str>,
+    ) -> Result<QwenAsrTranscript, String> {
+        let decode_params = DecodeParams::new(params, lang);
         unsafe {
-            let params = qwen3_asr_sys::qwen3_asr_default_params();
 
             let result = qwen3_asr_sys::qwen3_asr_transcribe(
                 self.ctx,
                 samples.as_ptr(),
                 samples.len() as i32,
-                params,
+                decode_params.ffi,
             );
 
-            if !result.success || result.text.is_null() {
-                return Err("Qwen3-ASR transcription failed".to_string());
-            }
-
-            let text = CStr::from_ptr(result.text)
-                .to_string_lossy()
-                .into_owned();
+            let text = take_result_text(&result, |text| qwen3_asr_sys::qwen3_asr_free_text(text))
+                .ok_or_else(|| "Qwen3-ASR transcription failed".to_string())?;
 
             log::debug!(
-                "Qwen3-ASR transcribed {} samples in {:.1}ms ({} tokens): '{}'",
+                "Qwen3-ASR transcribed {} samples in {:.1}ms ({} tokens, avg logprob {:.2}): '{}'",
                 samples.len(),
                 result.duration_ms,
                 result.n_tokens,
+                result.avg_logprob,
                 text
             );
 
-            qwen3_asr_sys::qwen3_asr_free_text(result.text);
+            Ok(self.finish_transcript(text, &result, params.repetition_threshold))
+        }
+    }
 
-            Ok(text)
+    /// Transcript of a finished decode, cut back at the loop onset if its text
+    /// repeats itself. A truncated transcript has no word timings, since those
+    /// cover the full text.
+    fn finish_transcript(
+        &self,
+        text: String,
+        result: &qwen3_asr_sys::qwen3_asr_result,
+        repetition_threshold: usize,
+    ) -> QwenAsrTranscript {
+        if let Some(truncated) = truncate_repetition(&text, repetition_threshold) {
+            log::warn!(
+                "Qwen3-ASR output repeats itself, truncated to: '{}'",
+                truncated
+            );
+            return QwenAsrTranscript::new(truncated, result);
+        }
+        QwenAsrTranscript {
+            words: self.last_word_timings(),
+            ..QwenAsrTranscript::new(text, result)
+        }
+    }
+
+    /// Word timings of the last transcription on this context. Read right after
+    /// the decode, while the caller still holds the model exclusively.
+    fn last_word_timings(&self) -> Vec<QwenAsrWordTiming> {
+        let mut n_words: c_int = 0;
+        unsafe {
+            let words = qwen3_asr_sys::qwen3_asr_get_word_timestamps(self.ctx, &mut n_words);
+            take_word_timings(words, n_words, |words, n_words| {
+                qwen3_asr_sys::qwen3_asr_free_word_timestamps(words, n_words)
+            })
         }
     }
 
@@ -96,47 +486,306 @@ impl QwenAsrModel {
     ///
     /// The `on_token` closure is called for each decoded token.
     /// Return `true` to continue, `false` to abort.
+    ///
+    /// Decoding is also aborted when the model falls into a repetition loop
+    /// (see [`RepetitionDetector`]); the text up to the loop onset is returned.
     pub fn transcribe_streaming<F>(
         &self,
         samples: &[f32],
+        params: &QwenAsrParams,
         on_token: F,
-    ) -> Result<String, String>
+    ) -> Result<QwenAsrTranscript, String>
     where
         F: FnMut(&str) -> bool,
     {
-        unsafe {
-            let params = qwen3_asr_sys::qwen3_asr_default_params();
+        self.transcribe_streaming_with_language(samples, params, None, on_token)
+    }
 
-            // Box the closure so we can pass a raw pointer to C
-            let mut callback_box: Box<dyn FnMut(&str) -> bool> = Box::new(on_token);
-            let user_data = &mut callback_box as *mut Box<dyn FnMut(&str) -> bool> as *mut c_void;
+    /// [`Self::transcribe_streaming`] in a fixed language; `lang` works as in
+    /// [`Self::transcribe_with_language`].
+    pub fn transcribe_streaming_with_language<F>(
+        &self,
+        samples: &[f32],
+        params: &QwenAsrParams,
+        lang: Option<&str>,
+        mut on_token: F,
+    ) -> Result<QwenAsrTranscript, String>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        let mut detector = RepetitionDetector::new(params.repetition_threshold);
+        let decode_params = DecodeParams::new(params, lang);
 
-            let result = qwen3_asr_sys::qwen3_asr_transcribe_streaming(
-                self.ctx,
-                samples.as_ptr(),
-                samples.len() as i32,
-                params,
-                Some(streaming_trampoline),
-                user_data,
+        // Cancelling a decode that never checks for it would do nothing
+        let interruptible = BuildInfo::current().interruptible_decode;
+        let ctx = ContextHandle(self.ctx);
+        let mut watchdog = AbortWatchdog::new(ABORT_GRACE, move || {
+            log::warn!(
+                "Qwen3-ASR decode still running {:?} after abort, cancelling context",
+                ABORT_GRACE
             );
+            unsafe { qwen3_asr_sys::qwen3_asr_cancel(ctx.get()) };
+        });
+
+        let result = {
+            // Box the closure so we can pass a raw pointer to C
+            let mut callback_box: Box<dyn FnMut(&str) -> bool + '_> = Box::new(|token: &str| {
+                let keep_going = detector.push(token) && on_token(token);
+                if !keep_going && interruptible {
+                    watchdog.arm();
+                }
+                keep_going
+            });
+            let user_data = &mut callback_box as *mut Box<dyn FnMut(&str) -> bool + '_> as *mut c_void;
 
-            if !result.success || result.text.is_null() {
-                return Err("Qwen3-ASR streaming transcription failed".to_string());
+            unsafe {
+                qwen3_asr_sys::qwen3_asr_transcribe_streaming(
+                    self.ctx,
+                    samples.as_ptr(),
+                    samples.len() as i32,
+                    decode_params.ffi,
+                    Some(streaming_trampoline),
+                    user_data,
+                )
             }
+        };
+        // Cancels the timer, so the context is never cancelled after return
+        watchdog.finish();
+
+        if detector.loop_detected() {
+            if !result.text.is_null() {
+                unsafe { qwen3_asr_sys::qwen3_asr_free_text(result.text) };
+            }
+            let text = detector.text();
+            log::warn!(
+                "Qwen3-ASR decoding loop detected, truncated output to: '{}'",
+                text
+            );
+            return Ok(QwenAsrTranscript::new(text, &result));
+        }
+
+        // Builds that deliver the text in one callback only show a loop here
+        unsafe { take_result_text(&result, |text| qwen3_asr_sys::qwen3_asr_free_text(text)) }
+            .map(|text| self.finish_transcript(text, &result, params.repetition_threshold))
+            .ok_or_else(|| "Qwen3-ASR streaming transcription failed".to_string())
+    }
+}
+
+/// Context pointer handed to the abort timer thread.
+struct ContextHandle(*mut qwen3_asr_sys::qwen3_asr_context);
+
+// SAFETY: only used for `qwen3_asr_cancel` and `qwen3_asr_request_discard`, which
+// are thread-safe. The watchdog is finished before the transcription that owns the
+// context returns, and discard handles check for null under their mutex, which the
+// model sets before freeing the context.
+unsafe impl Send for ContextHandle {}
+
+impl ContextHandle {
+    fn get(&self) -> *mut qwen3_asr_sys::qwen3_asr_context {
+        self.0
+    }
+}
+
+/// Runs `on_timeout` if a decode keeps going for `timeout` after it was asked
+/// to stop. Armed from the token callback; [`AbortWatchdog::finish`] cancels
+/// the timer once the FFI call returns, so `on_timeout` never runs after that.
+///
+/// The abort is best-effort: all watchdogs share one timer thread, so a
+/// timeout can fire a little late, and `qwen3_asr_cancel` only sets a flag the
+/// decode loop checks between tokens. Builds whose loop doesn't check it never
+/// arm the watchdog.
+struct AbortWatchdog {
+    timeout: Duration,
+    on_timeout: Option<Box<dyn FnOnce() + Send>>,
+    timer_id: Option<u64>,
+}
+
+impl AbortWatchdog {
+    fn new<F>(timeout: Duration, on_timeout: F) -> Self
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        Self {
+            timeout,
+            on_timeout: Some(Box::new(on_timeout)),
+            timer_id: None,
+        }
+    }
+
+    /// Start the timer. Later calls are no-ops.
+    fn arm(&mut self) {
+        if let Some(on_timeout) = self.on_timeout.take() {
+            let deadline = Instant::now() + self.timeout;
+            self.timer_id = Some(AbortTimer::shared().schedule(deadline, on_timeout));
+        }
+    }
+
+    fn finish(&mut self) {
+        if let Some(id) = self.timer_id.take() {
+            AbortTimer::shared().cancel(id);
+        }
+    }
+}
+
+impl Drop for AbortWatchdog {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// Timer thread shared by every [`AbortWatchdog`], so an abort never costs a
+/// thread of its own.
+struct AbortTimer {
+    state: Mutex<AbortTimerState>,
+    changed: Condvar,
+}
+
+#[derive(Default)]
+struct AbortTimerState {
+    next_id: u64,
+    pending: Vec<PendingAbort>,
+    firing: Option<u64>,
+}
+
+struct PendingAbort {
+    id: u64,
+    deadline: Instant,
+    on_timeout: Box<dyn FnOnce() + Send>,
+}
+
+impl AbortTimer {
+    fn shared() -> &'static AbortTimer {
+        static TIMER: OnceLock<&'static AbortTimer> = OnceLock::new();
+        TIMER.get_or_init(|| {
+            let timer: &'static AbortTimer = Box::leak(Box::new(AbortTimer {
+                state: Mutex::new(AbortTimerState::default()),
+                changed: Condvar::new(),
+            }));
+            std::thread::Builder::new()
+                .name("qwen-asr-abort-timer".to_string())
+                .spawn(move || timer.run())
+                .expect("failed to spawn the Qwen3-ASR abort timer thread");
+            timer
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, AbortTimerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn schedule(&self, deadline: Instant, on_timeout: Box<dyn FnOnce() + Send>) -> u64 {
+        let mut state = self.lock();
+        state.next_id += 1;
+        let id = state.next_id;
+        state.pending.push(PendingAbort { id, deadline, on_timeout });
+        self.changed.notify_all();
+        id
+    }
 
-            let text = CStr::from_ptr(result.text)
-                .to_string_lossy()
-                .into_owned();
+    /// Drop timer `id`, waiting for its callback if it is running right now
+    fn cancel(&self, id: u64) {
+        let mut state = self.lock();
+        state.pending.retain(|pending| pending.id != id);
+        while state.firing == Some(id) {
+            state = self.changed.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+    }
 
-            qwen3_asr_sys::qwen3_asr_free_text(result.text);
+    fn run(&self) {
+        let mut state = self.lock();
+        loop {
+            let next = state
+                .pending
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, pending)| pending.deadline)
+                .map(|(index, pending)| (index, pending.deadline));
+            let Some((index, deadline)) = next else {
+                state = self.changed.wait(state).unwrap_or_else(|e| e.into_inner());
+                continue;
+            };
+            let now = Instant::now();
+            if deadline > now {
+                state = self
+                    .changed
+                    .wait_timeout(state, deadline - now)
+                    .unwrap_or_else(|e| e.into_inner())
+                    .0;
+                continue;
+            }
 
-            Ok(text)
+            let PendingAbort { id, on_timeout, .. } = state.pending.swap_remove(index);
+            state.firing = Some(id);
+            drop(state);
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(on_timeout));
+            state = self.lock();
+            state.firing = None;
+            self.changed.notify_all();
         }
     }
 }
 
+/// Take the text out of a transcription result and release the C allocation.
+///
+/// Null text is a failure. A non-null empty string is a valid result (e.g.
+/// silence) and comes back as `Some("")`. Non-null text is always released
+/// through `free_text`, even when `success` is false.
+///
+/// # Safety
+/// `result.text` must be null or a live null-terminated string owned by the caller.
+unsafe fn take_result_text<F>(result: &qwen3_asr_sys::qwen3_asr_result, free_text: F) -> Option<String>
+where
+    F: FnOnce(*mut c_char),
+{
+    if result.text.is_null() {
+        return None;
+    }
+
+    let text = CStr::from_ptr(result.text).to_string_lossy().into_owned();
+    free_text(result.text);
+    result.success.then_some(text)
+}
+
+/// Copy word timings out of a C array and release it through `free_words`.
+///
+/// A null array means no timings. Entries with null or blank text are skipped.
+/// The array is always released once it has been read, with nothing in between
+/// that can return early.
+///
+/// # Safety
+/// `words` must be null or point to `n_words` live entries owned by the caller.
+unsafe fn take_word_timings<F>(
+    words: *mut qwen3_asr_sys::qwen3_asr_word,
+    n_words: c_int,
+    free_words: F,
+) -> Vec<QwenAsrWordTiming>
+where
+    F: FnOnce(*mut qwen3_asr_sys::qwen3_asr_word, c_int),
+{
+    if words.is_null() {
+        return Vec::new();
+    }
+
+    let entries = std::slice::from_raw_parts(words, n_words.max(0) as usize);
+    let timings = entries
+        .iter()
+        .filter(|w| !w.word.is_null())
+        .map(|w| QwenAsrWordTiming {
+            text: CStr::from_ptr(w.word).to_string_lossy().trim().to_string(),
+            start: w.start_ms as f64 / 1000.0,
+            end: w.end_ms as f64 / 1000.0,
+        })
+        .filter(|w| !w.text.is_empty())
+        .collect();
+    free_words(words, n_words);
+    timings
+}
+
 /// Trampoline function that bridges the C callback to the Rust closure.
 ///
+/// A panic in the closure must not unwind into C, so it is caught, logged, and
+/// reported as `false` to abort decoding.
+///
 /// # Safety
 /// - `user_data` must be a valid pointer to `Box<dyn FnMut(&str) -> bool>`
 /// - `token` must be a valid null-terminated C string
@@ -150,11 +799,118 @@ unsafe extern "C" fn streaming_trampoline(
 
     let callback = &mut *(user_data as *mut Box<dyn FnMut(&str) -> bool>);
     let token_str = CStr::from_ptr(token).to_string_lossy();
-    callback(&token_str)
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(&token_str))) {
+        Ok(keep_going) => keep_going,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            log::error!("Streaming callback panicked, aborting decode: {}", message);
+            false
+        }
+    }
+}
+
+/// FFT path used for the mel spectrogram. Process-wide, applied on the next
+/// transcription. `Portable` is several times slower than Accelerate for the
+/// mel step and exists to isolate FFT-related accuracy differences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MelBackend {
+    Auto,
+    Accelerate,
+    Portable,
+}
+
+impl MelBackend {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "auto" => Some(Self::Auto),
+            "accelerate" => Some(Self::Accelerate),
+            "portable" => Some(Self::Portable),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Accelerate => "accelerate",
+            Self::Portable => "portable",
+        }
+    }
+
+    fn to_ffi(self) -> i32 {
+        match self {
+            Self::Auto => qwen3_asr_sys::QWEN3_ASR_MEL_BACKEND_AUTO,
+            Self::Accelerate => qwen3_asr_sys::QWEN3_ASR_MEL_BACKEND_ACCELERATE,
+            Self::Portable => qwen3_asr_sys::QWEN3_ASR_MEL_BACKEND_PORTABLE,
+        }
+    }
+
+    /// Select the backend; fails if it was not compiled in.
+    pub fn apply(self) -> Result<(), String> {
+        if unsafe { qwen3_asr_sys::qwen3_asr_set_mel_backend(self.to_ffi()) } {
+            Ok(())
+        } else {
+            Err(format!("Mel backend '{}' is not compiled into this build", self.as_str()))
+        }
+    }
+
+    fn from_ffi(backend: i32) -> Self {
+        match backend {
+            qwen3_asr_sys::QWEN3_ASR_MEL_BACKEND_ACCELERATE => Self::Accelerate,
+            _ => Self::Portable,
+        }
+    }
+
+    /// Backend the next transcription will use (never `Auto`).
+    pub fn current() -> Self {
+        Self::from_ffi(unsafe { qwen3_asr_sys::qwen3_asr_get_mel_backend() })
+    }
+
+    /// Backends that can be selected in this build.
+    pub fn available() -> Vec<Self> {
+        [Self::Auto, Self::Accelerate, Self::Portable]
+            .into_iter()
+            .filter(|b| unsafe { qwen3_asr_sys::qwen3_asr_mel_backend_available(b.to_ffi()) })
+            .collect()
+    }
+}
+
+/// What the linked qwen3-asr library was compiled with. A stub build
+/// (`has_vendor == false`) returns placeholder text instead of transcripts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BuildInfo {
+    pub has_vendor: bool,
+    pub metal: bool,
+    pub cuda: bool,
+    pub accelerate: bool,
+    /// Mel FFT used unless another backend is selected at runtime
+    pub fft_backend: MelBackend,
+    /// Cancel and discard stop a running decode between tokens. Without it a
+    /// decode always runs to the end and they only change what it returns.
+    pub interruptible_decode: bool,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        let info = unsafe { qwen3_asr_sys::qwen3_asr_build_info() };
+        Self {
+            has_vendor: info.has_vendor,
+            metal: info.metal,
+            cuda: info.cuda,
+            accelerate: info.accelerate,
+            fft_backend: MelBackend::from_ffi(info.fft_backend),
+            interruptible_decode: info.interruptible_decode,
+        }
+    }
 }
 
 impl Drop for QwenAsrModel {
     fn drop(&mut self) {
+        self.discard_ctx.lock().unwrap_or_else(|e| e.into_inner()).0 = std::ptr::null_mut();
         if !self.ctx.is_null() {
             unsafe {
                 qwen3_asr_sys::qwen3_asr_free(self.ctx);
@@ -163,3 +919,423 @@ impl Drop for QwenAsrModel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mel_backend_auto_resolves_to_compiled_backend() {
+        MelBackend::Auto.apply().unwrap();
+        assert_ne!(MelBackend::current(), MelBackend::Auto);
+        assert_eq!(BuildInfo::current().fft_backend, MelBackend::current());
+        assert!(MelBackend::available().contains(&MelBackend::Auto));
+        assert_eq!(MelBackend::parse("portable"), Some(MelBackend::Portable));
+        assert_eq!(MelBackend::parse("fft"), None);
+    }
+
+    #[test]
+    fn decoder_prompt_is_passed_as_c_string() {
+        let params = QwenAsrParams::default().with_decoder_prompt(Some("Acme\0 roadmap".to_string()));
+        let prompt = params.prompt_cstring().unwrap();
+        assert_eq!(prompt.to_str().unwrap(), "Acme roadmap");
+        let ffi = params.to_ffi(Some(&prompt), None);
+        assert_eq!(ffi.decoder_prompt, prompt.as_ptr());
+
+        let params = QwenAsrParams::default().with_decoder_prompt(Some("  ".to_string()));
+        assert_eq!(params.decoder_prompt, None);
+        assert!(params.to_ffi(None, None).decoder_prompt.is_null());
+    }
+
+    #[test]
+    fn forced_language_is_passed_as_c_string() {
+        let language = language_cstring(Some(" English ")).unwrap();
+        assert_eq!(language.to_str().unwrap(), "English");
+        let ffi = QwenAsrParams::default().to_ffi(None, Some(&language));
+        assert_eq!(ffi.language, language.as_ptr());
+
+        assert_eq!(language_cstring(Some(" ")), None);
+        assert!(QwenAsrParams::default().to_ffi(None, None).language.is_null());
+    }
+
+    #[test]
+    fn decode_params_carry_prompt_and_language() {
+        let params = QwenAsrParams::default().with_decoder_prompt(Some("Acme roadmap".to_string()));
+        let decode = DecodeParams::new(&params, Some("English"));
+        let language = unsafe { CStr::from_ptr(decode.ffi.language) };
+        let prompt = unsafe { CStr::from_ptr(decode.ffi.decoder_prompt) };
+        assert_eq!(language.to_str().unwrap(), "English");
+        assert_eq!(prompt.to_str().unwrap(), "Acme roadmap");
+
+        let auto = DecodeParams::new(&QwenAsrParams::default(), None);
+        assert!(auto.ffi.language.is_null());
+        assert!(auto.ffi.decoder_prompt.is_null());
+    }
+
+    #[test]
+    fn streaming_accepts_a_forced_language() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stub.gguf");
+        std::fs::write(&path, b"GGUF\x03\x00\x00\x00").unwrap();
+        let model = QwenAsrModel::new(&path).unwrap();
+
+        let mut tokens = 0;
+        let result = model
+            .transcribe_streaming_with_language(&[0.0f32; 16000], &QwenAsrParams::default(), Some("English"), |_| {
+                tokens += 1;
+                true
+            })
+            .unwrap();
+        assert!(tokens > 0);
+        assert!(!result.text.is_empty());
+    }
+
+    #[test]
+    fn warmup_runs_once_per_model() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stub.gguf");
+        std::fs::write(&path, b"GGUF\x03\x00\x00\x00").unwrap();
+        let model = QwenAsrModel::new(&path).unwrap();
+
+        assert!(!model.is_warmed_up());
+        if model.warmup(&QwenAsrParams::default()) {
+            assert!(model.is_warmed_up());
+            assert!(model.warmup(&QwenAsrParams::default()));
+        }
+    }
+
+    #[test]
+    fn discard_fails_a_running_transcription() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stub.gguf");
+        std::fs::write(&path, b"GGUF\x03\x00\x00\x00").unwrap();
+        let model = QwenAsrModel::new(&path).unwrap();
+        let audio = vec![0.0f32; 16000 * 60];
+
+        let started = std::time::Instant::now();
+        let (token_tx, token_rx) = std::sync::mpsc::channel();
+        let result = std::thread::scope(|s| {
+            let decode = s.spawn(|| {
+                model.transcribe_streaming(&audio, &QwenAsrParams::default(), |_| {
+                    let _ = token_tx.send(());
+                    std::thread::sleep(Duration::from_millis(200));
+                    true
+                })
+            });
+            token_rx.recv().unwrap();
+            model.discard();
+            decode.join().unwrap()
+        });
+
+        // Discarded on every build; builds that check between tokens also stop
+        // early (the stub's four tokens take 800 ms when run to the end)
+        assert!(result.is_err());
+        if BuildInfo::current().interruptible_decode {
+            assert!(started.elapsed() < Duration::from_millis(600));
+        }
+
+        let handle = model.discard_handle();
+        drop(model);
+        handle.discard();
+    }
+
+    #[test]
+    fn abort_ends_a_running_transcription_early() {
+        // Needs a decode loop that checks for the discard between tokens
+        if !BuildInfo::current().interruptible_decode {
+            return;
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stub.gguf");
+        std::fs::write(&path, b"GGUF\x03\x00\x00\x00").unwrap();
+        let model = QwenAsrModel::new(&path).unwrap();
+        let params = QwenAsrParams::default()
+            .with_decoder_prompt(Some("one two three four five six seven eight".to_string()));
+
+        let mut tokens = 0;
+        let result = model.transcribe_streaming(&[0.0f32; 16000], &params, |_| {
+            tokens += 1;
+            if tokens == 2 {
+                model.discard();
+            }
+            true
+        });
+        assert!(result.is_err());
+        assert_eq!(tokens, 2);
+    }
+
+    #[test]
+    fn abort_watchdog_fires_only_while_decode_is_running() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let fired = Arc::new(AtomicBool::new(false));
+        let flag = fired.clone();
+        let mut watchdog = AbortWatchdog::new(Duration::from_millis(20), move || {
+            flag.store(true, Ordering::SeqCst)
+        });
+        watchdog.arm();
+        std::thread::sleep(Duration::from_millis(100));
+        watchdog.finish();
+        assert!(fired.load(Ordering::SeqCst));
+
+        let fired = Arc::new(AtomicBool::new(false));
+        let flag = fired.clone();
+        let mut watchdog = AbortWatchdog::new(Duration::from_secs(60), move || {
+            flag.store(true, Ordering::SeqCst)
+        });
+        watchdog.arm();
+        watchdog.finish();
+        assert!(!fired.load(Ordering::SeqCst));
+
+        // Watchdogs share one timer; finishing one leaves the others armed
+        let cancelled_fired = Arc::new(AtomicBool::new(false));
+        let cancelled_flag = cancelled_fired.clone();
+        let mut cancelled = AbortWatchdog::new(Duration::from_millis(10), move || {
+            cancelled_flag.store(true, Ordering::SeqCst)
+        });
+        let fired = Arc::new(AtomicBool::new(false));
+        let flag = fired.clone();
+        let mut running = AbortWatchdog::new(Duration::from_millis(20), move || {
+            flag.store(true, Ordering::SeqCst)
+        });
+        cancelled.arm();
+        running.arm();
+        cancelled.finish();
+        std::thread::sleep(Duration::from_millis(100));
+        running.finish();
+        assert!(!cancelled_fired.load(Ordering::SeqCst));
+        assert!(fired.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn streaming_trampoline_aborts_on_callback_panic() {
+        let mut calls = 0;
+        let mut callback: Box<dyn FnMut(&str) -> bool> = Box::new(|token: &str| {
+            calls += 1;
+            if token == "boom" {
+                panic!("callback failed");
+            }
+            true
+        });
+        let user_data = &mut callback as *mut Box<dyn FnMut(&str) -> bool> as *mut c_void;
+
+        let ok = CString::new("hello").unwrap();
+        let boom = CString::new("boom").unwrap();
+        unsafe {
+            assert!(streaming_trampoline(ok.as_ptr(), user_data));
+            assert!(!streaming_trampoline(boom.as_ptr(), user_data));
+        }
+        drop(callback);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn transcribe_truncates_a_repeating_decode() {
+        // The stub echoes the decoder prompt back as its transcript
+        if BuildInfo::current().has_vendor {
+            return;
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stub.gguf");
+        std::fs::write(&path, b"GGUF\x03\x00\x00\x00").unwrap();
+        let model = QwenAsrModel::new(&path).unwrap();
+        let audio = vec![0.0f32; 16000];
+        let looping = QwenAsrParams::default()
+            .with_repetition_threshold(4)
+            .with_decoder_prompt(Some("Hello there okay okay okay okay okay okay okay".to_string()));
+
+        assert_eq!(model.transcribe(&audio, &looping).unwrap().text, "Hello there okay");
+
+        let mut tokens = 0;
+        let streamed = model
+            .transcribe_streaming(&audio, &looping, |_| {
+                tokens += 1;
+                true
+            })
+            .unwrap();
+        assert_eq!(streamed.text, "Hello there okay");
+        assert_eq!(tokens, 5);
+
+        let speech = looping.with_decoder_prompt(Some("okay so okay then".to_string()));
+        assert_eq!(model.transcribe(&audio, &speech).unwrap().text, "okay so okay then");
+    }
+
+    #[test]
+    fn test_repetition_detector_stops_on_loop() {
+        let mut detector = RepetitionDetector::new(4);
+        let mut stream = vec!["Hello", " there", ","];
+        stream.extend(std::iter::repeat(" okay").take(20));
+
+        let mut accepted = 0;
+        for token in &stream {
+            if !detector.push(token) {
+                break;
+            }
+            accepted += 1;
+        }
+
+        assert!(detector.loop_detected());
+        assert!(accepted < stream.len());
+        assert_eq!(detector.text(), "Hello there, okay");
+    }
+
+    #[test]
+    fn test_repetition_detector_multi_token_phrase() {
+        let mut detector = RepetitionDetector::new(3);
+        let mut stopped = false;
+        for _ in 0..10 {
+            for token in [" thank", " you", "."] {
+                if !detector.push(token) {
+                    stopped = true;
+                    break;
+                }
+            }
+            if stopped {
+                break;
+            }
+        }
+
+        assert!(stopped);
+        assert_eq!(detector.text(), " thank you.");
+    }
+
+    #[test]
+    fn test_repetition_detector_allows_normal_speech() {
+        let mut detector = RepetitionDetector::new(DEFAULT_REPETITION_THRESHOLD);
+        for token in ["no", " no", ",", " that", " is", " not", " it", "."] {
+            assert!(detector.push(token));
+        }
+        assert!(!detector.loop_detected());
+        assert_eq!(detector.text(), "no no, that is not it.");
+    }
+
+    #[test]
+    fn test_repetition_detector_disabled() {
+        let mut detector = RepetitionDetector::new(0);
+        for _ in 0..50 {
+            assert!(detector.push(" okay"));
+        }
+        assert!(!detector.loop_detected());
+    }
+
+    #[test]
+    fn test_invalid_gpu_device_falls_back_to_cpu() {
+        let params = unsafe { qwen3_asr_sys::qwen3_asr_default_params() };
+        let gpu = qwen3_asr_sys::qwen3_asr_params { use_gpu: true, gpu_device: 1, ..params };
+
+        assert!(with_valid_gpu_device(gpu, 2).use_gpu);
+        assert!(!with_valid_gpu_device(gpu, 1).use_gpu);
+        assert!(!with_valid_gpu_device(gpu, 0).use_gpu);
+        let negative = qwen3_asr_sys::qwen3_asr_params { gpu_device: -1, ..gpu };
+        assert!(!with_valid_gpu_device(negative, 2).use_gpu);
+    }
+
+    #[test]
+    fn test_params_carry_device_and_threads_to_ffi() {
+        let cpu = QwenAsrParams { use_gpu: false, n_threads: 3, ..QwenAsrParams::default() };
+        let ffi = cpu.to_ffi(None, None);
+        assert!(!ffi.use_gpu);
+        assert_eq!(ffi.n_threads, 3);
+
+        let negative_threads = QwenAsrParams { n_threads: -4, ..QwenAsrParams::default() };
+        assert_eq!(negative_threads.to_ffi(None, None).n_threads, 0);
+    }
+
+    fn result_with_text(text: *mut c_char, success: bool) -> qwen3_asr_sys::qwen3_asr_result {
+        qwen3_asr_sys::qwen3_asr_result {
+            text,
+            n_tokens: 0,
+            duration_ms: 0.0,
+            success,
+            avg_logprob: 0.0,
+            no_speech_prob: 0.0,
+        }
+    }
+
+    #[test]
+    fn transcript_confidence_is_mean_token_probability() {
+        let mut result = result_with_text(std::ptr::null_mut(), true);
+        assert_eq!(QwenAsrTranscript::new("hi".to_string(), &result).confidence(), 1.0);
+
+        result.avg_logprob = 0.5f32.ln();
+        let transcript = QwenAsrTranscript::new("hi".to_string(), &result);
+        assert!((transcript.confidence() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_null_result_text_is_failure_and_not_freed() {
+        let mut freed = false;
+        let result = result_with_text(std::ptr::null_mut(), true);
+        let text = unsafe { take_result_text(&result, |_| freed = true) };
+        assert_eq!(text, None);
+        assert!(!freed);
+    }
+
+    #[test]
+    fn test_empty_result_text_is_success_and_freed() {
+        let mut freed = false;
+        let result = result_with_text(CString::new("").unwrap().into_raw(), true);
+        let text = unsafe {
+            take_result_text(&result, |ptr| {
+                drop(CString::from_raw(ptr));
+                freed = true;
+            })
+        };
+        assert_eq!(text.as_deref(), Some(""));
+        assert!(freed);
+    }
+
+    #[test]
+    fn word_timings_are_converted_to_seconds_and_freed() {
+        let texts: Vec<*mut c_char> = ["Hello", " ", "world"]
+            .iter()
+            .map(|w| CString::new(*w).unwrap().into_raw())
+            .collect();
+        let mut entries: Vec<qwen3_asr_sys::qwen3_asr_word> = texts
+            .iter()
+            .enumerate()
+            .map(|(i, &word)| qwen3_asr_sys::qwen3_asr_word {
+                start_ms: i as i32 * 500,
+                end_ms: i as i32 * 500 + 400,
+                word,
+            })
+            .collect();
+
+        let mut freed = false;
+        let timings = unsafe {
+            take_word_timings(entries.as_mut_ptr(), entries.len() as c_int, |_, n_words| {
+                assert_eq!(n_words, 3);
+                freed = true;
+            })
+        };
+        for text in texts {
+            drop(unsafe { CString::from_raw(text) });
+        }
+
+        assert!(freed);
+        assert_eq!(
+            timings,
+            vec![
+                QwenAsrWordTiming { text: "Hello".to_string(), start: 0.0, end: 0.4 },
+                QwenAsrWordTiming { text: "world".to_string(), start: 1.0, end: 1.4 },
+            ]
+        );
+
+        let none = unsafe { take_word_timings(std::ptr::null_mut(), 0, |_, _| panic!("nothing to free")) };
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_failed_result_text_is_still_freed() {
+        let mut freed = false;
+        let result = result_with_text(CString::new("partial").unwrap().into_raw(), false);
+        let text = unsafe {
+            take_result_text(&result, |ptr| {
+                drop(CString::from_raw(ptr));
+                freed = true;
+            })
+        };
+        assert_eq!(text, None);
+        assert!(freed);
+    }
+}
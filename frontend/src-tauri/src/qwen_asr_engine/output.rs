@@ -0,0 +1,65 @@
+//! Cleanup of raw Qwen3-ASR decoder output.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Remove QwenASR language-prefix artifacts.
+///
+/// Qwen3-ASR prepends a language tag directly before the transcript with NO separator:
+///   - `language EnglishWhat's your name?`
+///   - `language Chinese吃吃吃。`
+///   - `language None Hello`
+///
+/// We match the known language names exactly to avoid eating transcript content.
+pub fn clean_qwen_asr_output(text: &str) -> String {
+    // Known Qwen3-ASR language names (case-insensitive).
+    // These are directly concatenated to the transcript without any separator.
+    static LANGUAGE_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(concat!(
+            r"(?im)^\s*language\s+(?:",
+            r"English|Chinese|Japanese|Korean|French|German|Spanish|",
+            r"Portuguese|Russian|Italian|Dutch|Turkish|Arabic|Polish|",
+            r"Swedish|Norwegian|Danish|Finnish|Hungarian|Czech|Romanian|",
+            r"Bulgarian|Greek|Serbian|Croatian|Slovak|Slovenian|",
+            r"Ukrainian|Catalan|Vietnamese|Thai|Indonesian|Malay|",
+            r"Hindi|Tamil|Telugu|Bengali|Urdu|Persian|Hebrew|",
+            r"Cantonese|Yue|None|null",
+            r")[:：]?\s*"
+        ))
+        .expect("valid regex")
+    });
+    static LANGUAGE_SENTENCE_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(concat!(
+            r"(?i)([。！？.!?]\s*)language\s+(?:",
+            r"English|Chinese|Japanese|Korean|French|German|Spanish|",
+            r"Portuguese|Russian|Italian|Dutch|Turkish|Arabic|Polish|",
+            r"Swedish|Norwegian|Danish|Finnish|Hungarian|Czech|Romanian|",
+            r"Bulgarian|Greek|Serbian|Croatian|Slovak|Slovenian|",
+            r"Ukrainian|Catalan|Vietnamese|Thai|Indonesian|Malay|",
+            r"Hindi|Tamil|Telugu|Bengali|Urdu|Persian|Hebrew|",
+            r"Cantonese|Yue|None|null",
+            r")[:：]?\s*"
+        ))
+        .expect("valid regex")
+    });
+    static MULTISPACE_RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"[ \t]{2,}").expect("valid regex"));
+
+    let mut cleaned = text.trim().to_string();
+    if cleaned.is_empty() {
+        return cleaned;
+    }
+
+    cleaned = LANGUAGE_PREFIX_RE.replace_all(&cleaned, "").into_owned();
+    loop {
+        let next = LANGUAGE_SENTENCE_PREFIX_RE
+            .replace_all(&cleaned, "$1")
+            .into_owned();
+        if next == cleaned {
+            break;
+        }
+        cleaned = next;
+    }
+    cleaned = MULTISPACE_RE.replace_all(&cleaned, " ").into_owned();
+    cleaned.trim().to_string()
+}
@@ -1,15 +1,395 @@
-use crate::qwen_asr_engine::model::QwenAsrModel;
+use crate::audio::transcription::text_overlap::{append_transcript, remove_text_overlap};
+use crate::qwen_asr_engine::output::clean_qwen_asr_output;
+use crate::qwen_asr_engine::model::{gpu_device_count, QwenAsrDiscardHandle, QwenAsrModel, QwenAsrParams, QwenAsrTranscript};
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::fs;
-use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt, BufWriter};
 use tokio::sync::RwLock;
 use tokio::time::timeout;
 
+/// Sample rate expected by the model
+const SAMPLE_RATE: usize = 16000;
+
+/// Audio context of models without a catalog entry (imported GGUFs). Longer
+/// input is split into overlapping windows so nothing past the encoder context
+/// is silently dropped.
+const DEFAULT_CONTEXT_SECONDS: usize = 30;
+
+/// Overlap between consecutive windows, so words on a boundary are heard whole
+/// by at least one window. The duplicate text is removed when stitching.
+const WINDOW_OVERLAP_SECONDS: usize = 2;
+
+/// Split `total` samples into `[start, end)` windows of at most `window` samples,
+/// each starting `overlap` samples before the previous one ended.
+pub(crate) fn window_ranges(total: usize, window: usize, overlap: usize) -> Vec<(usize, usize)> {
+    if total == 0 || window == 0 {
+        return Vec::new();
+    }
+    let step = window.saturating_sub(overlap).max(1);
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    loop {
+        let end = (start + window).min(total);
+        ranges.push((start, end));
+        if end == total {
+            break;
+        }
+        start += step;
+    }
+    ranges
+}
+
+/// Tokens allowed per second of audio. Speech rarely goes past ~4 tokens/s,
+/// so this leaves room for fast talkers while cutting a decoding loop short.
+const MAX_TOKENS_PER_SECOND: usize = 6;
+
+/// Tokens allowed on top of the per-second budget, for the language tag and
+/// for very short clips.
+const MIN_NEW_TOKENS: usize = 32;
+
+/// `params` with the token cap scaled to `n_samples` of audio. The configured
+/// cap still applies when it is lower; 0 (no cap) stays uncapped.
+pub(crate) fn params_for_audio(params: &QwenAsrParams, n_samples: usize) -> QwenAsrParams {
+    if params.max_new_tokens == 0 {
+        return params.clone();
+    }
+    let budget = MIN_NEW_TOKENS + n_samples.div_ceil(SAMPLE_RATE) * MAX_TOKENS_PER_SECOND;
+    params.clone().with_max_new_tokens(budget.min(params.max_new_tokens))
+}
+
+/// How much of the previous transcript is fed back as the decoder prompt.
+/// Long prompts slow every chunk down and make run-on hallucination likelier.
+const DECODER_PROMPT_MAX_CHARS: usize = 200;
+
+/// Last `max_chars` characters of `text`, starting on a word boundary when the
+/// cut lands mid-word (text without spaces, e.g. Chinese, is cut as-is).
+pub(crate) fn decoder_prompt_tail(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    let char_count = text.chars().count();
+    if char_count <= max_chars {
+        return text.to_string();
+    }
+    let tail: String = text.chars().skip(char_count - max_chars).collect();
+    match tail.split_once(char::is_whitespace) {
+        Some((_, rest)) if !rest.trim().is_empty() => rest.trim_start().to_string(),
+        _ => tail,
+    }
+}
+
+/// Whether a model idle for `idle` should be unloaded. `timeout_secs` of 0 disables
+/// the timer; pinned models are never unloaded.
+fn idle_unload_due(timeout_secs: u64, pinned: bool, idle: Duration) -> bool {
+    timeout_secs > 0 && !pinned && idle >= Duration::from_secs(timeout_secs)
+}
+
+/// Prompt tail after a chunk transcribed as `text`. Chunks start with audio
+/// the previous chunk already covered, so the words `previous` ends with are
+/// dropped from `text` before it is appended.
+pub(crate) fn next_decoder_prompt(previous: &str, text: &str) -> String {
+    let mut combined = previous.to_string();
+    append_transcript(&mut combined, &remove_text_overlap(previous, text));
+    decoder_prompt_tail(&combined, DECODER_PROMPT_MAX_CHARS)
+}
+
+/// Rolling decoder prompt for one recording session. Each session owns one,
+/// so concurrent recordings and batch jobs never see each other's text.
+#[derive(Debug, Default)]
+pub struct QwenDecoderContext {
+    tail: tokio::sync::Mutex<String>,
+}
+
+/// Upper bound for the transcript cache size setting
+pub const MAX_TRANSCRIPT_CACHE_ENTRIES: usize = 256;
+
+/// Language name Qwen3-ASR uses in its tags for an app language code such as
+/// "en" or "zh-CN". `None` for auto modes and languages the model doesn't know,
+/// which leaves detection to the model.
+pub fn qwen_language_name(code: &str) -> Option<&'static str> {
+    let base = code.trim().split(['-', '_']).next().unwrap_or_default().to_ascii_lowercase();
+    let name = match base.as_str() {
+        "en" => "English",
+        "zh" => "Chinese",
+        "yue" => "Cantonese",
+        "ja" => "Japanese",
+        "ko" => "Korean",
+        "fr" => "French",
+        "de" => "German",
+        "es" => "Spanish",
+        "pt" => "Portuguese",
+        "ru" => "Russian",
+        "it" => "Italian",
+        "nl" => "Dutch",
+        "tr" => "Turkish",
+        "ar" => "Arabic",
+        "pl" => "Polish",
+        "sv" => "Swedish",
+        "no" | "nb" | "nn" => "Norwegian",
+        "da" => "Danish",
+        "fi" => "Finnish",
+        "hu" => "Hungarian",
+        "cs" => "Czech",
+        "ro" => "Romanian",
+        "bg" => "Bulgarian",
+        "el" => "Greek",
+        "sr" => "Serbian",
+        "hr" => "Croatian",
+        "sk" => "Slovak",
+        "sl" => "Slovenian",
+        "uk" => "Ukrainian",
+        "ca" => "Catalan",
+        "vi" => "Vietnamese",
+        "th" => "Thai",
+        "id" => "Indonesian",
+        "ms" => "Malay",
+        "hi" => "Hindi",
+        "ta" => "Tamil",
+        "te" => "Telugu",
+        "bn" => "Bengali",
+        "ur" => "Urdu",
+        "fa" => "Persian",
+        "he" => "Hebrew",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Identifies one batch decode: the exact samples, the model, the params and
+/// the forced language, if any.
+fn transcript_cache_key(audio: &[f32], model_name: &str, params: &QwenAsrParams, language: Option<&str>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    model_name.hash(&mut hasher);
+    language.hash(&mut hasher);
+    params.temperature.to_bits().hash(&mut hasher);
+    params.repetition_threshold.hash(&mut hasher);
+    params.decoder_prompt.hash(&mut hasher);
+    params.max_new_tokens.hash(&mut hasher);
+    audio.len().hash(&mut hasher);
+    for sample in audio {
+        sample.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Final transcripts of recently decoded audio, least recently used first.
+#[derive(Default)]
+struct TranscriptCache {
+    entries: VecDeque<(u64, QwenAsrTranscript)>,
+}
+
+impl TranscriptCache {
+    fn get(&mut self, key: u64) -> Option<QwenAsrTranscript> {
+        let index = self.entries.iter().position(|(k, _)| *k == key)?;
+        let entry = self.entries.remove(index)?;
+        let transcript = entry.1.clone();
+        self.entries.push_back(entry);
+        Some(transcript)
+    }
+
+    fn insert(&mut self, key: u64, transcript: QwenAsrTranscript, capacity: usize) {
+        self.entries.retain(|(k, _)| *k != key);
+        self.entries.push_back((key, transcript));
+        self.truncate(capacity);
+    }
+
+    fn truncate(&mut self, capacity: usize) {
+        while self.entries.len() > capacity {
+            self.entries.pop_front();
+        }
+    }
+}
+
+/// GGUF magic "GGUF" read as a little-endian u32
+const GGUF_MAGIC: u32 = 0x46554747;
+
+/// magic + version + tensor count + metadata count (GGUF v2+)
+const GGUF_HEADER_LEN: u64 = 24;
+
+/// Far above any real model; larger counts mean a corrupt header
+const GGUF_MAX_TENSORS: u64 = 65_536;
+const GGUF_MAX_METADATA_KV: u64 = 65_536;
+
+/// Smallest possible encodings: a tensor info is a one-byte name plus one
+/// dimension, type and offset; a metadata entry is a one-byte key, type and
+/// one-byte value.
+const GGUF_MIN_TENSOR_INFO_LEN: u64 = 8 + 1 + 4 + 8 + 4 + 8;
+const GGUF_MIN_METADATA_KV_LEN: u64 = 8 + 1 + 4 + 1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct GgufHeader {
+    pub version: u32,
+    pub tensor_count: u64,
+    pub metadata_kv_count: u64,
+}
+
+/// Read and sanity-check a GGUF header: magic, version, plausible counts, and
+/// a file long enough to hold the tensor and metadata tables it announces.
+///
+/// Catches files cut short by an interrupted download before the C loader
+/// sees them.
+pub(crate) fn read_gguf_header(file_path: &Path) -> Result<GgufHeader> {
+    use std::io::Read;
+
+    let file_len = std::fs::metadata(file_path)
+        .map_err(|e| anyhow!("Failed to read file metadata: {}", e))?
+        .len();
+
+    // GGUF files must be at least a few KB (header + metadata)
+    if file_len < 1024 {
+        return Err(anyhow!("File too small to be a valid GGUF: {} bytes", file_len));
+    }
+
+    let mut file = std::fs::File::open(file_path)
+        .map_err(|e| anyhow!("Failed to open file: {}", e))?;
+    let mut bytes = [0u8; GGUF_HEADER_LEN as usize];
+    file.read_exact(&mut bytes)
+        .map_err(|e| anyhow!("Failed to read GGUF header: {}", e))?;
+
+    let magic = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+    if magic != GGUF_MAGIC {
+        return Err(anyhow!("Invalid GGUF magic header: 0x{:08X} (expected 0x{:08X})", magic, GGUF_MAGIC));
+    }
+
+    let header = GgufHeader {
+        version: u32::from_le_bytes(bytes[4..8].try_into().unwrap()),
+        tensor_count: u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
+        metadata_kv_count: u64::from_le_bytes(bytes[16..24].try_into().unwrap()),
+    };
+
+    // v1 used 32-bit counts and is no longer produced
+    if !(2..=3).contains(&header.version) {
+        return Err(anyhow!("Unsupported GGUF version: {}", header.version));
+    }
+    if header.tensor_count == 0 || header.tensor_count > GGUF_MAX_TENSORS {
+        return Err(anyhow!("Implausible GGUF tensor count: {}", header.tensor_count));
+    }
+    if header.metadata_kv_count > GGUF_MAX_METADATA_KV {
+        return Err(anyhow!("Implausible GGUF metadata count: {}", header.metadata_kv_count));
+    }
+
+    let min_tables_end = GGUF_HEADER_LEN
+        + header.tensor_count * GGUF_MIN_TENSOR_INFO_LEN
+        + header.metadata_kv_count * GGUF_MIN_METADATA_KV_LEN;
+    if min_tables_end > file_len {
+        return Err(anyhow!(
+            "GGUF file truncated: header needs at least {} bytes, file has {}",
+            min_tables_end,
+            file_len
+        ));
+    }
+
+    Ok(header)
+}
+
+/// GGUF metadata value types that need more than a fixed-size skip
+const GGUF_TYPE_STRING: u32 = 8;
+const GGUF_TYPE_ARRAY: u32 = 9;
+/// Longest metadata string read into memory; real keys and values are far shorter
+const GGUF_MAX_STRING_LEN: u64 = 1 << 20;
+
+fn gguf_scalar_len(value_type: u32) -> Option<u64> {
+    match value_type {
+        0 | 1 | 7 => Some(1), // u8, i8, bool
+        2 | 3 => Some(2),     // u16, i16
+        4..=6 => Some(4),     // u32, i32, f32
+        10..=12 => Some(8),   // u64, i64, f64
+        _ => None,
+    }
+}
+
+fn read_gguf_u32(reader: &mut impl std::io::Read) -> Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_gguf_u64(reader: &mut impl std::io::Read) -> Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn skip_gguf_bytes(reader: &mut impl std::io::Read, len: u64) -> Result<()> {
+    let skipped = std::io::copy(&mut std::io::Read::take(reader, len), &mut std::io::sink())?;
+    if skipped != len {
+        return Err(anyhow!("GGUF metadata ends early"));
+    }
+    Ok(())
+}
+
+fn read_gguf_string(reader: &mut impl std::io::Read) -> Result<String> {
+    let len = read_gguf_u64(reader)?;
+    if len > GGUF_MAX_STRING_LEN {
+        return Err(anyhow!("Implausible GGUF string length: {}", len));
+    }
+    let mut bytes = vec![0u8; len as usize];
+    reader.read_exact(&mut bytes)?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn skip_gguf_value(reader: &mut impl std::io::Read, value_type: u32) -> Result<()> {
+    match value_type {
+        GGUF_TYPE_STRING => {
+            let len = read_gguf_u64(reader)?;
+            skip_gguf_bytes(reader, len)
+        }
+        GGUF_TYPE_ARRAY => {
+            let element_type = read_gguf_u32(reader)?;
+            let count = read_gguf_u64(reader)?;
+            match gguf_scalar_len(element_type) {
+                Some(len) => skip_gguf_bytes(reader, count.saturating_mul(len)),
+                None => (0..count).try_for_each(|_| skip_gguf_value(reader, element_type)),
+            }
+        }
+        other => match gguf_scalar_len(other) {
+            Some(len) => skip_gguf_bytes(reader, len),
+            None => Err(anyhow!("Unknown GGUF metadata type: {}", other)),
+        },
+    }
+}
+
+/// The `general.architecture` metadata value, or None if the file doesn't declare one
+pub(crate) fn read_gguf_architecture(file_path: &Path) -> Result<Option<String>> {
+    let header = read_gguf_header(file_path)?;
+    let file = std::fs::File::open(file_path).map_err(|e| anyhow!("Failed to open file: {}", e))?;
+    let mut reader = std::io::BufReader::new(file);
+    skip_gguf_bytes(&mut reader, GGUF_HEADER_LEN)?;
+
+    for _ in 0..header.metadata_kv_count {
+        let key = read_gguf_string(&mut reader)?;
+        let value_type = read_gguf_u32(&mut reader)?;
+        if key == "general.architecture" && value_type == GGUF_TYPE_STRING {
+            return read_gguf_string(&mut reader).map(Some);
+        }
+        skip_gguf_value(&mut reader, value_type)?;
+    }
+    Ok(None)
+}
+
+/// Refuse GGUFs built for another architecture (a Whisper model, an LLM) before the
+/// C loader sees them. Files without an architecture key are let through with a warning.
+pub(crate) fn check_model_compatibility(file_path: &Path) -> Result<()> {
+    match read_gguf_architecture(file_path)? {
+        Some(arch) if arch.to_lowercase().replace(['-', '_'], "").starts_with("qwen3asr") => Ok(()),
+        Some(arch) => Err(anyhow!(
+            "Incompatible model architecture '{}': {} is not a Qwen3-ASR model",
+            arch,
+            file_path.display()
+        )),
+        None => {
+            log::warn!("{} does not declare a GGUF architecture, loading it anyway", file_path.display());
+            Ok(())
+        }
+    }
+}
+
 /// Quantization type for Qwen ASR models (GGUF)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum QuantizationType {
@@ -42,12 +422,40 @@ pub struct DownloadProgress {
     pub total_mb: f64,
     pub speed_mbps: f64,
     pub percent: u8,
+    // Set only on the report announcing a resume attempt after a stall or dropped connection
+    pub retry_attempt: u32,
+    pub max_retries: u32,
+    pub phase: DownloadPhase,
+    // Progress through the finalizing steps (flush, validation), 0-100
+    pub finalize_percent: u8,
+}
+
+/// Stage of a model download. Transfer progress stops at 99% and `percent` only
+/// reaches 100 once the finalizing checks pass, so a slow flush or validation shows
+/// as its own phase rather than a bar stuck at 99%.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadPhase {
+    Downloading,
+    Finalizing,
+    #[serde(rename = "completed")]
+    Complete,
+}
+
+impl DownloadPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Downloading => "downloading",
+            Self::Finalizing => "finalizing",
+            Self::Complete => "completed",
+        }
+    }
 }
 
 impl DownloadProgress {
     pub fn new(downloaded: u64, total: u64, speed_mbps: f64) -> Self {
         let percent = if total > 0 {
-            ((downloaded as f64 / total as f64) * 100.0).min(100.0) as u8
+            ((downloaded as f64 / total as f64) * 100.0).min(99.0) as u8
         } else {
             0
         };
@@ -58,8 +466,304 @@ impl DownloadProgress {
             total_mb: total as f64 / (1024.0 * 1024.0),
             speed_mbps,
             percent,
+            retry_attempt: 0,
+            max_retries: 0,
+            phase: DownloadPhase::Downloading,
+            finalize_percent: 0,
+        }
+    }
+
+    /// Transfer is done; `finalize_percent` tracks the post-download checks
+    pub fn finalizing(mut self, finalize_percent: u8) -> Self {
+        self.phase = DownloadPhase::Finalizing;
+        self.finalize_percent = finalize_percent.min(100);
+        self
+    }
+
+    /// Only reported after validation passes
+    pub fn complete(mut self) -> Self {
+        self.phase = DownloadPhase::Complete;
+        self.finalize_percent = 100;
+        self.percent = 100;
+        self
+    }
+
+    /// Announce resume attempt `retry_attempt` of `max_retries`
+    pub fn with_retry_attempt(mut self, retry_attempt: u32, max_retries: u32) -> Self {
+        self.retry_attempt = retry_attempt;
+        self.max_retries = max_retries;
+        self
+    }
+}
+
+/// How a model download attempt ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DownloadOutcome {
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+/// One finished model download attempt, kept in the local download history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadRecord {
+    pub model_name: String,
+    pub timestamp: String, // RFC 3339, when the attempt ended
+    pub outcome: DownloadOutcome,
+    pub error: Option<String>,
+    pub resumed_from_bytes: u64,
+    pub downloaded_bytes: u64, // File offset reached; a repeated failure offset hints at a mirror issue
+    pub total_bytes: u64,
+    pub retries: u32,
+    pub duration_secs: f64,
+    pub avg_speed_mbps: f64,
+}
+
+/// Progress of the current download attempt, for its history record
+#[derive(Debug, Default)]
+struct DownloadStats {
+    started: bool, // Set once the transfer begins; earlier exits aren't recorded
+    resumed_from_bytes: u64,
+    downloaded_bytes: u64,
+    total_bytes: u64,
+    retries: u32,
+}
+
+/// Counters shared by the connections of a multi-connection download
+#[derive(Debug, Default)]
+struct RangeCounters {
+    downloaded: AtomicU64,
+    retries: AtomicU32,        // Resume attempts across all connections, for the history
+    highest_attempt: AtomicU32, // Attempt number of the connection furthest into its retries
+}
+
+const DOWNLOAD_HISTORY_FILE: &str = "download-history.json";
+const MAX_DOWNLOAD_HISTORY: usize = 50;
+
+/// Append `record` to the history file, keeping only the newest entries.
+/// An unreadable history is replaced rather than blocking the download.
+async fn append_download_history(path: &Path, record: DownloadRecord) -> Result<()> {
+    let mut history = read_download_history(path).await;
+    history.push(record);
+    if history.len() > MAX_DOWNLOAD_HISTORY {
+        history.drain(..history.len() - MAX_DOWNLOAD_HISTORY);
+    }
+    let json = serde_json::to_vec_pretty(&history)?;
+    fs::write(path, json).await?;
+    Ok(())
+}
+
+async fn read_download_history(path: &Path) -> Vec<DownloadRecord> {
+    match fs::read(path).await {
+        Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+            log::warn!("Ignoring unreadable download history {}: {}", path.display(), e);
+            Vec::new()
+        }),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Sidecar written next to a model file once it is known to be complete, so the
+/// next launch doesn't have to guess from the file size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct CompletionMarker {
+    size: u64,
+    verified: bool,
+}
+
+fn completion_marker_path(model_path: &Path) -> PathBuf {
+    let mut path = model_path.as_os_str().to_owned();
+    path.push(".complete");
+    PathBuf::from(path)
+}
+
+fn read_completion_marker(model_path: &Path) -> Option<CompletionMarker> {
+    let bytes = std::fs::read(completion_marker_path(model_path)).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+fn write_completion_marker(model_path: &Path, marker: CompletionMarker) {
+    let path = completion_marker_path(model_path);
+    let result = serde_json::to_vec(&marker)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| std::fs::write(&path, json).map_err(anyhow::Error::from));
+    if let Err(e) = result {
+        log::warn!("Failed to write {}: {}", path.display(), e);
+    }
+}
+
+/// SHA-256 of a file as lowercase hex. The file is read in chunks on a blocking
+/// thread, so multi-gigabyte models are never held in memory.
+async fn file_sha256(path: &Path) -> Result<String> {
+    use sha2::{Digest, Sha256};
+    use std::io::Read;
+
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || -> Result<String> {
+        let mut file = std::fs::File::open(&path)
+            .map_err(|e| anyhow!("Failed to open {}: {}", path.display(), e))?;
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0u8; 1024 * 1024];
+        loop {
+            let read = file
+                .read(&mut buffer)
+                .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    })
+    .await
+    .map_err(|e| anyhow!("Checksum task failed: {}", e))?
+}
+
+/// Free space kept beyond the model itself, so a download never fills the disk
+const DOWNLOAD_SPACE_MARGIN_BYTES: u64 = 200 * 1024 * 1024;
+
+/// Free bytes on the volume holding `path`, i.e. the disk with the longest
+/// mount point that contains it
+fn available_space(path: &Path) -> Result<u64> {
+    let path = path.canonicalize()?;
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
+        .ok_or_else(|| anyhow!("No disk found for {}", path.display()))
+}
+
+/// Error for a download needing `needed` bytes when only `available` are free
+fn insufficient_space(needed: u64, available: u64) -> Option<QwenAsrEngineError> {
+    const MB: u64 = 1024 * 1024;
+    (available < needed).then(|| {
+        QwenAsrEngineError::DownloadFailed(format!(
+            "Not enough disk space: {} MB required, {} MB available",
+            needed.div_ceil(MB),
+            available / MB
+        ))
+    })
+}
+
+/// Within 1% of the catalog size; `size_mb` is rounded so exact matches aren't expected
+fn is_full_size(file_size: u64, size_mb: u32) -> bool {
+    let expected = (size_mb as u64) * 1024 * 1024;
+    file_size > 0 && file_size >= (expected as f64 * 0.99) as u64
+}
+
+/// Default number of times a stalled or dropped download is resumed before giving up
+const DEFAULT_DOWNLOAD_RETRIES: u32 = 3;
+pub const MAX_DOWNLOAD_RETRIES: u32 = 10;
+
+/// Upper bound for parallel Range connections per download
+pub const MAX_DOWNLOAD_CONNECTIONS: u8 = 8;
+
+/// Temporary file a multi-connection download writes into. It is preallocated to
+/// full size, so it only takes the model's name once every range has arrived.
+fn parallel_part_path(model_path: &Path) -> PathBuf {
+    let mut path = model_path.as_os_str().to_owned();
+    path.push(".parts");
+    PathBuf::from(path)
+}
+
+/// Split `total` bytes into `connections` contiguous, disjoint ranges
+fn split_ranges(total: u64, connections: u64) -> Vec<std::ops::Range<u64>> {
+    let connections = connections.clamp(1, total.max(1));
+    let chunk = total.div_ceil(connections);
+    (0..connections)
+        .map(|i| (i * chunk).min(total)..((i + 1) * chunk).min(total))
+        .filter(|range| !range.is_empty())
+        .collect()
+}
+
+/// Error for a download that failed with `reason` after `retries` resume attempts
+fn retries_exhausted(reason: String, retries: u32) -> QwenAsrEngineError {
+    if retries == 0 {
+        QwenAsrEngineError::DownloadFailed(reason)
+    } else {
+        QwenAsrEngineError::DownloadFailed(format!("{} (gave up after {} retries)", reason, retries))
+    }
+}
+
+/// Full resource size from a `Content-Range: bytes 0-0/12345` header
+fn content_range_total(header: &str) -> Option<u64> {
+    header.rsplit_once('/')?.1.trim().parse().ok()
+}
+
+/// Wait before resume attempt `attempt` (1-based): 2s, 4s, 8s, ... capped at 30s
+fn download_retry_backoff(attempt: u32) -> Duration {
+    let secs = 2u64.saturating_pow(attempt.clamp(1, 5));
+    Duration::from_secs(secs.min(30))
+}
+
+/// Where and how Qwen ASR decodes, as chosen in the transcript settings.
+/// Saved alongside them and applied to the engine on startup.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QwenAsrConfig {
+    pub use_gpu: bool,
+    pub gpu_device: i32,
+    // 0 = let the library pick
+    pub n_threads: i32,
+    pub temperature: f32,
+}
+
+impl Default for QwenAsrConfig {
+    fn default() -> Self {
+        let params = QwenAsrParams::default();
+        Self {
+            use_gpu: params.use_gpu,
+            gpu_device: params.gpu_device,
+            n_threads: params.n_threads,
+            temperature: params.temperature,
+        }
+    }
+}
+
+impl QwenAsrConfig {
+    /// Reject values the library can't use. `gpu_device_count` is only checked when
+    /// the build has a GPU backend; without one, decoding falls back to the CPU anyway.
+    fn validate(&self, gpu_device_count: i32) -> Result<()> {
+        validate_temperature(self.temperature)?;
+        if self.n_threads < 0 {
+            return Err(anyhow!("Thread count must be 0 (automatic) or more, got {}", self.n_threads));
         }
+        if self.gpu_device < 0 {
+            return Err(anyhow!("GPU device must be 0 or more, got {}", self.gpu_device));
+        }
+        if self.use_gpu && gpu_device_count > 0 && self.gpu_device >= gpu_device_count {
+            return Err(anyhow!(
+                "GPU device {} not found ({} device(s) available)",
+                self.gpu_device,
+                gpu_device_count
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn validate_temperature(temperature: f32) -> Result<()> {
+    if !temperature.is_finite() || !(0.0..=2.0).contains(&temperature) {
+        return Err(anyhow!(
+            "Temperature must be between 0.0 and 2.0, got {}",
+            temperature
+        ));
     }
+    Ok(())
+}
+
+/// Progress of a windowed (long audio) transcription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowProgress {
+    pub window: usize,
+    pub total_windows: usize,
+    pub position_seconds: f64,
+    pub total_seconds: f64,
+    pub text: String, // Stitched transcript so far
 }
 
 /// Information about a Qwen ASR model
@@ -72,6 +776,10 @@ pub struct ModelInfo {
     pub speed: String,
     pub status: ModelStatus,
     pub description: String,
+    // The downloader saw every byte the server announced. False for files that were
+    // only size- and header-checked, e.g. after a crash before the download finished up.
+    #[serde(default)]
+    pub verified: bool,
 }
 
 #[derive(Debug)]
@@ -110,10 +818,30 @@ struct ModelConfig {
     name: &'static str,
     filename: &'static str,
     size_mb: u32,
+    // Longest audio, in seconds, the model's encoder handles in one pass
+    context_seconds: usize,
     quantization: QuantizationType,
     speed: &'static str,
     description: &'static str,
     huggingface_repo: &'static str,
+    // SHA-256 of the published file (hex), compared after every download. When empty,
+    // the digest Hugging Face publishes for the LFS file is looked up instead.
+    sha256: &'static str,
+}
+
+impl ModelConfig {
+    fn download_url(&self) -> String {
+        format!("https://huggingface.co/{}/resolve/main/{}", self.huggingface_repo, self.filename)
+    }
+}
+
+/// SHA-256 of an LFS file from a Hugging Face `resolve` response. The
+/// `X-Linked-Etag` header holds the LFS object id, which is the file's SHA-256;
+/// files stored in git directly carry a shorter blob id and yield None.
+fn lfs_sha256(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let etag = headers.get("x-linked-etag")?.to_str().ok()?;
+    let digest = etag.trim_start_matches("W/").trim_matches('"');
+    (digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit())).then(|| digest.to_ascii_lowercase())
 }
 
 const MODEL_CONFIGS: [ModelConfig; 4] = [
@@ -121,47 +849,137 @@ const MODEL_CONFIGS: [ModelConfig; 4] = [
         name: "qwen3-asr-1.7b-q8_0",
         filename: "qwen3-asr-1.7b-q8_0.gguf",
         size_mb: 3000,
+        context_seconds: 30,
         quantization: QuantizationType::Q8_0,
         speed: "Recommended (Q8)",
         description: "1.7B multilingual model, best quality/speed balance",
         huggingface_repo: "FlippyDora/qwen3-asr-1.7b-GGUF",
+        sha256: "",
     },
     ModelConfig {
         name: "qwen3-asr-1.7b-f16",
         filename: "qwen3-asr-1.7b-f16.gguf",
         size_mb: 4200,
+        context_seconds: 30,
         quantization: QuantizationType::F16,
         speed: "Best Quality (F16)",
         description: "1.7B multilingual model, highest accuracy",
         huggingface_repo: "FlippyDora/qwen3-asr-1.7b-GGUF",
+        sha256: "",
     },
     ModelConfig {
         name: "qwen3-asr-0.6b-q8_0",
         filename: "qwen3-asr-0.6b-q8_0.gguf",
         size_mb: 1350,
+        context_seconds: 30,
         quantization: QuantizationType::Q8_0,
         speed: "Fast (Q8)",
         description: "0.6B multilingual model, best speed/quality balance",
         huggingface_repo: "FlippyDora/qwen3-asr-0.6b-GGUF",
+        sha256: "",
     },
     ModelConfig {
         name: "qwen3-asr-0.6b-f16",
         filename: "qwen3-asr-0.6b-f16.gguf",
         size_mb: 1880,
+        context_seconds: 30,
         quantization: QuantizationType::F16,
         speed: "Accurate (F16)",
         description: "0.6B multilingual model, higher accuracy",
         huggingface_repo: "FlippyDora/qwen3-asr-0.6b-GGUF",
+        sha256: "",
     },
 ];
 
+/// Subdirectory of the models directory holding user-imported GGUFs, named `<model>.gguf`
+const IMPORTED_MODELS_DIR: &str = "imported";
+
+/// File name for an imported model called `display_name`. Spaces become dashes; anything
+/// that could escape the models directory is rejected.
+fn imported_model_name(display_name: &str) -> Result<String> {
+    let name = display_name.trim();
+    let name = name.strip_suffix(".gguf").unwrap_or(name).replace(char::is_whitespace, "-");
+    if name.is_empty() || name.starts_with('.') {
+        return Err(anyhow!("Invalid model name '{}'", display_name));
+    }
+    if let Some(c) = name.chars().find(|c| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.'))) {
+        return Err(anyhow!("Invalid model name '{}': '{}' is not allowed", display_name, c));
+    }
+    Ok(name)
+}
+
+/// Imported files don't come from the catalog, so their quantization is read off the name
+fn quantization_from_name(name: &str) -> QuantizationType {
+    if name.to_lowercase().contains("f16") {
+        QuantizationType::F16
+    } else {
+        QuantizationType::Q8_0
+    }
+}
+
+/// Model to load when none is configured: the first Q8_0 model in catalog order
+/// (best speed/quality across model families), else the first available one.
+pub fn preferred_model(available: &[ModelInfo]) -> Option<&ModelInfo> {
+    available
+        .iter()
+        .find(|model| model.quantization == QuantizationType::Q8_0)
+        .or_else(|| available.first())
+}
+
 pub struct QwenAsrEngine {
     models_dir: PathBuf,
     current_model: Arc<RwLock<Option<QwenAsrModel>>>,
+    // Aborts the loaded model's running transcription without waiting for its lock
+    discard_handle: Arc<std::sync::Mutex<Option<QwenAsrDiscardHandle>>>,
     current_model_name: Arc<RwLock<Option<String>>>,
+    // A model loaded in the background so the next switch to it is instant
+    staged_model: Arc<RwLock<Option<(String, QwenAsrModel)>>>,
     pub(crate) available_models: Arc<RwLock<HashMap<String, ModelInfo>>>,
     cancel_download_flag: Arc<RwLock<Option<String>>>,
+    // Resume attempts per download after a timeout or stream error
+    download_retries: Arc<AtomicU32>,
+    // Parallel Range connections per download (1 = a single stream)
+    download_connections: Arc<AtomicU8>,
     pub(crate) active_downloads: Arc<RwLock<HashSet<String>>>,
+    decode_params: Arc<RwLock<QwenAsrParams>>,
+    decoder_context_enabled: Arc<AtomicBool>,
+    in_flight: Arc<AtomicUsize>,
+    // Last transcribe call (start or end), for the idle-unload timer
+    last_activity: Arc<std::sync::Mutex<Instant>>,
+    // Seconds without a transcribe call before the model is unloaded (0 = never)
+    idle_unload_secs: Arc<AtomicU64>,
+    // Model the idle timer unloaded, so the next validate reloads that one
+    idle_unloaded_model: Arc<std::sync::Mutex<Option<String>>>,
+    // Pinned models are exempt from idle unload
+    pinned: Arc<AtomicBool>,
+    // Entries kept in `transcript_cache` (0 = caching disabled)
+    transcript_cache_entries: Arc<AtomicUsize>,
+    transcript_cache: Arc<std::sync::Mutex<TranscriptCache>>,
+}
+
+/// Counts a transcription as in flight for as long as it is alive, and marks
+/// the engine as active when it starts and ends.
+struct InFlightGuard {
+    in_flight: Arc<AtomicUsize>,
+    last_activity: Arc<std::sync::Mutex<Instant>>,
+}
+
+impl InFlightGuard {
+    fn new(engine: &QwenAsrEngine) -> Self {
+        engine.in_flight.fetch_add(1, Ordering::SeqCst);
+        engine.touch_activity();
+        Self {
+            in_flight: engine.in_flight.clone(),
+            last_activity: engine.last_activity.clone(),
+        }
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        *self.last_activity.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 impl QwenAsrEngine {
@@ -204,41 +1022,61 @@ impl QwenAsrEngine {
         Ok(Self {
             models_dir,
             current_model: Arc::new(RwLock::new(None)),
+            discard_handle: Arc::new(std::sync::Mutex::new(None)),
             current_model_name: Arc::new(RwLock::new(None)),
+            staged_model: Arc::new(RwLock::new(None)),
             available_models: Arc::new(RwLock::new(HashMap::new())),
             cancel_download_flag: Arc::new(RwLock::new(None)),
+            download_retries: Arc::new(AtomicU32::new(DEFAULT_DOWNLOAD_RETRIES)),
+            download_connections: Arc::new(AtomicU8::new(1)),
             active_downloads: Arc::new(RwLock::new(HashSet::new())),
+            decode_params: Arc::new(RwLock::new(QwenAsrParams::default())),
+            decoder_context_enabled: Arc::new(AtomicBool::new(false)),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            last_activity: Arc::new(std::sync::Mutex::new(Instant::now())),
+            idle_unload_secs: Arc::new(AtomicU64::new(0)),
+            idle_unloaded_model: Arc::new(std::sync::Mutex::new(None)),
+            pinned: Arc::new(AtomicBool::new(false)),
+            transcript_cache_entries: Arc::new(AtomicUsize::new(0)),
+            transcript_cache: Arc::new(std::sync::Mutex::new(TranscriptCache::default())),
         })
     }
 
     /// Discover available Qwen ASR models (single GGUF files)
+    ///
+    /// An unreadable models directory (unmounted drive, changed permissions) yields an
+    /// empty list rather than an error, and a model file that can't be accessed is
+    /// reported as `Error` without failing discovery of the others.
     pub async fn discover_models(&self) -> Result<Vec<ModelInfo>> {
         let models_dir = &self.models_dir;
         let mut models = Vec::new();
 
+        if let Err(e) = fs::read_dir(models_dir).await {
+            log::warn!(
+                "Qwen ASR models directory {} is unavailable, no models listed: {}",
+                models_dir.display(),
+                e
+            );
+            self.available_models.write().await.clear();
+            return Ok(models);
+        }
+
         let active_downloads = self.active_downloads.read().await;
 
         for config in Self::model_configs() {
             let model_path = models_dir.join(config.filename);
 
-            let status = if active_downloads.contains(config.name) {
-                ModelStatus::Downloading { progress: 0 }
-            } else if model_path.exists() {
-                match self.validate_gguf_file(&model_path).await {
-                    Ok(_) => ModelStatus::Available,
-                    Err(_) => {
-                        log::warn!("GGUF file {} appears corrupted", config.filename);
-                        let file_size = std::fs::metadata(&model_path)
-                            .map(|m| m.len())
-                            .unwrap_or(0);
-                        ModelStatus::Corrupted {
-                            file_size,
-                            expected_min_size: (config.size_mb as u64) * 1024 * 1024,
-                        }
+            let (status, verified) = if active_downloads.contains(config.name) {
+                (ModelStatus::Downloading { progress: 0 }, false)
+            } else {
+                match fs::try_exists(&model_path).await {
+                    Ok(true) => self.finalize_model_file(config, &model_path).await,
+                    Ok(false) => (ModelStatus::Missing, false),
+                    Err(e) => {
+                        log::warn!("Cannot access Qwen ASR model {}: {}", model_path.display(), e);
+                        (ModelStatus::Error(format!("Cannot access {}: {}", config.filename, e)), false)
                     }
                 }
-            } else {
-                ModelStatus::Missing
             };
 
             let model_info = ModelInfo {
@@ -249,11 +1087,14 @@ impl QwenAsrEngine {
                 speed: config.speed.to_string(),
                 status,
                 description: config.description.to_string(),
+                verified,
             };
 
             models.push(model_info);
         }
 
+        models.extend(self.discover_imported_models().await);
+
         // Update internal cache
         let mut available_models = self.available_models.write().await;
         available_models.clear();
@@ -264,88 +1105,567 @@ impl QwenAsrEngine {
         Ok(models)
     }
 
-    /// Validate GGUF file by checking magic header and minimum size
-    async fn validate_gguf_file(&self, file_path: &PathBuf) -> Result<()> {
-        use std::io::Read;
-
-        let metadata = std::fs::metadata(file_path)
-            .map_err(|e| anyhow!("Failed to read file metadata: {}", e))?;
+    /// Models imported from local files, after the catalog models
+    async fn discover_imported_models(&self) -> Vec<ModelInfo> {
+        let dir = self.models_dir.join(IMPORTED_MODELS_DIR);
+        let Ok(mut entries) = fs::read_dir(&dir).await else {
+            return Vec::new();
+        };
 
-        // GGUF files must be at least a few KB (header + metadata)
-        if metadata.len() < 1024 {
-            return Err(anyhow!("File too small to be a valid GGUF: {} bytes", metadata.len()));
+        let mut paths = Vec::new();
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "gguf") {
+                paths.push(path);
+            }
         }
+        paths.sort();
 
-        // Check GGUF magic header: "GGUF" = bytes [0x47, 0x47, 0x55, 0x46]
-        // As little-endian u32: 0x46554747
-        let mut file = std::fs::File::open(file_path)
-            .map_err(|e| anyhow!("Failed to open file: {}", e))?;
-        let mut magic_bytes = [0u8; 4];
-        file.read_exact(&mut magic_bytes)
-            .map_err(|e| anyhow!("Failed to read GGUF header: {}", e))?;
-
-        let magic = u32::from_le_bytes(magic_bytes);
-        if magic != 0x46554747 {
-            return Err(anyhow!("Invalid GGUF magic header: 0x{:08X} (expected 0x46554747)", magic));
+        let mut models = Vec::new();
+        for path in paths {
+            models.push(self.imported_model_info(path).await);
         }
-
-        Ok(())
+        models
     }
 
-    /// Load a Qwen ASR model
-    pub async fn load_model(&self, model_name: &str) -> Result<()> {
-        let model_info = {
-            let models = self.available_models.read().await;
-            models.get(model_name).cloned()
-        };
-
-        let model_info = model_info.ok_or_else(|| anyhow!("Model {} not found", model_name))?;
-
-        match model_info.status {
-            ModelStatus::Available => {
-                // Check if already loaded
-                if let Some(current_model) = self.current_model_name.read().await.as_ref() {
-                    if current_model == model_name {
-                        log::info!("Qwen ASR model {} is already loaded, skipping reload", model_name);
-                        return Ok(());
-                    }
-                    log::info!("Unloading current Qwen ASR model '{}' before loading '{}'", current_model, model_name);
-                    self.unload_model().await;
+    async fn imported_model_info(&self, path: PathBuf) -> ModelInfo {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file_size = fs::metadata(&path).await.map(|m| m.len()).unwrap_or(0);
+        let status = match self.validate_gguf_file(&path).await {
+            Ok(()) => ModelStatus::Available,
+            Err(e) => {
+                log::warn!("Imported model {} appears corrupted: {}", path.display(), e);
+                ModelStatus::Corrupted {
+                    file_size,
+                    expected_min_size: file_size,
                 }
+            }
+        };
+        ModelInfo {
+            quantization: quantization_from_name(&name),
+            name,
+            path,
+            size_mb: (file_size / (1024 * 1024)) as u32,
+            speed: "Imported".to_string(),
+            status,
+            description: "Imported from a local file".to_string(),
+            verified: false,
+        }
+    }
 
-                log::info!("Loading Qwen ASR model: {} from {}", model_name, model_info.path.display());
-
-                let model = QwenAsrModel::new(&model_info.path)
-                    .map_err(|e| anyhow!("Failed to load Qwen ASR model {}: {}", model_name, e))?;
+    /// Add a Qwen3-ASR GGUF the user already has, instead of downloading it. The file
+    /// is hard-linked into the models directory when possible and copied otherwise.
+    pub async fn import_local_model(&self, src_path: &Path, display_name: &str) -> Result<ModelInfo> {
+        let name = imported_model_name(display_name)?;
+        if Self::get_model_config(&name).is_some() || self.available_models.read().await.contains_key(&name) {
+            return Err(anyhow!("A model named '{}' already exists", name));
+        }
 
-                *self.current_model.write().await = Some(model);
-                *self.current_model_name.write().await = Some(model_name.to_string());
+        self.validate_gguf_file(src_path)
+            .await
+            .map_err(|e| anyhow!("{} is not a valid GGUF model: {}", src_path.display(), e))?;
+        check_model_compatibility(src_path)?;
+
+        let dir = self.models_dir.join(IMPORTED_MODELS_DIR);
+        fs::create_dir_all(&dir)
+            .await
+            .map_err(|e| anyhow!("Failed to create {}: {}", dir.display(), e))?;
+        let dest = dir.join(format!("{}.gguf", name));
+        if fs::try_exists(&dest).await.unwrap_or(true) {
+            return Err(anyhow!("A model named '{}' already exists", name));
+        }
 
-                log::info!("Successfully loaded Qwen ASR model: {} ({:?})", model_name, model_info.quantization);
-                Ok(())
+        // Copy under a temporary name so an interrupted import never looks like a model
+        let partial = dir.join(format!("{}.gguf.partial", name));
+        if fs::hard_link(src_path, &dest).await.is_err() {
+            if let Err(e) = fs::copy(src_path, &partial).await {
+                let _ = fs::remove_file(&partial).await;
+                return Err(anyhow!("Failed to copy {}: {}", src_path.display(), e));
             }
-            ModelStatus::Missing => Err(anyhow!("Qwen ASR model {} is not downloaded", model_name)),
-            ModelStatus::Downloading { .. } => Err(anyhow!("Qwen ASR model {} is currently downloading", model_name)),
-            ModelStatus::Error(ref err) => Err(anyhow!("Qwen ASR model {} has error: {}", model_name, err)),
-            ModelStatus::Corrupted { .. } => Err(anyhow!("Qwen ASR model {} is corrupted", model_name)),
+            fs::rename(&partial, &dest)
+                .await
+                .map_err(|e| anyhow!("Failed to move imported model into place: {}", e))?;
         }
+
+        let model = self.imported_model_info(dest).await;
+        log::info!("Imported Qwen ASR model '{}' from {}", name, src_path.display());
+        self.available_models
+            .write()
+            .await
+            .insert(model.name.clone(), model.clone());
+        Ok(model)
     }
 
-    /// Unload the current model
-    pub async fn unload_model(&self) -> bool {
-        let mut model_guard = self.current_model.write().await;
-        let unloaded = model_guard.take().is_some();
-        if unloaded {
-            log::info!("Qwen ASR model unloaded");
+    /// Cached models whose status matches `filter`, in catalog order.
+    /// Disk is only scanned when the cache has never been populated.
+    pub async fn cached_models_where<F>(&self, filter: F) -> Result<Vec<ModelInfo>>
+    where
+        F: Fn(&ModelStatus) -> bool,
+    {
+        if self.available_models.read().await.is_empty() {
+            self.discover_models().await?;
         }
-        let mut model_name_guard = self.current_model_name.write().await;
-        model_name_guard.take();
-        unloaded
+
+        let models = self.available_models.read().await;
+        // Imported models follow the catalog, by name
+        let mut imported: Vec<&ModelInfo> = models
+            .values()
+            .filter(|model| Self::get_model_config(&model.name).is_none())
+            .collect();
+        imported.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self::model_configs()
+            .iter()
+            .filter_map(|config| models.get(config.name))
+            .chain(imported)
+            .filter(|model| filter(&model.status))
+            .cloned()
+            .collect())
     }
 
-    /// Get the currently loaded model name
-    pub async fn get_current_model(&self) -> Option<String> {
-        self.current_model_name.read().await.clone()
+    /// Downloaded, valid models from the discovery cache
+    pub async fn available_models(&self) -> Result<Vec<ModelInfo>> {
+        self.cached_models_where(|status| matches!(status, ModelStatus::Available))
+            .await
+    }
+
+    /// Like `available_models`, but re-validates the models directory first
+    pub async fn refresh_available_models(&self) -> Result<Vec<ModelInfo>> {
+        self.discover_models().await?;
+        self.available_models().await
+    }
+
+    /// Names of the models in `available_models`
+    pub async fn available_model_names(&self) -> Result<Vec<String>> {
+        Ok(self
+            .available_models()
+            .await?
+            .into_iter()
+            .map(|model| model.name)
+            .collect())
+    }
+
+    /// Whether any downloaded model is ready to load, without re-scanning disk
+    pub async fn has_available_models(&self) -> Result<bool> {
+        Ok(!self.available_models().await?.is_empty())
+    }
+
+    /// Status of a model file found on disk.
+    ///
+    /// Files with a completion marker only need the header check. A full-size
+    /// file without one (the app stopped before the download finished up) is
+    /// marked Available as unverified instead of being re-downloaded, and a
+    /// short one is an interrupted download that the next download resumes.
+    /// Only files with a bad header, or that changed size since completing,
+    /// are Corrupted.
+    async fn finalize_model_file(&self, config: &ModelConfig, model_path: &Path) -> (ModelStatus, bool) {
+        // I/O failures mean the file is unreachable, not that its contents are bad
+        let file_size = match std::fs::File::open(model_path).and_then(|f| f.metadata()) {
+            Ok(metadata) => metadata.len(),
+            Err(e) => {
+                log::warn!("Cannot read GGUF file {}: {}", config.filename, e);
+                return (ModelStatus::Error(format!("Cannot read {}: {}", config.filename, e)), false);
+            }
+        };
+        let corrupted = ModelStatus::Corrupted {
+            file_size,
+            expected_min_size: (config.size_mb as u64) * 1024 * 1024,
+        };
+
+        let marker = read_completion_marker(model_path);
+        if marker.is_none() && !is_full_size(file_size, config.size_mb) {
+            log::info!(
+                "GGUF file {} is an interrupted download ({} bytes), it will be resumed",
+                config.filename,
+                file_size
+            );
+            return (ModelStatus::Missing, false);
+        }
+
+        if let Err(e) = self.validate_gguf_file(model_path).await {
+            log::warn!("GGUF file {} appears corrupted: {}", config.filename, e);
+            return (corrupted, false);
+        }
+
+        match marker {
+            Some(marker) if marker.size == file_size => (ModelStatus::Available, marker.verified),
+            Some(marker) => {
+                log::warn!(
+                    "GGUF file {} changed size since it was downloaded ({} -> {} bytes)",
+                    config.filename,
+                    marker.size,
+                    file_size
+                );
+                (corrupted, false)
+            }
+            None => {
+                log::info!("Finalized previously downloaded {} (unverified)", config.filename);
+                write_completion_marker(model_path, CompletionMarker { size: file_size, verified: false });
+                (ModelStatus::Available, false)
+            }
+        }
+    }
+
+    /// Validate GGUF file by checking the header and minimum size
+    async fn validate_gguf_file(&self, file_path: &Path) -> Result<()> {
+        let header = read_gguf_header(file_path)?;
+        log::debug!(
+            "GGUF {} header: version {}, {} tensors, {} metadata entries",
+            file_path.display(),
+            header.version,
+            header.tensor_count,
+            header.metadata_kv_count
+        );
+        Ok(())
+    }
+
+    /// Re-check a downloaded model against its published SHA-256. A mismatching
+    /// file is deleted and the model marked Corrupted.
+    pub async fn verify_model(&self, model_name: &str) -> Result<()> {
+        let config = Self::get_model_config(model_name)
+            .ok_or_else(|| anyhow!("Unsupported model: {}", model_name))?;
+        let sha256 = self.published_sha256(config).await.unwrap_or_default();
+        self.verify_model_file(config, &sha256).await
+    }
+
+    async fn verify_model_file(&self, config: &ModelConfig, sha256: &str) -> Result<()> {
+        if sha256.is_empty() {
+            return Err(anyhow!("No checksum is published for {}", config.name));
+        }
+        if self.active_downloads.read().await.contains(config.name) {
+            return Err(anyhow!("{} is still downloading", config.name));
+        }
+
+        let path = self.models_dir.join(config.filename);
+        let file_size = fs::metadata(&path)
+            .await
+            .map_err(|e| anyhow!("Cannot read {}: {}", config.filename, e))?
+            .len();
+        let actual = file_sha256(&path).await?;
+        if !actual.eq_ignore_ascii_case(sha256) {
+            self.discard_corrupted_file(config.name, &path, file_size, (config.size_mb as u64) * 1024 * 1024)
+                .await;
+            return Err(anyhow!(
+                "Checksum mismatch for {}: expected {}, got {}",
+                config.filename,
+                sha256,
+                actual
+            ));
+        }
+
+        log::info!("Checksum verified for {}", config.filename);
+        write_completion_marker(&path, CompletionMarker { size: file_size, verified: true });
+        let mut models = self.available_models.write().await;
+        if let Some(model) = models.get_mut(config.name) {
+            model.status = ModelStatus::Available;
+            model.verified = true;
+        }
+        Ok(())
+    }
+
+    /// Expected SHA-256 of `config`'s file: the pinned digest, else the one
+    /// Hugging Face publishes for the LFS object. None when neither is available
+    /// (e.g. offline), in which case the checksum is skipped.
+    async fn published_sha256(&self, config: &ModelConfig) -> Option<String> {
+        if !config.sha256.is_empty() {
+            return Some(config.sha256.to_string());
+        }
+        // The redirect to the CDN drops the LFS headers, so don't follow it
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(Duration::from_secs(15))
+            .build()
+            .ok()?;
+        match client.head(config.download_url()).send().await {
+            Ok(response) => {
+                let digest = lfs_sha256(response.headers());
+                if digest.is_none() {
+                    log::warn!("No published checksum for {}, skipping verification", config.filename);
+                }
+                digest
+            }
+            Err(e) => {
+                log::warn!("Could not look up the checksum of {}: {}", config.filename, e);
+                None
+            }
+        }
+    }
+
+    /// Delete a model file that failed its checksum and mark the model Corrupted
+    async fn discard_corrupted_file(&self, model_name: &str, path: &Path, file_size: u64, expected_size: u64) {
+        if let Err(e) = fs::remove_file(path).await {
+            log::warn!("Failed to delete corrupted {}: {}", path.display(), e);
+        }
+        let _ = fs::remove_file(completion_marker_path(path)).await;
+
+        let mut models = self.available_models.write().await;
+        if let Some(model) = models.get_mut(model_name) {
+            model.status = ModelStatus::Corrupted {
+                file_size,
+                expected_min_size: expected_size,
+            };
+            model.verified = false;
+        }
+    }
+
+    /// Load a Qwen ASR model
+    pub async fn load_model(&self, model_name: &str) -> Result<()> {
+        // A freshly loaded model gets a full idle period before it can be unloaded
+        self.touch_activity();
+        let model_info = {
+            let models = self.available_models.read().await;
+            models.get(model_name).cloned()
+        };
+
+        let model_info = model_info.ok_or_else(|| anyhow!("Model {} not found", model_name))?;
+
+        match model_info.status {
+            ModelStatus::Available => {
+                // Check if already loaded
+                if self.current_model_name.read().await.as_deref() == Some(model_name) {
+                    log::info!("Qwen ASR model {} is already loaded, skipping reload", model_name);
+                    return Ok(());
+                }
+
+                // Take a preloaded copy before unloading, which clears the staging slot
+                let staged = {
+                    let mut staged_guard = self.staged_model.write().await;
+                    if staged_guard.as_ref().is_some_and(|(name, _)| name == model_name) {
+                        staged_guard.take().map(|(_, model)| model)
+                    } else {
+                        None
+                    }
+                };
+
+                if let Some(current_model) = self.get_current_model().await {
+                    log::info!("Unloading current Qwen ASR model '{}' before loading '{}'", current_model, model_name);
+                    self.unload_model().await;
+                }
+
+                let model = match staged {
+                    Some(model) => {
+                        log::info!("Using preloaded Qwen ASR model: {}", model_name);
+                        model
+                    }
+                    None => {
+                        log::info!("Loading Qwen ASR model: {} from {}", model_name, model_info.path.display());
+                        check_model_compatibility(&model_info.path)?;
+                        QwenAsrModel::new(&model_info.path)
+                            .map_err(|e| anyhow!("Failed to load Qwen ASR model {}: {}", model_name, e))?
+                    }
+                };
+
+                // Failure only costs a slower first chunk, so it doesn't fail the load.
+                // The warmup is a full decode, so keep it off the async runtime.
+                let params = self.get_decode_params().await;
+                let model = tokio::task::spawn_blocking(move || {
+                    model.warmup(&params);
+                    model
+                })
+                .await
+                .map_err(|e| anyhow!("Qwen ASR warmup task failed: {}", e))?;
+
+                *self.discard_handle.lock().unwrap_or_else(|e| e.into_inner()) = Some(model.discard_handle());
+                *self.current_model.write().await = Some(model);
+                *self.current_model_name.write().await = Some(model_name.to_string());
+                self.idle_unloaded_model.lock().unwrap_or_else(|e| e.into_inner()).take();
+
+                log::info!("Successfully loaded Qwen ASR model: {} ({:?})", model_name, model_info.quantization);
+                Ok(())
+            }
+            ModelStatus::Missing => Err(anyhow!("Qwen ASR model {} is not downloaded", model_name)),
+            ModelStatus::Downloading { .. } => Err(anyhow!("Qwen ASR model {} is currently downloading", model_name)),
+            ModelStatus::Error(ref err) => Err(anyhow!("Qwen ASR model {} has error: {}", model_name, err)),
+            ModelStatus::Corrupted { .. } => Err(anyhow!("Qwen ASR model {} is corrupted", model_name)),
+        }
+    }
+
+    /// Warm up the loaded model if its warmup hasn't run yet (see
+    /// [`QwenAsrModel::warmup`]). Returns false when no model is loaded or the
+    /// warmup decode failed.
+    pub async fn warm_up(&self) -> bool {
+        let params = self.get_decode_params().await;
+        let current_model = Arc::clone(&self.current_model);
+        tokio::task::spawn_blocking(move || {
+            let model_guard = current_model.blocking_write();
+            model_guard.as_ref().is_some_and(|model| model.warmup(&params))
+        })
+        .await
+        .unwrap_or(false)
+    }
+
+    /// Unload the current model (and any preloaded one)
+    pub async fn unload_model(&self) -> bool {
+        let mut model_guard = self.current_model.write().await;
+        let unloaded = model_guard.take().is_some();
+        if unloaded {
+            log::info!("Qwen ASR model unloaded");
+        }
+        let mut model_name_guard = self.current_model_name.write().await;
+        model_name_guard.take();
+        if let Some((staged_name, _)) = self.staged_model.write().await.take() {
+            log::info!("Dropped preloaded Qwen ASR model '{}'", staged_name);
+        }
+        unloaded
+    }
+
+    /// Unload the current model if it has gone the idle timeout without a
+    /// transcribe call. Pinned models and running transcriptions are left alone.
+    /// Returns the unloaded model's name.
+    pub async fn unload_if_idle(&self) -> Option<String> {
+        let timeout_secs = self.idle_unload_secs.load(Ordering::SeqCst);
+        if !idle_unload_due(timeout_secs, self.is_model_pinned(), self.idle_duration()) {
+            return None;
+        }
+
+        // Re-checked under the model lock so a transcription that is already
+        // waiting on the model still finds it loaded
+        let mut model_guard = self.current_model.write().await;
+        if self.is_transcribing() || model_guard.take().is_none() {
+            return None;
+        }
+        drop(model_guard);
+
+        let model_name = self.current_model_name.write().await.take().unwrap_or_default();
+        self.staged_model.write().await.take();
+        *self.idle_unloaded_model.lock().unwrap_or_else(|e| e.into_inner()) = Some(model_name.clone());
+        log::info!("Qwen ASR model '{}' unloaded after {}s idle", model_name, timeout_secs);
+        Some(model_name)
+    }
+
+    /// Set how long without a transcribe call unloads the model; None (or 0) disables it
+    pub fn set_idle_unload_secs(&self, secs: Option<u64>) {
+        let secs = secs.unwrap_or(0);
+        self.idle_unload_secs.store(secs, Ordering::SeqCst);
+        log::info!("Qwen ASR idle unload set to {}s (0 = never)", secs);
+    }
+
+    pub fn get_idle_unload_secs(&self) -> Option<u64> {
+        Some(self.idle_unload_secs.load(Ordering::SeqCst)).filter(|&secs| secs > 0)
+    }
+
+    /// Set how many minutes without a transcribe call unload the model (0 = never)
+    pub fn set_idle_unload_minutes(&self, minutes: u64) {
+        self.set_idle_unload_secs(Some(minutes * 60));
+    }
+
+    pub fn get_idle_unload_minutes(&self) -> u64 {
+        self.get_idle_unload_secs().unwrap_or(0) / 60
+    }
+
+    /// The model the idle timer last unloaded, until another model is loaded
+    pub fn idle_unloaded_model(&self) -> Option<String> {
+        self.idle_unloaded_model.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Keep the current model loaded regardless of the idle timer
+    pub fn set_model_pinned(&self, pinned: bool) {
+        self.pinned.store(pinned, Ordering::SeqCst);
+    }
+
+    pub fn is_model_pinned(&self) -> bool {
+        self.pinned.load(Ordering::SeqCst)
+    }
+
+    /// Restart the idle timer, e.g. while a recording keeps the model in use
+    pub fn touch_activity(&self) {
+        *self.last_activity.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+    }
+
+    fn idle_duration(&self) -> Duration {
+        self.last_activity.lock().unwrap_or_else(|e| e.into_inner()).elapsed()
+    }
+
+    /// Load a model into the staging slot without making it current, so a later
+    /// `load_model` with the same name is instant. Replaces any previously staged
+    /// model. Returns `false` if the model is already current or staged.
+    pub async fn preload_model(&self, model_name: &str) -> Result<bool> {
+        if self.current_model_name.read().await.as_deref() == Some(model_name) {
+            return Ok(false);
+        }
+        if self
+            .staged_model
+            .read()
+            .await
+            .as_ref()
+            .is_some_and(|(name, _)| name == model_name)
+        {
+            return Ok(false);
+        }
+
+        let model_info = {
+            let models = self.available_models.read().await;
+            models.get(model_name).cloned()
+        }
+        .ok_or_else(|| anyhow!("Model {} not found", model_name))?;
+
+        if !matches!(model_info.status, ModelStatus::Available) {
+            return Err(anyhow!("Qwen ASR model {} is not available for preloading", model_name));
+        }
+
+        log::info!("Preloading Qwen ASR model: {} from {}", model_name, model_info.path.display());
+
+        check_model_compatibility(&model_info.path)?;
+        let path = model_info.path.clone();
+        let model = tokio::task::spawn_blocking(move || QwenAsrModel::new(&path))
+            .await
+            .map_err(|e| anyhow!("Preload task failed: {}", e))?
+            .map_err(|e| anyhow!("Failed to preload Qwen ASR model {}: {}", model_name, e))?;
+
+        *self.staged_model.write().await = Some((model_name.to_string(), model));
+        log::info!("Qwen ASR model {} preloaded", model_name);
+        Ok(true)
+    }
+
+    /// Name of the model currently held in the staging slot, if any
+    pub async fn get_preloaded_model(&self) -> Option<String> {
+        self.staged_model
+            .read()
+            .await
+            .as_ref()
+            .map(|(name, _)| name.clone())
+    }
+
+    /// Whether any transcription is currently running (or waiting on the model)
+    pub fn is_transcribing(&self) -> bool {
+        self.in_flight.load(Ordering::SeqCst) > 0
+    }
+
+    /// Discard the transcription running on the loaded model, if any: that call
+    /// stops decoding at the next token and returns an error instead of its text.
+    /// Builds whose decoder can't be interrupted still finish the decode first.
+    /// Transcriptions still waiting for the model are not affected. Returns
+    /// whether one was running.
+    pub fn discard_running_transcription(&self) -> bool {
+        if !self.is_transcribing() {
+            return false;
+        }
+        let handle = self.discard_handle.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        match handle {
+            Some(handle) => {
+                handle.discard();
+                log::info!("Qwen ASR running transcription marked for discard");
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the currently loaded model name
+    pub async fn get_current_model(&self) -> Option<String> {
+        self.current_model_name.read().await.clone()
+    }
+
+    /// Samples the loaded model decodes in one pass; longer audio is windowed
+    async fn context_samples(&self) -> usize {
+        let model_name = self.current_model_name.read().await;
+        let seconds = model_name
+            .as_deref()
+            .and_then(Self::get_model_config)
+            .map_or(DEFAULT_CONTEXT_SECONDS, |config| config.context_seconds);
+        seconds * SAMPLE_RATE
     }
 
     /// Check if a model is loaded
@@ -353,13 +1673,164 @@ impl QwenAsrEngine {
         self.current_model.read().await.is_some()
     }
 
-    /// Transcribe audio samples using the loaded model (batch mode)
+    /// Run `f` against the loaded model, for capabilities the engine doesn't wrap yet.
+    /// Holds the write guard so `f` never overlaps a transcription or an unload; keep it
+    /// short. Fails with `QwenAsrEngineError::ModelNotLoaded` when no model is loaded.
+    pub async fn with_model<R>(&self, f: impl FnOnce(&QwenAsrModel) -> R) -> Result<R> {
+        let model_guard = self.current_model.write().await;
+        let model = model_guard.as_ref().ok_or(QwenAsrEngineError::ModelNotLoaded)?;
+        Ok(f(model))
+    }
+
+    /// Set the decoding temperature used for subsequent transcriptions.
+    ///
+    /// 0.0 (the default) is greedy and deterministic. Higher values sample
+    /// from the token distribution, which can help the decoder recover on
+    /// noisy or ambiguous audio at the cost of run-to-run consistency.
+    pub async fn set_temperature(&self, temperature: f32) -> Result<()> {
+        validate_temperature(temperature)?;
+        let mut params = self.decode_params.write().await;
+        params.temperature = temperature;
+        log::info!("Qwen ASR decoding temperature set to {:.2}", temperature);
+        Ok(())
+    }
+
+    /// Apply the GPU device, thread count and temperature used for subsequent
+    /// transcriptions. A GPU index beyond the devices the library reports is rejected.
+    pub async fn set_config(&self, config: QwenAsrConfig) -> Result<()> {
+        config.validate(gpu_device_count())?;
+        let mut params = self.decode_params.write().await;
+        params.use_gpu = config.use_gpu;
+        params.gpu_device = config.gpu_device;
+        params.n_threads = config.n_threads;
+        params.temperature = config.temperature;
+        log::info!(
+            "Qwen ASR config set: gpu={} device={} threads={} temperature={:.2}",
+            config.use_gpu,
+            config.gpu_device,
+            config.n_threads,
+            config.temperature
+        );
+        Ok(())
+    }
+
+    pub async fn get_config(&self) -> QwenAsrConfig {
+        let params = self.decode_params.read().await;
+        QwenAsrConfig {
+            use_gpu: params.use_gpu,
+            gpu_device: params.gpu_device,
+            n_threads: params.n_threads,
+            temperature: params.temperature,
+        }
+    }
+
+    /// Set how many consecutive repeats of a short phrase abort streaming
+    /// decoding. Values below 2 disable the loop check.
+    pub async fn set_repetition_threshold(&self, threshold: usize) {
+        let mut params = self.decode_params.write().await;
+        params.repetition_threshold = threshold;
+        log::info!("Qwen ASR repetition threshold set to {}", threshold);
+    }
+
+    /// Get the decoding parameters used for transcription
+    pub async fn get_decode_params(&self) -> QwenAsrParams {
+        self.decode_params.read().await.clone()
+    }
+
+    /// Enable feeding the previous chunk's transcript to the decoder (off by
+    /// default). Adds prompt tokens to every chunk and can make the model
+    /// continue the previous sentence over silence.
+    pub fn set_decoder_context_enabled(&self, enabled: bool) {
+        self.decoder_context_enabled.store(enabled, Ordering::SeqCst);
+        log::info!("Qwen ASR cross-chunk decoder context {}", if enabled { "enabled" } else { "disabled" });
+    }
+
+    pub fn is_decoder_context_enabled(&self) -> bool {
+        self.decoder_context_enabled.load(Ordering::SeqCst)
+    }
+
+    /// Keep the final transcripts of the last `entries` batch decodes so the
+    /// same audio (a retry, an unchanged re-transcription range) isn't decoded
+    /// twice. 0 disables the cache and frees it.
+    pub fn set_transcript_cache_entries(&self, entries: usize) {
+        let entries = entries.min(MAX_TRANSCRIPT_CACHE_ENTRIES);
+        self.transcript_cache_entries.store(entries, Ordering::SeqCst);
+        self.transcript_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .truncate(entries);
+        log::info!("Qwen ASR transcript cache size set to {} entries", entries);
+    }
+
+    pub fn get_transcript_cache_entries(&self) -> usize {
+        self.transcript_cache_entries.load(Ordering::SeqCst)
+    }
+
+    /// Transcribe audio samples using the loaded model (batch mode). The text
+    /// comes back without the model's language tag.
+    ///
+    /// Served from the transcript cache when it is enabled and the same audio
+    /// was decoded with the same model and params before.
     pub async fn transcribe_audio(&self, audio_data: Vec<f32>) -> Result<String> {
-        let mut model_guard = self.current_model.write().await;
-        let model = model_guard
-            .as_mut()
-            .ok_or_else(|| anyhow!("No Qwen ASR model loaded. Please load a model first."))?;
+        Ok(self.transcribe_audio_with_confidence(audio_data).await?.text)
+    }
 
+    /// [`Self::transcribe_audio`] in a fixed language given as an app language
+    /// code ("en", "zh", ...). Auto modes and codes the model doesn't know
+    /// auto-detect.
+    pub async fn transcribe_audio_with_language(&self, audio_data: Vec<f32>, language: Option<&str>) -> Result<String> {
+        let language = language.and_then(qwen_language_name);
+        Ok(self.transcribe_audio_in_language(audio_data, language).await?.text)
+    }
+
+    /// [`Self::transcribe_audio`], keeping the decoder's token scores
+    pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>) -> Result<QwenAsrTranscript> {
+        self.transcribe_audio_in_language(audio_data, None).await
+    }
+
+    /// Cached batch decode; `language` is a model language name or `None` to
+    /// auto-detect.
+    async fn transcribe_audio_in_language(
+        &self,
+        audio_data: Vec<f32>,
+        language: Option<&str>,
+    ) -> Result<QwenAsrTranscript> {
+        let _in_flight = InFlightGuard::new(self);
+        let params = self.get_decode_params().await;
+
+        let cache_entries = self.get_transcript_cache_entries();
+        let cache_key = if cache_entries > 0 {
+            let model_name = self.get_current_model().await.unwrap_or_default();
+            Some(transcript_cache_key(&audio_data, &model_name, &params, language))
+        } else {
+            None
+        };
+        if let Some(key) = cache_key {
+            let cached = self.transcript_cache.lock().unwrap_or_else(|e| e.into_inner()).get(key);
+            if let Some(transcript) = cached {
+                log::debug!("Qwen ASR transcript cache hit for {} samples", audio_data.len());
+                return Ok(transcript);
+            }
+        }
+
+        let result = self.transcribe_audio_uncached(audio_data, &params, language).await?;
+
+        if let Some(key) = cache_key {
+            self.transcript_cache
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(key, result.clone(), cache_entries);
+        }
+        Ok(result)
+    }
+
+    async fn transcribe_audio_uncached(
+        &self,
+        audio_data: Vec<f32>,
+        params: &QwenAsrParams,
+        language: Option<&str>,
+    ) -> Result<QwenAsrTranscript> {
+        let context_samples = self.context_samples().await;
         let duration_seconds = audio_data.len() as f64 / 16000.0;
         log::debug!(
             "Qwen ASR transcribing {} samples ({:.1}s duration)",
@@ -367,12 +1838,165 @@ impl QwenAsrEngine {
             duration_seconds
         );
 
+        if audio_data.len() > context_samples {
+            log::info!(
+                "Qwen ASR input of {:.1}s exceeds the {}s model context, transcribing in windows",
+                duration_seconds,
+                context_samples / SAMPLE_RATE
+            );
+            let stitched = self
+                .transcribe_windowed_blocking(audio_data, params.clone(), language, context_samples, None, |_| {})
+                .await?;
+            log::debug!("Qwen ASR windowed transcription result: '{}'", stitched.text);
+            return Ok(stitched);
+        }
+
+        let mut model_guard = self.current_model.write().await;
+        let model = model_guard
+            .as_mut()
+            .ok_or_else(|| anyhow!("No Qwen ASR model loaded. Please load a model first."))?;
         let result = model
-            .transcribe(&audio_data)
+            .transcribe_with_language(&audio_data, &params_for_audio(params, audio_data.len()), language)
             .map_err(|e| anyhow!("Qwen ASR transcription failed: {}", e))?;
 
-        log::debug!("Qwen ASR transcription result: '{}'", result);
-        Ok(result)
+        log::debug!("Qwen ASR transcription result: '{}'", result.text);
+        // Windows are cleaned before stitching, so clean single passes the same way
+        Ok(QwenAsrTranscript {
+            text: clean_qwen_asr_output(&result.text),
+            ..result
+        })
+    }
+
+    /// Transcribe a long recording window by window, reporting progress.
+    ///
+    /// `on_progress` is called after every window with the text stitched so far.
+    /// Setting `cancel_flag` stops before the next window and returns an error.
+    pub async fn transcribe_audio_with_progress<F>(
+        &self,
+        audio_data: Vec<f32>,
+        cancel_flag: &'static AtomicBool,
+        on_progress: F,
+    ) -> Result<String>
+    where
+        F: FnMut(WindowProgress) + Send,
+    {
+        let _in_flight = InFlightGuard::new(self);
+        let params = self.get_decode_params().await;
+        let context_samples = self.context_samples().await;
+
+        self.transcribe_windowed_blocking(audio_data, params, None, context_samples, Some(cancel_flag), on_progress)
+            .await
+            .map(|transcript| transcript.text)
+    }
+
+    /// Run [`Self::transcribe_windowed`] on the blocking pool so a long
+    /// recording doesn't stall the async runtime. The model's write lock is
+    /// taken inside the task; progress comes back over a channel and is handed
+    /// to `on_progress` here.
+    async fn transcribe_windowed_blocking<F>(
+        &self,
+        audio_data: Vec<f32>,
+        params: QwenAsrParams,
+        language: Option<&str>,
+        window_samples: usize,
+        cancel_flag: Option<&'static AtomicBool>,
+        mut on_progress: F,
+    ) -> Result<QwenAsrTranscript>
+    where
+        F: FnMut(WindowProgress) + Send,
+    {
+        let current_model = Arc::clone(&self.current_model);
+        let language = language.map(str::to_string);
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let task = tokio::task::spawn_blocking(move || {
+            let model_guard = current_model.blocking_write();
+            let model = model_guard
+                .as_ref()
+                .ok_or_else(|| anyhow!("No Qwen ASR model loaded. Please load a model first."))?;
+            Self::transcribe_windowed(
+                model,
+                &audio_data,
+                &params,
+                language.as_deref(),
+                window_samples,
+                cancel_flag,
+                |progress| {
+                    let _ = progress_tx.send(progress);
+                },
+            )
+        });
+
+        // Ends once the task finishes and drops its sender
+        while let Some(progress) = progress_rx.recv().await {
+            on_progress(progress);
+        }
+        task.await.map_err(|e| anyhow!("Qwen ASR windowed transcription task failed: {}", e))?
+    }
+
+    /// Split audio into overlapping windows of at most `window_samples`, transcribe
+    /// each and stitch the results, dropping text repeated across window
+    /// boundaries. Token scores are averaged over the windows.
+    fn transcribe_windowed<F>(
+        model: &QwenAsrModel,
+        audio_data: &[f32],
+        params: &QwenAsrParams,
+        language: Option<&str>,
+        window_samples: usize,
+        cancel_flag: Option<&AtomicBool>,
+        mut on_progress: F,
+    ) -> Result<QwenAsrTranscript>
+    where
+        F: FnMut(WindowProgress),
+    {
+        let ranges = window_ranges(
+            audio_data.len(),
+            window_samples,
+            WINDOW_OVERLAP_SECONDS * SAMPLE_RATE,
+        );
+        let total_windows = ranges.len();
+        let total_seconds = audio_data.len() as f64 / SAMPLE_RATE as f64;
+        log::info!(
+            "Qwen ASR transcribing {:.1}s of audio in {} windows",
+            total_seconds,
+            total_windows
+        );
+
+        // Each window carries its own language tag, so clean before stitching
+        let mut stitched = String::new();
+        let mut logprob_sum = 0.0;
+        let mut no_speech_sum = 0.0;
+        for (index, (start, end)) in ranges.into_iter().enumerate() {
+            if cancel_flag.is_some_and(|flag| flag.load(Ordering::SeqCst)) {
+                log::info!("Qwen ASR windowed transcription cancelled at window {}/{}", index + 1, total_windows);
+                return Err(anyhow!("Transcription cancelled by user"));
+            }
+
+            let window = model
+                .transcribe_with_language(&audio_data[start..end], &params_for_audio(params, end - start), language)
+                .map_err(|e| anyhow!("Qwen ASR transcription failed: {}", e))?;
+            logprob_sum += window.avg_logprob;
+            no_speech_sum += window.no_speech_prob;
+            let window_text = clean_qwen_asr_output(&window.text);
+            let new_text = remove_text_overlap(&stitched, &window_text);
+            append_transcript(&mut stitched, &new_text);
+
+            on_progress(WindowProgress {
+                window: index + 1,
+                total_windows,
+                position_seconds: end as f64 / SAMPLE_RATE as f64,
+                total_seconds,
+                text: stitched.clone(),
+            });
+        }
+
+        let windows = total_windows.max(1) as f32;
+        Ok(QwenAsrTranscript {
+            text: stitched,
+            avg_logprob: logprob_sum / windows,
+            no_speech_prob: no_speech_sum / windows,
+            words: Vec::new(),
+        })
     }
 
     /// Transcribe audio with streaming token output
@@ -380,22 +2004,69 @@ impl QwenAsrEngine {
         &self,
         audio_data: Vec<f32>,
         on_token: F,
-    ) -> Result<String>
+    ) -> Result<QwenAsrTranscript>
     where
         F: FnMut(&str) -> bool + Send,
     {
+        let _in_flight = InFlightGuard::new(self);
+        let params = params_for_audio(&self.get_decode_params().await, audio_data.len());
         let mut model_guard = self.current_model.write().await;
         let model = model_guard
             .as_mut()
             .ok_or_else(|| anyhow!("No Qwen ASR model loaded."))?;
 
         let result = model
-            .transcribe_streaming(&audio_data, on_token)
+            .transcribe_streaming(&audio_data, &params, on_token)
             .map_err(|e| anyhow!("Qwen ASR streaming transcription failed: {}", e))?;
 
         Ok(result)
     }
 
+    /// Streaming transcription of one chunk of a recording, prompted with the
+    /// tail of what was transcribed earlier into `context`.
+    ///
+    /// Falls back to [`Self::transcribe_audio_streaming`] when decoder context
+    /// is disabled. A chunk that comes back empty (silence) clears the context
+    /// so the next chunk doesn't pick up a stale sentence.
+    pub async fn transcribe_audio_with_context<F>(
+        &self,
+        audio_data: Vec<f32>,
+        context: &QwenDecoderContext,
+        on_token: F,
+    ) -> Result<QwenAsrTranscript>
+    where
+        F: FnMut(&str) -> bool + Send,
+    {
+        if !self.is_decoder_context_enabled() {
+            return self.transcribe_audio_streaming(audio_data, on_token).await;
+        }
+
+        let _in_flight = InFlightGuard::new(self);
+        let previous = context.tail.lock().await.clone();
+        let params = params_for_audio(&self.get_decode_params().await, audio_data.len())
+            .with_decoder_prompt(Some(previous.clone()));
+
+        let result = {
+            let mut model_guard = self.current_model.write().await;
+            let model = model_guard
+                .as_mut()
+                .ok_or_else(|| anyhow!("No Qwen ASR model loaded."))?;
+            model
+                .transcribe_streaming(&audio_data, &params, on_token)
+                .map_err(|e| anyhow!("Qwen ASR streaming transcription failed: {}", e))?
+        };
+
+        let cleaned = clean_qwen_asr_output(&result.text);
+        let next = if cleaned.is_empty() {
+            String::new()
+        } else {
+            next_decoder_prompt(&previous, &cleaned)
+        };
+        *context.tail.lock().await = next;
+
+        Ok(result)
+    }
+
     /// Get the models directory path
     pub async fn get_models_directory(&self) -> PathBuf {
         self.models_dir.clone()
@@ -419,11 +2090,13 @@ impl QwenAsrEngine {
                         .map_err(|e| anyhow!("Failed to delete '{}': {}", model_info.path.display(), e))?;
                     log::info!("Successfully deleted Qwen ASR model file: {}", model_info.path.display());
                 }
+                let _ = fs::remove_file(completion_marker_path(&model_info.path)).await;
 
                 {
                     let mut models = self.available_models.write().await;
                     if let Some(model) = models.get_mut(model_name) {
                         model.status = ModelStatus::Missing;
+                        model.verified = false;
                     }
                 }
 
@@ -436,11 +2109,98 @@ impl QwenAsrEngine {
         }
     }
 
-    /// Download a Qwen ASR model with detailed progress
+    /// Set how many times a download is resumed after a stall or dropped
+    /// connection before it fails
+    pub fn set_download_retries(&self, retries: u32) {
+        let retries = retries.min(MAX_DOWNLOAD_RETRIES);
+        self.download_retries.store(retries, Ordering::SeqCst);
+        log::info!("Qwen ASR download retries set to {}", retries);
+    }
+
+    pub fn get_download_retries(&self) -> u32 {
+        self.download_retries.load(Ordering::SeqCst)
+    }
+
+    /// Set how many parallel Range connections a fresh download uses (1 = single stream)
+    pub fn set_download_connections(&self, connections: u8) {
+        let connections = connections.clamp(1, MAX_DOWNLOAD_CONNECTIONS);
+        self.download_connections.store(connections, Ordering::SeqCst);
+        log::info!("Qwen ASR download connections set to {}", connections);
+    }
+
+    pub fn get_download_connections(&self) -> u8 {
+        self.download_connections.load(Ordering::SeqCst)
+    }
+
+    /// Past download attempts, oldest first
+    pub async fn get_download_history(&self) -> Vec<DownloadRecord> {
+        read_download_history(&self.models_dir.join(DOWNLOAD_HISTORY_FILE)).await
+    }
+
+    /// Download a Qwen ASR model with detailed progress.
+    ///
+    /// A stalled or dropped connection is resumed with a Range request from the
+    /// bytes already written, up to `get_download_retries` times with backoff.
+    /// Every attempt that reaches the network is added to the download history.
     pub async fn download_model_detailed(
         &self,
         model_name: &str,
         progress_callback: Option<Box<dyn Fn(DownloadProgress) + Send>>,
+    ) -> Result<()> {
+        let started = Instant::now();
+        let mut stats = DownloadStats::default();
+        let result = self
+            .download_model_inner(model_name, progress_callback, &mut stats)
+            .await;
+        if stats.started {
+            self.record_download(model_name, &result, &stats, started.elapsed()).await;
+        }
+        result
+    }
+
+    async fn record_download(
+        &self,
+        model_name: &str,
+        result: &Result<()>,
+        stats: &DownloadStats,
+        elapsed: Duration,
+    ) {
+        let cancelled = self.cancel_download_flag.read().await.as_deref() == Some(model_name);
+        let outcome = match result {
+            Ok(()) => DownloadOutcome::Completed,
+            Err(_) if cancelled => DownloadOutcome::Cancelled,
+            Err(_) => DownloadOutcome::Failed,
+        };
+        let duration_secs = elapsed.as_secs_f64();
+        let transferred = stats.downloaded_bytes.saturating_sub(stats.resumed_from_bytes);
+        let record = DownloadRecord {
+            model_name: model_name.to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            outcome,
+            error: result.as_ref().err().map(|e| e.to_string()),
+            resumed_from_bytes: stats.resumed_from_bytes,
+            downloaded_bytes: stats.downloaded_bytes,
+            total_bytes: stats.total_bytes,
+            retries: stats.retries,
+            duration_secs,
+            avg_speed_mbps: if duration_secs > 0.0 {
+                transferred as f64 / (1024.0 * 1024.0) / duration_secs
+            } else {
+                0.0
+            },
+        };
+
+        let path = self.models_dir.join(DOWNLOAD_HISTORY_FILE);
+        if let Err(e) = append_download_history(&path, record).await {
+            log::warn!("Failed to update download history: {}", e);
+        }
+    }
+
+    async fn download_model_inner(
+        &self,
+        model_name: &str,
+        progress_callback: Option<Box<dyn Fn(DownloadProgress) + Send>>,
+        stats: &mut DownloadStats,
     ) -> Result<()> {
         log::info!("Starting download for Qwen ASR model: {}", model_name);
 
@@ -494,11 +2254,7 @@ impl QwenAsrEngine {
         };
 
         // HuggingFace URL for Qwen3-ASR GGUF models
-        let download_url = format!(
-            "https://huggingface.co/{}/resolve/main/{}",
-            model_config.huggingface_repo,
-            model_config.filename
-        );
+        let download_url = model_config.download_url();
 
         let file_path = self.models_dir.join(model_config.filename);
 
@@ -524,24 +2280,65 @@ impl QwenAsrEngine {
         let expected_size = (model_info.size_mb as u64) * 1024 * 1024;
 
         // Skip if already downloaded (within 1% tolerance)
-        if existing_size > 0 && existing_size >= (expected_size as f64 * 0.99) as u64 {
+        if is_full_size(existing_size, model_info.size_mb) {
             // Validate the file
             if self.validate_gguf_file(&file_path).await.is_ok() {
                 log::info!("Model {} already downloaded and valid", model_name);
+                let marker = read_completion_marker(&file_path)
+                    .filter(|marker| marker.size == existing_size)
+                    .unwrap_or_else(|| {
+                        let marker = CompletionMarker { size: existing_size, verified: false };
+                        write_completion_marker(&file_path, marker);
+                        marker
+                    });
                 {
                     let mut models = self.available_models.write().await;
                     if let Some(model) = models.get_mut(model_name) {
                         model.status = ModelStatus::Available;
+                        model.verified = marker.verified;
+                    }
+                }
+                {
+                    let mut active = self.active_downloads.write().await;
+                    active.remove(model_name);
+                }
+                return Ok(());
+            }
+        }
+
+        // Fail before writing anything rather than with a write error near the end
+        let needed = expected_size.saturating_sub(existing_size) + DOWNLOAD_SPACE_MARGIN_BYTES;
+        match available_space(&self.models_dir) {
+            Ok(available) => {
+                if let Some(err) = insufficient_space(needed, available) {
+                    {
+                        let mut active = self.active_downloads.write().await;
+                        active.remove(model_name);
+                    }
+                    {
+                        let mut models = self.available_models.write().await;
+                        if let Some(model) = models.get_mut(model_name) {
+                            model.status = ModelStatus::Missing;
+                        }
                     }
+                    return Err(err.into());
                 }
-                {
-                    let mut active = self.active_downloads.write().await;
-                    active.remove(model_name);
-                }
-                return Ok(());
             }
+            Err(e) => log::warn!(
+                "Could not check free space in {}, downloading anyway: {}",
+                self.models_dir.display(),
+                e
+            ),
         }
 
+        // The file is about to change; it gets a new marker when the download completes
+        let _ = fs::remove_file(completion_marker_path(&file_path)).await;
+
+        stats.started = true;
+        stats.resumed_from_bytes = existing_size;
+        stats.downloaded_bytes = existing_size;
+        stats.total_bytes = expected_size;
+
         // HTTP client for download
         let client = reqwest::Client::builder()
             .tcp_nodelay(true)
@@ -551,6 +2348,66 @@ impl QwenAsrEngine {
             .build()
             .map_err(|e| anyhow!("Failed to create HTTP client: {}", e))?;
 
+        // Fresh downloads can be split across connections; resuming a partial file stays
+        // on the single stream, which knows how to continue it
+        let connections = self.get_download_connections();
+        if connections > 1 && existing_size == 0 {
+            let part_path = parallel_part_path(&file_path);
+            let parallel_start = Instant::now();
+            match self
+                .download_parallel(
+                    &client,
+                    &download_url,
+                    model_name,
+                    &part_path,
+                    connections,
+                    &progress_callback,
+                    stats,
+                )
+                .await
+            {
+                Ok(Some(total_size)) => {
+                    if let Err(e) = fs::rename(&part_path, &file_path).await {
+                        let _ = fs::remove_file(&part_path).await;
+                        let mut active = self.active_downloads.write().await;
+                        active.remove(model_name);
+                        return Err(anyhow!("Failed to move downloaded file into place: {}", e));
+                    }
+                    let elapsed = parallel_start.elapsed().as_secs_f64();
+                    let final_speed = if elapsed > 0.0 {
+                        (total_size as f64 / (1024.0 * 1024.0)) / elapsed
+                    } else {
+                        0.0
+                    };
+                    let transfer = DownloadProgress::new(total_size, total_size, final_speed);
+                    if let Some(ref callback) = progress_callback {
+                        callback(transfer.clone().finalizing(0));
+                    }
+                    return self
+                        .finish_download(model_name, model_config, file_path, transfer, &progress_callback)
+                        .await;
+                }
+                Ok(None) => log::info!(
+                    "Server doesn't serve byte ranges, downloading {} over a single connection",
+                    model_name
+                ),
+                Err(e) => {
+                    let _ = fs::remove_file(&part_path).await;
+                    {
+                        let mut active = self.active_downloads.write().await;
+                        active.remove(model_name);
+                    }
+                    {
+                        let mut models = self.available_models.write().await;
+                        if let Some(model) = models.get_mut(model_name) {
+                            model.status = ModelStatus::Missing;
+                        }
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
         // Build request with optional Range header for resume
         let mut request = client.get(&download_url);
         if existing_size > 0 {
@@ -580,15 +2437,14 @@ impl QwenAsrEngine {
 
         // Open file
         let file = if resuming {
-            fs::OpenOptions::new()
-                .append(true)
-                .open(&file_path)
-                .await
-                .map_err(|e| anyhow!("Failed to open file for resume: {}", e))?
+            match fs::OpenOptions::new().append(true).open(&file_path).await {
+                Ok(file) => file,
+                Err(e) => {
+                    return Err(self.fail_download(model_name, anyhow!("Failed to open file for resume: {}", e)).await)
+                }
+            }
         } else {
-            fs::File::create(&file_path)
-                .await
-                .map_err(|e| anyhow!("Failed to create file: {}", e))?
+            self.create_download_file(model_name, &file_path).await?
         };
 
         let mut writer = BufWriter::with_capacity(8 * 1024 * 1024, file);
@@ -597,6 +2453,11 @@ impl QwenAsrEngine {
         use futures_util::StreamExt;
         let mut stream = response.bytes_stream();
         let mut downloaded = if resuming { existing_size } else { 0u64 };
+        stats.resumed_from_bytes = downloaded;
+        stats.downloaded_bytes = downloaded;
+        stats.total_bytes = total_size;
+        let max_retries = self.get_download_retries();
+        let mut retry_attempt: u32 = 0;
         let download_start = Instant::now();
         let mut last_report_time = Instant::now();
         let mut bytes_since_last_report: u64 = 0;
@@ -617,27 +2478,20 @@ impl QwenAsrEngine {
 
             let next_result = timeout(Duration::from_secs(30), stream.next()).await;
 
-            let chunk = match next_result {
-                Err(_) => {
-                    let _ = writer.flush().await;
-                    {
-                        let mut active = self.active_downloads.write().await;
-                        active.remove(model_name);
-                    }
-                    {
-                        let mut models = self.available_models.write().await;
-                        if let Some(model) = models.get_mut(model_name) {
-                            model.status = ModelStatus::Missing;
-                        }
-                    }
-                    return Err(anyhow!("Download timeout - no data for 30 seconds"));
-                }
+            let chunk_result = match next_result {
                 Ok(None) => break,
-                Ok(Some(chunk_result)) => {
-                    match chunk_result {
-                        Ok(c) => c,
-                        Err(e) => {
-                            let _ = writer.flush().await;
+                Ok(Some(result)) => result.map_err(|e| format!("Download error: {}", e)),
+                Err(_) => Err("Download timeout - no data for 30 seconds".to_string()),
+            };
+
+            let chunk = match chunk_result {
+                Ok(c) => c,
+                Err(reason) => {
+                    let _ = writer.flush().await;
+
+                    // Reconnect from the current offset until a request succeeds or retries run out
+                    loop {
+                        if retry_attempt >= max_retries {
                             {
                                 let mut active = self.active_downloads.write().await;
                                 active.remove(model_name);
@@ -648,22 +2502,64 @@ impl QwenAsrEngine {
                                     model.status = ModelStatus::Missing;
                                 }
                             }
-                            return Err(anyhow!("Download error: {}", e));
+                            return Err(retries_exhausted(reason, retry_attempt).into());
+                        }
+
+                        retry_attempt += 1;
+                        stats.retries = retry_attempt;
+                        let backoff = download_retry_backoff(retry_attempt);
+                        log::warn!(
+                            "{} for {}; resuming from byte {} in {:?} (attempt {}/{})",
+                            reason, model_name, downloaded, backoff, retry_attempt, max_retries
+                        );
+                        if let Some(ref callback) = progress_callback {
+                            callback(
+                                DownloadProgress::new(downloaded, total_size, 0.0)
+                                    .with_retry_attempt(retry_attempt, max_retries),
+                            );
+                        }
+                        if !self.backoff_unless_cancelled(model_name, backoff).await {
+                            break; // Handled by the cancellation check at the top of the outer loop
+                        }
+
+                        let mut request = client.get(&download_url);
+                        if downloaded > 0 {
+                            request = request.header("Range", format!("bytes={}-", downloaded));
+                        }
+                        match request.send().await {
+                            Ok(response) if response.status() == reqwest::StatusCode::PARTIAL_CONTENT => {
+                                stream = response.bytes_stream();
+                                break;
+                            }
+                            Ok(response) if response.status().is_success() => {
+                                // Server ignored the Range header, so start the file over
+                                log::warn!("Server did not honour the resume request for {}, restarting download", model_name);
+                                let file = self.create_download_file(model_name, &file_path).await?;
+                                writer = BufWriter::with_capacity(8 * 1024 * 1024, file);
+                                downloaded = 0;
+                                stats.resumed_from_bytes = 0;
+                                stream = response.bytes_stream();
+                                break;
+                            }
+                            Ok(response) => {
+                                log::warn!("Resume request for {} failed with status: {}", model_name, response.status());
+                            }
+                            Err(e) => {
+                                log::warn!("Resume request for {} failed: {}", model_name, e);
+                            }
                         }
                     }
+                    continue;
                 }
             };
 
             if let Err(e) = writer.write_all(&chunk).await {
-                {
-                    let mut active = self.active_downloads.write().await;
-                    active.remove(model_name);
-                }
-                return Err(anyhow!("Failed to write chunk: {}", e));
+                return Err(self.fail_download(model_name, anyhow!("Failed to write chunk: {}", e)).await);
             }
 
             let chunk_len = chunk.len() as u64;
             downloaded += chunk_len;
+            stats.downloaded_bytes = downloaded;
             bytes_since_last_report += chunk_len;
 
             let overall_progress = if total_size > 0 {
@@ -706,6 +2602,19 @@ impl QwenAsrEngine {
             }
         }
 
+        let total_elapsed = download_start.elapsed().as_secs_f64();
+        let final_speed = if total_elapsed > 0.0 {
+            (downloaded as f64 / (1024.0 * 1024.0)) / total_elapsed
+        } else {
+            0.0
+        };
+        let report_finalizing = |finalize_percent: u8| {
+            if let Some(ref callback) = progress_callback {
+                callback(DownloadProgress::new(downloaded, total_size, final_speed).finalizing(finalize_percent));
+            }
+        };
+        report_finalizing(0);
+
         // Flush
         if let Err(e) = writer.flush().await {
             {
@@ -714,25 +2623,98 @@ impl QwenAsrEngine {
             }
             return Err(anyhow!("Failed to flush file: {}", e));
         }
+        drop(writer);
+        self.finish_download(
+            model_name,
+            model_config,
+            file_path,
+            DownloadProgress::new(downloaded, total_size, final_speed),
+            &progress_callback,
+        )
+        .await
+    }
 
-        // Report 100%
-        let total_elapsed = download_start.elapsed().as_secs_f64();
-        let final_speed = if total_elapsed > 0.0 {
-            (downloaded as f64 / (1024.0 * 1024.0)) / total_elapsed
-        } else {
-            0.0
+    /// Validate a fully written download and mark the model Available. `transfer`
+    /// describes the finished transfer and is reported through the finalizing phase.
+    async fn finish_download(
+        &self,
+        model_name: &str,
+        model_config: &ModelConfig,
+        file_path: PathBuf,
+        transfer: DownloadProgress,
+        progress_callback: &Option<Box<dyn Fn(DownloadProgress) + Send>>,
+    ) -> Result<()> {
+        let (downloaded, total_size) = (transfer.downloaded_bytes, transfer.total_bytes);
+        let report_finalizing = |finalize_percent: u8| {
+            if let Some(ref callback) = progress_callback {
+                callback(transfer.clone().finalizing(finalize_percent));
+            }
         };
-        let final_progress = DownloadProgress::new(total_size, total_size, final_speed);
+        report_finalizing(50);
+
+        // Validate before reporting 100%, so a truncated or garbage file never shows as done
+        if let Err(e) = self.validate_gguf_file(&file_path).await {
+            {
+                let mut active = self.active_downloads.write().await;
+                active.remove(model_name);
+            }
+            {
+                let mut models = self.available_models.write().await;
+                if let Some(model) = models.get_mut(model_name) {
+                    model.status = ModelStatus::Corrupted {
+                        file_size: downloaded,
+                        expected_min_size: total_size,
+                    };
+                }
+            }
+            return Err(anyhow!("Downloaded file failed validation: {}", e));
+        }
+        report_finalizing(75);
+
+        // A header check can't catch a corrupted body, so compare the published digest
+        let expected_sha256 = self.published_sha256(model_config).await;
+        let checksum_verified = expected_sha256.is_some();
+        if let Some(expected_sha256) = expected_sha256 {
+            let checksum = file_sha256(&file_path).await;
+            let mismatch = match checksum {
+                Ok(ref actual) if actual.eq_ignore_ascii_case(&expected_sha256) => None,
+                Ok(actual) => Some(format!(
+                    "Checksum mismatch: expected {}, got {}",
+                    expected_sha256, actual
+                )),
+                Err(e) => {
+                    let mut active = self.active_downloads.write().await;
+                    active.remove(model_name);
+                    return Err(anyhow!("Failed to checksum downloaded file: {}", e));
+                }
+            };
+            if let Some(mismatch) = mismatch {
+                {
+                    let mut active = self.active_downloads.write().await;
+                    active.remove(model_name);
+                }
+                self.discard_corrupted_file(model_name, &file_path, downloaded, total_size).await;
+                return Err(anyhow!("Downloaded file is corrupted. {}", mismatch));
+            }
+        }
+        report_finalizing(100);
+
         if let Some(ref callback) = progress_callback {
-            callback(final_progress);
+            callback(transfer.complete());
         }
 
+        // Record completion before the status flips, so a crash after this point
+        // doesn't leave a finished file looking like an interrupted download
+        let verified = checksum_verified || (total_size > 0 && downloaded == total_size);
+        write_completion_marker(&file_path, CompletionMarker { size: downloaded, verified });
+
         // Update status
         {
             let mut models = self.available_models.write().await;
             if let Some(model) = models.get_mut(model_name) {
                 model.status = ModelStatus::Available;
                 model.path = file_path;
+                model.verified = verified;
             }
         }
 
@@ -752,6 +2734,238 @@ impl QwenAsrEngine {
         Ok(())
     }
 
+    /// Download `url` into `part_path` over `connections` parallel Range requests,
+    /// each writing its own region of a preallocated file. Returns the file size, or
+    /// None when the server doesn't serve byte ranges and the caller should fall back
+    /// to a single stream. `stats` gets the bytes written and the retries of all
+    /// connections, whether the transfer succeeds or fails.
+    #[allow(clippy::too_many_arguments)]
+    async fn download_parallel(
+        &self,
+        client: &reqwest::Client,
+        url: &str,
+        model_name: &str,
+        part_path: &Path,
+        connections: u8,
+        progress_callback: &Option<Box<dyn Fn(DownloadProgress) + Send>>,
+        stats: &mut DownloadStats,
+    ) -> Result<Option<u64>> {
+        let probe = client
+            .get(url)
+            .header("Range", "bytes=0-0")
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to start download: {}", e))?;
+        let serves_ranges = probe.status() == reqwest::StatusCode::PARTIAL_CONTENT
+            && !matches!(
+                probe.headers().get(reqwest::header::ACCEPT_RANGES),
+                Some(value) if value == "none"
+            );
+        let total_size = probe
+            .headers()
+            .get(reqwest::header::CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(content_range_total)
+            .filter(|&total| serves_ranges && total > 0);
+        drop(probe);
+        let Some(total_size) = total_size else {
+            return Ok(None);
+        };
+
+        let file = fs::File::create(part_path)
+            .await
+            .map_err(|e| anyhow!("Failed to create file: {}", e))?;
+        file.set_len(total_size)
+            .await
+            .map_err(|e| anyhow!("Failed to preallocate {} bytes: {}", total_size, e))?;
+        drop(file);
+        stats.total_bytes = total_size;
+
+        let ranges = split_ranges(total_size, connections as u64);
+        log::info!(
+            "Downloading {} ({} bytes) over {} connections",
+            model_name,
+            total_size,
+            ranges.len()
+        );
+
+        // Every connection adds to shared counters, reported on a fixed cadence
+        let counters = RangeCounters::default();
+        let transfers = futures_util::future::try_join_all(
+            ranges
+                .into_iter()
+                .map(|range| self.download_range(client, url, model_name, part_path, range, &counters)),
+        );
+        tokio::pin!(transfers);
+        let max_retries = self.get_download_retries();
+        let mut ticker = tokio::time::interval(Duration::from_millis(500));
+        let (mut last_report_time, mut last_report_bytes) = (Instant::now(), 0u64);
+        let mut last_reported_retries = 0u32;
+        loop {
+            tokio::select! {
+                result = &mut transfers => {
+                    stats.downloaded_bytes = counters.downloaded.load(Ordering::Relaxed);
+                    stats.retries = counters.retries.load(Ordering::Relaxed);
+                    result?;
+                    return Ok(Some(total_size));
+                }
+                _ = ticker.tick() => {
+                    let bytes = counters.downloaded.load(Ordering::Relaxed);
+                    let elapsed = last_report_time.elapsed().as_secs_f64();
+                    let speed_mbps = if elapsed > 0.0 {
+                        (bytes - last_report_bytes) as f64 / (1024.0 * 1024.0) / elapsed
+                    } else {
+                        0.0
+                    };
+                    (last_report_time, last_report_bytes) = (Instant::now(), bytes);
+
+                    let mut progress = DownloadProgress::new(bytes, total_size, speed_mbps);
+                    // A connection started resuming since the last report
+                    let retries = counters.retries.load(Ordering::Relaxed);
+                    if retries > last_reported_retries {
+                        last_reported_retries = retries;
+                        let attempt = counters.highest_attempt.load(Ordering::Relaxed);
+                        progress = progress.with_retry_attempt(attempt, max_retries);
+                    }
+                    let percent = progress.percent;
+                    if let Some(ref callback) = progress_callback {
+                        callback(progress);
+                    }
+                    let mut models = self.available_models.write().await;
+                    if let Some(model) = models.get_mut(model_name) {
+                        model.status = ModelStatus::Downloading { progress: percent };
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fetch `range` of `url` into the same region of `part_path`, resuming from the
+    /// last byte written after a stall or dropped connection.
+    async fn download_range(
+        &self,
+        client: &reqwest::Client,
+        url: &str,
+        model_name: &str,
+        part_path: &Path,
+        range: std::ops::Range<u64>,
+        counters: &RangeCounters,
+    ) -> Result<()> {
+        use futures_util::StreamExt;
+
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .open(part_path)
+            .await
+            .map_err(|e| anyhow!("Failed to open file: {}", e))?;
+        let max_retries = self.get_download_retries();
+        let mut retry_attempt: u32 = 0;
+        let mut offset = range.start;
+
+        while offset < range.end {
+            let failure = match client
+                .get(url)
+                .header("Range", format!("bytes={}-{}", offset, range.end - 1))
+                .send()
+                .await
+            {
+                Ok(response) if response.status() == reqwest::StatusCode::PARTIAL_CONTENT => {
+                    file.seek(std::io::SeekFrom::Start(offset))
+                        .await
+                        .map_err(|e| anyhow!("Failed to seek in file: {}", e))?;
+                    let mut writer = BufWriter::with_capacity(1024 * 1024, &mut file);
+                    let mut stream = response.bytes_stream();
+                    let failure = loop {
+                        if self.cancel_download_flag.read().await.as_deref() == Some(model_name) {
+                            return Err(anyhow!("Download cancelled by user"));
+                        }
+                        match timeout(Duration::from_secs(30), stream.next()).await {
+                            Ok(None) => break None,
+                            Ok(Some(Ok(chunk))) => {
+                                // Never write past this connection's region
+                                let len = (chunk.len() as u64).min(range.end - offset);
+                                writer
+                                    .write_all(&chunk[..len as usize])
+                                    .await
+                                    .map_err(|e| anyhow!("Failed to write chunk: {}", e))?;
+                                offset += len;
+                                counters.downloaded.fetch_add(len, Ordering::Relaxed);
+                                if offset >= range.end {
+                                    break None;
+                                }
+                            }
+                            Ok(Some(Err(e))) => break Some(format!("Download error: {}", e)),
+                            Err(_) => break Some("Download timeout - no data for 30 seconds".to_string()),
+                        }
+                    };
+                    writer
+                        .flush()
+                        .await
+                        .map_err(|e| anyhow!("Failed to flush file: {}", e))?;
+                    failure
+                }
+                Ok(response) => Some(format!("Range request failed with status: {}", response.status())),
+                Err(e) => Some(format!("Range request failed: {}", e)),
+            };
+
+            let reason = match failure {
+                Some(reason) => reason,
+                None if offset >= range.end => break,
+                None => "Connection closed before the range was complete".to_string(),
+            };
+            if retry_attempt >= max_retries {
+                return Err(retries_exhausted(reason, retry_attempt).into());
+            }
+            retry_attempt += 1;
+            counters.retries.fetch_add(1, Ordering::Relaxed);
+            counters.highest_attempt.fetch_max(retry_attempt, Ordering::Relaxed);
+            let backoff = download_retry_backoff(retry_attempt);
+            log::warn!(
+                "{} for {} bytes {}-{}; resuming from byte {} in {:?} (attempt {}/{})",
+                reason, model_name, range.start, range.end - 1, offset, backoff, retry_attempt, max_retries
+            );
+            if !self.backoff_unless_cancelled(model_name, backoff).await {
+                return Err(anyhow!("Download cancelled by user"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Create (or truncate) the file a download streams into. On failure the
+    /// download is ended through [`Self::fail_download`].
+    async fn create_download_file(&self, model_name: &str, file_path: &Path) -> Result<fs::File> {
+        match fs::File::create(file_path).await {
+            Ok(file) => Ok(file),
+            Err(e) => Err(self.fail_download(model_name, anyhow!("Failed to create file: {}", e)).await),
+        }
+    }
+
+    /// Drop a failed download from `active_downloads` and put the error on the
+    /// model, so it doesn't stay stuck in `Downloading`. Returns `error`.
+    async fn fail_download(&self, model_name: &str, error: anyhow::Error) -> anyhow::Error {
+        self.active_downloads.write().await.remove(model_name);
+        if let Some(model) = self.available_models.write().await.get_mut(model_name) {
+            model.status = ModelStatus::Error(error.to_string());
+        }
+        error
+    }
+
+    /// Wait out a retry backoff, returning false as soon as the download of
+    /// `model_name` is cancelled instead of sleeping through the rest of it
+    async fn backoff_unless_cancelled(&self, model_name: &str, backoff: Duration) -> bool {
+        let deadline = Instant::now() + backoff;
+        loop {
+            if self.cancel_download_flag.read().await.as_deref() == Some(model_name) {
+                return false;
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return true;
+            }
+            tokio::time::sleep(remaining.min(Duration::from_millis(250))).await;
+        }
+    }
+
     /// Cancel an ongoing model download
     pub async fn cancel_download(&self, model_name: &str) -> Result<()> {
         log::info!("Cancelling download for Qwen ASR model: {}", model_name);
@@ -795,3 +3009,540 @@ impl QwenAsrEngine {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_cap_scales_with_audio_duration() {
+        let params = QwenAsrParams::default();
+        assert_eq!(params_for_audio(&params, SAMPLE_RATE / 2).max_new_tokens, 38);
+        assert_eq!(params_for_audio(&params, 10 * SAMPLE_RATE).max_new_tokens, 92);
+        // A 30 s window is still within the configured cap...
+        assert_eq!(params_for_audio(&params, 30 * SAMPLE_RATE).max_new_tokens, 212);
+        // ...and ten minutes of silence can't decode more than it
+        assert_eq!(
+            params_for_audio(&params, 600 * SAMPLE_RATE).max_new_tokens,
+            params.max_new_tokens
+        );
+
+        let uncapped = params.with_max_new_tokens(0);
+        assert_eq!(params_for_audio(&uncapped, 600 * SAMPLE_RATE).max_new_tokens, 0);
+    }
+
+    #[test]
+    fn decoder_prompt_tail_starts_on_a_word() {
+        assert_eq!(decoder_prompt_tail(" short text ", 200), "short text");
+        assert_eq!(
+            decoder_prompt_tail("we talked to the Acme roadmap team", 16),
+            "roadmap team"
+        );
+        assert_eq!(decoder_prompt_tail("今天我们讨论路线图", 4), "论路线图");
+    }
+
+    #[test]
+    fn next_decoder_prompt_skips_the_overlap_and_cjk_spaces() {
+        assert_eq!(next_decoder_prompt("", "hello there"), "hello there");
+        assert_eq!(
+            next_decoder_prompt("we reviewed the Acme roadmap", "the Acme roadmap for next year"),
+            "we reviewed the Acme roadmap for next year"
+        );
+        assert_eq!(next_decoder_prompt("今天我们讨论", "路线图"), "今天我们讨论路线图");
+    }
+
+    fn gguf_fixture(tensor_count: u64, metadata_kv_count: u64, len: usize) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(len);
+        bytes.extend_from_slice(&GGUF_MAGIC.to_le_bytes());
+        bytes.extend_from_slice(&3u32.to_le_bytes());
+        bytes.extend_from_slice(&tensor_count.to_le_bytes());
+        bytes.extend_from_slice(&metadata_kv_count.to_le_bytes());
+        bytes.resize(len, 0);
+        bytes
+    }
+
+    fn gguf_with_metadata(architecture: &str) -> Vec<u8> {
+        let string = |bytes: &mut Vec<u8>, s: &str| {
+            bytes.extend_from_slice(&(s.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(s.as_bytes());
+        };
+        let mut bytes = gguf_fixture(1, 3, GGUF_HEADER_LEN as usize);
+        // An array and a scalar ahead of the architecture must be skipped correctly
+        string(&mut bytes, "tokenizer.tokens");
+        bytes.extend_from_slice(&GGUF_TYPE_ARRAY.to_le_bytes());
+        bytes.extend_from_slice(&GGUF_TYPE_STRING.to_le_bytes());
+        bytes.extend_from_slice(&2u64.to_le_bytes());
+        string(&mut bytes, "hello");
+        string(&mut bytes, "world");
+        string(&mut bytes, "general.alignment");
+        bytes.extend_from_slice(&4u32.to_le_bytes());
+        bytes.extend_from_slice(&32u32.to_le_bytes());
+        string(&mut bytes, "general.architecture");
+        bytes.extend_from_slice(&GGUF_TYPE_STRING.to_le_bytes());
+        string(&mut bytes, architecture);
+        bytes.resize(4096, 0);
+        bytes
+    }
+
+    #[test]
+    fn model_compatibility_checks_gguf_architecture() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("model.gguf");
+
+        std::fs::write(&path, gguf_with_metadata("qwen3-asr")).unwrap();
+        assert_eq!(read_gguf_architecture(&path).unwrap().as_deref(), Some("qwen3-asr"));
+        assert!(check_model_compatibility(&path).is_ok());
+
+        std::fs::write(&path, gguf_with_metadata("whisper")).unwrap();
+        let err = check_model_compatibility(&path).unwrap_err().to_string();
+        assert!(err.contains("Incompatible model architecture 'whisper'"), "{err}");
+
+        // No architecture key at all
+        std::fs::write(&path, gguf_fixture(1, 0, 4096)).unwrap();
+        assert_eq!(read_gguf_architecture(&path).unwrap(), None);
+    }
+
+    #[test]
+    fn gguf_header_validation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("model.gguf");
+
+        std::fs::write(&path, gguf_fixture(20, 30, 4096)).unwrap();
+        let header = read_gguf_header(&path).unwrap();
+        assert_eq!(
+            header,
+            GgufHeader { version: 3, tensor_count: 20, metadata_kv_count: 30 }
+        );
+
+        // Announces more tensor infos than the file can hold
+        std::fs::write(&path, gguf_fixture(400, 30, 4096)).unwrap();
+        assert!(read_gguf_header(&path).unwrap_err().to_string().contains("truncated"));
+
+        // Cut off right after the magic
+        std::fs::write(&path, &gguf_fixture(20, 30, 4096)[..4]).unwrap();
+        assert!(read_gguf_header(&path).is_err());
+
+        std::fs::write(&path, gguf_fixture(u64::MAX, 30, 4096)).unwrap();
+        assert!(read_gguf_header(&path).unwrap_err().to_string().contains("tensor count"));
+    }
+
+    #[tokio::test]
+    async fn available_models_reads_cache_until_refreshed() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        let path = engine.models_dir.join(MODEL_CONFIGS[1].filename);
+        std::fs::write(&path, gguf_fixture(20, 30, 4096)).unwrap();
+        write_completion_marker(&path, CompletionMarker { size: 4096, verified: true });
+
+        assert_eq!(engine.available_model_names().await.unwrap(), vec![MODEL_CONFIGS[1].name]);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(engine.has_available_models().await.unwrap());
+        assert!(engine.refresh_available_models().await.unwrap().is_empty());
+        assert!(!engine.has_available_models().await.unwrap());
+    }
+
+    #[test]
+    fn language_codes_map_to_model_language_names() {
+        assert_eq!(qwen_language_name("en"), Some("English"));
+        assert_eq!(qwen_language_name("zh-CN"), Some("Chinese"));
+        assert_eq!(qwen_language_name("PT_br"), Some("Portuguese"));
+        assert_eq!(qwen_language_name("auto"), None);
+        assert_eq!(qwen_language_name("auto-translate"), None);
+        assert_eq!(qwen_language_name(""), None);
+    }
+
+    #[test]
+    fn transcript_cache_evicts_least_recently_used() {
+        let params = QwenAsrParams::default();
+        let key = |audio: &[f32]| transcript_cache_key(audio, "model", &params, None);
+        let (a, b, c) = (key(&[0.1, 0.2]), key(&[0.1, 0.3]), key(&[0.1, 0.2, 0.0]));
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, transcript_cache_key(&[0.1, 0.2], "other", &params, None));
+        assert_ne!(a, transcript_cache_key(&[0.1, 0.2], "model", &params.clone().with_temperature(0.5), None));
+        assert_ne!(a, transcript_cache_key(&[0.1, 0.2], "model", &params, Some("English")));
+
+        let transcript = |text: &str| QwenAsrTranscript {
+            text: text.to_string(),
+            avg_logprob: -0.1,
+            no_speech_prob: 0.0,
+            words: Vec::new(),
+        };
+        let mut cache = TranscriptCache::default();
+        cache.insert(a, transcript("a"), 2);
+        cache.insert(b, transcript("b"), 2);
+        assert_eq!(cache.get(a), Some(transcript("a")));
+        cache.insert(c, transcript("c"), 2);
+        assert_eq!(cache.get(b), None);
+        assert_eq!(cache.get(a), Some(transcript("a")));
+        assert_eq!(cache.get(c), Some(transcript("c")));
+    }
+
+    #[test]
+    fn download_retry_backoff_doubles_up_to_cap() {
+        assert_eq!(download_retry_backoff(1), Duration::from_secs(2));
+        assert_eq!(download_retry_backoff(2), Duration::from_secs(4));
+        assert_eq!(download_retry_backoff(3), Duration::from_secs(8));
+        assert_eq!(download_retry_backoff(10), Duration::from_secs(30));
+    }
+
+    #[tokio::test]
+    async fn download_history_keeps_newest_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DOWNLOAD_HISTORY_FILE);
+        let record = |offset: u64| DownloadRecord {
+            model_name: "qwen3-asr-0.6b-q8_0".to_string(),
+            timestamp: String::new(),
+            outcome: DownloadOutcome::Failed,
+            error: Some("Download timeout - no data for 30 seconds".to_string()),
+            resumed_from_bytes: 0,
+            downloaded_bytes: offset,
+            total_bytes: 1000,
+            retries: 3,
+            duration_secs: 1.0,
+            avg_speed_mbps: 0.0,
+        };
+
+        for offset in 0..(MAX_DOWNLOAD_HISTORY as u64 + 5) {
+            append_download_history(&path, record(offset)).await.unwrap();
+        }
+        let history = read_download_history(&path).await;
+        assert_eq!(history.len(), MAX_DOWNLOAD_HISTORY);
+        assert_eq!(history[0].downloaded_bytes, 5);
+        assert_eq!(history.last().unwrap().downloaded_bytes, MAX_DOWNLOAD_HISTORY as u64 + 4);
+
+        std::fs::write(&path, "not json").unwrap();
+        append_download_history(&path, record(7)).await.unwrap();
+        assert_eq!(read_download_history(&path).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn discover_lists_no_models_when_directory_is_gone() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        assert_eq!(engine.discover_models().await.unwrap().len(), MODEL_CONFIGS.len());
+
+        std::fs::remove_dir_all(&engine.models_dir).unwrap();
+        assert!(engine.discover_models().await.unwrap().is_empty());
+        assert!(engine.available_models.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn with_model_requires_a_loaded_model() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        let err = engine.with_model(|_| ()).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<QwenAsrEngineError>(),
+            Some(QwenAsrEngineError::ModelNotLoaded)
+        ));
+    }
+
+    #[tokio::test]
+    async fn discover_finalizes_complete_files_and_resumes_partial_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        let config = ModelConfig { size_mb: 1, ..MODEL_CONFIGS[0] };
+        let path = engine.models_dir.join(config.filename);
+
+        // Interrupted download: header is fine but the file is short
+        std::fs::write(&path, gguf_fixture(20, 30, 4096)).unwrap();
+        assert!(matches!(engine.finalize_model_file(&config, &path).await, (ModelStatus::Missing, false)));
+
+        // Full size without a marker: finalized as available but unverified
+        std::fs::write(&path, gguf_fixture(20, 30, 1024 * 1024)).unwrap();
+        assert!(matches!(engine.finalize_model_file(&config, &path).await, (ModelStatus::Available, false)));
+        assert_eq!(
+            read_completion_marker(&path),
+            Some(CompletionMarker { size: 1024 * 1024, verified: false })
+        );
+
+        // Verified by the downloader
+        write_completion_marker(&path, CompletionMarker { size: 1024 * 1024, verified: true });
+        assert!(matches!(engine.finalize_model_file(&config, &path).await, (ModelStatus::Available, true)));
+
+        // Truncated after completing
+        std::fs::write(&path, gguf_fixture(20, 30, 4096)).unwrap();
+        assert!(matches!(
+            engine.finalize_model_file(&config, &path).await,
+            (ModelStatus::Corrupted { .. }, false)
+        ));
+    }
+
+    #[test]
+    fn download_needs_free_space_for_the_model_and_margin() {
+        assert!(insufficient_space(100, 100).is_none());
+        let err = insufficient_space(3000 * 1024 * 1024, 1200 * 1024 * 1024 + 5).unwrap();
+        assert!(matches!(err, QwenAsrEngineError::DownloadFailed(_)));
+        assert_eq!(
+            err.to_string(),
+            "Download failed: Not enough disk space: 3000 MB required, 1200 MB available"
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        if let Ok(available) = available_space(dir.path()) {
+            assert!(insufficient_space(u64::MAX, available).is_some());
+        }
+        assert!(available_space(&dir.path().join("missing")).is_err());
+    }
+
+    #[tokio::test]
+    async fn config_is_validated_and_applied_to_decode_params() {
+        let config = QwenAsrConfig { use_gpu: true, gpu_device: 1, n_threads: 4, temperature: 0.3 };
+        assert!(config.validate(2).is_ok());
+        assert!(config.validate(1).is_err());
+        assert!(config.validate(0).is_ok());
+        assert!(QwenAsrConfig { use_gpu: false, ..config }.validate(1).is_ok());
+        assert!(QwenAsrConfig { n_threads: -1, ..config }.validate(2).is_err());
+        assert!(QwenAsrConfig { gpu_device: -1, ..config }.validate(2).is_err());
+        assert!(QwenAsrConfig { temperature: 3.0, ..config }.validate(2).is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        assert_eq!(engine.get_config().await, QwenAsrConfig::default());
+        let cpu = QwenAsrConfig { use_gpu: false, ..config };
+        engine.set_config(cpu).await.unwrap();
+        assert_eq!(engine.get_config().await, cpu);
+        let params = engine.get_decode_params().await;
+        assert_eq!((params.use_gpu, params.n_threads), (false, 4));
+    }
+
+    #[tokio::test]
+    async fn cancel_interrupts_a_retry_backoff() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        assert!(engine.backoff_unless_cancelled("model", Duration::from_millis(10)).await);
+
+        *engine.cancel_download_flag.write().await = Some("model".to_string());
+        let started = Instant::now();
+        assert!(!engine.backoff_unless_cancelled("model", Duration::from_secs(30)).await);
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(engine.backoff_unless_cancelled("other", Duration::from_millis(10)).await);
+
+        let err = retries_exhausted("Download error: reset".to_string(), 3);
+        assert_eq!(err.to_string(), "Download failed: Download error: reset (gave up after 3 retries)");
+    }
+
+    #[tokio::test]
+    async fn failing_to_create_the_download_file_ends_the_download() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        engine.discover_models().await.unwrap();
+        let name = MODEL_CONFIGS[0].name;
+        engine.active_downloads.write().await.insert(name.to_string());
+        if let Some(model) = engine.available_models.write().await.get_mut(name) {
+            model.status = ModelStatus::Downloading { progress: 40 };
+        }
+
+        let unwritable = dir.path().join("missing").join(MODEL_CONFIGS[0].filename);
+        assert!(engine.create_download_file(name, &unwritable).await.is_err());
+        assert!(!engine.active_downloads.read().await.contains(name));
+        let status = engine.available_models.read().await[name].status.clone();
+        assert!(matches!(status, ModelStatus::Error(ref e) if e.starts_with("Failed to create file")));
+    }
+
+    #[tokio::test]
+    async fn file_sha256_hashes_the_whole_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("model.gguf");
+        std::fs::write(&path, b"abc").unwrap();
+        assert_eq!(
+            file_sha256(&path).await.unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        // Larger than one read buffer
+        use sha2::Digest;
+        let data = vec![b'a'; 3 * 1024 * 1024 + 7];
+        std::fs::write(&path, &data).unwrap();
+        assert_eq!(file_sha256(&path).await.unwrap(), format!("{:x}", sha2::Sha256::digest(&data)));
+    }
+
+    #[tokio::test]
+    async fn verify_model_deletes_a_file_with_the_wrong_checksum() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        let config = MODEL_CONFIGS[0];
+        let sha256 = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        let path = engine.models_dir.join(config.filename);
+        engine.discover_models().await.unwrap();
+
+        std::fs::write(&path, b"abc").unwrap();
+        engine.verify_model_file(&config, sha256).await.unwrap();
+        assert_eq!(read_completion_marker(&path), Some(CompletionMarker { size: 3, verified: true }));
+
+        std::fs::write(&path, b"abd").unwrap();
+        let err = engine.verify_model_file(&config, sha256).await.unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"));
+        assert!(!path.exists());
+        assert!(read_completion_marker(&path).is_none());
+        assert!(matches!(
+            engine.available_models.read().await[config.name].status,
+            ModelStatus::Corrupted { file_size: 3, .. }
+        ));
+
+        // Nothing to compare against
+        assert!(engine.verify_model_file(&MODEL_CONFIGS[1], "").await.is_err());
+    }
+
+    #[test]
+    fn lfs_sha256_reads_the_linked_etag() {
+        let digest = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(lfs_sha256(&headers), None);
+        headers.insert("x-linked-etag", format!("\"{}\"", digest).parse().unwrap());
+        assert_eq!(lfs_sha256(&headers).as_deref(), Some(digest));
+        // Non-LFS files carry a git blob id, not a SHA-256
+        headers.insert("x-linked-etag", "\"9f1c2e\"".parse().unwrap());
+        assert_eq!(lfs_sha256(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn model_families_with_the_same_quantization_coexist() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        let (large, small) = (MODEL_CONFIGS[0], MODEL_CONFIGS[2]);
+        assert_eq!(large.quantization, small.quantization);
+        for config in [large, small] {
+            let path = engine.models_dir.join(config.filename);
+            std::fs::write(&path, gguf_fixture(20, 30, 4096)).unwrap();
+            write_completion_marker(&path, CompletionMarker { size: 4096, verified: true });
+        }
+
+        let available = engine.available_models().await.unwrap();
+        assert_eq!(available.len(), 2);
+        assert_eq!(preferred_model(&available).unwrap().name, large.name);
+        let paths: Vec<_> = available.iter().map(|model| model.path.clone()).collect();
+        assert_eq!(paths, vec![engine.models_dir.join(large.filename), engine.models_dir.join(small.filename)]);
+
+        // Deleting one family leaves the other in place
+        engine.delete_model(large.name).await.unwrap();
+        assert!(!paths[0].exists());
+        assert!(paths[1].exists());
+        let available = engine.refresh_available_models().await.unwrap();
+        assert_eq!(preferred_model(&available).unwrap().name, small.name);
+    }
+
+    #[tokio::test]
+    async fn import_local_model_adds_an_available_model() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        let source = dir.path().join("downloads");
+        std::fs::create_dir_all(&source).unwrap();
+        let src = source.join("asr.gguf");
+        std::fs::write(&src, gguf_with_metadata("qwen3-asr")).unwrap();
+
+        let model = engine.import_local_model(&src, "My Qwen f16").await.unwrap();
+        assert_eq!(model.name, "My-Qwen-f16");
+        assert_eq!(model.quantization, QuantizationType::F16);
+        assert!(matches!(model.status, ModelStatus::Available));
+        assert!(model.path.starts_with(&engine.models_dir));
+        assert!(src.exists());
+
+        // Still listed after rescanning the models directory
+        let names = engine.refresh_available_models().await.unwrap();
+        assert_eq!(names.iter().map(|m| m.name.as_str()).collect::<Vec<_>>(), vec!["My-Qwen-f16"]);
+
+        let err = engine.import_local_model(&src, "My-Qwen-f16").await.unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        let err = engine.import_local_model(&src, MODEL_CONFIGS[0].name).await.unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        assert!(engine.import_local_model(&src, "../escape").await.is_err());
+
+        std::fs::write(&src, b"not a model").unwrap();
+        let err = engine.import_local_model(&src, "garbage").await.unwrap_err();
+        assert!(err.to_string().contains("is not a valid GGUF model"));
+        std::fs::write(&src, gguf_with_metadata("whisper")).unwrap();
+        let err = engine.import_local_model(&src, "whisper").await.unwrap_err();
+        assert!(err.to_string().contains("Incompatible model architecture"));
+        assert!(!engine.models_dir.join(IMPORTED_MODELS_DIR).join("whisper.gguf").exists());
+    }
+
+    #[test]
+    fn parallel_download_ranges_cover_the_file_once() {
+        for (total, connections) in [(10, 3), (9, 3), (2, 8), (1, 1), (3_000_000_001, 8)] {
+            let ranges = split_ranges(total, connections);
+            assert!(ranges.len() as u64 <= connections);
+            assert_eq!(ranges.first().unwrap().start, 0);
+            assert_eq!(ranges.last().unwrap().end, total);
+            assert!(ranges.windows(2).all(|pair| pair[0].end == pair[1].start));
+        }
+        assert_eq!(split_ranges(10, 3), vec![0..4, 4..8, 8..10]);
+
+        assert_eq!(content_range_total("bytes 0-0/1887436800"), Some(1887436800));
+        assert_eq!(content_range_total("bytes 0-0/*"), None);
+        assert_eq!(parallel_part_path(Path::new("/m/a.gguf")), PathBuf::from("/m/a.gguf.parts"));
+    }
+
+    #[test]
+    fn idle_unload_respects_timeout_and_pinning() {
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+        assert!(!idle_unload_due(0, false, minutes(120)));
+        assert!(!idle_unload_due(300, false, minutes(2)));
+        assert!(!idle_unload_due(300, true, minutes(10)));
+        assert!(idle_unload_due(300, false, minutes(5)));
+        assert!(idle_unload_due(45, false, Duration::from_secs(45)));
+    }
+
+    #[tokio::test]
+    async fn idle_unload_remembers_the_model_until_the_next_load() {
+        // Loads through the stub decoder, which accepts any GGUF header
+        if crate::qwen_asr_engine::model::BuildInfo::current().has_vendor {
+            return;
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        let name = MODEL_CONFIGS[2].name;
+        let path = engine.models_dir.join(MODEL_CONFIGS[2].filename);
+        std::fs::write(&path, gguf_with_metadata("qwen3-asr")).unwrap();
+        write_completion_marker(&path, CompletionMarker { size: std::fs::metadata(&path).unwrap().len(), verified: true });
+        engine.discover_models().await.unwrap();
+
+        engine.set_idle_unload_secs(None);
+        assert_eq!(engine.get_idle_unload_secs(), None);
+        engine.load_model(name).await.unwrap();
+        *engine.last_activity.lock().unwrap() = Instant::now() - Duration::from_secs(120);
+        assert!(engine.unload_if_idle().await.is_none());
+
+        engine.set_idle_unload_secs(Some(60));
+        assert_eq!(engine.get_idle_unload_minutes(), 1);
+        assert_eq!(engine.unload_if_idle().await.as_deref(), Some(name));
+        assert!(!engine.is_model_loaded().await);
+        assert_eq!(engine.idle_unloaded_model().as_deref(), Some(name));
+
+        // Loading resets both the idle clock and the remembered model
+        engine.load_model(name).await.unwrap();
+        assert!(engine.unload_if_idle().await.is_none());
+        assert_eq!(engine.idle_unloaded_model(), None);
+    }
+
+    #[tokio::test]
+    async fn windowed_transcription_reports_every_window() {
+        static CANCELLED: AtomicBool = AtomicBool::new(false);
+        if crate::qwen_asr_engine::model::BuildInfo::current().has_vendor {
+            return;
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let engine = QwenAsrEngine::new_with_models_dir(Some(dir.path().to_path_buf())).unwrap();
+        assert!(engine.transcribe_audio_with_progress(vec![0.0; 16], &CANCELLED, |_| {}).await.is_err());
+
+        let config = &MODEL_CONFIGS[2];
+        let path = engine.models_dir.join(config.filename);
+        std::fs::write(&path, gguf_with_metadata("qwen3-asr")).unwrap();
+        write_completion_marker(&path, CompletionMarker { size: std::fs::metadata(&path).unwrap().len(), verified: true });
+        engine.discover_models().await.unwrap();
+        engine.load_model(config.name).await.unwrap();
+
+        let window_samples = config.context_seconds * SAMPLE_RATE;
+        let audio = vec![0.0; window_samples * 2 + SAMPLE_RATE];
+        let expected = window_ranges(audio.len(), window_samples, WINDOW_OVERLAP_SECONDS * SAMPLE_RATE).len();
+        let mut reported = Vec::new();
+        let text = engine
+            .transcribe_audio_with_progress(audio, &CANCELLED, |progress| reported.push(progress.window))
+            .await
+            .unwrap();
+        assert_eq!(reported, (1..=expected).collect::<Vec<_>>());
+        assert!(!text.is_empty());
+    }
+}
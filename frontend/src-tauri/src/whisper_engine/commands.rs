@@ -386,14 +386,23 @@ pub async fn whisper_validate_model_ready_with_config<R: tauri::Runtime>(
 
 #[command]
 pub async fn whisper_transcribe_audio(audio_data: Vec<f32>) -> Result<String, String> {
+    // Get language preference
+    let language = crate::get_language_preference_internal();
+    whisper_transcribe_audio_with_language(audio_data, language).await
+}
+
+/// Same as `whisper_transcribe_audio`, but with an explicit language instead of the
+/// global preference (used by dictation's own language setting)
+pub async fn whisper_transcribe_audio_with_language(
+    audio_data: Vec<f32>,
+    language: Option<String>,
+) -> Result<String, String> {
     let engine = {
         let guard = WHISPER_ENGINE.lock().unwrap();
         guard.as_ref().cloned()
     };
 
     if let Some(engine) = engine {
-        // Get language preference
-        let language = crate::get_language_preference_internal();
         engine
             .transcribe_audio(audio_data, language)
             .await
@@ -3,29 +3,49 @@
 import { useEffect, useState, type CSSProperties } from 'react';
 import { listen } from '@tauri-apps/api/event';
 import { invoke } from '@tauri-apps/api/core';
-import { Mic, Loader2, CheckCircle2, AlertCircle } from 'lucide-react';
+import { Mic, Loader2, CheckCircle2, AlertCircle, XCircle } from 'lucide-react';
 
-type WidgetState = 'idle' | 'recording' | 'processing' | 'success' | 'error';
+type WidgetState = 'idle' | 'preparing' | 'recording' | 'processing' | 'success' | 'error' | 'cancelled';
+type DictationMode = 'pushToTalk' | 'toggle';
 
 interface WidgetPayload {
   state: WidgetState;
   message: string;
   transcript?: string;
   hotkey: string;
+  mode: DictationMode;
+  language?: string;
+}
+
+interface LevelPayload {
+  level: number;
+  peak: number;
 }
 
 const DEFAULT_PAYLOAD: WidgetPayload = {
   state: 'idle',
   message: 'Press hotkey to start dictation',
-  hotkey: 'fn+space',
+  hotkey: '',
+  mode: 'pushToTalk',
 };
 
+// Auto modes ("auto", "auto-translate") have no fixed language to show
+function languageLabel(language?: string): string | null {
+  if (!language || language.startsWith('auto')) {
+    return null;
+  }
+  return language;
+}
+
 const DRAG_REGION_STYLE = { WebkitAppRegion: 'drag' } as CSSProperties;
 
 function StateIcon({ state }: { state: WidgetState }) {
   if (state === 'recording') {
     return <Mic className="w-4 h-4 text-rose-500" />;
   }
+  if (state === 'preparing') {
+    return <Loader2 className="w-4 h-4 text-rose-400 animate-spin" />;
+  }
   if (state === 'processing') {
     return <Loader2 className="w-4 h-4 text-blue-500 animate-spin" />;
   }
@@ -35,11 +55,16 @@ function StateIcon({ state }: { state: WidgetState }) {
   if (state === 'error') {
     return <AlertCircle className="w-4 h-4 text-amber-500" />;
   }
+  if (state === 'cancelled') {
+    return <XCircle className="w-4 h-4 text-slate-400" />;
+  }
   return <Mic className="w-4 h-4 text-slate-400" />;
 }
 
 export default function DictationWidgetPage() {
   const [payload, setPayload] = useState<WidgetPayload>(DEFAULT_PAYLOAD);
+  const [level, setLevel] = useState(0);
+  const language = languageLabel(payload.language);
 
   useEffect(() => {
     let mounted = true;
@@ -53,6 +78,15 @@ export default function DictationWidgetPage() {
         // Ignore bootstrap read failures.
       });
 
+    invoke<DictationMode>('dictation_get_mode')
+      .then((mode) => {
+        if (!mounted) return;
+        setPayload((prev) => ({ ...prev, mode }));
+      })
+      .catch(() => {
+        // Ignore bootstrap read failures.
+      });
+
     const unlistenPromise = listen<WidgetPayload>('dictation-widget-update', (event) => {
       if (!mounted) return;
       setPayload((prev) => ({
@@ -61,9 +95,15 @@ export default function DictationWidgetPage() {
       }));
     });
 
+    const unlistenLevelPromise = listen<LevelPayload>('dictation-level', (event) => {
+      if (!mounted) return;
+      setLevel(event.payload.level);
+    });
+
     return () => {
       mounted = false;
       unlistenPromise.then((unlisten) => unlisten());
+      unlistenLevelPromise.then((unlisten) => unlisten());
     };
   }, []);
 
@@ -83,16 +123,32 @@ export default function DictationWidgetPage() {
             <div className="min-w-0">
               <p className="text-[13px] font-semibold text-slate-900 truncate">{payload.message}</p>
               <p className="text-[11px] text-slate-500 mt-0.5">
-                Hold <span className="font-medium text-slate-700">{payload.hotkey}</span>
+                {payload.mode === 'toggle' ? 'Press' : 'Hold'}{' '}
+                <span className="font-medium text-slate-700">{payload.hotkey}</span>
+                {language && (
+                  <>
+                    {' · '}
+                    <span className="uppercase">{language}</span>
+                  </>
+                )}
               </p>
             </div>
           </div>
 
           <span className="text-[10px] px-2 py-1 rounded-full bg-slate-100 text-slate-600 uppercase tracking-wide">
-            Dictation
+            {payload.mode === 'toggle' ? 'Toggle' : 'Push to talk'}
           </span>
         </div>
 
+        {payload.state === 'recording' && (
+          <div className="mt-2 h-1.5 rounded-full bg-slate-100 overflow-hidden">
+            <div
+              className="h-full rounded-full bg-rose-400 transition-[width] duration-100 ease-out"
+              style={{ width: `${Math.round(level * 100)}%` }}
+            />
+          </div>
+        )}
+
         {payload.transcript && (
           <p className="mt-2 text-[12px] leading-5 text-slate-700 bg-slate-50 rounded-lg px-2.5 py-2 min-h-0 line-clamp-2 break-words">
             {payload.transcript}
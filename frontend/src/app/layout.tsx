@@ -188,6 +188,43 @@ export default function RootLayout({
     };
   }, [showOnboarding]);
 
+  useEffect(() => {
+    if (isOverlayWindow) return;
+
+    // Meeting detected while alerts go to system notifications: offer the actions here,
+    // since desktop notifications can't carry buttons
+    const unlisten = listen<{ app_name: string; countdown_secs?: number }>('meeting-detected-notification', (event) => {
+      const { app_name, countdown_secs } = event.payload;
+      const startNow = () => {
+        invoke('accept_meeting_banner').catch((e) => console.error('Failed to start recording:', e));
+      };
+
+      if (countdown_secs) {
+        toast(`Meeting detected in ${app_name}`, {
+          description: `Recording starts in ${countdown_secs}s`,
+          duration: countdown_secs * 1000,
+          action: { label: 'Start now', onClick: startNow },
+          cancel: {
+            label: 'Cancel',
+            onClick: () => {
+              invoke('dismiss_meeting_banner').catch((e) => console.error('Failed to cancel auto-start:', e));
+            },
+          },
+        });
+      } else {
+        toast(`Meeting detected in ${app_name}`, {
+          description: 'Start recording?',
+          duration: 15000,
+          action: { label: 'Start recording', onClick: startNow },
+        });
+      }
+    });
+
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [isOverlayWindow]);
+
   // Handle file drop for audio import
   const handleFileDrop = useCallback((paths: string[]) => {
     // Check if beta features are enabled (read from localStorage directly since we're outside ConfigProvider)
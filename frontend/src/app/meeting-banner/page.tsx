@@ -1,13 +1,44 @@
 'use client';
 
-import { Suspense } from 'react';
+import { Suspense, useEffect, useState } from 'react';
 import { useSearchParams } from 'next/navigation';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { Mic, X } from 'lucide-react';
 
+interface MeetingAppDetected {
+  app_name: string;
+  countdown_secs?: number;
+}
+
+function parseCountdown(value: string | null): number | null {
+  const secs = value ? parseInt(value, 10) : NaN;
+  return Number.isFinite(secs) && secs > 0 ? secs : null;
+}
+
 function BannerContent() {
   const searchParams = useSearchParams();
-  const appName = searchParams.get('app') || 'Meeting';
+  const [appName, setAppName] = useState(searchParams.get('app') || 'Meeting');
+  // Seconds left before recording starts on its own; null for a plain banner
+  const [countdown, setCountdown] = useState<number | null>(parseCountdown(searchParams.get('countdown')));
+
+  useEffect(() => {
+    // The window is reused when another meeting is detected while it's open
+    const unlistenPromise = listen<MeetingAppDetected>('meeting-app-detected', (event) => {
+      setAppName(event.payload.app_name);
+      setCountdown(event.payload.countdown_secs ?? null);
+    });
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten());
+    };
+  }, []);
+
+  useEffect(() => {
+    // Display only; the backend starts the recording when its own timer runs out
+    if (countdown === null || countdown <= 0) return;
+    const timer = setTimeout(() => setCountdown((secs) => (secs === null ? null : secs - 1)), 1000);
+    return () => clearTimeout(timer);
+  }, [countdown]);
 
   const handleStart = async () => {
     try {
@@ -48,7 +79,7 @@ function BannerContent() {
         {/* Text */}
         <div className="flex flex-col leading-tight mr-1">
           <span className="text-[13px] font-semibold whitespace-nowrap">
-            Start AI Meeting Note
+            {countdown === null ? 'Start AI Meeting Note' : `Recording in ${countdown}s — cancel?`}
           </span>
           <span className="text-[11px] text-gray-400 whitespace-nowrap">
             {appName} meeting detected
@@ -62,12 +93,13 @@ function BannerContent() {
           style={{ WebkitAppRegion: 'no-drag' } as React.CSSProperties}
         >
           <Mic className="w-3.5 h-3.5" />
-          Start transcribing
+          {countdown === null ? 'Start transcribing' : 'Start now'}
         </button>
 
-        {/* Dismiss */}
+        {/* Dismiss (also cancels an auto-start countdown) */}
         <button
           onClick={handleDismiss}
+          title={countdown === null ? 'Dismiss' : 'Cancel auto-start'}
           className="flex-shrink-0 p-1.5 rounded-full hover:bg-white/10 transition-colors cursor-pointer"
           style={{ WebkitAppRegion: 'no-drag' } as React.CSSProperties}
         >
@@ -2,12 +2,15 @@
 
 import { useEffect, useState, useRef } from "react"
 import { Switch } from "./ui/switch"
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "./ui/select"
 import { FolderOpen } from "lucide-react"
 import { invoke } from "@tauri-apps/api/core"
 import Analytics from "@/lib/analytics"
 import AnalyticsConsentSwitch from "./AnalyticsConsentSwitch"
 import { useConfig, NotificationSettings } from "@/contexts/ConfigContext"
 
+type MeetingAlertDelivery = 'banner' | 'notification' | 'both'
+
 export function PreferenceSettings() {
   const {
     notificationSettings,
@@ -19,6 +22,8 @@ export function PreferenceSettings() {
 
   const [notificationsEnabled, setNotificationsEnabled] = useState<boolean | null>(null);
   const [meetingDetectionEnabled, setMeetingDetectionEnabled] = useState<boolean>(true);
+  const [meetingAutoStartEnabled, setMeetingAutoStartEnabled] = useState<boolean>(false);
+  const [meetingAlertDelivery, setMeetingAlertDelivery] = useState<MeetingAlertDelivery>('banner');
   const [isInitialLoad, setIsInitialLoad] = useState(true);
   const [previousNotificationsEnabled, setPreviousNotificationsEnabled] = useState<boolean | null>(null);
   const hasTrackedViewRef = useRef(false);
@@ -33,6 +38,12 @@ export function PreferenceSettings() {
     invoke<boolean>('get_meeting_detection_enabled')
       .then(setMeetingDetectionEnabled)
       .catch(() => setMeetingDetectionEnabled(true));
+    invoke<MeetingAlertDelivery>('get_meeting_alert_delivery')
+      .then(setMeetingAlertDelivery)
+      .catch(() => setMeetingAlertDelivery('banner'));
+    invoke<boolean>('get_meeting_auto_start_enabled')
+      .then(setMeetingAutoStartEnabled)
+      .catch(() => setMeetingAutoStartEnabled(false));
   }, [loadPreferences]);
 
   // Track preferences viewed analytics on every tab visit (once per mount)
@@ -125,6 +136,24 @@ export function PreferenceSettings() {
     }
   };
 
+  const handleMeetingAlertDeliveryChange = async (delivery: MeetingAlertDelivery) => {
+    setMeetingAlertDelivery(delivery);
+    try {
+      await invoke('set_meeting_alert_delivery', { delivery });
+    } catch (error) {
+      console.error('Failed to update meeting alert delivery:', error);
+    }
+  };
+
+  const handleMeetingAutoStartChange = async (enabled: boolean) => {
+    setMeetingAutoStartEnabled(enabled);
+    try {
+      await invoke('set_meeting_auto_start_enabled', { enabled });
+    } catch (error) {
+      console.error('Failed to update meeting auto-start setting:', error);
+    }
+  };
+
   const handleOpenFolder = async (folderType: 'database' | 'models' | 'recordings') => {
     try {
       switch (folderType) {
@@ -183,6 +212,37 @@ export function PreferenceSettings() {
           </div>
           <Switch checked={meetingDetectionEnabled} onCheckedChange={handleMeetingDetectionChange} />
         </div>
+        <div className="flex items-center justify-between mt-4 pt-4 border-t border-gray-100">
+          <div>
+            <h4 className="text-sm font-medium text-gray-900 mb-1">Alert Style</h4>
+            <p className="text-sm text-gray-600">Floating banner, system notification, or both</p>
+          </div>
+          <Select
+            value={meetingAlertDelivery}
+            onValueChange={(value) => handleMeetingAlertDeliveryChange(value as MeetingAlertDelivery)}
+            disabled={!meetingDetectionEnabled}
+          >
+            <SelectTrigger className="w-40 h-8 text-sm">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="banner">Banner</SelectItem>
+              <SelectItem value="notification">Notification</SelectItem>
+              <SelectItem value="both">Both</SelectItem>
+            </SelectContent>
+          </Select>
+        </div>
+        <div className="flex items-center justify-between mt-4 pt-4 border-t border-gray-100">
+          <div>
+            <h4 className="text-sm font-medium text-gray-900 mb-1">Start Recording Automatically</h4>
+            <p className="text-sm text-gray-600">Begin recording after a short countdown you can cancel from the banner</p>
+          </div>
+          <Switch
+            checked={meetingAutoStartEnabled}
+            onCheckedChange={handleMeetingAutoStartChange}
+            disabled={!meetingDetectionEnabled}
+          />
+        </div>
       </div>
 
       {/* Data Storage Locations Section */}
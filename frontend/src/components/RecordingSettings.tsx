@@ -24,7 +24,6 @@ interface DictationPermissionState {
   input_monitoring_granted: boolean;
 }
 
-const DEFAULT_DICTATION_HOTKEY = 'fn+space';
 const DICTATION_HOTKEY_STORE_KEY = 'dictation_hotkey';
 const MODIFIER_KEYS = new Set([
   'Shift',
@@ -111,7 +110,9 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
   const [loading, setLoading] = useState(true);
   const [saving, setSaving] = useState(false);
   const [showRecordingNotification, setShowRecordingNotification] = useState(true);
-  const [dictationHotkey, setDictationHotkey] = useState(DEFAULT_DICTATION_HOTKEY);
+  const [dictationHotkey, setDictationHotkey] = useState('');
+  // Platform default from the backend: fn only reaches apps on macOS
+  const [defaultDictationHotkey, setDefaultDictationHotkey] = useState('');
   const [isSavingDictationHotkey, setIsSavingDictationHotkey] = useState(false);
   const [isCapturingDictationHotkey, setIsCapturingDictationHotkey] = useState(false);
   const [captureHint, setCaptureHint] = useState<string | null>(null);
@@ -162,8 +163,12 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
   useEffect(() => {
     const loadDictationHotkey = async () => {
       try {
-        const backendHotkey = await invoke<string>('dictation_get_hotkey');
-        let resolvedHotkey = backendHotkey || DEFAULT_DICTATION_HOTKEY;
+        const [backendHotkey, defaultHotkey] = await Promise.all([
+          invoke<string>('dictation_get_hotkey'),
+          invoke<string>('dictation_get_default_hotkey'),
+        ]);
+        setDefaultDictationHotkey(defaultHotkey);
+        let resolvedHotkey = backendHotkey || defaultHotkey;
 
         const { Store } = await import('@tauri-apps/plugin-store');
         const store = await Store.load('preferences.json');
@@ -384,8 +389,9 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
   };
 
   const handleResetDictationHotkey = async () => {
-    setDictationHotkey(DEFAULT_DICTATION_HOTKEY);
-    await handleSaveDictationHotkey(DEFAULT_DICTATION_HOTKEY);
+    if (!defaultDictationHotkey) return;
+    setDictationHotkey(defaultDictationHotkey);
+    await handleSaveDictationHotkey(defaultDictationHotkey);
   };
 
   useEffect(() => {
@@ -439,14 +445,14 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
         return;
       }
 
-      setCaptureHint('请按下至少一个修饰键 + 一个主键，例如 fn+space / cmd+shift+space');
+      setCaptureHint(`请按下至少一个修饰键 + 一个主键，例如 ${defaultDictationHotkey || 'ctrl+shift+space'}`);
     };
 
     window.addEventListener('keydown', onCaptureKeyDown, true);
     return () => {
       window.removeEventListener('keydown', onCaptureKeyDown, true);
     };
-  }, [isCapturingDictationHotkey, handleSaveDictationHotkey]);
+  }, [isCapturingDictationHotkey, handleSaveDictationHotkey, defaultDictationHotkey]);
 
   const savePreferences = async (prefs: RecordingPreferences) => {
     setSaving(true);
@@ -569,9 +575,9 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
             <div className="font-medium">Push-to-talk Dictation Hotkey</div>
             <div className="text-sm text-gray-600">
               Hold this hotkey to dictate into WeChat/Slack/chat inputs. Example formats:
-              <span className="font-medium"> fn+space</span>,
+              <span className="font-medium"> {defaultDictationHotkey || 'ctrl+shift+space'}</span>,
               <span className="font-medium"> ctrl+space</span>,
-              <span className="font-medium"> cmd+shift+space</span>.
+              <span className="font-medium"> {isMacOS ? 'cmd+shift+space' : 'ctrl+alt+space'}</span>.
             </div>
           </div>
         </div>
@@ -14,6 +14,9 @@ interface DownloadProgress {
   speedMbps: number;
   status: 'downloading' | 'completed' | 'error' | 'cancelled';
   error?: string;
+  // Set while a dropped connection is being resumed
+  retryAttempt?: number;
+  maxRetries?: number;
 }
 
 // Categorize error messages for better user experience
@@ -104,7 +107,11 @@ function DownloadToastContent({
                 {download.downloadedMb.toFixed(1)} / {download.totalMb.toFixed(1)} MB
               </span>
               <span className="flex items-center gap-1">
-                {download.speedMbps > 0 && (
+                {download.retryAttempt ? (
+                  <span>
+                    Retrying (attempt {download.retryAttempt}/{download.maxRetries})
+                  </span>
+                ) : download.speedMbps > 0 && (
                   <span>{download.speedMbps.toFixed(1)} MB/s</span>
                 )}
                 <span className="text-gray-900 font-medium">
@@ -313,9 +320,13 @@ export function useDownloadProgressToast() {
       downloaded_mb?: number;
       total_mb?: number;
       speed_mbps?: number;
+      retry_attempt?: number;
+      max_retries?: number;
       status?: string;
     }>('qwen-asr-model-download-progress', (event) => {
-      const { modelName, progress, downloaded_mb, total_mb, speed_mbps, status } = event.payload;
+      const {
+        modelName, progress, downloaded_mb, total_mb, speed_mbps, retry_attempt, max_retries, status,
+      } = event.payload;
       const fallbackSizeMb = getQwenFallbackSizeMb(modelName);
 
       const downloadData: DownloadProgress = {
@@ -330,6 +341,8 @@ export function useDownloadProgressToast() {
           : status === 'completed' || progress >= 100
             ? 'completed'
             : 'downloading',
+        retryAttempt: status === 'retrying' ? retry_attempt : undefined,
+        maxRetries: max_retries,
       };
 
       updateDownload(modelName, downloadData);
@@ -355,7 +355,22 @@ export function TranscriptProvider({ children }: { children: ReactNode }) {
               console.log(`🔍 Refinement segment seq=${incoming.sequence_id}: audio=[${incoming.audio_start_time?.toFixed(1)}, ${incoming.audio_end_time?.toFixed(1)}], covered=${covered.length} segments, text="${incoming.text.substring(0, 50)}..."`);
             }
 
-            if (shouldReplaceWithConsolidated(incoming, covered)) {
+            if (incoming.replaces_range) {
+              // Backend told us exactly which audio range this refinement supersedes
+              const [rangeStart, rangeEnd] = incoming.replaces_range;
+              const superseded = new Set(
+                combined
+                  .filter(existing =>
+                    existing.id !== incoming.id &&
+                    hasAudioRange(existing) &&
+                    (existing.audio_start_time as number) >= rangeStart - RANGE_EPSILON_SEC &&
+                    (existing.audio_end_time as number) <= rangeEnd + RANGE_EPSILON_SEC
+                  )
+                  .map(t => t.id)
+              );
+              combined = combined.filter(t => !superseded.has(t.id));
+              console.log(`🔁 Refinement seq=${incoming.sequence_id} replaced ${superseded.size} segments in [${rangeStart.toFixed(1)}, ${rangeEnd.toFixed(1)}]`);
+            } else if (shouldReplaceWithConsolidated(incoming, covered)) {
               const coveredIds = new Set(covered.map(t => t.id));
               combined = combined.filter(t => !coveredIds.has(t.id));
               console.log(`🔁 Consolidated transcript seq=${incoming.sequence_id} (refinement=${incoming.is_refinement}) replaced ${covered.length} chunk segments`);
@@ -422,6 +437,7 @@ export function TranscriptProvider({ children }: { children: ReactNode }) {
             audio_end_time: update.audio_end_time,
             duration: update.duration,
             is_refinement: update.is_refinement,
+            replaces_range: update.replaces_range,
           };
 
           // Add to buffer
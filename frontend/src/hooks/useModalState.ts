@@ -40,7 +40,7 @@ interface UseModalStateReturn {
  *
  * Features:
  * - Unified modal state management
- * - Event listeners for chunk drops, transcription errors, model downloads
+ * - Event listeners for chunk drops, transcription errors, provider fallbacks, model downloads
  * - Auto-close on model download completion
  */
 export function useModalState(transcriptModelConfig?: TranscriptModelProps): UseModalStateReturn {
@@ -163,6 +163,33 @@ export function useModalState(transcriptModelConfig?: TranscriptModelProps): Use
     };
   }, [showModal]);
 
+  // Warn when transcription silently switched to a fallback provider
+  useEffect(() => {
+    let unlistenFn: (() => void) | undefined;
+
+    const setupProviderFallbackListener = async () => {
+      try {
+        unlistenFn = await listen<{ provider: string, fallbackProvider: string, error: string, userMessage: string }>('transcription-provider-fallback', (event) => {
+          console.warn('Transcription provider fallback:', event.payload);
+          toast.warning('', {
+            description: event.payload.userMessage,
+            duration: 5000,
+          });
+        });
+      } catch (error) {
+        console.error('Failed to setup provider fallback listener:', error);
+      }
+    };
+
+    setupProviderFallbackListener();
+
+    return () => {
+      if (unlistenFn) {
+        unlistenFn();
+      }
+    };
+  }, []);
+
   // Listen for model download completion to auto-close modal
   useEffect(() => {
     const setupDownloadListeners = async () => {
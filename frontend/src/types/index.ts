@@ -17,6 +17,7 @@ export interface Transcript {
   audio_end_time?: number;   // Seconds from recording start (e.g., 128.6)
   duration?: number;          // Segment duration in seconds (e.g., 3.3)
   is_refinement?: boolean;   // True for full-run refinement segments that should replace chunks
+  replaces_range?: [number, number] | null; // Audio range superseded by this refinement
 }
 
 export interface TranscriptUpdate {
@@ -32,6 +33,7 @@ export interface TranscriptUpdate {
   audio_end_time: number;   // Seconds from recording start
   duration: number;          // Segment duration in seconds
   is_refinement?: boolean;  // True for full-run refinement segments that should replace chunks
+  replaces_range?: [number, number] | null; // Audio range [start, end] this refinement supersedes
 }
 
 export interface Block {